@@ -114,6 +114,7 @@ fn main() -> Result<()> {
                     },
                 },
                 close_proposal_on_execution_failure: false,
+                review_period: None,
                 veto: None,
             })?,
             admin: Some(Admin::CoreModule {}),
@@ -73,6 +73,7 @@ pub fn create_dao(
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                review_period: None,
                 pre_propose_info: PreProposeInfo::ModuleMayPropose {
                     info: ModuleInstantiateInfo {
                         code_id: chain.orc.contract_map.code_id("dao_pre_propose_single")?,
@@ -252,6 +253,7 @@ pub fn create_proposal(
                     description: "desc".to_string(),
                     msgs,
                     vote: None,
+                    schedule: None,
                 },
             },
             key,
@@ -1,10 +1,13 @@
+use cosmwasm_schema::cw_serde;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo,
-    Order, Reply, Response, StdError, StdResult, SubMsg, WasmMsg,
+    from_json, to_json_binary, Addr, BankMsg, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, IbcMsg,
+    MessageInfo, Order, Reply, Response, StdError, StdResult, SubMsg, Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use cw20::Cw20ReceiveMsg;
+use cw721::Cw721ReceiveMsg;
 use cw_paginate_storage::{paginate_map, paginate_map_keys, paginate_map_values};
 use cw_storage_plus::Map;
 use cw_utils::{parse_reply_instantiate_data, Duration};
@@ -12,19 +15,22 @@ use dao_interface::{
     msg::{ExecuteMsg, InitialItem, InstantiateMsg, MigrateMsg, QueryMsg},
     query::{
         AdminNominationResponse, Cw20BalanceResponse, DaoURIResponse, DumpStateResponse,
-        GetItemResponse, PauseInfoResponse, ProposalModuleCountResponse, SubDao,
+        GetItemResponse, LedgerAsset, LedgerDirection, ModulePolicyResponse, ModuleSpending,
+        PauseInfoResponse, ProposalModuleCountResponse, SubDao,
     },
     state::{
-        Admin, Config, ModuleInstantiateCallback, ModuleInstantiateInfo, ProposalModule,
-        ProposalModuleStatus,
+        Admin, Config, ModuleInstantiateCallback, ModuleInstantiateInfo, ModulePolicy, MsgKind,
+        ProposalModule, ProposalModuleStatus,
     },
     voting,
 };
 
 use crate::error::ContractError;
+use crate::ledger;
 use crate::state::{
-    ACTIVE_PROPOSAL_MODULE_COUNT, ADMIN, CONFIG, CW20_LIST, CW721_LIST, ITEMS, NOMINATED_ADMIN,
-    PAUSED, PROPOSAL_MODULES, SUBDAO_LIST, TOTAL_PROPOSAL_MODULE_COUNT, VOTING_MODULE,
+    ACTIVE_PROPOSAL_MODULE_COUNT, ADMIN, CONFIG, CW20_LIST, CW721_LIST, ITEMS, MODULE_POLICIES,
+    MODULE_SPENDING, NOMINATED_ADMIN, PAUSED, PROPOSAL_MODULES, SUBDAO_LIST,
+    TOTAL_PROPOSAL_MODULE_COUNT, VOTING_MODULE,
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-dao-core";
@@ -118,17 +124,19 @@ pub fn execute(
         }
     }
 
+    ledger::record_inbound_funds(deps.storage, &env.block, &info.sender, &info.funds)?;
+
     match msg {
         ExecuteMsg::ExecuteAdminMsgs { msgs } => {
             execute_admin_msgs(deps.as_ref(), info.sender, msgs)
         }
-        ExecuteMsg::ExecuteProposalHook { msgs } => {
-            execute_proposal_hook(deps.as_ref(), info.sender, msgs)
+        ExecuteMsg::ExecuteProposalHook { msgs, proposal_id } => {
+            execute_proposal_hook(deps, env, info.sender, msgs, proposal_id)
         }
         ExecuteMsg::Pause { duration } => execute_pause(deps, env, info.sender, duration),
         ExecuteMsg::Unpause {} => execute_unpause(deps, info.sender),
-        ExecuteMsg::Receive(_) => execute_receive_cw20(deps, info.sender),
-        ExecuteMsg::ReceiveNft(_) => execute_receive_cw721(deps, info.sender),
+        ExecuteMsg::Receive(wrapper) => execute_receive_cw20(deps, env, info.sender, wrapper),
+        ExecuteMsg::ReceiveNft(wrapper) => execute_receive_cw721(deps, env, info.sender, wrapper),
         ExecuteMsg::RemoveItem { key } => execute_remove_item(deps, env, info.sender, key),
         ExecuteMsg::SetItem { key, value } => execute_set_item(deps, env, info.sender, key, value),
         ExecuteMsg::UpdateConfig { config } => {
@@ -156,6 +164,9 @@ pub fn execute(
         ExecuteMsg::UpdateSubDaos { to_add, to_remove } => {
             execute_update_sub_daos_list(deps, env, info.sender, to_add, to_remove)
         }
+        ExecuteMsg::UpdateModulePolicy { module, policy } => {
+            execute_update_module_policy(deps, env, info.sender, module, policy)
+        }
     }
 }
 
@@ -213,9 +224,11 @@ pub fn execute_admin_msgs(
 }
 
 pub fn execute_proposal_hook(
-    deps: Deps,
+    mut deps: DepsMut,
+    env: Env,
     sender: Addr,
     msgs: Vec<CosmosMsg<Empty>>,
+    proposal_id: Option<u64>,
 ) -> Result<Response, ContractError> {
     let module = PROPOSAL_MODULES
         .may_load(deps.storage, sender.clone())?
@@ -226,11 +239,235 @@ pub fn execute_proposal_hook(
         return Err(ContractError::ModuleDisabledCannotExecute { address: sender });
     }
 
+    if let Some(policy) = MODULE_POLICIES.may_load(deps.storage, sender.clone())? {
+        enforce_module_policy(deps.branch(), &env, &sender, &policy, &msgs)?;
+    }
+
+    ledger::record_outbound(
+        deps.storage,
+        &env.block,
+        &ledger::Source {
+            module: sender,
+            proposal_id,
+        },
+        &msgs,
+    )?;
+
     Ok(Response::default()
         .add_attribute("action", "execute_proposal_hook")
         .add_messages(msgs))
 }
 
+/// Checks that a proposal module's policy allows it to execute
+/// `msgs`, and records what they spend against its spend limits.
+fn enforce_module_policy(
+    deps: DepsMut,
+    env: &Env,
+    module: &Addr,
+    policy: &ModulePolicy,
+    msgs: &[CosmosMsg<Empty>],
+) -> Result<(), ContractError> {
+    let mut spends: Vec<(String, Uint128)> = vec![];
+    for msg in msgs {
+        // Messages to core could remove the policy or add an
+        // unrestricted module, so restricted modules may not send any.
+        if let CosmosMsg::Wasm(
+            WasmMsg::Execute { contract_addr, .. }
+            | WasmMsg::Migrate { contract_addr, .. }
+            | WasmMsg::UpdateAdmin { contract_addr, .. }
+            | WasmMsg::ClearAdmin { contract_addr },
+        ) = msg
+        {
+            if *contract_addr == env.contract.address {
+                return Err(ContractError::CoreMsgNotAllowed {});
+            }
+        }
+
+        let kind = MsgKind::of(msg);
+        if !policy.spend_limits.is_empty() {
+            check_uncounted_spending(deps.as_ref(), msg)?;
+        }
+        // What stargate, staking and distribution messages spend can't
+        // be counted, so modules with spend limits may not send them.
+        if !policy.spend_limits.is_empty() && matches!(kind, MsgKind::Stargate | MsgKind::Other) {
+            return Err(ContractError::MsgNotAllowed {
+                kind: kind.to_string(),
+            });
+        }
+        if let Some(allowed) = &policy.allowed_msgs {
+            if !allowed.contains(&kind) {
+                return Err(ContractError::MsgNotAllowed {
+                    kind: kind.to_string(),
+                });
+            }
+        }
+        if let (Some(allowed), CosmosMsg::Wasm(wasm)) = (&policy.allowed_contracts, msg) {
+            let contract = match wasm {
+                WasmMsg::Execute { contract_addr, .. }
+                | WasmMsg::Migrate { contract_addr, .. }
+                | WasmMsg::UpdateAdmin { contract_addr, .. }
+                | WasmMsg::ClearAdmin { contract_addr } => contract_addr,
+                _ => {
+                    return Err(ContractError::MsgNotAllowed {
+                        kind: "wasm instantiate".to_string(),
+                    })
+                }
+            };
+            if !allowed.contains(contract) {
+                return Err(ContractError::ContractNotAllowed {
+                    contract: contract.clone(),
+                });
+            }
+        }
+        spends.extend(spent_by(msg));
+    }
+
+    for limit in &policy.spend_limits {
+        let amount: Uint128 = spends
+            .iter()
+            .filter(|(denom, _)| *denom == limit.denom)
+            .map(|(_, amount)| amount)
+            .sum();
+        if amount.is_zero() {
+            continue;
+        }
+
+        let key = (module.clone(), limit.denom.clone());
+        let spending = match MODULE_SPENDING.may_load(deps.storage, key.clone())? {
+            Some(spending) if !spending.resets.is_expired(&env.block) => spending,
+            // The previous period has ended, so a new one begins.
+            _ => ModuleSpending {
+                denom: limit.denom.clone(),
+                spent: Uint128::zero(),
+                resets: limit.period.after(&env.block),
+            },
+        };
+        let spent = spending
+            .spent
+            .checked_add(amount)
+            .map_err(|_| ContractError::Overflow {})?;
+        if spent > limit.amount {
+            return Err(ContractError::SpendLimitExceeded {
+                denom: limit.denom.clone(),
+                limit: limit.amount,
+                remaining: limit.amount.saturating_sub(spending.spent),
+            });
+        }
+        MODULE_SPENDING.save(deps.storage, key, &ModuleSpending { spent, ..spending })?;
+    }
+
+    Ok(())
+}
+
+/// Core is the admin of the DAO's modules and executes messages as the
+/// DAO, so a module with spend limits could otherwise spend through
+/// them without it being counted: by migrating them, changing their
+/// admin, or reconfiguring them. NFTs can't be counted against a spend
+/// limit either.
+fn check_uncounted_spending(deps: Deps, msg: &CosmosMsg<Empty>) -> Result<(), ContractError> {
+    let (contract_addr, msg) = match msg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr, msg, ..
+        }) => (contract_addr, msg),
+        CosmosMsg::Wasm(WasmMsg::Migrate { .. }) => {
+            return Err(ContractError::MsgNotAllowed {
+                kind: "wasm migrate".to_string(),
+            })
+        }
+        CosmosMsg::Wasm(WasmMsg::UpdateAdmin { .. } | WasmMsg::ClearAdmin { .. }) => {
+            return Err(ContractError::MsgNotAllowed {
+                kind: "wasm admin".to_string(),
+            })
+        }
+        _ => return Ok(()),
+    };
+
+    if is_dao_module(deps, contract_addr)? {
+        return Err(ContractError::ContractNotAllowed {
+            contract: contract_addr.clone(),
+        });
+    }
+    if let Ok(
+        cw721::Cw721ExecuteMsg::TransferNft { .. }
+        | cw721::Cw721ExecuteMsg::SendNft { .. }
+        | cw721::Cw721ExecuteMsg::Approve { .. }
+        | cw721::Cw721ExecuteMsg::ApproveAll { .. }
+        | cw721::Cw721ExecuteMsg::Burn { .. },
+    ) = from_json(msg)
+    {
+        return Err(ContractError::MsgNotAllowed {
+            kind: "cw721".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// The query pre-propose modules answer with the proposal module they
+/// belong to.
+#[cw_serde]
+enum PreProposeQuery {
+    ProposalModule {},
+}
+
+/// Whether `contract` is the DAO's voting module, one of its proposal
+/// modules, or the pre-propose module of one of them.
+fn is_dao_module(deps: Deps, contract: &str) -> StdResult<bool> {
+    let contract = Addr::unchecked(contract);
+    if VOTING_MODULE.load(deps.storage)? == contract
+        || PROPOSAL_MODULES.has(deps.storage, contract.clone())
+    {
+        return Ok(true);
+    }
+    // Anything that doesn't answer is not a pre-propose module.
+    let proposal_module: StdResult<Addr> = deps
+        .querier
+        .query_wasm_smart(contract, &PreProposeQuery::ProposalModule {});
+    Ok(proposal_module.is_ok_and(|module| PROPOSAL_MODULES.has(deps.storage, module)))
+}
+
+/// The amounts of native denoms and cw20 tokens that executing `msg`
+/// would spend from the treasury. cw20 tokens are identified by their
+/// contract address.
+fn spent_by(msg: &CosmosMsg<Empty>) -> Vec<(String, Uint128)> {
+    let native = |coins: &[cosmwasm_std::Coin]| {
+        coins
+            .iter()
+            .map(|c| (c.denom.clone(), c.amount))
+            .collect::<Vec<_>>()
+    };
+    match msg {
+        CosmosMsg::Bank(BankMsg::Send { amount, .. })
+        | CosmosMsg::Bank(BankMsg::Burn { amount }) => native(amount),
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) => {
+            let mut spends = native(funds);
+            // Messages that do not parse as a cw20 message are not
+            // spending cw20 tokens.
+            if let Ok(
+                cw20::Cw20ExecuteMsg::Transfer { amount, .. }
+                | cw20::Cw20ExecuteMsg::Send { amount, .. }
+                | cw20::Cw20ExecuteMsg::Burn { amount }
+                | cw20::Cw20ExecuteMsg::IncreaseAllowance { amount, .. }
+                | cw20::Cw20ExecuteMsg::TransferFrom { amount, .. }
+                | cw20::Cw20ExecuteMsg::SendFrom { amount, .. }
+                | cw20::Cw20ExecuteMsg::BurnFrom { amount, .. },
+            ) = from_json(msg)
+            {
+                spends.push((contract_addr.clone(), amount));
+            }
+            spends
+        }
+        CosmosMsg::Wasm(WasmMsg::Instantiate { funds, .. }) => native(funds),
+        CosmosMsg::Ibc(IbcMsg::Transfer { amount, .. }) => {
+            vec![(amount.denom.clone(), amount.amount)]
+        }
+        _ => vec![],
+    }
+}
+
 pub fn execute_nominate_admin(
     deps: DepsMut,
     env: Env,
@@ -537,28 +774,125 @@ pub fn execute_update_sub_daos_list(
         .add_attribute("sender", sender))
 }
 
-pub fn execute_receive_cw20(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
+pub fn execute_update_module_policy(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    module: String,
+    policy: Option<ModulePolicy>,
+) -> Result<Response, ContractError> {
+    if env.contract.address != sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let module = deps.api.addr_validate(&module)?;
+    if !PROPOSAL_MODULES.has(deps.storage, module.clone()) {
+        return Err(ContractError::ProposalModuleDoesNotExist { address: module });
+    }
+
+    match policy {
+        Some(mut policy) => {
+            // Without a list of allowed contracts, a module could spend
+            // through any contract the DAO owns, such as a token issuer.
+            if !policy.spend_limits.is_empty() && policy.allowed_contracts.is_none() {
+                return Err(ContractError::SpendLimitsRequireAllowedContracts {});
+            }
+            policy.allowed_contracts = policy
+                .allowed_contracts
+                .map(|contracts| {
+                    contracts
+                        .into_iter()
+                        .map(|c| deps.api.addr_validate(&c).map(Addr::into_string))
+                        .collect::<StdResult<Vec<_>>>()
+                })
+                .transpose()?;
+            MODULE_POLICIES.save(deps.storage, module.clone(), &policy)?;
+        }
+        None => {
+            MODULE_POLICIES.remove(deps.storage, module.clone());
+            let denoms = MODULE_SPENDING
+                .prefix(module.clone())
+                .keys(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()?;
+            for denom in denoms {
+                MODULE_SPENDING.remove(deps.storage, (module.clone(), denom));
+            }
+        }
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "execute_update_module_policy")
+        .add_attribute("module", module))
+}
+
+pub fn execute_receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    wrapper: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    if !config.automatically_add_cw20s {
-        Ok(Response::new())
+    let response = if !config.automatically_add_cw20s {
+        Response::new()
     } else {
         CW20_LIST.save(deps.storage, sender.clone(), &Empty {})?;
-        Ok(Response::new()
+        Response::new()
             .add_attribute("action", "receive_cw20")
-            .add_attribute("token", sender))
+            .add_attribute("token", sender.clone())
+    };
+
+    // Any contract may claim to have sent the DAO tokens, so only
+    // tokens in the DAO's token list are recorded in the ledger.
+    if CW20_LIST.has(deps.storage, sender.clone()) {
+        ledger::record(
+            deps.storage,
+            &env.block,
+            LedgerDirection::Inbound,
+            LedgerAsset::Cw20 {
+                address: sender.to_string(),
+                amount: wrapper.amount,
+            },
+            Some(wrapper.sender),
+            None,
+        )?;
     }
+
+    Ok(response)
 }
 
-pub fn execute_receive_cw721(deps: DepsMut, sender: Addr) -> Result<Response, ContractError> {
+pub fn execute_receive_cw721(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    wrapper: Cw721ReceiveMsg,
+) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    if !config.automatically_add_cw721s {
-        Ok(Response::new())
+    let response = if !config.automatically_add_cw721s {
+        Response::new()
     } else {
         CW721_LIST.save(deps.storage, sender.clone(), &Empty {})?;
-        Ok(Response::new()
+        Response::new()
             .add_attribute("action", "receive_cw721")
-            .add_attribute("token", sender))
+            .add_attribute("token", sender.clone())
+    };
+
+    // As with cw20s, only NFTs from contracts in the DAO's cw721
+    // list are recorded.
+    if CW721_LIST.has(deps.storage, sender.clone()) {
+        ledger::record(
+            deps.storage,
+            &env.block,
+            LedgerDirection::Inbound,
+            LedgerAsset::Cw721 {
+                address: sender.to_string(),
+                token_id: wrapper.token_id,
+            },
+            Some(wrapper.sender),
+            None,
+        )?;
     }
+
+    Ok(response)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -595,6 +929,21 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             query_list_sub_daos(deps, start_after, limit)
         }
         QueryMsg::DaoURI {} => query_dao_uri(deps),
+        QueryMsg::ModulePolicy { module } => query_module_policy(deps, env, module),
+        QueryMsg::TreasuryLedger {
+            denom,
+            min_height,
+            max_height,
+            start_after,
+            limit,
+        } => to_json_binary(&ledger::query_ledger(
+            deps,
+            denom,
+            min_height,
+            max_height,
+            start_after,
+            limit,
+        )?),
     }
 }
 
@@ -861,6 +1210,23 @@ pub fn query_dao_uri(deps: Deps) -> StdResult<Binary> {
     })
 }
 
+pub fn query_module_policy(deps: Deps, env: Env, module: String) -> StdResult<Binary> {
+    let module = deps.api.addr_validate(&module)?;
+    let policy = MODULE_POLICIES.may_load(deps.storage, module.clone())?;
+    // Spending from periods that have ended no longer counts against
+    // the module's limits.
+    let spending = MODULE_SPENDING
+        .prefix(module)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, spending)| spending))
+        .filter(|item| {
+            item.as_ref()
+                .map_or(true, |spending| !spending.resets.is_expired(&env.block))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    to_json_binary(&ModulePolicyResponse { policy, spending })
+}
+
 pub fn query_proposal_module_count(deps: Deps) -> StdResult<Binary> {
     to_json_binary(&ProposalModuleCountResponse {
         active_proposal_module_count: ACTIVE_PROPOSAL_MODULE_COUNT.load(deps.storage)?,
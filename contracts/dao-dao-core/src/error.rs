@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, StdError};
+use cosmwasm_std::{Addr, StdError, Uint128};
 use cw_utils::ParseReplyError;
 use thiserror::Error;
 
@@ -51,6 +51,25 @@ pub enum ContractError {
     #[error("Proposal module with address is disabled and cannot execute messages.")]
     ModuleDisabledCannotExecute { address: Addr },
 
+    #[error("Proposal module may not execute {kind} messages.")]
+    MsgNotAllowed { kind: String },
+
+    #[error("Proposal modules with a policy may not execute messages on the DAO core.")]
+    CoreMsgNotAllowed {},
+
+    #[error("Proposal module may not execute messages on contract ({contract}).")]
+    ContractNotAllowed { contract: String },
+
+    #[error("Proposal modules with spend limits must have a list of allowed contracts.")]
+    SpendLimitsRequireAllowedContracts {},
+
+    #[error("Proposal module may spend at most ({limit}) {denom} per period and has ({remaining}) remaining.")]
+    SpendLimitExceeded {
+        denom: String,
+        limit: Uint128,
+        remaining: Uint128,
+    },
+
     #[error("Duplicate initial item: ({item})")]
     DuplicateInitialItem { item: String },
 
@@ -0,0 +1,234 @@
+use cosmwasm_std::{
+    from_json, Addr, BankMsg, BlockInfo, Coin, CosmosMsg, Deps, Empty, IbcMsg, Order, StdResult,
+    Storage, Uint128, WasmMsg,
+};
+use cw_storage_plus::Bound;
+use dao_interface::query::{LedgerAsset, LedgerDirection, LedgerEntry};
+
+use crate::state::{LEDGER, LEDGER_BY_DENOM, LEDGER_COUNT, LEDGER_HEIGHTS};
+
+const DEFAULT_LIMIT: u32 = 30;
+const MAX_LIMIT: u32 = 100;
+
+/// Where outbound assets were sent from: a proposal module and,
+/// optionally, the proposal being executed.
+pub(crate) struct Source {
+    pub module: Addr,
+    pub proposal_id: Option<u64>,
+}
+
+/// Records an entry in the treasury ledger.
+pub(crate) fn record(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    direction: LedgerDirection,
+    asset: LedgerAsset,
+    counterparty: Option<String>,
+    source: Option<&Source>,
+) -> StdResult<()> {
+    let id = LEDGER_COUNT.may_load(storage)?.unwrap_or_default() + 1;
+    LEDGER_COUNT.save(storage, &id)?;
+    if !LEDGER_HEIGHTS.has(storage, block.height) {
+        LEDGER_HEIGHTS.save(storage, block.height, &id)?;
+    }
+    LEDGER_BY_DENOM.save(storage, (asset.denom().to_string(), id), &Empty {})?;
+    LEDGER.save(
+        storage,
+        id,
+        &LedgerEntry {
+            id,
+            height: block.height,
+            time: block.time,
+            direction,
+            asset,
+            counterparty,
+            module: source.map(|s| s.module.clone()),
+            proposal_id: source.and_then(|s| s.proposal_id),
+        },
+    )
+}
+
+/// Records native funds sent to the DAO along with a message.
+pub(crate) fn record_inbound_funds(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    sender: &Addr,
+    funds: &[Coin],
+) -> StdResult<()> {
+    for coin in funds.iter().filter(|c| !c.amount.is_zero()) {
+        record(
+            storage,
+            block,
+            LedgerDirection::Inbound,
+            LedgerAsset::Native {
+                denom: coin.denom.clone(),
+                amount: coin.amount,
+            },
+            Some(sender.to_string()),
+            None,
+        )?;
+    }
+    Ok(())
+}
+
+/// Records the assets `msgs` send out of the treasury.
+pub(crate) fn record_outbound(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    source: &Source,
+    msgs: &[CosmosMsg<Empty>],
+) -> StdResult<()> {
+    for (asset, counterparty) in msgs.iter().flat_map(outflows) {
+        record(
+            storage,
+            block,
+            LedgerDirection::Outbound,
+            asset,
+            counterparty,
+            Some(source),
+        )?;
+    }
+    Ok(())
+}
+
+/// The assets executing `msg` would send out of the treasury, along
+/// with their recipients.
+fn outflows(msg: &CosmosMsg<Empty>) -> Vec<(LedgerAsset, Option<String>)> {
+    let native = |coins: &[Coin], recipient: Option<&String>| {
+        coins
+            .iter()
+            .filter(|c| !c.amount.is_zero())
+            .map(|c| {
+                (
+                    LedgerAsset::Native {
+                        denom: c.denom.clone(),
+                        amount: c.amount,
+                    },
+                    recipient.cloned(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    match msg {
+        CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => native(amount, Some(to_address)),
+        CosmosMsg::Bank(BankMsg::Burn { amount }) => native(amount, None),
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) => {
+            let mut outflows = native(funds, Some(contract_addr));
+            let cw20 = |amount: Uint128, recipient: Option<String>| {
+                (
+                    LedgerAsset::Cw20 {
+                        address: contract_addr.clone(),
+                        amount,
+                    },
+                    recipient,
+                )
+            };
+            let cw721 = |token_id: String, recipient: Option<String>| {
+                (
+                    LedgerAsset::Cw721 {
+                        address: contract_addr.clone(),
+                        token_id,
+                    },
+                    recipient,
+                )
+            };
+            if let Ok(msg) = from_json::<cw20::Cw20ExecuteMsg>(msg) {
+                match msg {
+                    cw20::Cw20ExecuteMsg::Transfer { recipient, amount } => {
+                        outflows.push(cw20(amount, Some(recipient)))
+                    }
+                    cw20::Cw20ExecuteMsg::Send {
+                        contract, amount, ..
+                    } => outflows.push(cw20(amount, Some(contract))),
+                    cw20::Cw20ExecuteMsg::Burn { amount } => outflows.push(cw20(amount, None)),
+                    _ => (),
+                }
+            } else if let Ok(msg) = from_json::<cw721::Cw721ExecuteMsg>(msg) {
+                match msg {
+                    cw721::Cw721ExecuteMsg::TransferNft {
+                        recipient,
+                        token_id,
+                    } => outflows.push(cw721(token_id, Some(recipient))),
+                    cw721::Cw721ExecuteMsg::SendNft {
+                        contract, token_id, ..
+                    } => outflows.push(cw721(token_id, Some(contract))),
+                    cw721::Cw721ExecuteMsg::Burn { token_id } => {
+                        outflows.push(cw721(token_id, None))
+                    }
+                    _ => (),
+                }
+            }
+            outflows
+        }
+        CosmosMsg::Wasm(WasmMsg::Instantiate { funds, .. }) => native(funds, None),
+        CosmosMsg::Ibc(IbcMsg::Transfer {
+            to_address, amount, ..
+        }) => native(std::slice::from_ref(amount), Some(to_address)),
+        _ => vec![],
+    }
+}
+
+/// The ID of the first ledger entry recorded at or after `height`.
+fn first_entry_from(storage: &dyn Storage, height: u64) -> StdResult<Option<u64>> {
+    LEDGER_HEIGHTS
+        .range(
+            storage,
+            Some(Bound::inclusive(height)),
+            None,
+            Order::Ascending,
+        )
+        .next()
+        .transpose()
+        .map(|first| first.map(|(_, id)| id))
+}
+
+pub(crate) fn query_ledger(
+    deps: Deps,
+    denom: Option<String>,
+    min_height: Option<u64>,
+    max_height: Option<u64>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<LedgerEntry>> {
+    let mut start = start_after.map_or(0, |id| id + 1);
+    if let Some(min_height) = min_height {
+        match first_entry_from(deps.storage, min_height)? {
+            Some(id) => start = start.max(id),
+            // Nothing has been recorded at or after `min_height`.
+            None => return Ok(vec![]),
+        }
+    }
+    let end = match max_height.and_then(|h| h.checked_add(1)) {
+        Some(height) => first_entry_from(deps.storage, height)?.map(Bound::exclusive),
+        None => None,
+    };
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    match denom {
+        Some(denom) => LEDGER_BY_DENOM
+            .prefix(denom)
+            .keys(
+                deps.storage,
+                Some(Bound::inclusive(start)),
+                end,
+                Order::Ascending,
+            )
+            .take(limit)
+            .map(|id| LEDGER.load(deps.storage, id?))
+            .collect(),
+        None => LEDGER
+            .range(
+                deps.storage,
+                Some(Bound::inclusive(start)),
+                end,
+                Order::Ascending,
+            )
+            .take(limit)
+            .map(|item| item.map(|(_, entry)| entry))
+            .collect(),
+    }
+}
@@ -2,6 +2,7 @@
 
 pub mod contract;
 mod error;
+mod ledger;
 pub mod state;
 
 #[cfg(test)]
@@ -1,7 +1,10 @@
 use cosmwasm_std::{Addr, Empty};
 use cw_storage_plus::{Item, Map};
 use cw_utils::Expiration;
-use dao_interface::state::{Config, ProposalModule};
+use dao_interface::{
+    query::{LedgerEntry, ModuleSpending},
+    state::{Config, ModulePolicy, ProposalModule},
+};
 
 /// The admin of the contract. Typically a DAO. The contract admin may
 /// unilaterally execute messages on this contract.
@@ -35,6 +38,15 @@ pub const VOTING_MODULE: Item<Addr> = Item::new("voting_module");
 /// to create a new namespace for the changed state.
 pub const PROPOSAL_MODULES: Map<Addr, ProposalModule> = Map::new("proposal_modules_v2");
 
+/// Policies limiting the messages proposal modules may execute,
+/// keyed by module address. Modules without a policy are
+/// unrestricted.
+pub const MODULE_POLICIES: Map<Addr, ModulePolicy> = Map::new("module_policies");
+
+/// The amount of each limited denom proposal modules have spent in
+/// their current period, keyed by (module, denom).
+pub const MODULE_SPENDING: Map<(Addr, String), ModuleSpending> = Map::new("module_spending");
+
 /// The count of active proposal modules associated with this contract.
 pub const ACTIVE_PROPOSAL_MODULE_COUNT: Item<u32> = Item::new("active_proposal_module_count");
 
@@ -53,3 +65,15 @@ pub const CW721_LIST: Map<Addr, Empty> = Map::new("cw721s");
 
 /// List of SubDAOs associated to this DAO. Each SubDAO has an optional charter.
 pub const SUBDAO_LIST: Map<&Addr, Option<String>> = Map::new("sub_daos");
+
+/// The treasury ledger, keyed by entry ID.
+pub const LEDGER: Map<u64, LedgerEntry> = Map::new("ledger");
+/// The number of entries in the treasury ledger.
+pub const LEDGER_COUNT: Item<u64> = Item::new("ledger_count");
+/// Index of treasury ledger entries by (denom, entry ID). For cw20 and
+/// cw721 tokens the denom is the token's contract address.
+pub const LEDGER_BY_DENOM: Map<(String, u64), Empty> = Map::new("ledger_by_denom");
+/// The ID of the first treasury ledger entry recorded at each block
+/// height. As entry IDs increase with height this is used to find the
+/// entries in a range of heights.
+pub const LEDGER_HEIGHTS: Map<u64, u64> = Map::new("ledger_heights");
@@ -9,9 +9,9 @@ use v1::DaoDaoCoreV1;
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    from_json,
+    coin, coins, from_json,
     testing::{mock_dependencies, mock_env},
-    to_json_binary, Addr, CosmosMsg, Empty, Storage, Uint128, WasmMsg,
+    to_json_binary, Addr, BankMsg, Binary, CosmosMsg, Empty, Storage, Uint128, WasmMsg,
 };
 use cw2::{set_contract_version, ContractVersion};
 use cw_orch::prelude::*;
@@ -26,9 +26,13 @@ use dao_interface::{
     msg::{ExecuteMsg, InitialItem, InstantiateMsg, MigrateMsg},
     query::{
         AdminNominationResponse, Cw20BalanceResponse, DumpStateResponse, GetItemResponse,
+        LedgerAsset, LedgerDirection, LedgerEntry, ModulePolicyResponse, ModuleSpending,
         PauseInfoResponse, ProposalModuleCountResponse, SubDao,
     },
-    state::{Admin, Config, ModuleInstantiateInfo, ProposalModule, ProposalModuleStatus},
+    state::{
+        Admin, Config, ModuleInstantiateInfo, ModulePolicy, MsgKind, ProposalModule,
+        ProposalModuleStatus, SpendLimit,
+    },
     voting::{InfoResponse, VotingPowerAtHeightResponse},
 };
 use dao_proposal_sudo::msg::ExecuteMsgFns as _;
@@ -890,15 +894,18 @@ fn test_admin_permissions() {
     // specified so the admin defaulted to the core contract.
 
     core.call_as(&proposal.address().unwrap())
-        .execute_proposal_hook(vec![WasmMsg::Execute {
-            contract_addr: core.address().unwrap().to_string(),
-            msg: to_json_binary(&ExecuteMsg::Pause {
-                duration: Duration::Height(10),
-            })
-            .unwrap(),
-            funds: vec![],
-        }
-        .into()])
+        .execute_proposal_hook(
+            vec![WasmMsg::Execute {
+                contract_addr: core.address().unwrap().to_string(),
+                msg: to_json_binary(&ExecuteMsg::Pause {
+                    duration: Duration::Height(10),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+            None,
+        )
         .unwrap();
 
     // Instantiate new DAO with an admin
@@ -1764,15 +1771,18 @@ fn test_pause() {
     // the execute hook.
     assert_contains(err, ContractError::Unauthorized {});
     gov.call_as(&proposal_module.address)
-        .execute_proposal_hook(vec![WasmMsg::Execute {
-            contract_addr: gov.address().unwrap().to_string(),
-            msg: to_json_binary(&ExecuteMsg::Pause {
-                duration: Duration::Height(10),
-            })
-            .unwrap(),
-            funds: vec![],
-        }
-        .into()])
+        .execute_proposal_hook(
+            vec![WasmMsg::Execute {
+                contract_addr: gov.address().unwrap().to_string(),
+                msg: to_json_binary(&ExecuteMsg::Pause {
+                    duration: Duration::Height(10),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+            None,
+        )
         .unwrap();
 
     assert_eq!(
@@ -1801,15 +1811,18 @@ fn test_pause() {
 
     let err = gov
         .call_as(&proposal_module.address)
-        .execute_proposal_hook(vec![WasmMsg::Execute {
-            contract_addr: gov.address().unwrap().to_string(),
-            msg: to_json_binary(&ExecuteMsg::Pause {
-                duration: Duration::Height(10),
-            })
-            .unwrap(),
-            funds: vec![],
-        }
-        .into()])
+        .execute_proposal_hook(
+            vec![WasmMsg::Execute {
+                contract_addr: gov.address().unwrap().to_string(),
+                msg: to_json_binary(&ExecuteMsg::Pause {
+                    duration: Duration::Height(10),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+            None,
+        )
         .unwrap_err();
 
     assert_contains(err, ContractError::Paused {});
@@ -1820,15 +1833,18 @@ fn test_pause() {
 
     let err = gov
         .call_as(&proposal_module.address)
-        .execute_proposal_hook(vec![WasmMsg::Execute {
-            contract_addr: gov.address().unwrap().to_string(),
-            msg: to_json_binary(&ExecuteMsg::Pause {
-                duration: Duration::Height(10),
-            })
-            .unwrap(),
-            funds: vec![],
-        }
-        .into()])
+        .execute_proposal_hook(
+            vec![WasmMsg::Execute {
+                contract_addr: gov.address().unwrap().to_string(),
+                msg: to_json_binary(&ExecuteMsg::Pause {
+                    duration: Duration::Height(10),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+            None,
+        )
         .unwrap_err();
 
     assert_contains(err, ContractError::Paused {});
@@ -1843,15 +1859,18 @@ fn test_pause() {
 
     // Now its unpaused so we should be able to pause again.
     gov.call_as(&proposal_module.address)
-        .execute_proposal_hook(vec![WasmMsg::Execute {
-            contract_addr: gov.address().unwrap().to_string(),
-            msg: to_json_binary(&ExecuteMsg::Pause {
-                duration: Duration::Height(10),
-            })
-            .unwrap(),
-            funds: vec![],
-        }
-        .into()])
+        .execute_proposal_hook(
+            vec![WasmMsg::Execute {
+                contract_addr: gov.address().unwrap().to_string(),
+                msg: to_json_binary(&ExecuteMsg::Pause {
+                    duration: Duration::Height(10),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+            None,
+        )
         .unwrap();
 
     assert_eq!(
@@ -2133,12 +2152,13 @@ fn test_execute_stargate_msg() {
     assert_eq!(proposal_modules.len(), 1);
     let proposal_module = proposal_modules.into_iter().next().unwrap();
 
-    let res = gov
-        .call_as(&proposal_module.address)
-        .execute_proposal_hook(vec![CosmosMsg::Stargate {
+    let res = gov.call_as(&proposal_module.address).execute_proposal_hook(
+        vec![CosmosMsg::Stargate {
             type_url: "foo_type".to_string(),
             value: to_json_binary("foo_bin").unwrap(),
-        }]);
+        }],
+        None,
+    );
 
     // TODO: Once cw-multi-test supports executing stargate/ibc messages we can change this test assert
     assert!(res.is_err());
@@ -2312,3 +2332,769 @@ fn test_query_info() {
         }
     )
 }
+
+fn update_module_policy(
+    gov: &DaoDaoCore<MockBech32>,
+    module: &Addr,
+    policy: Option<ModulePolicy>,
+) -> Result<(), CwOrchError> {
+    gov.execute(
+        &ExecuteMsg::UpdateModulePolicy {
+            module: module.to_string(),
+            policy,
+        },
+        None,
+    )
+    .map(|_| ())
+}
+
+fn query_module_policy(gov: &DaoDaoCore<MockBech32>, module: &Addr) -> ModulePolicyResponse {
+    gov.query(&dao_interface::msg::QueryMsg::ModulePolicy {
+        module: module.to_string(),
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_module_policy_permissions() {
+    let (gov, proposal, mock, _) = do_standard_instantiate(true, false);
+    let module = proposal.address().unwrap();
+    let random = mock.addr_make("random");
+    let policy = ModulePolicy {
+        spend_limits: vec![],
+        allowed_msgs: Some(vec![MsgKind::Bank]),
+        allowed_contracts: None,
+    };
+
+    // Only the core module may update module policies.
+    let err =
+        update_module_policy(&gov.call_as(&random), &module, Some(policy.clone())).unwrap_err();
+    assert_contains(err, ContractError::Unauthorized {});
+
+    // Policies may only be set for proposal modules.
+    let err = update_module_policy(&gov, &random, Some(policy.clone())).unwrap_err();
+    assert_contains(
+        err,
+        ContractError::ProposalModuleDoesNotExist {
+            address: random.clone(),
+        },
+    );
+
+    assert_eq!(
+        query_module_policy(&gov, &module),
+        ModulePolicyResponse {
+            policy: None,
+            spending: vec![],
+        }
+    );
+    update_module_policy(&gov, &module, Some(policy.clone())).unwrap();
+    assert_eq!(query_module_policy(&gov, &module).policy, Some(policy));
+
+    // The module may no longer execute wasm messages.
+    let err = gov
+        .call_as(&module)
+        .execute_proposal_hook(
+            vec![WasmMsg::Execute {
+                contract_addr: random.to_string(),
+                msg: to_json_binary(&Empty {}).unwrap(),
+                funds: vec![],
+            }
+            .into()],
+            None,
+        )
+        .unwrap_err();
+    assert_contains(
+        err,
+        ContractError::MsgNotAllowed {
+            kind: "wasm".to_string(),
+        },
+    );
+
+    // Removing the policy lifts the restriction.
+    update_module_policy(&gov, &module, None).unwrap();
+    gov.call_as(&module)
+        .execute_proposal_hook(
+            vec![WasmMsg::Execute {
+                contract_addr: gov.address().unwrap().to_string(),
+                msg: to_json_binary(&ExecuteMsg::Pause {
+                    duration: Duration::Height(10),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into()],
+            None,
+        )
+        .unwrap();
+    assert_eq!(query_module_policy(&gov, &module).policy, None);
+}
+
+#[test]
+fn test_module_policy_core_msgs() {
+    let (gov, proposal, mock, _) = do_standard_instantiate(true, false);
+    let module = proposal.address().unwrap();
+    let policy = ModulePolicy {
+        spend_limits: vec![SpendLimit {
+            denom: "ujuno".to_string(),
+            amount: Uint128::new(50),
+            period: Duration::Height(10),
+        }],
+        allowed_msgs: None,
+        allowed_contracts: Some(vec![]),
+    };
+    update_module_policy(&gov, &module, Some(policy.clone())).unwrap();
+
+    let to_core = |msg: &ExecuteMsg| -> CosmosMsg {
+        WasmMsg::Execute {
+            contract_addr: gov.address().unwrap().to_string(),
+            msg: to_json_binary(msg).unwrap(),
+            funds: vec![],
+        }
+        .into()
+    };
+
+    // The module may not remove its own policy through core, nor add
+    // an unrestricted module to spend for it.
+    for msg in [
+        ExecuteMsg::UpdateModulePolicy {
+            module: module.to_string(),
+            policy: None,
+        },
+        ExecuteMsg::UpdateProposalModules {
+            to_add: vec![],
+            to_disable: vec![],
+        },
+    ] {
+        let err = gov
+            .call_as(&module)
+            .execute_proposal_hook(
+                vec![
+                    to_core(&msg),
+                    BankMsg::Send {
+                        to_address: mock.addr_make("recipient").to_string(),
+                        amount: coins(1000, "ujuno"),
+                    }
+                    .into(),
+                ],
+                None,
+            )
+            .unwrap_err();
+        assert_contains(err, ContractError::CoreMsgNotAllowed {});
+    }
+    assert_eq!(query_module_policy(&gov, &module).policy, Some(policy));
+
+    // Messages whose spending can't be counted are not allowed either.
+    let err = gov
+        .call_as(&module)
+        .execute_proposal_hook(
+            vec![CosmosMsg::Stargate {
+                type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                value: Binary::default(),
+            }],
+            None,
+        )
+        .unwrap_err();
+    assert_contains(
+        err,
+        ContractError::MsgNotAllowed {
+            kind: "stargate".to_string(),
+        },
+    );
+    let err = gov
+        .call_as(&module)
+        .execute_proposal_hook(vec![CosmosMsg::Custom(Empty {})], None)
+        .unwrap_err();
+    assert_contains(
+        err,
+        ContractError::MsgNotAllowed {
+            kind: "other".to_string(),
+        },
+    );
+}
+
+#[test]
+fn test_module_native_spend_limit() {
+    let (gov, proposal, mock, _) = do_standard_instantiate(true, false);
+    let module = proposal.address().unwrap();
+    let recipient = mock.addr_make("recipient");
+    mock.set_balance(
+        &gov.address().unwrap(),
+        vec![coin(1000, "uatom"), coin(1000, "ujuno")],
+    )
+    .unwrap();
+
+    update_module_policy(
+        &gov,
+        &module,
+        Some(ModulePolicy {
+            spend_limits: vec![SpendLimit {
+                denom: "ujuno".to_string(),
+                amount: Uint128::new(50),
+                period: Duration::Height(10),
+            }],
+            allowed_msgs: None,
+            allowed_contracts: Some(vec![]),
+        }),
+    )
+    .unwrap();
+
+    let send = |amount: u128| -> CosmosMsg {
+        BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(amount, "ujuno"),
+        }
+        .into()
+    };
+
+    gov.call_as(&module)
+        .execute_proposal_hook(vec![send(30)], None)
+        .unwrap();
+    let resets = Duration::Height(10).after(&mock.block_info().unwrap());
+
+    // Amounts are summed across all of a proposal's messages.
+    let err = gov
+        .call_as(&module)
+        .execute_proposal_hook(vec![send(10), send(11)], None)
+        .unwrap_err();
+    assert_contains(
+        err,
+        ContractError::SpendLimitExceeded {
+            denom: "ujuno".to_string(),
+            limit: Uint128::new(50),
+            remaining: Uint128::new(20),
+        },
+    );
+
+    // Denoms without a limit may be spent freely.
+    gov.call_as(&module)
+        .execute_proposal_hook(
+            vec![
+                send(20),
+                BankMsg::Send {
+                    to_address: recipient.to_string(),
+                    amount: coins(1000, "uatom"),
+                }
+                .into(),
+            ],
+            None,
+        )
+        .unwrap();
+    assert_eq!(
+        query_module_policy(&gov, &module).spending,
+        vec![ModuleSpending {
+            denom: "ujuno".to_string(),
+            spent: Uint128::new(50),
+            resets,
+        }]
+    );
+
+    let err = gov
+        .call_as(&module)
+        .execute_proposal_hook(vec![send(1)], None)
+        .unwrap_err();
+    assert_contains(
+        err,
+        ContractError::SpendLimitExceeded {
+            denom: "ujuno".to_string(),
+            limit: Uint128::new(50),
+            remaining: Uint128::zero(),
+        },
+    );
+
+    // Once the period ends the module may spend again.
+    mock.wait_blocks(10).unwrap();
+    assert_eq!(query_module_policy(&gov, &module).spending, vec![]);
+    gov.call_as(&module)
+        .execute_proposal_hook(vec![send(50)], None)
+        .unwrap();
+    assert_eq!(
+        mock.query_balance(&recipient, "ujuno").unwrap(),
+        Uint128::new(100)
+    );
+
+    // Other proposal modules are not limited by the policy.
+    gov.execute_proposal_hook(vec![send(100)], None).unwrap();
+}
+
+#[test]
+fn test_module_allowed_contracts_and_cw20_spend_limit() {
+    let (gov, proposal, mock, _) = do_standard_instantiate(true, false);
+    let module = proposal.address().unwrap();
+    let recipient = mock.addr_make("recipient");
+
+    let voting = DaoVotingCw20Balance::new("dao-voting", mock.clone());
+    voting.set_address(&gov.voting_module().unwrap());
+    let gov_token = Cw20Base::new("cw20", mock.clone());
+    gov_token.set_address(&voting.token_contract().unwrap());
+    gov_token
+        .transfer(Uint128::new(2), gov.address().unwrap().to_string())
+        .unwrap();
+
+    update_module_policy(
+        &gov,
+        &module,
+        Some(ModulePolicy {
+            spend_limits: vec![SpendLimit {
+                denom: gov_token.address().unwrap().to_string(),
+                amount: Uint128::new(1),
+                period: Duration::Height(10),
+            }],
+            allowed_msgs: None,
+            allowed_contracts: Some(vec![gov_token.address().unwrap().to_string()]),
+        }),
+    )
+    .unwrap();
+
+    let transfer: CosmosMsg = WasmMsg::Execute {
+        contract_addr: gov_token.address().unwrap().to_string(),
+        msg: to_json_binary(&cw20::Cw20ExecuteMsg::Transfer {
+            recipient: recipient.to_string(),
+            amount: Uint128::new(1),
+        })
+        .unwrap(),
+        funds: vec![],
+    }
+    .into();
+
+    gov.call_as(&module)
+        .execute_proposal_hook(vec![transfer.clone()], None)
+        .unwrap();
+    let err = gov
+        .call_as(&module)
+        .execute_proposal_hook(vec![transfer], None)
+        .unwrap_err();
+    assert_contains(
+        err,
+        ContractError::SpendLimitExceeded {
+            denom: gov_token.address().unwrap().to_string(),
+            limit: Uint128::new(1),
+            remaining: Uint128::zero(),
+        },
+    );
+
+    // Contracts not in the list may not be executed.
+    let other = mock.addr_make("other");
+    let err = gov
+        .call_as(&module)
+        .execute_proposal_hook(
+            vec![WasmMsg::Execute {
+                contract_addr: other.to_string(),
+                msg: to_json_binary(&Empty {}).unwrap(),
+                funds: vec![],
+            }
+            .into()],
+            None,
+        )
+        .unwrap_err();
+    assert_contains(
+        err,
+        ContractError::ContractNotAllowed {
+            contract: other.to_string(),
+        },
+    );
+}
+
+/// Stands in for a pre-propose module, answering with the proposal
+/// module that instantiated it.
+pub mod pre_propose {
+    use cosmwasm_schema::{cw_serde, QueryResponses};
+    use cosmwasm_std::{
+        to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+    };
+    use cw_orch::{interface, prelude::*};
+    use cw_storage_plus::Item;
+
+    const PROPOSAL_MODULE: Item<Addr> = Item::new("proposal_module");
+
+    #[cw_serde]
+    #[derive(QueryResponses)]
+    pub enum QueryMsg {
+        #[returns(Addr)]
+        ProposalModule {},
+    }
+
+    fn instantiate(deps: DepsMut, _: Env, info: MessageInfo, _: Empty) -> StdResult<Response> {
+        PROPOSAL_MODULE.save(deps.storage, &info.sender)?;
+        Ok(Response::default())
+    }
+
+    fn execute(_: DepsMut, _: Env, _: MessageInfo, _: Empty) -> StdResult<Response> {
+        Ok(Response::default())
+    }
+
+    fn query(deps: Deps, _: Env, msg: QueryMsg) -> StdResult<Binary> {
+        match msg {
+            QueryMsg::ProposalModule {} => to_json_binary(&PROPOSAL_MODULE.load(deps.storage)?),
+        }
+    }
+
+    #[interface(Empty, Empty, QueryMsg, Empty)]
+    pub struct MockPrePropose;
+
+    impl<Chain> Uploadable for MockPrePropose<Chain> {
+        fn wrapper() -> Box<dyn MockContract<Empty>> {
+            Box::new(ContractWrapper::new_with_empty(execute, instantiate, query))
+        }
+    }
+}
+
+#[test]
+fn test_module_spend_limit_bypasses() {
+    let (gov, proposal, mock, _) = do_standard_instantiate(true, false);
+    let module = proposal.address().unwrap();
+    let voting = DaoVotingCw20Balance::new("dao-voting", mock.clone());
+    voting.set_address(&gov.voting_module().unwrap());
+    let gov_token = voting.token_contract().unwrap();
+
+    let pre_propose = pre_propose::MockPrePropose::new("pre-propose", mock.clone());
+    pre_propose.upload().unwrap();
+    pre_propose
+        .call_as(&module)
+        .instantiate(&Empty {}, None, None)
+        .unwrap();
+    let pre_propose = pre_propose.address().unwrap();
+    let issuer = mock.addr_make("issuer");
+    let nft = mock.addr_make("nft");
+
+    let spend_limits = vec![SpendLimit {
+        denom: gov_token.to_string(),
+        amount: Uint128::new(1),
+        period: Duration::Height(10),
+    }];
+
+    // Without a list of allowed contracts the module could spend
+    // through any contract the DAO owns, such as a token issuer.
+    let err = update_module_policy(
+        &gov,
+        &module,
+        Some(ModulePolicy {
+            spend_limits: spend_limits.clone(),
+            allowed_msgs: None,
+            allowed_contracts: None,
+        }),
+    )
+    .unwrap_err();
+    assert_contains(err, ContractError::SpendLimitsRequireAllowedContracts {});
+
+    update_module_policy(
+        &gov,
+        &module,
+        Some(ModulePolicy {
+            spend_limits,
+            allowed_msgs: None,
+            allowed_contracts: Some(
+                [
+                    &module,
+                    &voting.address().unwrap(),
+                    &pre_propose,
+                    &gov_token,
+                    &nft,
+                ]
+                .iter()
+                .map(|c| c.to_string())
+                .collect(),
+            ),
+        }),
+    )
+    .unwrap();
+
+    let execute = |contract: &Addr, msg: Binary| -> CosmosMsg {
+        WasmMsg::Execute {
+            contract_addr: contract.to_string(),
+            msg,
+            funds: vec![],
+        }
+        .into()
+    };
+    let try_execute = |msgs: Vec<CosmosMsg>| {
+        gov.call_as(&module)
+            .execute_proposal_hook(msgs, None)
+            .unwrap_err()
+    };
+
+    // Contracts the DAO owns but did not allow.
+    let err = try_execute(vec![execute(&issuer, to_json_binary(&Empty {}).unwrap())]);
+    assert_contains(
+        err,
+        ContractError::ContractNotAllowed {
+            contract: issuer.to_string(),
+        },
+    );
+
+    // The DAO's own modules, even when allowed.
+    for contract in [&module, &voting.address().unwrap(), &pre_propose] {
+        let err = try_execute(vec![execute(contract, to_json_binary(&Empty {}).unwrap())]);
+        assert_contains(
+            err,
+            ContractError::ContractNotAllowed {
+                contract: contract.to_string(),
+            },
+        );
+    }
+
+    // Migrating or changing the admin of allowed contracts.
+    let err = try_execute(vec![WasmMsg::Migrate {
+        contract_addr: gov_token.to_string(),
+        new_code_id: 1,
+        msg: to_json_binary(&Empty {}).unwrap(),
+    }
+    .into()]);
+    assert_contains(
+        err,
+        ContractError::MsgNotAllowed {
+            kind: "wasm migrate".to_string(),
+        },
+    );
+    for msg in [
+        WasmMsg::UpdateAdmin {
+            contract_addr: gov_token.to_string(),
+            admin: mock.addr_make("admin").to_string(),
+        },
+        WasmMsg::ClearAdmin {
+            contract_addr: gov_token.to_string(),
+        },
+    ] {
+        let err = try_execute(vec![msg.into()]);
+        assert_contains(
+            err,
+            ContractError::MsgNotAllowed {
+                kind: "wasm admin".to_string(),
+            },
+        );
+    }
+
+    // NFTs can't be counted against a spend limit.
+    let err = try_execute(vec![execute(
+        &nft,
+        to_json_binary(&cw721::Cw721ExecuteMsg::TransferNft {
+            recipient: mock.addr_make("recipient").to_string(),
+            token_id: "1".to_string(),
+        })
+        .unwrap(),
+    )]);
+    assert_contains(
+        err,
+        ContractError::MsgNotAllowed {
+            kind: "cw721".to_string(),
+        },
+    );
+
+    // Spending allowances counts against the limit.
+    let err = try_execute(vec![execute(
+        &gov_token,
+        to_json_binary(&cw20::Cw20ExecuteMsg::TransferFrom {
+            owner: mock.sender_addr().to_string(),
+            recipient: mock.addr_make("recipient").to_string(),
+            amount: Uint128::new(2),
+        })
+        .unwrap(),
+    )]);
+    assert_contains(
+        err,
+        ContractError::SpendLimitExceeded {
+            denom: gov_token.to_string(),
+            limit: Uint128::new(1),
+            remaining: Uint128::new(1),
+        },
+    );
+}
+
+fn query_treasury_ledger(
+    gov: &DaoDaoCore<MockBech32>,
+    denom: Option<&str>,
+    min_height: Option<u64>,
+    max_height: Option<u64>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Vec<LedgerEntry> {
+    gov.query(&dao_interface::msg::QueryMsg::TreasuryLedger {
+        denom: denom.map(|d| d.to_string()),
+        min_height,
+        max_height,
+        start_after,
+        limit,
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_treasury_ledger() {
+    let (gov, proposal, mock, _) = do_standard_instantiate(true, false);
+    let module = proposal.address().unwrap();
+    let recipient = mock.addr_make("recipient");
+
+    let voting = DaoVotingCw20Balance::new("dao-voting", mock.clone());
+    voting.set_address(&gov.voting_module().unwrap());
+    let gov_token = Cw20Base::new("cw20", mock.clone());
+    gov_token.set_address(&voting.token_contract().unwrap());
+    let token = gov_token.address().unwrap().to_string();
+
+    assert_eq!(
+        query_treasury_ledger(&gov, None, None, None, None, None),
+        vec![]
+    );
+    let start_height = mock.block_info().unwrap().height;
+
+    // Native funds sent along with a message are recorded.
+    mock.set_balance(&module, coins(10, "ujuno")).unwrap();
+    gov.call_as(&module)
+        .execute(
+            &ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![],
+                proposal_id: None,
+            },
+            Some(&coins(10, "ujuno")),
+        )
+        .unwrap();
+
+    // As are cw20 tokens received via `Send`.
+    gov_token
+        .send(
+            Uint128::new(2),
+            gov.address().unwrap().to_string(),
+            to_json_binary(&"").unwrap(),
+        )
+        .unwrap();
+    let first_height = mock.block_info().unwrap().height;
+
+    mock.wait_blocks(5).unwrap();
+    let second_height = mock.block_info().unwrap().height;
+    let time = mock.block_info().unwrap().time;
+
+    // Outbound transfers are tagged with the proposal module and
+    // proposal that made them.
+    gov.call_as(&module)
+        .execute_proposal_hook(
+            vec![
+                BankMsg::Send {
+                    to_address: recipient.to_string(),
+                    amount: coins(4, "ujuno"),
+                }
+                .into(),
+                WasmMsg::Execute {
+                    contract_addr: token.clone(),
+                    msg: to_json_binary(&cw20::Cw20ExecuteMsg::Transfer {
+                        recipient: recipient.to_string(),
+                        amount: Uint128::new(1),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+            ],
+            Some(7),
+        )
+        .unwrap();
+
+    let ledger = query_treasury_ledger(&gov, None, None, None, None, None);
+    assert_eq!(ledger.len(), 4);
+    assert_eq!(
+        ledger[0].asset,
+        LedgerAsset::Native {
+            denom: "ujuno".to_string(),
+            amount: Uint128::new(10),
+        }
+    );
+    assert_eq!(ledger[0].direction, LedgerDirection::Inbound);
+    assert_eq!(ledger[0].counterparty, Some(module.to_string()));
+    assert_eq!(ledger[0].module, None);
+    assert_eq!(
+        ledger[1].asset,
+        LedgerAsset::Cw20 {
+            address: token.clone(),
+            amount: Uint128::new(2),
+        }
+    );
+    assert_eq!(ledger[1].counterparty, Some(mock.sender_addr().to_string()));
+    assert_eq!(
+        ledger[3],
+        LedgerEntry {
+            id: 4,
+            height: second_height,
+            time,
+            direction: LedgerDirection::Outbound,
+            asset: LedgerAsset::Cw20 {
+                address: token.clone(),
+                amount: Uint128::new(1),
+            },
+            counterparty: Some(recipient.to_string()),
+            module: Some(module.clone()),
+            proposal_id: Some(7),
+        }
+    );
+
+    // Entries may be filtered by denom.
+    let ujuno = query_treasury_ledger(&gov, Some("ujuno"), None, None, None, None);
+    assert_eq!(ujuno.iter().map(|e| e.id).collect::<Vec<_>>(), vec![1, 3]);
+    let cw20 = query_treasury_ledger(&gov, Some(&token), None, None, None, None);
+    assert_eq!(cw20.iter().map(|e| e.id).collect::<Vec<_>>(), vec![2, 4]);
+
+    // And by height.
+    let before = query_treasury_ledger(&gov, None, None, Some(first_height), None, None);
+    assert_eq!(before.iter().map(|e| e.id).collect::<Vec<_>>(), vec![1, 2]);
+    let after = query_treasury_ledger(
+        &gov,
+        Some("ujuno"),
+        Some(first_height + 1),
+        None,
+        None,
+        None,
+    );
+    assert_eq!(after.iter().map(|e| e.id).collect::<Vec<_>>(), vec![3]);
+    assert_eq!(
+        query_treasury_ledger(&gov, None, Some(second_height + 1), None, None, None),
+        vec![]
+    );
+    assert_eq!(
+        query_treasury_ledger(&gov, None, None, Some(start_height - 1), None, None),
+        vec![]
+    );
+
+    // And paginated.
+    let page = query_treasury_ledger(&gov, None, None, None, Some(1), Some(2));
+    assert_eq!(page.iter().map(|e| e.id).collect::<Vec<_>>(), vec![2, 3]);
+}
+
+#[test]
+fn test_treasury_ledger_unlisted_tokens() {
+    let (gov, _, mock, _) = do_standard_instantiate(false, false);
+
+    let voting = DaoVotingCw20Balance::new("dao-voting", mock.clone());
+    voting.set_address(&gov.voting_module().unwrap());
+    let gov_token = Cw20Base::new("cw20", mock.clone());
+    gov_token.set_address(&voting.token_contract().unwrap());
+    let token = gov_token.address().unwrap().to_string();
+
+    // Tokens that are not in the DAO's cw20 list are not recorded.
+    gov_token
+        .send(
+            Uint128::new(1),
+            gov.address().unwrap().to_string(),
+            to_json_binary(&"").unwrap(),
+        )
+        .unwrap();
+    assert_eq!(
+        query_treasury_ledger(&gov, None, None, None, None, None),
+        vec![]
+    );
+
+    gov.call_as(&gov.address().unwrap())
+        .update_cw_20_list(vec![token.clone()], vec![])
+        .unwrap();
+    gov_token
+        .send(
+            Uint128::new(2),
+            gov.address().unwrap().to_string(),
+            to_json_binary(&"").unwrap(),
+        )
+        .unwrap();
+    let ledger = query_treasury_ledger(&gov, None, None, None, None, None);
+    assert_eq!(ledger.len(), 1);
+    assert_eq!(
+        ledger[0].asset,
+        LedgerAsset::Cw20 {
+            address: token,
+            amount: Uint128::new(2),
+        }
+    );
+}
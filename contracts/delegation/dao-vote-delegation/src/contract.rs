@@ -0,0 +1,575 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response,
+    StdResult, Uint128,
+};
+use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use cw_storage_plus::Bound;
+use cw_utils::nonpayable;
+use dao_interface::voting::{
+    InfoResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+};
+
+use crate::helpers::{
+    get_current_voting_power, get_voting_power, is_delegate_registered, set_delegation_power,
+    sync_delegations, validate_voting_power_contract,
+};
+use crate::hooks::{execute_membership_changed, execute_nft_stake_changed, execute_stake_changed};
+use crate::msg::{
+    Delegate, DelegatedPower, DelegatedPowerResponse, DelegatesResponse, Delegation,
+    DelegationsResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
+};
+use crate::state::{
+    Config, ACTIVE_DELEGATIONS, CONFIG, DEFAULT_MAX_DELEGATIONS, DELEGATED_POWER, DELEGATES,
+    DELEGATIONS, DELEGATION_POWER, PERCENT_DELEGATED, VP_HOOK_CALLERS,
+};
+use crate::ContractError;
+
+pub(crate) const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const DEFAULT_LIMIT: u32 = 10;
+pub const MAX_LIMIT: u32 = 50;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let dao = msg
+        .dao
+        .map(|dao| deps.api.addr_validate(&dao))
+        .transpose()?
+        .unwrap_or(info.sender);
+
+    // Default to the DAO's voting module.
+    let vp_contract = match msg.vp_contract {
+        Some(vp_contract) => deps.api.addr_validate(&vp_contract)?,
+        None => deps
+            .querier
+            .query_wasm_smart(&dao, &dao_interface::msg::QueryMsg::VotingModule {})?,
+    };
+    validate_voting_power_contract(deps.as_ref(), &vp_contract)?;
+
+    let max_delegations = msg.max_delegations.unwrap_or(DEFAULT_MAX_DELEGATIONS);
+    if max_delegations == 0 {
+        return Err(ContractError::InvalidMaxDelegations {});
+    }
+
+    for caller in msg.vp_hook_callers.unwrap_or_default() {
+        let caller = deps.api.addr_validate(&caller)?;
+        VP_HOOK_CALLERS.save(deps.storage, &caller, &Empty {})?;
+    }
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            dao: dao.clone(),
+            vp_contract: vp_contract.clone(),
+            max_delegations,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", dao)
+        .add_attribute("vp_contract", vp_contract))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    match msg {
+        ExecuteMsg::Register {} => execute_register(deps, env, info),
+        ExecuteMsg::Unregister {} => execute_unregister(deps, env, info),
+        ExecuteMsg::Delegate { delegate, percent } => {
+            execute_delegate(deps, env, info, delegate, percent)
+        }
+        ExecuteMsg::Undelegate { delegate } => execute_undelegate(deps, env, info, delegate),
+        ExecuteMsg::Sync { delegator } => execute_sync(deps, env, delegator),
+        ExecuteMsg::UpdateConfig { max_delegations } => {
+            execute_update_config(deps, info, max_delegations)
+        }
+        ExecuteMsg::UpdateVotingPowerHookCallers { add, remove } => {
+            execute_update_voting_power_hook_callers(deps, info, add, remove)
+        }
+        ExecuteMsg::MemberChangedHook(msg) => execute_membership_changed(deps, env, info, msg),
+        ExecuteMsg::NftStakeChangeHook(msg) => execute_nft_stake_changed(deps, env, info, msg),
+        ExecuteMsg::StakeChangeHook(msg) => execute_stake_changed(deps, env, info, msg),
+    }
+}
+
+fn execute_register(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    if DELEGATES.has(deps.storage, &info.sender) {
+        return Err(ContractError::DelegateAlreadyRegistered {});
+    }
+
+    // Delegates vote with their own voting power, so they cannot also
+    // hand it to someone else.
+    if PERCENT_DELEGATED.has(deps.storage, &info.sender) {
+        return Err(ContractError::CannotRegisterWithDelegations {});
+    }
+
+    if get_current_voting_power(deps.as_ref(), &env, &info.sender)?.is_zero() {
+        return Err(ContractError::NoVotingPower {});
+    }
+
+    DELEGATES.save(deps.storage, &info.sender, &Empty {}, env.block.height)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register")
+        .add_attribute("delegate", info.sender))
+}
+
+fn execute_unregister(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    if !DELEGATES.has(deps.storage, &info.sender) {
+        return Err(ContractError::DelegateNotRegistered {});
+    }
+
+    DELEGATES.remove(deps.storage, &info.sender, env.block.height)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "unregister")
+        .add_attribute("delegate", info.sender))
+}
+
+fn execute_delegate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    delegate: String,
+    percent: Decimal,
+) -> Result<Response, ContractError> {
+    if percent.is_zero() || percent > Decimal::one() {
+        return Err(ContractError::InvalidDelegationPercent {});
+    }
+
+    let delegator = info.sender;
+    let delegate = deps.api.addr_validate(&delegate)?;
+    if delegator == delegate {
+        return Err(ContractError::CannotDelegateToSelf {});
+    }
+    if DELEGATES.has(deps.storage, &delegator) {
+        return Err(ContractError::DelegatesCannotDelegate {});
+    }
+    if !DELEGATES.has(deps.storage, &delegate) {
+        return Err(ContractError::DelegateNotRegistered {});
+    }
+
+    let existing = DELEGATIONS.may_load(deps.storage, (&delegator, &delegate))?;
+
+    // Only new delegations count towards the delegation limit.
+    if existing.is_none() {
+        let config = CONFIG.load(deps.storage)?;
+        let count = DELEGATIONS
+            .prefix(&delegator)
+            .keys(deps.storage, None, None, Order::Ascending)
+            .count() as u64;
+        if count >= config.max_delegations {
+            return Err(ContractError::MaxDelegationsReached {
+                max: config.max_delegations,
+            });
+        }
+    }
+
+    let percent_delegated = PERCENT_DELEGATED
+        .may_load(deps.storage, &delegator)?
+        .unwrap_or_default()
+        .checked_sub(existing.unwrap_or_default())?
+        .checked_add(percent)?;
+    if percent_delegated > Decimal::one() {
+        return Err(ContractError::CannotDelegateMoreThan100Percent {});
+    }
+
+    let voting_power = get_current_voting_power(deps.as_ref(), &env, &delegator)?;
+    if voting_power.is_zero() {
+        return Err(ContractError::NoVotingPower {});
+    }
+
+    DELEGATIONS.save(deps.storage, (&delegator, &delegate), &percent)?;
+    PERCENT_DELEGATED.save(deps.storage, &delegator, &percent_delegated)?;
+    set_delegation_power(
+        deps.storage,
+        env.block.height,
+        &delegator,
+        &delegate,
+        voting_power.mul_floor(percent),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "delegate")
+        .add_attribute("delegator", delegator)
+        .add_attribute("delegate", delegate)
+        .add_attribute("percent", percent.to_string()))
+}
+
+fn execute_undelegate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    delegate: String,
+) -> Result<Response, ContractError> {
+    let delegator = info.sender;
+    let delegate = deps.api.addr_validate(&delegate)?;
+
+    let percent = DELEGATIONS
+        .may_load(deps.storage, (&delegator, &delegate))?
+        .ok_or(ContractError::DelegationDoesNotExist {})?;
+
+    DELEGATIONS.remove(deps.storage, (&delegator, &delegate));
+
+    let percent_delegated = PERCENT_DELEGATED
+        .load(deps.storage, &delegator)?
+        .checked_sub(percent)?;
+    if percent_delegated.is_zero() {
+        PERCENT_DELEGATED.remove(deps.storage, &delegator);
+    } else {
+        PERCENT_DELEGATED.save(deps.storage, &delegator, &percent_delegated)?;
+    }
+
+    set_delegation_power(
+        deps.storage,
+        env.block.height,
+        &delegator,
+        &delegate,
+        Uint128::zero(),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "undelegate")
+        .add_attribute("delegator", delegator)
+        .add_attribute("delegate", delegate))
+}
+
+fn execute_sync(deps: DepsMut, env: Env, delegator: String) -> Result<Response, ContractError> {
+    let delegator = deps.api.addr_validate(&delegator)?;
+    sync_delegations(deps, &env, &delegator)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "sync")
+        .add_attribute("delegator", delegator))
+}
+
+fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    max_delegations: Option<u64>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(max_delegations) = max_delegations {
+        if max_delegations == 0 {
+            return Err(ContractError::InvalidMaxDelegations {});
+        }
+        config.max_delegations = max_delegations;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "update_config")
+        .add_attribute("max_delegations", config.max_delegations.to_string()))
+}
+
+fn execute_update_voting_power_hook_callers(
+    deps: DepsMut,
+    info: MessageInfo,
+    add: Option<Vec<String>>,
+    remove: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    for caller in remove.unwrap_or_default() {
+        let caller = deps.api.addr_validate(&caller)?;
+        VP_HOOK_CALLERS.remove(deps.storage, &caller);
+    }
+
+    for caller in add.unwrap_or_default() {
+        let caller = deps.api.addr_validate(&caller)?;
+        VP_HOOK_CALLERS.save(deps.storage, &caller, &Empty {})?;
+    }
+
+    Ok(Response::new().add_attribute("action", "update_voting_power_hook_callers"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Dao {} => to_json_binary(&CONFIG.load(deps.storage)?.dao),
+        QueryMsg::Info {} => query_info(deps),
+        QueryMsg::VotingPowerAtHeight { address, height } => {
+            to_json_binary(&query_voting_power_at_height(deps, env, address, height)?)
+        }
+        QueryMsg::TotalPowerAtHeight { height } => {
+            to_json_binary(&query_total_power_at_height(deps, env, height)?)
+        }
+        QueryMsg::Delegates { start_after, limit } => {
+            to_json_binary(&query_delegates(deps, start_after, limit)?)
+        }
+        QueryMsg::Delegations {
+            delegator,
+            start_after,
+            limit,
+        } => to_json_binary(&query_delegations(deps, delegator, start_after, limit)?),
+        QueryMsg::DelegatedVotingPower { delegate, height } => {
+            to_json_binary(&query_delegated_voting_power(deps, env, delegate, height)?)
+        }
+        QueryMsg::DelegatedPowerAtHeight {
+            delegator,
+            height,
+            start_after,
+            limit,
+        } => to_json_binary(&query_delegated_power_at_height(
+            deps,
+            env,
+            delegator,
+            height,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::VotingPowerHookCallers { start_after, limit } => {
+            to_json_binary(&query_voting_power_hook_callers(deps, start_after, limit)?)
+        }
+    }
+}
+
+fn query_info(deps: Deps) -> StdResult<Binary> {
+    let info = cw2::get_contract_version(deps.storage)?;
+    to_json_binary(&InfoResponse { info })
+}
+
+/// Returns an address's voting power after delegations: their own
+/// voting power, less what they delegated to registered delegates,
+/// plus what was delegated to them if they are a registered delegate.
+fn query_voting_power_at_height(
+    deps: Deps,
+    env: Env,
+    address: String,
+    height: Option<u64>,
+) -> StdResult<VotingPowerAtHeightResponse> {
+    let height = height.unwrap_or(env.block.height);
+    let address = deps.api.addr_validate(&address)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let own = get_voting_power(deps, &config.vp_contract, &address, Some(height))?;
+
+    let mut delegated_away = Uint128::zero();
+    for delegate in ACTIVE_DELEGATIONS
+        .may_load_at_height(deps.storage, &address, height)?
+        .unwrap_or_default()
+    {
+        if is_delegate_registered(deps.storage, &delegate, height)? {
+            delegated_away += DELEGATION_POWER
+                .may_load_at_height(deps.storage, (&address, &delegate), height)?
+                .unwrap_or_default();
+        }
+    }
+
+    let delegated_in = if is_delegate_registered(deps.storage, &address, height)? {
+        DELEGATED_POWER
+            .may_load_at_height(deps.storage, &address, height)?
+            .unwrap_or_default()
+    } else {
+        Uint128::zero()
+    };
+
+    // Delegations are synced from the delegator's voting power when
+    // voting power change hooks are received, so if a change was not
+    // reported the delegator may have delegated more than they have
+    // until their delegations are synced with `Sync`.
+    Ok(VotingPowerAtHeightResponse {
+        power: own.saturating_sub(delegated_away) + delegated_in,
+        height,
+    })
+}
+
+fn query_total_power_at_height(
+    deps: Deps,
+    env: Env,
+    height: Option<u64>,
+) -> StdResult<TotalPowerAtHeightResponse> {
+    let height = height.unwrap_or(env.block.height);
+    let config = CONFIG.load(deps.storage)?;
+    // Delegation only moves voting power around, so the total is
+    // unchanged.
+    let resp: TotalPowerAtHeightResponse = deps.querier.query_wasm_smart(
+        config.vp_contract,
+        &dao_interface::voting::Query::TotalPowerAtHeight {
+            height: Some(height),
+        },
+    )?;
+    Ok(resp)
+}
+
+fn query_delegates(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<DelegatesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    let delegates = DELEGATES
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|delegate| {
+            let delegate = delegate?;
+            let power = DELEGATED_POWER
+                .may_load(deps.storage, &delegate)?
+                .unwrap_or_default();
+            Ok(Delegate { delegate, power })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(DelegatesResponse { delegates })
+}
+
+fn query_delegations(
+    deps: Deps,
+    delegator: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<DelegationsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let delegator = deps.api.addr_validate(&delegator)?;
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    let delegations = DELEGATIONS
+        .prefix(&delegator)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (delegate, percent) = item?;
+            let power = DELEGATION_POWER
+                .may_load(deps.storage, (&delegator, &delegate))?
+                .unwrap_or_default();
+            Ok(Delegation {
+                delegate,
+                percent,
+                power,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(DelegationsResponse { delegations })
+}
+
+fn query_delegated_voting_power(
+    deps: Deps,
+    env: Env,
+    delegate: String,
+    height: Option<u64>,
+) -> StdResult<VotingPowerAtHeightResponse> {
+    let height = height.unwrap_or(env.block.height);
+    let delegate = deps.api.addr_validate(&delegate)?;
+
+    let power = if is_delegate_registered(deps.storage, &delegate, height)? {
+        DELEGATED_POWER
+            .may_load_at_height(deps.storage, &delegate, height)?
+            .unwrap_or_default()
+    } else {
+        Uint128::zero()
+    };
+
+    Ok(VotingPowerAtHeightResponse { power, height })
+}
+
+fn query_delegated_power_at_height(
+    deps: Deps,
+    env: Env,
+    delegator: String,
+    height: Option<u64>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<DelegatedPowerResponse> {
+    let height = height.unwrap_or(env.block.height);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let delegator = deps.api.addr_validate(&delegator)?;
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+
+    let mut delegations = vec![];
+    for delegate in ACTIVE_DELEGATIONS
+        .may_load_at_height(deps.storage, &delegator, height)?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|delegate| start_after.as_ref().map_or(true, |start| delegate > start))
+    {
+        if delegations.len() >= limit {
+            break;
+        }
+        if !is_delegate_registered(deps.storage, &delegate, height)? {
+            continue;
+        }
+        let power = DELEGATION_POWER
+            .may_load_at_height(deps.storage, (&delegator, &delegate), height)?
+            .unwrap_or_default();
+        if !power.is_zero() {
+            delegations.push(DelegatedPower { delegate, power });
+        }
+    }
+
+    Ok(DelegatedPowerResponse {
+        delegations,
+        height,
+    })
+}
+
+fn query_voting_power_hook_callers(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Addr>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let start = start_after.as_ref().map(Bound::exclusive);
+
+    VP_HOOK_CALLERS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect()
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let storage_version: ContractVersion = get_contract_version(deps.storage)?;
+
+    // Only migrate if newer
+    if storage_version.version.as_str() < CONTRACT_VERSION {
+        // Set contract to version to latest
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    }
+
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
@@ -0,0 +1,54 @@
+use cosmwasm_std::{OverflowError, StdError};
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Overflow(#[from] OverflowError),
+
+    #[error(transparent)]
+    Payment(#[from] PaymentError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Voting power changed hook sender incorrect")]
+    InvalidVotingPowerHookCaller {},
+
+    #[error("Delegate already registered")]
+    DelegateAlreadyRegistered {},
+
+    #[error("Delegate not registered")]
+    DelegateNotRegistered {},
+
+    #[error("Delegates cannot delegate their voting power")]
+    DelegatesCannotDelegate {},
+
+    #[error("Cannot register as a delegate while delegating voting power. Undelegate first")]
+    CannotRegisterWithDelegations {},
+
+    #[error("Cannot delegate to yourself")]
+    CannotDelegateToSelf {},
+
+    #[error("No voting power")]
+    NoVotingPower {},
+
+    #[error("Delegation percent must be greater than 0 and at most 100%")]
+    InvalidDelegationPercent {},
+
+    #[error("Cannot delegate more than 100% of voting power")]
+    CannotDelegateMoreThan100Percent {},
+
+    #[error("Cannot have more than {max} delegations")]
+    MaxDelegationsReached { max: u64 },
+
+    #[error("Max delegations must be greater than 0")]
+    InvalidMaxDelegations {},
+
+    #[error("Delegation does not exist")]
+    DelegationDoesNotExist {},
+}
@@ -0,0 +1,131 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, Order, StdResult, Storage, Uint128};
+use dao_interface::voting::{
+    Query as VotingQueryMsg, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+};
+
+use crate::state::{
+    ACTIVE_DELEGATIONS, CONFIG, DELEGATED_POWER, DELEGATES, DELEGATIONS, DELEGATION_POWER,
+};
+use crate::ContractError;
+
+pub fn get_voting_power(
+    deps: Deps,
+    vp_contract: &Addr,
+    addr: &Addr,
+    height: Option<u64>,
+) -> StdResult<Uint128> {
+    let resp: VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
+        vp_contract,
+        &VotingQueryMsg::VotingPowerAtHeight {
+            address: addr.to_string(),
+            height,
+        },
+    )?;
+    Ok(resp.power)
+}
+
+/// Returns the voting power of `addr` including any changes made
+/// earlier in the current block. Voting modules snapshot at the start
+/// of each block, so changes made during a block only become visible
+/// at the next height.
+pub fn get_current_voting_power(deps: Deps, env: &Env, addr: &Addr) -> StdResult<Uint128> {
+    let config = CONFIG.load(deps.storage)?;
+    get_voting_power(deps, &config.vp_contract, addr, Some(env.block.height + 1))
+}
+
+pub fn validate_voting_power_contract(deps: Deps, vp_contract: &Addr) -> StdResult<()> {
+    let _: TotalPowerAtHeightResponse = deps.querier.query_wasm_smart(
+        vp_contract,
+        &VotingQueryMsg::TotalPowerAtHeight { height: None },
+    )?;
+    Ok(())
+}
+
+/// Returns whether `delegate` is a registered delegate at `height`.
+pub fn is_delegate_registered(
+    storage: &dyn Storage,
+    delegate: &Addr,
+    height: u64,
+) -> StdResult<bool> {
+    Ok(DELEGATES
+        .may_load_at_height(storage, delegate, height)?
+        .is_some())
+}
+
+/// Sets the voting power carried by a delegation and updates the
+/// delegate's delegated total accordingly.
+pub fn set_delegation_power(
+    storage: &mut dyn Storage,
+    height: u64,
+    delegator: &Addr,
+    delegate: &Addr,
+    power: Uint128,
+) -> Result<(), ContractError> {
+    let previous = DELEGATION_POWER
+        .may_load(storage, (delegator, delegate))?
+        .unwrap_or_default();
+    if previous == power {
+        return Ok(());
+    }
+
+    if power.is_zero() {
+        DELEGATION_POWER.remove(storage, (delegator, delegate), height)?;
+    } else {
+        DELEGATION_POWER.save(storage, (delegator, delegate), &power, height)?;
+    }
+
+    // Add or remove the delegate from the delegator's active
+    // delegations if the delegation started or stopped carrying
+    // voting power.
+    if previous.is_zero() || power.is_zero() {
+        let mut delegates = ACTIVE_DELEGATIONS
+            .may_load(storage, delegator)?
+            .unwrap_or_default();
+        match delegates.binary_search(delegate) {
+            Ok(index) => {
+                delegates.remove(index);
+            }
+            Err(index) => delegates.insert(index, delegate.clone()),
+        }
+        if delegates.is_empty() {
+            ACTIVE_DELEGATIONS.remove(storage, delegator, height)?;
+        } else {
+            ACTIVE_DELEGATIONS.save(storage, delegator, &delegates, height)?;
+        }
+    }
+
+    let total = DELEGATED_POWER
+        .may_load(storage, delegate)?
+        .unwrap_or_default()
+        .checked_sub(previous)?
+        .checked_add(power)?;
+    DELEGATED_POWER.save(storage, delegate, &total, height)?;
+
+    Ok(())
+}
+
+/// Recomputes the voting power carried by each of a delegator's
+/// delegations from their current voting power. Called whenever the
+/// delegator's voting power changes.
+pub fn sync_delegations(deps: DepsMut, env: &Env, delegator: &Addr) -> Result<(), ContractError> {
+    let delegations = DELEGATIONS
+        .prefix(delegator)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    if delegations.is_empty() {
+        return Ok(());
+    }
+
+    let voting_power = get_current_voting_power(deps.as_ref(), env, delegator)?;
+    for (delegate, percent) in delegations {
+        set_delegation_power(
+            deps.storage,
+            env.block.height,
+            delegator,
+            &delegate,
+            voting_power.mul_floor(percent),
+        )?;
+    }
+
+    Ok(())
+}
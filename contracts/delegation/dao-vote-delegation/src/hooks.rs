@@ -0,0 +1,69 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use cw4::MemberChangedHookMsg;
+use dao_hooks::{nft_stake::NftStakeChangedHookMsg, stake::StakeChangedHookMsg};
+
+use crate::{helpers::sync_delegations, state::VP_HOOK_CALLERS, ContractError};
+
+/// Ensures hooks that update voting power are only called by a
+/// designated hook caller contract.
+fn assert_vp_hook_caller(deps: &DepsMut, info: &MessageInfo) -> Result<(), ContractError> {
+    if !VP_HOOK_CALLERS.has(deps.storage, &info.sender) {
+        return Err(ContractError::InvalidVotingPowerHookCaller {});
+    }
+    Ok(())
+}
+
+pub(crate) fn execute_stake_changed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: StakeChangedHookMsg,
+) -> Result<Response, ContractError> {
+    assert_vp_hook_caller(&deps, &info)?;
+
+    let addr = match msg {
+        StakeChangedHookMsg::Stake { addr, .. } => addr,
+        StakeChangedHookMsg::Unstake { addr, .. } => addr,
+    };
+    sync_delegations(deps, &env, &addr)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "stake_changed")
+        .add_attribute("address", addr))
+}
+
+pub(crate) fn execute_nft_stake_changed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: NftStakeChangedHookMsg,
+) -> Result<Response, ContractError> {
+    assert_vp_hook_caller(&deps, &info)?;
+
+    let addr = match msg {
+        NftStakeChangedHookMsg::Stake { addr, .. } => addr,
+        NftStakeChangedHookMsg::Unstake { addr, .. } => addr,
+    };
+    sync_delegations(deps, &env, &addr)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "nft_stake_changed")
+        .add_attribute("address", addr))
+}
+
+pub(crate) fn execute_membership_changed(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: MemberChangedHookMsg,
+) -> Result<Response, ContractError> {
+    assert_vp_hook_caller(&deps, &info)?;
+
+    // Get the addresses of members whose voting power has changed.
+    for member in msg.diffs {
+        let addr = deps.api.addr_validate(&member.key)?;
+        sync_delegations(deps.branch(), &env, &addr)?;
+    }
+
+    Ok(Response::new().add_attribute("action", "membership_changed"))
+}
@@ -0,0 +1,13 @@
+#![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
+
+pub mod contract;
+mod error;
+pub mod helpers;
+pub mod hooks;
+pub mod msg;
+pub mod state;
+
+#[cfg(test)]
+mod testing;
+
+pub use crate::error::ContractError;
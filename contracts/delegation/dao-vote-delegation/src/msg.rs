@@ -0,0 +1,144 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Uint128};
+use cw4::MemberChangedHookMsg;
+use dao_dao_macros::voting_module_query;
+use dao_hooks::{nft_stake::NftStakeChangedHookMsg, stake::StakeChangedHookMsg};
+
+// so that consumers don't need a dao_interface dependency to consume
+// this contract's delegation queries.
+pub use dao_interface::delegation::{DelegatedPower, DelegatedPowerResponse};
+
+use crate::state::Config;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The DAO this delegation module belongs to. Defaults to the
+    /// instantiator.
+    pub dao: Option<String>,
+    /// The voting module whose voting power is delegated. Defaults to
+    /// the DAO's voting module.
+    pub vp_contract: Option<String>,
+    /// Contracts allowed to call the voting power change hooks, such
+    /// as a staking contract or cw4-group.
+    pub vp_hook_callers: Option<Vec<String>>,
+    /// The maximum number of delegations a single delegator may
+    /// have. Defaults to 50.
+    pub max_delegations: Option<u64>,
+}
+
+#[cw_serde]
+#[derive(cw_orch::ExecuteFns)]
+pub enum ExecuteMsg {
+    /// Registers the sender as a delegate that others may delegate
+    /// their voting power to.
+    Register {},
+    /// Unregisters the sender as a delegate. Existing delegations are
+    /// kept but carry no voting power until the sender registers
+    /// again.
+    Unregister {},
+    /// Delegates a percent of the sender's voting power to a
+    /// registered delegate. Delegating to the same delegate again
+    /// replaces the previous percent.
+    Delegate {
+        delegate: String,
+        /// The percent of the sender's voting power to delegate.
+        percent: Decimal,
+    },
+    /// Removes the sender's delegation to a delegate.
+    Undelegate { delegate: String },
+    /// Recomputes the voting power carried by a delegator's
+    /// delegations from their current voting power. Callable by
+    /// anyone, for use if the voting module did not report a change in
+    /// the delegator's voting power.
+    Sync { delegator: String },
+    /// Updates the config. Only the DAO may call this method.
+    UpdateConfig { max_delegations: Option<u64> },
+    /// Adds or removes contracts allowed to call the voting power
+    /// change hooks. Only the DAO may call this method.
+    UpdateVotingPowerHookCallers {
+        add: Option<Vec<String>>,
+        remove: Option<Vec<String>>,
+    },
+    /// Called when a member is added or removed
+    /// to a cw4-groups or cw721-roles contract.
+    MemberChangedHook(MemberChangedHookMsg),
+    /// Called when NFTs are staked or unstaked.
+    NftStakeChangeHook(NftStakeChangedHookMsg),
+    /// Called when tokens are staked or unstaked.
+    StakeChangeHook(StakeChangedHookMsg),
+}
+
+#[voting_module_query]
+#[cw_serde]
+#[derive(QueryResponses, cw_orch::QueryFns)]
+pub enum QueryMsg {
+    /// Returns the config.
+    #[returns(Config)]
+    Config {},
+    /// Lists registered delegates and the voting power currently
+    /// delegated to them.
+    #[returns(DelegatesResponse)]
+    Delegates {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Lists a delegator's current delegations.
+    #[returns(DelegationsResponse)]
+    Delegations {
+        delegator: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the total voting power delegated to a registered
+    /// delegate at a given height.
+    #[returns(::dao_interface::voting::VotingPowerAtHeightResponse)]
+    DelegatedVotingPower {
+        delegate: String,
+        height: Option<u64>,
+    },
+    /// Returns the voting power a delegator had delegated to each of
+    /// their registered delegates at a given height.
+    #[returns(DelegatedPowerResponse)]
+    DelegatedPowerAtHeight {
+        delegator: String,
+        height: Option<u64>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Lists the contracts allowed to call the voting power change
+    /// hooks.
+    #[returns(Vec<Addr>)]
+    VotingPowerHookCallers {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub struct Delegate {
+    pub delegate: Addr,
+    /// The voting power currently delegated to this delegate.
+    pub power: Uint128,
+}
+
+#[cw_serde]
+pub struct DelegatesResponse {
+    pub delegates: Vec<Delegate>,
+}
+
+#[cw_serde]
+pub struct Delegation {
+    pub delegate: Addr,
+    /// The percent of the delegator's voting power delegated.
+    pub percent: Decimal,
+    /// The voting power this delegation currently carries.
+    pub power: Uint128,
+}
+
+#[cw_serde]
+pub struct DelegationsResponse {
+    pub delegations: Vec<Delegation>,
+}
@@ -0,0 +1,66 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Decimal, Empty, Uint128};
+use cw_storage_plus::{Item, Map, SnapshotMap, Strategy};
+
+/// The default maximum number of delegations a delegator may have.
+pub const DEFAULT_MAX_DELEGATIONS: u64 = 50;
+
+#[cw_serde]
+pub struct Config {
+    /// The DAO this delegation module belongs to.
+    pub dao: Addr,
+    /// The voting module whose voting power is delegated.
+    pub vp_contract: Addr,
+    /// The maximum number of delegations a single delegator may have.
+    pub max_delegations: u64,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// Contracts allowed to call the voting power change hooks, such as
+/// a staking contract or cw4-group.
+pub const VP_HOOK_CALLERS: Map<&Addr, Empty> = Map::new("vp_hook_callers");
+
+/// Registered delegates over time.
+pub const DELEGATES: SnapshotMap<&Addr, Empty> = SnapshotMap::new(
+    "delegates",
+    "delegates__checkpoints",
+    "delegates__changelog",
+    Strategy::EveryBlock,
+);
+
+/// The percent of voting power a delegator currently delegates to
+/// each delegate, keyed by (delegator, delegate).
+pub const DELEGATIONS: Map<(&Addr, &Addr), Decimal> = Map::new("delegations");
+
+/// The total percent of voting power each delegator has delegated.
+pub const PERCENT_DELEGATED: Map<&Addr, Decimal> = Map::new("percent_delegated");
+
+/// The voting power each delegation carries over time, keyed by
+/// (delegator, delegate). Entries are removed once a delegation
+/// carries no voting power; `ACTIVE_DELEGATIONS` records which
+/// entries existed at past heights.
+pub const DELEGATION_POWER: SnapshotMap<(&Addr, &Addr), Uint128> = SnapshotMap::new(
+    "delegation_power",
+    "delegation_power__checkpoints",
+    "delegation_power__changelog",
+    Strategy::EveryBlock,
+);
+
+/// The delegates each delegator's delegations carrying voting power
+/// are to over time, in ascending order. Bounded by the maximum number
+/// of delegations.
+pub const ACTIVE_DELEGATIONS: SnapshotMap<&Addr, Vec<Addr>> = SnapshotMap::new(
+    "active_delegations",
+    "active_delegations__checkpoints",
+    "active_delegations__changelog",
+    Strategy::EveryBlock,
+);
+
+/// The total voting power delegated to each delegate over time.
+pub const DELEGATED_POWER: SnapshotMap<&Addr, Uint128> = SnapshotMap::new(
+    "delegated_power",
+    "delegated_power__checkpoints",
+    "delegated_power__changelog",
+    Strategy::EveryBlock,
+);
@@ -0,0 +1,201 @@
+use cosmwasm_std::{to_json_binary, Addr, Empty, Uint128};
+use cw20::Cw20Coin;
+use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use cw_utils::Duration;
+use dao_testing::{
+    contracts::proposal_single_contract, helpers::instantiate_with_cw4_groups_governance,
+};
+use dao_voting::{
+    multiple_choice::VotingStrategy,
+    pre_propose::PreProposeInfo,
+    threshold::{PercentageThreshold, Threshold},
+};
+
+use crate::msg::InstantiateMsg;
+
+mod tests;
+
+pub const ALICE: &str = "alice";
+pub const BOB: &str = "bob";
+pub const CAROL: &str = "carol";
+pub const DAVE: &str = "dave";
+
+pub fn contract_delegation() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    );
+    Box::new(contract)
+}
+
+pub fn proposal_multiple_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_proposal_multiple::contract::execute,
+        dao_proposal_multiple::contract::instantiate,
+        dao_proposal_multiple::contract::query,
+    )
+    .with_reply(dao_proposal_multiple::contract::reply);
+    Box::new(contract)
+}
+
+pub struct Suite {
+    pub app: App,
+    pub dao: Addr,
+    pub group: Addr,
+    pub proposal_single: Addr,
+    pub delegation: Addr,
+}
+
+/// Sets up a cw4 DAO with a single choice proposal module and a
+/// delegation module subscribed to the cw4-group's member change
+/// hooks.
+///
+/// Voting power: alice 10, bob 20, carol 30, dave 40.
+pub fn setup() -> Suite {
+    let mut app = App::default();
+    let proposal_single_id = app.store_code(proposal_single_contract());
+    let delegation_id = app.store_code(contract_delegation());
+
+    let dao = instantiate_with_cw4_groups_governance(
+        &mut app,
+        proposal_single_id,
+        to_json_binary(&dao_proposal_single::msg::InstantiateMsg {
+            threshold: Threshold::AbsolutePercentage {
+                percentage: PercentageThreshold::Majority {},
+            },
+            max_voting_period: Duration::Height(10),
+            min_voting_period: None,
+            only_members_execute: false,
+            // Delegator overrides can lower a delegate's vote after it
+            // was cast, so proposals should not complete early.
+            allow_revoting: true,
+            pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+            close_proposal_on_execution_failure: true,
+            review_period: None,
+            veto: None,
+        })
+        .unwrap(),
+        Some(
+            [(ALICE, 10), (BOB, 20), (CAROL, 30), (DAVE, 40)]
+                .into_iter()
+                .map(|(address, amount)| Cw20Coin {
+                    address: address.to_string(),
+                    amount: Uint128::new(amount),
+                })
+                .collect(),
+        ),
+    );
+
+    let voting_module: Addr = app
+        .wrap()
+        .query_wasm_smart(&dao, &dao_interface::msg::QueryMsg::VotingModule {})
+        .unwrap();
+    let group: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            &voting_module,
+            &dao_voting_cw4::msg::QueryMsg::GroupContract {},
+        )
+        .unwrap();
+    let proposal_modules: Vec<dao_interface::state::ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            &dao,
+            &dao_interface::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_single = proposal_modules[0].address.clone();
+
+    let delegation = app
+        .instantiate_contract(
+            delegation_id,
+            dao.clone(),
+            &InstantiateMsg {
+                dao: None,
+                vp_contract: None,
+                vp_hook_callers: Some(vec![group.to_string()]),
+                max_delegations: None,
+            },
+            &[],
+            "delegation",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        dao.clone(),
+        group.clone(),
+        &cw4_group::msg::ExecuteMsg::AddHook {
+            addr: delegation.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        dao.clone(),
+        proposal_single.clone(),
+        &dao_proposal_single::msg::ExecuteMsg::UpdateDelegationModule {
+            module: Some(delegation.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+
+    Suite {
+        app,
+        dao,
+        group,
+        proposal_single,
+        delegation,
+    }
+}
+
+impl Suite {
+    /// Adds a multiple choice proposal module to the DAO that uses the
+    /// delegation module.
+    pub fn add_proposal_multiple(&mut self) -> Addr {
+        let code_id = self.app.store_code(proposal_multiple_contract());
+        let module = self
+            .app
+            .instantiate_contract(
+                code_id,
+                self.dao.clone(),
+                &dao_proposal_multiple::msg::InstantiateMsg {
+                    voting_strategy: VotingStrategy::SingleChoice {
+                        quorum: PercentageThreshold::Majority {},
+                    },
+                    max_voting_period: Duration::Height(10),
+                    min_voting_period: None,
+                    only_members_execute: false,
+                    allow_revoting: true,
+                    pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+                    close_proposal_on_execution_failure: true,
+                    veto: None,
+                },
+                &[],
+                "proposal multiple",
+                None,
+            )
+            .unwrap();
+        self.app
+            .execute_contract(
+                self.dao.clone(),
+                module.clone(),
+                &dao_proposal_multiple::msg::ExecuteMsg::UpdateDelegationModule {
+                    module: Some(self.delegation.to_string()),
+                },
+                &[],
+            )
+            .unwrap();
+        module
+    }
+
+    pub fn advance_block(&mut self) {
+        self.app.update_block(|b| b.height += 1);
+    }
+}
@@ -0,0 +1,688 @@
+use cosmwasm_std::{Addr, Decimal, Uint128};
+use cw4::{Member, MemberChangedHookMsg, MemberDiff};
+use cw_multi_test::Executor;
+use dao_interface::voting::{TotalPowerAtHeightResponse, VotingPowerAtHeightResponse};
+use dao_voting::{
+    multiple_choice::{MultipleChoiceOption, MultipleChoiceOptions, MultipleChoiceVote},
+    proposal::{MultipleChoiceProposeMsg, SingleChoiceProposeMsg},
+    voting::{Vote, Votes},
+};
+
+use crate::{
+    msg::{
+        DelegatedPower, DelegatedPowerResponse, DelegatesResponse, Delegation, DelegationsResponse,
+        ExecuteMsg, QueryMsg,
+    },
+    state::{Config, DEFAULT_MAX_DELEGATIONS},
+    ContractError,
+};
+
+use super::{setup, Suite, ALICE, BOB, CAROL, DAVE};
+
+impl Suite {
+    fn register(&mut self, delegate: &str) {
+        self.app
+            .execute_contract(
+                Addr::unchecked(delegate),
+                self.delegation.clone(),
+                &ExecuteMsg::Register {},
+                &[],
+            )
+            .unwrap();
+    }
+
+    fn delegate(
+        &mut self,
+        delegator: &str,
+        delegate: &str,
+        percent: Decimal,
+    ) -> Result<(), ContractError> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(delegator),
+                self.delegation.clone(),
+                &ExecuteMsg::Delegate {
+                    delegate: delegate.to_string(),
+                    percent,
+                },
+                &[],
+            )
+            .map(|_| ())
+            .map_err(|e| e.downcast().unwrap())
+    }
+
+    fn voting_power(&self, address: &str, height: Option<u64>) -> Uint128 {
+        let resp: VotingPowerAtHeightResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.delegation,
+                &QueryMsg::VotingPowerAtHeight {
+                    address: address.to_string(),
+                    height,
+                },
+            )
+            .unwrap();
+        resp.power
+    }
+
+    fn delegated_voting_power(&self, delegate: &str, height: Option<u64>) -> Uint128 {
+        let resp: VotingPowerAtHeightResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.delegation,
+                &QueryMsg::DelegatedVotingPower {
+                    delegate: delegate.to_string(),
+                    height,
+                },
+            )
+            .unwrap();
+        resp.power
+    }
+
+    fn propose_single(&mut self) -> u64 {
+        self.app
+            .execute_contract(
+                Addr::unchecked(DAVE),
+                self.proposal_single.clone(),
+                &dao_proposal_single::msg::ExecuteMsg::Propose(SingleChoiceProposeMsg {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    proposer: None,
+                    vote: None,
+                    schedule: None,
+                }),
+                &[],
+            )
+            .unwrap();
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.proposal_single,
+                &dao_proposal_single::msg::QueryMsg::ProposalCount {},
+            )
+            .unwrap()
+    }
+
+    fn vote_single(&mut self, voter: &str, proposal_id: u64, vote: Vote) {
+        self.app
+            .execute_contract(
+                Addr::unchecked(voter),
+                self.proposal_single.clone(),
+                &dao_proposal_single::msg::ExecuteMsg::Vote {
+                    proposal_id,
+                    vote,
+                    rationale: None,
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    fn ballot_power_single(&self, voter: &str, proposal_id: u64) -> Uint128 {
+        let resp: dao_proposal_single::query::VoteResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.proposal_single,
+                &dao_proposal_single::msg::QueryMsg::GetVote {
+                    proposal_id,
+                    voter: voter.to_string(),
+                },
+            )
+            .unwrap();
+        resp.vote.unwrap().power
+    }
+
+    fn votes_single(&self, proposal_id: u64) -> Votes {
+        let resp: dao_proposal_single::query::ProposalResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.proposal_single,
+                &dao_proposal_single::msg::QueryMsg::Proposal { proposal_id },
+            )
+            .unwrap();
+        resp.proposal.votes
+    }
+}
+
+#[test]
+fn test_instantiate_defaults() {
+    let suite = setup();
+
+    let voting_module: Addr = suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.dao, &dao_interface::msg::QueryMsg::VotingModule {})
+        .unwrap();
+    let config: Config = suite
+        .app
+        .wrap()
+        .query_wasm_smart(&suite.delegation, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(
+        config,
+        Config {
+            dao: suite.dao.clone(),
+            vp_contract: voting_module,
+            max_delegations: DEFAULT_MAX_DELEGATIONS,
+        }
+    );
+
+    let callers: Vec<Addr> = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.delegation,
+            &QueryMsg::VotingPowerHookCallers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(callers, vec![suite.group.clone()]);
+}
+
+#[test]
+fn test_delegate() {
+    let mut suite = setup();
+
+    suite.register(CAROL);
+    suite.delegate(ALICE, CAROL, Decimal::percent(50)).unwrap();
+
+    // Delegations take effect at the next block, like voting power.
+    assert_eq!(suite.delegated_voting_power(CAROL, None), Uint128::zero());
+    suite.advance_block();
+
+    assert_eq!(suite.delegated_voting_power(CAROL, None), Uint128::new(5));
+    assert_eq!(suite.voting_power(ALICE, None), Uint128::new(5));
+    assert_eq!(suite.voting_power(CAROL, None), Uint128::new(35));
+    assert_eq!(suite.voting_power(BOB, None), Uint128::new(20));
+
+    let total: TotalPowerAtHeightResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.delegation,
+            &QueryMsg::TotalPowerAtHeight { height: None },
+        )
+        .unwrap();
+    assert_eq!(total.power, Uint128::new(100));
+
+    let delegations: DelegationsResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.delegation,
+            &QueryMsg::Delegations {
+                delegator: ALICE.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        delegations.delegations,
+        vec![Delegation {
+            delegate: Addr::unchecked(CAROL),
+            percent: Decimal::percent(50),
+            power: Uint128::new(5),
+        }]
+    );
+
+    let delegates: DelegatesResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.delegation,
+            &QueryMsg::Delegates {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(delegates.delegates.len(), 1);
+    assert_eq!(delegates.delegates[0].power, Uint128::new(5));
+
+    // Delegating to the same delegate again replaces the percent.
+    suite.delegate(ALICE, CAROL, Decimal::percent(100)).unwrap();
+    suite.advance_block();
+    assert_eq!(suite.delegated_voting_power(CAROL, None), Uint128::new(10));
+    assert_eq!(suite.voting_power(ALICE, None), Uint128::zero());
+}
+
+#[test]
+fn test_delegate_errors() {
+    let mut suite = setup();
+
+    let err = suite
+        .delegate(ALICE, CAROL, Decimal::percent(50))
+        .unwrap_err();
+    assert_eq!(err, ContractError::DelegateNotRegistered {});
+
+    suite.register(CAROL);
+    suite.register(DAVE);
+
+    let err = suite.delegate(ALICE, CAROL, Decimal::zero()).unwrap_err();
+    assert_eq!(err, ContractError::InvalidDelegationPercent {});
+    let err = suite
+        .delegate(ALICE, CAROL, Decimal::percent(101))
+        .unwrap_err();
+    assert_eq!(err, ContractError::InvalidDelegationPercent {});
+
+    let err = suite
+        .delegate(CAROL, CAROL, Decimal::percent(50))
+        .unwrap_err();
+    assert_eq!(err, ContractError::CannotDelegateToSelf {});
+
+    let err = suite
+        .delegate(CAROL, DAVE, Decimal::percent(50))
+        .unwrap_err();
+    assert_eq!(err, ContractError::DelegatesCannotDelegate {});
+
+    let err = suite
+        .delegate("nonmember", CAROL, Decimal::percent(50))
+        .unwrap_err();
+    assert_eq!(err, ContractError::NoVotingPower {});
+
+    suite.delegate(ALICE, CAROL, Decimal::percent(60)).unwrap();
+    let err = suite
+        .delegate(ALICE, DAVE, Decimal::percent(50))
+        .unwrap_err();
+    assert_eq!(err, ContractError::CannotDelegateMoreThan100Percent {});
+
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            Addr::unchecked(ALICE),
+            suite.delegation.clone(),
+            &ExecuteMsg::Register {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::CannotRegisterWithDelegations {});
+}
+
+#[test]
+fn test_max_delegations() {
+    let mut suite = setup();
+    suite.register(CAROL);
+    suite.register(DAVE);
+
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            Addr::unchecked(ALICE),
+            suite.delegation.clone(),
+            &ExecuteMsg::UpdateConfig {
+                max_delegations: Some(1),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    suite
+        .app
+        .execute_contract(
+            suite.dao.clone(),
+            suite.delegation.clone(),
+            &ExecuteMsg::UpdateConfig {
+                max_delegations: Some(1),
+            },
+            &[],
+        )
+        .unwrap();
+
+    suite.delegate(ALICE, CAROL, Decimal::percent(10)).unwrap();
+    let err = suite
+        .delegate(ALICE, DAVE, Decimal::percent(10))
+        .unwrap_err();
+    assert_eq!(err, ContractError::MaxDelegationsReached { max: 1 });
+
+    // Updating an existing delegation does not count as a new one.
+    suite.delegate(ALICE, CAROL, Decimal::percent(20)).unwrap();
+}
+
+#[test]
+fn test_voting_power_change_hooks() {
+    let mut suite = setup();
+    suite.register(CAROL);
+    suite.delegate(ALICE, CAROL, Decimal::percent(100)).unwrap();
+    suite.advance_block();
+    assert_eq!(suite.delegated_voting_power(CAROL, None), Uint128::new(10));
+
+    suite
+        .app
+        .execute_contract(
+            suite.dao.clone(),
+            suite.group.clone(),
+            &cw4_group::msg::ExecuteMsg::UpdateMembers {
+                remove: vec![],
+                add: vec![Member {
+                    addr: ALICE.to_string(),
+                    weight: 50,
+                }],
+            },
+            &[],
+        )
+        .unwrap();
+    suite.advance_block();
+
+    assert_eq!(suite.delegated_voting_power(CAROL, None), Uint128::new(50));
+    assert_eq!(suite.voting_power(CAROL, None), Uint128::new(80));
+    assert_eq!(suite.voting_power(ALICE, None), Uint128::zero());
+
+    // Only registered hook callers may report voting power changes.
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            Addr::unchecked(ALICE),
+            suite.delegation.clone(),
+            &ExecuteMsg::MemberChangedHook(MemberChangedHookMsg {
+                diffs: vec![MemberDiff::new(ALICE, Some(50), Some(0))],
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidVotingPowerHookCaller {});
+}
+
+#[test]
+fn test_sync_unreported_voting_power_change() {
+    let mut suite = setup();
+    suite.register(CAROL);
+    suite.delegate(ALICE, CAROL, Decimal::percent(100)).unwrap();
+    suite.advance_block();
+
+    // Stop the group from reporting voting power changes, then lower
+    // Alice's weight below what she has delegated.
+    suite
+        .app
+        .execute_contract(
+            suite.dao.clone(),
+            suite.group.clone(),
+            &cw4_group::msg::ExecuteMsg::RemoveHook {
+                addr: suite.delegation.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    suite
+        .app
+        .execute_contract(
+            suite.dao.clone(),
+            suite.group.clone(),
+            &cw4_group::msg::ExecuteMsg::UpdateMembers {
+                remove: vec![],
+                add: vec![Member {
+                    addr: ALICE.to_string(),
+                    weight: 4,
+                }],
+            },
+            &[],
+        )
+        .unwrap();
+    suite.advance_block();
+
+    // The stale delegation does not make Alice's voting power query fail.
+    assert_eq!(suite.voting_power(ALICE, None), Uint128::zero());
+    assert_eq!(suite.delegated_voting_power(CAROL, None), Uint128::new(10));
+
+    // Anyone can sync the delegation with Alice's voting power.
+    suite
+        .app
+        .execute_contract(
+            Addr::unchecked(BOB),
+            suite.delegation.clone(),
+            &ExecuteMsg::Sync {
+                delegator: ALICE.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    suite.advance_block();
+
+    assert_eq!(suite.delegated_voting_power(CAROL, None), Uint128::new(4));
+    assert_eq!(suite.voting_power(CAROL, None), Uint128::new(34));
+    assert_eq!(suite.voting_power(ALICE, None), Uint128::zero());
+}
+
+#[test]
+fn test_undelegate_and_unregister() {
+    let mut suite = setup();
+    suite.register(CAROL);
+    suite.register(DAVE);
+    suite.delegate(ALICE, CAROL, Decimal::percent(50)).unwrap();
+    suite.delegate(BOB, DAVE, Decimal::percent(50)).unwrap();
+    suite.advance_block();
+    let delegated_height = suite.app.block_info().height;
+
+    suite
+        .app
+        .execute_contract(
+            Addr::unchecked(ALICE),
+            suite.delegation.clone(),
+            &ExecuteMsg::Undelegate {
+                delegate: CAROL.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    suite
+        .app
+        .execute_contract(
+            Addr::unchecked(DAVE),
+            suite.delegation.clone(),
+            &ExecuteMsg::Unregister {},
+            &[],
+        )
+        .unwrap();
+    suite.advance_block();
+
+    assert_eq!(suite.delegated_voting_power(CAROL, None), Uint128::zero());
+    assert_eq!(suite.voting_power(ALICE, None), Uint128::new(10));
+
+    // Bob's delegation to an unregistered delegate carries no power.
+    assert_eq!(suite.delegated_voting_power(DAVE, None), Uint128::zero());
+    assert_eq!(suite.voting_power(BOB, None), Uint128::new(20));
+    assert_eq!(suite.voting_power(DAVE, None), Uint128::new(40));
+
+    // History is preserved.
+    assert_eq!(
+        suite.delegated_voting_power(CAROL, Some(delegated_height)),
+        Uint128::new(5)
+    );
+    let history: DelegatedPowerResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.delegation,
+            &QueryMsg::DelegatedPowerAtHeight {
+                delegator: ALICE.to_string(),
+                height: Some(delegated_height),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        history.delegations,
+        vec![DelegatedPower {
+            delegate: Addr::unchecked(CAROL),
+            power: Uint128::new(5),
+        }]
+    );
+    assert_eq!(
+        suite.voting_power(ALICE, Some(delegated_height)),
+        Uint128::new(5)
+    );
+    let current: DelegatedPowerResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &suite.delegation,
+            &QueryMsg::DelegatedPowerAtHeight {
+                delegator: ALICE.to_string(),
+                height: None,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(current.delegations, vec![]);
+
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            Addr::unchecked(ALICE),
+            suite.delegation.clone(),
+            &ExecuteMsg::Undelegate {
+                delegate: CAROL.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::DelegationDoesNotExist {});
+}
+
+#[test]
+fn test_delegate_votes_then_delegator_overrides() {
+    let mut suite = setup();
+    suite.register(CAROL);
+    suite.delegate(ALICE, CAROL, Decimal::percent(50)).unwrap();
+    suite.delegate(BOB, CAROL, Decimal::percent(100)).unwrap();
+    suite.advance_block();
+
+    let proposal_id = suite.propose_single();
+
+    // Carol votes with her own 30 plus the 25 delegated to her.
+    suite.vote_single(CAROL, proposal_id, Vote::Yes);
+    assert_eq!(
+        suite.ballot_power_single(CAROL, proposal_id),
+        Uint128::new(55)
+    );
+
+    // Alice votes directly with all of her voting power, taking back
+    // the 5 she delegated from Carol's vote.
+    suite.vote_single(ALICE, proposal_id, Vote::No);
+    assert_eq!(
+        suite.ballot_power_single(ALICE, proposal_id),
+        Uint128::new(10)
+    );
+    assert_eq!(
+        suite.ballot_power_single(CAROL, proposal_id),
+        Uint128::new(50)
+    );
+
+    let votes = suite.votes_single(proposal_id);
+    assert_eq!(votes.yes, Uint128::new(50));
+    assert_eq!(votes.no, Uint128::new(10));
+
+    // Delegations made after the proposal was created do not count.
+    suite.delegate(ALICE, CAROL, Decimal::percent(100)).unwrap();
+    suite.advance_block();
+    suite.vote_single(CAROL, proposal_id, Vote::Abstain);
+    assert_eq!(
+        suite.ballot_power_single(CAROL, proposal_id),
+        Uint128::new(50)
+    );
+}
+
+#[test]
+fn test_delegator_votes_before_delegate() {
+    let mut suite = setup();
+    suite.register(CAROL);
+    suite.delegate(ALICE, CAROL, Decimal::percent(50)).unwrap();
+    suite.delegate(BOB, CAROL, Decimal::percent(100)).unwrap();
+    suite.advance_block();
+
+    let proposal_id = suite.propose_single();
+
+    suite.vote_single(ALICE, proposal_id, Vote::No);
+    suite.vote_single(CAROL, proposal_id, Vote::Yes);
+    assert_eq!(
+        suite.ballot_power_single(CAROL, proposal_id),
+        Uint128::new(50)
+    );
+
+    let votes = suite.votes_single(proposal_id);
+    assert_eq!(votes.yes, Uint128::new(50));
+    assert_eq!(votes.no, Uint128::new(10));
+}
+
+#[test]
+fn test_delegator_override_multiple_choice() {
+    let mut suite = setup();
+    let proposal_multiple = suite.add_proposal_multiple();
+    suite.register(CAROL);
+    suite.delegate(ALICE, CAROL, Decimal::percent(100)).unwrap();
+    suite.advance_block();
+
+    suite
+        .app
+        .execute_contract(
+            Addr::unchecked(DAVE),
+            proposal_multiple.clone(),
+            &dao_proposal_multiple::msg::ExecuteMsg::Propose(MultipleChoiceProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                choices: MultipleChoiceOptions {
+                    options: vec![
+                        MultipleChoiceOption {
+                            title: "a".to_string(),
+                            description: "a".to_string(),
+                            msgs: vec![],
+                        },
+                        MultipleChoiceOption {
+                            title: "b".to_string(),
+                            description: "b".to_string(),
+                            msgs: vec![],
+                        },
+                    ],
+                },
+                proposer: None,
+                vote: None,
+            }),
+            &[],
+        )
+        .unwrap();
+
+    for (voter, option_id) in [(CAROL, 0), (ALICE, 1)] {
+        suite
+            .app
+            .execute_contract(
+                Addr::unchecked(voter),
+                proposal_multiple.clone(),
+                &dao_proposal_multiple::msg::ExecuteMsg::Vote {
+                    proposal_id: 1,
+                    vote: MultipleChoiceVote { option_id },
+                    rationale: None,
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    let resp: dao_proposal_multiple::query::ProposalResponse = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &proposal_multiple,
+            &dao_proposal_multiple::msg::QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(resp.proposal.votes.vote_weights[0], Uint128::new(30));
+    assert_eq!(resp.proposal.votes.vote_weights[1], Uint128::new(10));
+}
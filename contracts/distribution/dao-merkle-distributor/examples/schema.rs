@@ -0,0 +1,10 @@
+use cosmwasm_schema::write_api;
+use dao_merkle_distributor::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        query: QueryMsg,
+        execute: ExecuteMsg,
+    }
+}
@@ -0,0 +1,170 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, HexBinary, MessageInfo, Response,
+    StdResult, Uint128,
+};
+use cw2::set_contract_version;
+use cw_denom::{CheckedDenom, UncheckedDenom};
+use cw_utils::Expiration;
+
+use crate::{
+    error::ContractError,
+    merkle::{leaf_hash, verify_proof},
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    state::{Config, CLAIMED, CONFIG},
+};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-merkle-distributor";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let owner = msg
+        .owner
+        .map(|owner| deps.api.addr_validate(&owner))
+        .transpose()?
+        .unwrap_or(info.sender);
+
+    if msg.merkle_root.len() != 32 {
+        return Err(ContractError::InvalidMerkleRoot {});
+    }
+    // Unclaimed funds can only be reclaimed once the distribution
+    // expires, so it must expire at some point.
+    if matches!(msg.expiration, Expiration::Never {}) || msg.expiration.is_expired(&env.block) {
+        return Err(ContractError::InvalidExpiration {});
+    }
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            owner: owner.clone(),
+            merkle_root: msg.merkle_root.clone(),
+            expiration: msg.expiration,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "instantiate")
+        .add_attribute("owner", owner)
+        .add_attribute("merkle_root", msg.merkle_root.to_hex())
+        .add_attribute("expiration", msg.expiration.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Claim {
+            denom,
+            amount,
+            proof,
+        } => execute_claim(deps, env, info, denom, amount, proof),
+        ExecuteMsg::Reclaim { denoms } => execute_reclaim(deps, env, info, denoms),
+    }
+}
+
+pub fn execute_claim(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: UncheckedDenom,
+    amount: Uint128,
+    proof: Vec<HexBinary>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.expiration.is_expired(&env.block) {
+        return Err(ContractError::Expired {});
+    }
+    if amount.is_zero() {
+        return Err(ContractError::ZeroClaim {});
+    }
+
+    let denom = denom.into_checked(deps.as_ref())?;
+    let leaf = leaf_hash(&info.sender, &denom, amount);
+    if CLAIMED.has(deps.storage, &leaf) {
+        return Err(ContractError::AlreadyClaimed {});
+    }
+    if !verify_proof(config.merkle_root.as_slice(), leaf, &proof) {
+        return Err(ContractError::InvalidProof {});
+    }
+    CLAIMED.save(deps.storage, &leaf, &Empty {})?;
+
+    Ok(Response::default()
+        .add_message(denom.get_transfer_to_message(&info.sender, amount)?)
+        .add_attribute("action", "claim")
+        .add_attribute("address", info.sender)
+        .add_attribute("denom", denom.to_string())
+        .add_attribute("amount", amount))
+}
+
+pub fn execute_reclaim(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denoms: Vec<UncheckedDenom>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+    if !config.expiration.is_expired(&env.block) {
+        return Err(ContractError::NotExpired {});
+    }
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    for denom in denoms {
+        let denom = denom.into_checked(deps.as_ref())?;
+        let balance = denom.query_balance(&deps.querier, &env.contract.address)?;
+        if !balance.is_zero() {
+            msgs.push(denom.get_transfer_to_message(&config.owner, balance)?);
+        }
+    }
+    if msgs.is_empty() {
+        return Err(ContractError::NothingToReclaim {});
+    }
+
+    Ok(Response::default()
+        .add_messages(msgs)
+        .add_attribute("action", "reclaim")
+        .add_attribute("owner", config.owner))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::IsClaimed {
+            address,
+            denom,
+            amount,
+        } => to_json_binary(&query_is_claimed(deps, address, denom, amount)?),
+    }
+}
+
+pub fn query_is_claimed(
+    deps: Deps,
+    address: String,
+    denom: UncheckedDenom,
+    amount: Uint128,
+) -> StdResult<bool> {
+    let address = deps.api.addr_validate(&address)?;
+    // Only the leaf is needed, so there is no need to check that a
+    // cw20 responds to queries.
+    let denom = match denom {
+        UncheckedDenom::Native(denom) => CheckedDenom::Native(denom),
+        UncheckedDenom::Cw20(contract) => CheckedDenom::Cw20(deps.api.addr_validate(&contract)?),
+    };
+    Ok(CLAIMED.has(deps.storage, &leaf_hash(&address, &denom, amount)))
+}
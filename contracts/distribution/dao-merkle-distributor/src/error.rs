@@ -0,0 +1,39 @@
+use cosmwasm_std::StdError;
+use cw_denom::DenomError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Denom(#[from] DenomError),
+
+    #[error("unauthorized")]
+    Unauthorized {},
+
+    #[error("merkle root must be a 32 byte sha256 hash")]
+    InvalidMerkleRoot {},
+
+    #[error("expiration must be a height or time in the future")]
+    InvalidExpiration {},
+
+    #[error("proof does not match the merkle root")]
+    InvalidProof {},
+
+    #[error("claim amount must be non-zero")]
+    ZeroClaim {},
+
+    #[error("allocation has already been claimed")]
+    AlreadyClaimed {},
+
+    #[error("the distribution has expired")]
+    Expired {},
+
+    #[error("unclaimed funds may only be reclaimed after the distribution expires")]
+    NotExpired {},
+
+    #[error("nothing to reclaim")]
+    NothingToReclaim {},
+}
@@ -0,0 +1,12 @@
+#![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
+
+pub mod contract;
+mod error;
+pub mod merkle;
+pub mod msg;
+pub mod state;
+
+#[cfg(test)]
+mod testing;
+
+pub use crate::error::ContractError;
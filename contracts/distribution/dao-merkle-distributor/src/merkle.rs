@@ -0,0 +1,42 @@
+//! Merkle tree hashing. Off-chain tooling building a tree of
+//! allocations must hash leaves and nodes the same way.
+
+use cosmwasm_std::{Addr, HexBinary, Uint128};
+use cw_denom::CheckedDenom;
+use sha2::{Digest, Sha256};
+
+/// Hashes an allocation of `amount` of `denom` to `address`. The
+/// leaf is the sha256 hash of `{address},native:{denom},{amount}`
+/// for native denoms, and `{address},cw20:{contract},{amount}` for
+/// cw20 tokens. For example, `juno1...,native:ujuno,100`.
+pub fn leaf_hash(address: &Addr, denom: &CheckedDenom, amount: Uint128) -> [u8; 32] {
+    let denom = match denom {
+        CheckedDenom::Native(denom) => format!("native:{denom}"),
+        CheckedDenom::Cw20(contract) => format!("cw20:{contract}"),
+    };
+    Sha256::digest(format!("{address},{denom},{amount}").as_bytes()).into()
+}
+
+/// Hashes two sibling nodes into their parent. The siblings are
+/// sorted before hashing, so proofs do not need to record whether
+/// each sibling is on the left or right.
+pub fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut hasher = Sha256::new();
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Checks that `proof` proves `leaf` is in the tree with root
+/// `root`. Returns false if any node in the proof is not 32 bytes.
+pub fn verify_proof(root: &[u8], leaf: [u8; 32], proof: &[HexBinary]) -> bool {
+    let mut hash = leaf;
+    for sibling in proof {
+        match <[u8; 32]>::try_from(sibling.as_slice()) {
+            Ok(sibling) => hash = node_hash(&hash, &sibling),
+            Err(_) => return false,
+        }
+    }
+    hash.as_slice() == root
+}
@@ -0,0 +1,50 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{HexBinary, Uint128};
+use cw_denom::UncheckedDenom;
+use cw_utils::Expiration;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The address that may reclaim unclaimed funds after the
+    /// distribution expires. Defaults to the instantiator.
+    pub owner: Option<String>,
+    /// The root of the merkle tree of allocations. See the `merkle`
+    /// module for how leaves and nodes are hashed.
+    pub merkle_root: HexBinary,
+    /// When allocations may no longer be claimed, and unclaimed funds
+    /// may be reclaimed by the owner.
+    pub expiration: Expiration,
+}
+
+#[cw_serde]
+#[derive(cw_orch::ExecuteFns)]
+pub enum ExecuteMsg {
+    /// Claims the sender's allocation of `amount` of `denom`. `proof`
+    /// is the list of sibling hashes from the allocation's leaf to
+    /// the merkle root.
+    Claim {
+        denom: UncheckedDenom,
+        amount: Uint128,
+        proof: Vec<HexBinary>,
+    },
+    /// Callable by the owner once the distribution has expired. Sends
+    /// the contract's remaining balance of each of `denoms` to the
+    /// owner.
+    Reclaim { denoms: Vec<UncheckedDenom> },
+}
+
+#[cw_serde]
+#[derive(QueryResponses, cw_orch::QueryFns)]
+pub enum QueryMsg {
+    /// Gets the contract's config.
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Gets whether `address` has claimed its allocation of `amount`
+    /// of `denom`.
+    #[returns(bool)]
+    IsClaimed {
+        address: String,
+        denom: UncheckedDenom,
+        amount: Uint128,
+    },
+}
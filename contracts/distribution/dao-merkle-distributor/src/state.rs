@@ -0,0 +1,21 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Empty, HexBinary};
+use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
+
+#[cw_serde]
+pub struct Config {
+    /// The address that may reclaim unclaimed funds after the
+    /// distribution expires. Usually the DAO.
+    pub owner: Addr,
+    /// The root of the merkle tree of allocations.
+    pub merkle_root: HexBinary,
+    /// When allocations may no longer be claimed, and unclaimed funds
+    /// may be reclaimed by the owner.
+    pub expiration: Expiration,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// The leaves of the merkle tree that have been claimed.
+pub const CLAIMED: Map<&[u8], Empty> = Map::new("claimed");
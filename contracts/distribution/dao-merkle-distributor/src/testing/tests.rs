@@ -0,0 +1,321 @@
+use cosmwasm_std::{coins, Addr, Empty, HexBinary, Uint128};
+use cw_denom::{CheckedDenom, UncheckedDenom};
+use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor, SudoMsg};
+use cw_utils::Expiration;
+use dao_testing::contracts::cw20_base_contract;
+
+use crate::{
+    merkle::{leaf_hash, node_hash},
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    ContractError,
+};
+
+const OWNER: &str = "dao";
+const DENOM: &str = "ujuno";
+
+fn merkle_distributor_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    ))
+}
+
+/// Builds a merkle tree from `leaves`, returning its root and a proof
+/// for each leaf. A node without a sibling is carried up to the next
+/// level unchanged.
+fn merkle_tree(leaves: &[[u8; 32]]) -> (HexBinary, Vec<Vec<HexBinary>>) {
+    let mut proofs = vec![vec![]; leaves.len()];
+    // The index of each leaf's ancestor in the current level.
+    let mut indices: Vec<usize> = (0..leaves.len()).collect();
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        for (proof, index) in proofs.iter_mut().zip(indices.iter_mut()) {
+            let sibling = *index ^ 1;
+            if sibling < level.len() {
+                proof.push(HexBinary::from(level[sibling].as_slice()));
+            }
+            *index /= 2;
+        }
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => node_hash(a, b),
+                [a] => *a,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    (HexBinary::from(level[0].as_slice()), proofs)
+}
+
+struct Setup {
+    app: App,
+    distributor: Addr,
+    cw20: Addr,
+    proofs: Vec<Vec<HexBinary>>,
+}
+
+/// Allocates 100 ujuno to alice, 50 ujuno to bob, and 30 of a cw20
+/// to alice, and funds the distributor with 200 ujuno and 30 of the
+/// cw20. The distribution expires at height 12_400.
+fn setup() -> Setup {
+    let mut app = App::default();
+    let cw20_id = app.store_code(cw20_base_contract());
+    let distributor_id = app.store_code(merkle_distributor_contract());
+
+    let cw20 = app
+        .instantiate_contract(
+            cw20_id,
+            Addr::unchecked(OWNER),
+            &cw20_base::msg::InstantiateMsg {
+                name: "token".to_string(),
+                symbol: "TOKEN".to_string(),
+                decimals: 6,
+                initial_balances: vec![cw20::Cw20Coin {
+                    address: OWNER.to_string(),
+                    amount: Uint128::new(30),
+                }],
+                mint: None,
+                marketing: None,
+            },
+            &[],
+            "cw20",
+            None,
+        )
+        .unwrap();
+
+    let leaves = [
+        leaf_hash(
+            &Addr::unchecked("alice"),
+            &CheckedDenom::Native(DENOM.to_string()),
+            Uint128::new(100),
+        ),
+        leaf_hash(
+            &Addr::unchecked("bob"),
+            &CheckedDenom::Native(DENOM.to_string()),
+            Uint128::new(50),
+        ),
+        leaf_hash(
+            &Addr::unchecked("alice"),
+            &CheckedDenom::Cw20(cw20.clone()),
+            Uint128::new(30),
+        ),
+    ];
+    let (merkle_root, proofs) = merkle_tree(&leaves);
+
+    let distributor = app
+        .instantiate_contract(
+            distributor_id,
+            Addr::unchecked(OWNER),
+            &InstantiateMsg {
+                owner: None,
+                merkle_root,
+                expiration: Expiration::AtHeight(12_400),
+            },
+            &[],
+            "merkle distributor",
+            None,
+        )
+        .unwrap();
+
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: distributor.to_string(),
+        amount: coins(200, DENOM),
+    }))
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(OWNER),
+        cw20.clone(),
+        &cw20::Cw20ExecuteMsg::Transfer {
+            recipient: distributor.to_string(),
+            amount: Uint128::new(30),
+        },
+        &[],
+    )
+    .unwrap();
+
+    Setup {
+        app,
+        distributor,
+        cw20,
+        proofs,
+    }
+}
+
+fn claim(
+    setup: &mut Setup,
+    sender: &str,
+    denom: UncheckedDenom,
+    amount: u128,
+    proof: usize,
+) -> Result<(), ContractError> {
+    setup
+        .app
+        .execute_contract(
+            Addr::unchecked(sender),
+            setup.distributor.clone(),
+            &ExecuteMsg::Claim {
+                denom,
+                amount: Uint128::new(amount),
+                proof: setup.proofs[proof].clone(),
+            },
+            &[],
+        )
+        .map(|_| ())
+        .map_err(|e| e.downcast().unwrap())
+}
+
+fn reclaim(
+    setup: &mut Setup,
+    sender: &str,
+    denoms: Vec<UncheckedDenom>,
+) -> Result<(), ContractError> {
+    setup
+        .app
+        .execute_contract(
+            Addr::unchecked(sender),
+            setup.distributor.clone(),
+            &ExecuteMsg::Reclaim { denoms },
+            &[],
+        )
+        .map(|_| ())
+        .map_err(|e| e.downcast().unwrap())
+}
+
+fn cw20_balance(setup: &Setup, address: &str) -> u128 {
+    let balance: cw20::BalanceResponse = setup
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &setup.cw20,
+            &cw20::Cw20QueryMsg::Balance {
+                address: address.to_string(),
+            },
+        )
+        .unwrap();
+    balance.balance.u128()
+}
+
+fn native_balance(setup: &Setup, address: &str) -> u128 {
+    setup
+        .app
+        .wrap()
+        .query_balance(address, DENOM)
+        .unwrap()
+        .amount
+        .u128()
+}
+
+#[test]
+fn test_claim() {
+    let mut setup = setup();
+    let native = UncheckedDenom::Native(DENOM.to_string());
+    let cw20 = UncheckedDenom::Cw20(setup.cw20.to_string());
+
+    claim(&mut setup, "alice", native.clone(), 100, 0).unwrap();
+    assert_eq!(native_balance(&setup, "alice"), 100);
+
+    // Allocations may only be claimed once.
+    let err = claim(&mut setup, "alice", native.clone(), 100, 0).unwrap_err();
+    assert_eq!(err, ContractError::AlreadyClaimed {});
+
+    // Claims must match an allocation exactly.
+    let err = claim(&mut setup, "bob", native.clone(), 60, 1).unwrap_err();
+    assert_eq!(err, ContractError::InvalidProof {});
+    let err = claim(&mut setup, "bob", cw20.clone(), 50, 1).unwrap_err();
+    assert_eq!(err, ContractError::InvalidProof {});
+    let err = claim(&mut setup, "carol", native.clone(), 50, 1).unwrap_err();
+    assert_eq!(err, ContractError::InvalidProof {});
+    let err = claim(&mut setup, "bob", native.clone(), 0, 1).unwrap_err();
+    assert_eq!(err, ContractError::ZeroClaim {});
+
+    claim(&mut setup, "bob", native.clone(), 50, 1).unwrap();
+    assert_eq!(native_balance(&setup, "bob"), 50);
+
+    claim(&mut setup, "alice", cw20.clone(), 30, 2).unwrap();
+    assert_eq!(cw20_balance(&setup, "alice"), 30);
+
+    let is_claimed = |setup: &Setup, address: &str, denom: UncheckedDenom, amount: u128| {
+        setup
+            .app
+            .wrap()
+            .query_wasm_smart::<bool>(
+                &setup.distributor,
+                &QueryMsg::IsClaimed {
+                    address: address.to_string(),
+                    denom,
+                    amount: Uint128::new(amount),
+                },
+            )
+            .unwrap()
+    };
+    assert!(is_claimed(&setup, "alice", native.clone(), 100));
+    assert!(is_claimed(&setup, "alice", cw20, 30));
+    assert!(!is_claimed(&setup, "alice", native, 50));
+}
+
+#[test]
+fn test_reclaim() {
+    let mut setup = setup();
+    let native = UncheckedDenom::Native(DENOM.to_string());
+    let cw20 = UncheckedDenom::Cw20(setup.cw20.to_string());
+
+    claim(&mut setup, "alice", native.clone(), 100, 0).unwrap();
+
+    let err = reclaim(&mut setup, OWNER, vec![native.clone()]).unwrap_err();
+    assert_eq!(err, ContractError::NotExpired {});
+
+    setup.app.update_block(|block| block.height = 12_400);
+
+    let err = claim(&mut setup, "bob", native.clone(), 50, 1).unwrap_err();
+    assert_eq!(err, ContractError::Expired {});
+
+    let err = reclaim(&mut setup, "alice", vec![native.clone()]).unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    reclaim(&mut setup, OWNER, vec![native.clone(), cw20.clone()]).unwrap();
+    assert_eq!(native_balance(&setup, OWNER), 100);
+    assert_eq!(cw20_balance(&setup, OWNER), 30);
+    assert_eq!(native_balance(&setup, setup.distributor.as_str()), 0);
+
+    let err = reclaim(&mut setup, OWNER, vec![native, cw20]).unwrap_err();
+    assert_eq!(err, ContractError::NothingToReclaim {});
+}
+
+#[test]
+fn test_instantiate_invalid() {
+    let mut app = App::default();
+    let distributor_id = app.store_code(merkle_distributor_contract());
+    let mut instantiate = |merkle_root: HexBinary, expiration: Expiration| -> ContractError {
+        app.instantiate_contract(
+            distributor_id,
+            Addr::unchecked(OWNER),
+            &InstantiateMsg {
+                owner: None,
+                merkle_root,
+                expiration,
+            },
+            &[],
+            "merkle distributor",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap()
+    };
+
+    let root = HexBinary::from([0u8; 32].as_slice());
+
+    let err = instantiate(
+        HexBinary::from([0u8; 31].as_slice()),
+        Expiration::AtHeight(20_000),
+    );
+    assert_eq!(err, ContractError::InvalidMerkleRoot {});
+
+    let err = instantiate(root.clone(), Expiration::Never {});
+    assert_eq!(err, ContractError::InvalidExpiration {});
+
+    let err = instantiate(root, Expiration::AtHeight(1));
+    assert_eq!(err, ContractError::InvalidExpiration {});
+}
@@ -0,0 +1,518 @@
+use cosmwasm_schema::cw_serde;
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdResult, Storage, Uint128, Uint256,
+};
+use cw2::set_contract_version;
+use cw20::Cw20ReceiveMsg;
+use cw_denom::CheckedDenom;
+use cw_utils::Duration;
+use dao_hooks::{proposal::ProposalHookMsg, vote::VoteHookMsg};
+use dao_interface::state::ProposalModuleStatus;
+use serde::Deserialize;
+
+use crate::{
+    error::ContractError,
+    msg::{EpochResponse, ExecuteMsg, InstantiateMsg, QueryMsg, ReceiveMsg, RewardsResponse},
+    state::{
+        Claim, Config, Payout, CLAIMS, CLOSED, CONFIG, EPOCH_CLAIMED, EPOCH_FUNDS, EPOCH_PAID,
+        EPOCH_POWER, PROPOSAL_POWER, VOTES,
+    },
+};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-participation-rewards";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The query `dao-proposal-single` and `dao-proposal-multiple` both
+/// answer with a voter's ballot.
+#[cw_serde]
+enum ProposalModuleQuery {
+    GetVote { proposal_id: u64, voter: String },
+}
+
+/// The parts of a `GetVote` response common to both proposal
+/// modules. Their votes have different types, so only the power is
+/// read.
+#[derive(Deserialize)]
+struct VoteResponse {
+    vote: Option<Ballot>,
+}
+
+#[derive(Deserialize)]
+struct Ballot {
+    power: Uint128,
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let start = match msg.epoch_duration {
+        Duration::Height(0) | Duration::Time(0) => {
+            return Err(ContractError::InvalidEpochDuration {})
+        }
+        Duration::Height(_) => env.block.height,
+        Duration::Time(_) => env.block.time.seconds(),
+    };
+    let dao = deps.api.addr_validate(&msg.dao)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            dao: dao.clone(),
+            epoch_duration: msg.epoch_duration,
+            start,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", dao))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::VoteHook(msg) => execute_vote_hook(deps, env, info, msg),
+        ExecuteMsg::ProposalHook(msg) => execute_proposal_hook(deps, env, info, msg),
+        ExecuteMsg::Fund { epoch } => execute_fund_native(deps, env, info, epoch),
+        ExecuteMsg::Receive(msg) => execute_receive_cw20(deps, env, info, msg),
+        ExecuteMsg::Claim { epoch } => execute_claim(deps, env, info, epoch),
+        ExecuteMsg::Withdraw { epoch } => execute_withdraw(deps, env, info, epoch),
+    }
+}
+
+/// Errors unless `sender` is an enabled proposal module of the DAO.
+fn check_proposal_module(deps: Deps, dao: &Addr, sender: &Addr) -> Result<(), ContractError> {
+    let module =
+        dao_dao_core::state::PROPOSAL_MODULES.query(&deps.querier, dao.clone(), sender.clone())?;
+    match module {
+        Some(module) if module.status == ProposalModuleStatus::Enabled => Ok(()),
+        _ => Err(ContractError::UnknownProposalModule {
+            module: sender.clone(),
+        }),
+    }
+}
+
+pub fn execute_vote_hook(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: VoteHookMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    check_proposal_module(deps.as_ref(), &config.dao, &info.sender)?;
+
+    let VoteHookMsg::NewVote {
+        proposal_id, voter, ..
+    } = msg;
+    let voter = deps.api.addr_validate(&voter)?;
+    let module = info.sender;
+
+    // Revotes do not change the power behind a ballot, so only the
+    // first vote is counted.
+    if VOTES.has(deps.storage, (&voter, &module, proposal_id)) {
+        return Ok(Response::default().add_attribute("action", "vote_hook"));
+    }
+
+    // Proposals keep accepting votes after they pass or are rejected.
+    // Votes cast once the epoch the proposal closed in has ended are
+    // not counted, as its rewards may already have been claimed.
+    let closed = CLOSED.may_load(deps.storage, (&module, proposal_id))?;
+    if closed.is_some_and(|epoch| epoch < config.epoch_at(&env.block)) {
+        return Ok(Response::default().add_attribute("action", "vote_hook"));
+    }
+
+    let vote: VoteResponse = deps.querier.query_wasm_smart(
+        &module,
+        &ProposalModuleQuery::GetVote {
+            proposal_id,
+            voter: voter.to_string(),
+        },
+    )?;
+    let power = vote.vote.map(|ballot| ballot.power).unwrap_or_default();
+    if power.is_zero() {
+        return Ok(Response::default().add_attribute("action", "vote_hook"));
+    }
+
+    VOTES.save(deps.storage, (&voter, &module, proposal_id), &power)?;
+    PROPOSAL_POWER.update(
+        deps.storage,
+        (&module, proposal_id),
+        |total| -> StdResult<_> { Ok(total.unwrap_or_default().checked_add(power)?) },
+    )?;
+    // A vote that passes or rejects a proposal is reported after the
+    // proposal's status change, so it counts towards the epoch the
+    // proposal already closed in.
+    if let Some(epoch) = closed {
+        EPOCH_POWER.update(deps.storage, epoch, |total| -> StdResult<_> {
+            Ok(total.unwrap_or_default().checked_add(power)?)
+        })?;
+    }
+
+    Ok(Response::default()
+        .add_attribute("action", "vote_hook")
+        .add_attribute("proposal_module", module)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("voter", voter)
+        .add_attribute("power", power))
+}
+
+pub fn execute_proposal_hook(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ProposalHookMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    check_proposal_module(deps.as_ref(), &config.dao, &info.sender)?;
+
+    let (id, new_status) = match msg {
+        ProposalHookMsg::ProposalStatusChanged { id, new_status, .. } => (id, new_status),
+        ProposalHookMsg::NewProposal { .. } | ProposalHookMsg::ProposalAmended { .. } => {
+            return Ok(Response::default().add_attribute("action", "proposal_hook"))
+        }
+    };
+    let module = info.sender;
+
+    // A proposal closes the first time it leaves the open status,
+    // whether it passed, was rejected, or was vetoed.
+    if new_status == "open" || CLOSED.has(deps.storage, (&module, id)) {
+        return Ok(Response::default().add_attribute("action", "proposal_hook"));
+    }
+
+    let epoch = config.epoch_at(&env.block);
+    CLOSED.save(deps.storage, (&module, id), &epoch)?;
+    let power = PROPOSAL_POWER
+        .may_load(deps.storage, (&module, id))?
+        .unwrap_or_default();
+    EPOCH_POWER.update(deps.storage, epoch, |total| -> StdResult<_> {
+        Ok(total.unwrap_or_default().checked_add(power)?)
+    })?;
+
+    Ok(Response::default()
+        .add_attribute("action", "proposal_hook")
+        .add_attribute("proposal_module", module)
+        .add_attribute("proposal_id", id.to_string())
+        .add_attribute("epoch", epoch.to_string()))
+}
+
+pub fn execute_fund_native(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    epoch: Option<u64>,
+) -> Result<Response, ContractError> {
+    let funds = info
+        .funds
+        .into_iter()
+        .filter(|coin| !coin.amount.is_zero())
+        .map(|coin| Payout {
+            denom: CheckedDenom::Native(coin.denom),
+            amount: coin.amount,
+        })
+        .collect();
+    execute_fund(deps, env, epoch, funds)
+}
+
+pub fn execute_receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    wrapper: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    match from_json(&wrapper.msg)? {
+        ReceiveMsg::Fund { epoch } => {
+            let funds = if wrapper.amount.is_zero() {
+                vec![]
+            } else {
+                vec![Payout {
+                    denom: CheckedDenom::Cw20(info.sender),
+                    amount: wrapper.amount,
+                }]
+            };
+            execute_fund(deps, env, epoch, funds)
+        }
+    }
+}
+
+fn execute_fund(
+    deps: DepsMut,
+    env: Env,
+    epoch: Option<u64>,
+    funds: Vec<Payout>,
+) -> Result<Response, ContractError> {
+    if funds.is_empty() {
+        return Err(ContractError::NoFunds {});
+    }
+    let config = CONFIG.load(deps.storage)?;
+    let current = config.epoch_at(&env.block);
+    let epoch = epoch.unwrap_or(current);
+    // Voters may have already claimed their share of an ended epoch.
+    if epoch < current {
+        return Err(ContractError::EpochEnded { epoch });
+    }
+
+    let mut epoch_funds = EPOCH_FUNDS
+        .may_load(deps.storage, epoch)?
+        .unwrap_or_default();
+    for payout in funds {
+        match epoch_funds.iter_mut().find(|p| p.denom == payout.denom) {
+            Some(existing) => existing.amount = existing.amount.checked_add(payout.amount)?,
+            None => epoch_funds.push(payout),
+        }
+    }
+    EPOCH_FUNDS.save(deps.storage, epoch, &epoch_funds)?;
+
+    Ok(Response::default()
+        .add_attribute("action", "fund")
+        .add_attribute("epoch", epoch.to_string()))
+}
+
+pub fn execute_claim(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    epoch: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if epoch >= config.epoch_at(&env.block) {
+        return Err(ContractError::EpochNotEnded { epoch });
+    }
+    if CLAIMS.has(deps.storage, (epoch, &info.sender)) {
+        return Err(ContractError::AlreadyClaimed {});
+    }
+
+    let (participation, rewards) = rewards(deps.as_ref(), epoch, &info.sender)?;
+    if participation.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+    // The votes are no longer needed once claimed.
+    for (module, proposal_id) in epoch_votes(deps.storage, epoch, &info.sender)? {
+        VOTES.remove(deps.storage, (&info.sender, &module, proposal_id));
+    }
+    CLAIMS.save(
+        deps.storage,
+        (epoch, &info.sender),
+        &Claim {
+            participation,
+            rewards: rewards.clone(),
+        },
+    )?;
+    EPOCH_CLAIMED.update(deps.storage, epoch, |claimed| -> StdResult<_> {
+        Ok(claimed.unwrap_or_default().checked_add(participation)?)
+    })?;
+    add_paid(deps.storage, epoch, &rewards)?;
+
+    let msgs = rewards
+        .iter()
+        .map(|payout| {
+            payout
+                .denom
+                .get_transfer_to_message(&info.sender, payout.amount)
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(Response::default()
+        .add_messages(msgs)
+        .add_attribute("action", "claim")
+        .add_attribute("epoch", epoch.to_string())
+        .add_attribute("claimer", info.sender)
+        .add_attribute("participation", participation))
+}
+
+pub fn execute_withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    epoch: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    if epoch >= config.epoch_at(&env.block) {
+        return Err(ContractError::EpochNotEnded { epoch });
+    }
+    let participation = EPOCH_POWER
+        .may_load(deps.storage, epoch)?
+        .unwrap_or_default();
+    let unclaimed = participation.saturating_sub(
+        EPOCH_CLAIMED
+            .may_load(deps.storage, epoch)?
+            .unwrap_or_default(),
+    );
+    let paid = EPOCH_PAID
+        .may_load(deps.storage, epoch)?
+        .unwrap_or_default();
+
+    // Voters who have not claimed are owed at most their share rounded
+    // up, so whatever is left once that and what was paid is set aside
+    // belongs to no one.
+    let funds = EPOCH_FUNDS
+        .may_load(deps.storage, epoch)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|payout| {
+            let owed = if unclaimed.is_zero() {
+                Uint128::zero()
+            } else {
+                multiply_ratio_ceil(payout.amount, unclaimed, participation)?
+            };
+            let paid = paid
+                .iter()
+                .find(|p| p.denom == payout.denom)
+                .map(|p| p.amount)
+                .unwrap_or_default();
+            Ok(Payout {
+                amount: payout.amount.saturating_sub(paid).saturating_sub(owed),
+                denom: payout.denom,
+            })
+        })
+        .filter(|payout| !matches!(payout, Ok(payout) if payout.amount.is_zero()))
+        .collect::<StdResult<Vec<_>>>()?;
+    if funds.is_empty() {
+        return Err(ContractError::NothingToWithdraw {});
+    }
+    add_paid(deps.storage, epoch, &funds)?;
+
+    let msgs = funds
+        .iter()
+        .map(|payout| {
+            payout
+                .denom
+                .get_transfer_to_message(&config.dao, payout.amount)
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(Response::default()
+        .add_messages(msgs)
+        .add_attribute("action", "withdraw")
+        .add_attribute("epoch", epoch.to_string()))
+}
+
+/// The (proposal module, proposal ID) of the votes `address` cast on
+/// proposals that closed during `epoch` and it has not yet claimed.
+fn epoch_votes(storage: &dyn Storage, epoch: u64, address: &Addr) -> StdResult<Vec<(Addr, u64)>> {
+    let mut votes = vec![];
+    for vote in VOTES
+        .sub_prefix(address)
+        .keys(storage, None, None, Order::Ascending)
+    {
+        let (module, proposal_id) = vote?;
+        if CLOSED.may_load(storage, (&module, proposal_id))? == Some(epoch) {
+            votes.push((module, proposal_id));
+        }
+    }
+    Ok(votes)
+}
+
+/// Adds `payouts` to what has been paid out of `epoch`'s rewards.
+fn add_paid(storage: &mut dyn Storage, epoch: u64, payouts: &[Payout]) -> StdResult<()> {
+    let mut paid = EPOCH_PAID.may_load(storage, epoch)?.unwrap_or_default();
+    for payout in payouts {
+        match paid.iter_mut().find(|p| p.denom == payout.denom) {
+            Some(existing) => existing.amount = existing.amount.checked_add(payout.amount)?,
+            None => paid.push(payout.clone()),
+        }
+    }
+    EPOCH_PAID.save(storage, epoch, &paid)
+}
+
+/// `amount * numerator / denominator`, rounded up.
+fn multiply_ratio_ceil(
+    amount: Uint128,
+    numerator: Uint128,
+    denominator: Uint128,
+) -> StdResult<Uint128> {
+    let product = amount.full_mul(numerator);
+    let denominator = Uint256::from(denominator);
+    let mut result = product / denominator;
+    if !(product % denominator).is_zero() {
+        result += Uint256::one();
+    }
+    Ok(result.try_into()?)
+}
+
+/// Computes the voting power `address` cast on proposals that closed
+/// during `epoch`, and its share of the epoch's rewards.
+fn rewards(deps: Deps, epoch: u64, address: &Addr) -> StdResult<(Uint128, Vec<Payout>)> {
+    let mut participation = Uint128::zero();
+    for (module, proposal_id) in epoch_votes(deps.storage, epoch, address)? {
+        participation = participation
+            .checked_add(VOTES.load(deps.storage, (address, &module, proposal_id))?)?;
+    }
+    if participation.is_zero() {
+        return Ok((participation, vec![]));
+    }
+
+    let total = EPOCH_POWER.load(deps.storage, epoch)?;
+    let rewards = EPOCH_FUNDS
+        .may_load(deps.storage, epoch)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|payout| Payout {
+            amount: payout.amount.multiply_ratio(participation, total),
+            denom: payout.denom,
+        })
+        .filter(|payout| !payout.amount.is_zero())
+        .collect();
+    Ok((participation, rewards))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::CurrentEpoch {} => {
+            to_json_binary(&CONFIG.load(deps.storage)?.epoch_at(&env.block))
+        }
+        QueryMsg::Epoch { epoch } => query_epoch(deps, epoch),
+        QueryMsg::Rewards { epoch, address } => query_rewards(deps, epoch, address),
+    }
+}
+
+pub fn query_epoch(deps: Deps, epoch: u64) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    to_json_binary(&EpochResponse {
+        epoch,
+        ends_at: config.epoch_end(epoch),
+        participation: EPOCH_POWER
+            .may_load(deps.storage, epoch)?
+            .unwrap_or_default(),
+        funds: EPOCH_FUNDS
+            .may_load(deps.storage, epoch)?
+            .unwrap_or_default(),
+    })
+}
+
+pub fn query_rewards(deps: Deps, epoch: u64, address: String) -> StdResult<Binary> {
+    let address = deps.api.addr_validate(&address)?;
+    let response = match CLAIMS.may_load(deps.storage, (epoch, &address))? {
+        Some(claim) => RewardsResponse {
+            participation: claim.participation,
+            rewards: claim.rewards,
+            claimed: true,
+        },
+        None => {
+            let (participation, rewards) = rewards(deps, epoch, &address)?;
+            RewardsResponse {
+                participation,
+                rewards,
+                claimed: false,
+            }
+        }
+    };
+    to_json_binary(&response)
+}
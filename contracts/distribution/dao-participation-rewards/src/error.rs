@@ -0,0 +1,39 @@
+use cosmwasm_std::{Addr, StdError};
+use cw_denom::DenomError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Denom(#[from] DenomError),
+
+    #[error("unauthorized")]
+    Unauthorized {},
+
+    #[error("epoch duration must be non-zero")]
+    InvalidEpochDuration {},
+
+    #[error("({module}) is not an enabled proposal module of this DAO")]
+    UnknownProposalModule { module: Addr },
+
+    #[error("must send non-zero funds")]
+    NoFunds {},
+
+    #[error("epoch ({epoch}) has ended")]
+    EpochEnded { epoch: u64 },
+
+    #[error("epoch ({epoch}) has not ended")]
+    EpochNotEnded { epoch: u64 },
+
+    #[error("already claimed rewards for this epoch")]
+    AlreadyClaimed {},
+
+    #[error("no rewards to claim")]
+    NothingToClaim {},
+
+    #[error("no rewards to withdraw")]
+    NothingToWithdraw {},
+}
@@ -0,0 +1,90 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+use cw20::Cw20ReceiveMsg;
+use cw_utils::{Duration, Expiration};
+use dao_hooks::{proposal::ProposalHookMsg, vote::VoteHookMsg};
+
+use crate::state::Payout;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The DAO whose proposal modules will report votes to this
+    /// contract. The DAO must add this contract as a vote hook and a
+    /// proposal hook of each proposal module whose votes should be
+    /// rewarded.
+    pub dao: String,
+    /// How long each epoch lasts. The first epoch starts when the
+    /// contract is instantiated.
+    pub epoch_duration: Duration,
+}
+
+#[cw_serde]
+#[derive(cw_orch::ExecuteFns)]
+pub enum ExecuteMsg {
+    /// Called by a proposal module of the DAO when a vote is cast.
+    VoteHook(VoteHookMsg),
+    /// Called by a proposal module of the DAO when a proposal is
+    /// created or changes status.
+    ProposalHook(ProposalHookMsg),
+    /// Funds the rewards of an epoch with native tokens. Defaults to
+    /// the current epoch.
+    #[cw_orch(payable)]
+    Fund { epoch: Option<u64> },
+    /// Used to fund the rewards of an epoch with cw20 tokens.
+    Receive(Cw20ReceiveMsg),
+    /// Claims the sender's share of the rewards of an epoch that has
+    /// ended.
+    Claim { epoch: u64 },
+    /// Callable by the DAO. Sends the rewards of an ended epoch that
+    /// are not owed to voters to the DAO. That is all of them if no
+    /// votes were counted, and otherwise what is left to rounding once
+    /// voters have claimed.
+    Withdraw { epoch: u64 },
+}
+
+#[cw_serde]
+pub enum ReceiveMsg {
+    /// Funds the rewards of an epoch. Defaults to the current epoch.
+    Fund { epoch: Option<u64> },
+}
+
+#[cw_serde]
+#[derive(QueryResponses, cw_orch::QueryFns)]
+pub enum QueryMsg {
+    /// Gets the contract's config.
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Gets the epoch in progress.
+    #[returns(u64)]
+    CurrentEpoch {},
+    /// Gets the participation and rewards of an epoch.
+    #[returns(EpochResponse)]
+    Epoch { epoch: u64 },
+    /// Gets an address's participation and share of the rewards of an
+    /// epoch.
+    #[returns(RewardsResponse)]
+    Rewards { epoch: u64, address: String },
+}
+
+#[cw_serde]
+pub struct EpochResponse {
+    pub epoch: u64,
+    /// When the epoch ends and its rewards may be claimed.
+    pub ends_at: Expiration,
+    /// The total voting power cast on proposals that closed during
+    /// the epoch.
+    pub participation: Uint128,
+    /// The rewards funded for the epoch.
+    pub funds: Vec<Payout>,
+}
+
+#[cw_serde]
+pub struct RewardsResponse {
+    /// The voting power the address cast on proposals that closed
+    /// during the epoch.
+    pub participation: Uint128,
+    /// The address's share of the epoch's rewards, or what it claimed
+    /// if it has already claimed.
+    pub rewards: Vec<Payout>,
+    pub claimed: bool,
+}
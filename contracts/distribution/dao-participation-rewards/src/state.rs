@@ -0,0 +1,86 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, Timestamp, Uint128};
+use cw_denom::CheckedDenom;
+use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
+
+#[cw_serde]
+pub struct Config {
+    /// The DAO whose proposal modules report votes to this contract,
+    /// and which may withdraw rewards no one earned.
+    pub dao: Addr,
+    /// How long each epoch lasts.
+    pub epoch_duration: Duration,
+    /// When the first epoch started, as a height or a time in seconds
+    /// depending on the unit of `epoch_duration`.
+    pub start: u64,
+}
+
+impl Config {
+    /// The epoch in progress at `block`.
+    pub fn epoch_at(&self, block: &BlockInfo) -> u64 {
+        match self.epoch_duration {
+            Duration::Height(blocks) => (block.height - self.start) / blocks,
+            Duration::Time(seconds) => (block.time.seconds() - self.start) / seconds,
+        }
+    }
+
+    /// When `epoch` ends and its rewards may be claimed.
+    pub fn epoch_end(&self, epoch: u64) -> Expiration {
+        match self.epoch_duration {
+            Duration::Height(blocks) => {
+                Expiration::AtHeight(self.start + blocks.saturating_mul(epoch + 1))
+            }
+            Duration::Time(seconds) => Expiration::AtTime(Timestamp::from_seconds(
+                self.start + seconds.saturating_mul(epoch + 1),
+            )),
+        }
+    }
+}
+
+/// An amount of a denom paid as rewards.
+#[cw_serde]
+pub struct Payout {
+    pub denom: CheckedDenom,
+    pub amount: Uint128,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// What a voter claimed for an epoch.
+#[cw_serde]
+pub struct Claim {
+    pub participation: Uint128,
+    pub rewards: Vec<Payout>,
+}
+
+/// The voting power cast by each voter, keyed by (voter, proposal
+/// module, proposal ID). Only the first vote on a proposal is counted.
+/// Votes are removed once the voter claims the epoch their proposal
+/// closed in, so claiming only walks the voter's own unclaimed votes.
+pub const VOTES: Map<(&Addr, &Addr, u64), Uint128> = Map::new("votes");
+
+/// The total voting power cast on each proposal, keyed by (proposal
+/// module, proposal ID).
+pub const PROPOSAL_POWER: Map<(&Addr, u64), Uint128> = Map::new("proposal_power");
+
+/// The epoch each proposal closed in, keyed by (proposal module,
+/// proposal ID).
+pub const CLOSED: Map<(&Addr, u64), u64> = Map::new("closed");
+
+/// The total voting power cast on proposals that closed in each
+/// epoch.
+pub const EPOCH_POWER: Map<u64, Uint128> = Map::new("epoch_power");
+
+/// The rewards funded for each epoch.
+pub const EPOCH_FUNDS: Map<u64, Vec<Payout>> = Map::new("epoch_funds");
+
+/// The total participation of the voters who claimed each epoch's
+/// rewards.
+pub const EPOCH_CLAIMED: Map<u64, Uint128> = Map::new("epoch_claimed");
+
+/// The rewards of each epoch paid out so far, to voters or to the DAO.
+pub const EPOCH_PAID: Map<u64, Vec<Payout>> = Map::new("epoch_paid");
+
+/// What each voter claimed, keyed by (epoch, voter).
+pub const CLAIMS: Map<(u64, &Addr), Claim> = Map::new("claims");
@@ -0,0 +1,419 @@
+use cosmwasm_std::{coins, to_json_binary, Addr, Empty, Uint128};
+use cw_multi_test::{
+    next_block, App, AppResponse, BankSudo, Contract, ContractWrapper, Executor, SudoMsg,
+};
+use cw_utils::Duration;
+use dao_interface::state::{Admin, ModuleInstantiateInfo, ProposalModule};
+use dao_testing::contracts::{
+    dao_dao_contract, native_staked_balances_voting_contract, proposal_single_contract,
+};
+use dao_voting::{
+    multiple_choice::{
+        MultipleChoiceOption, MultipleChoiceOptions, MultipleChoiceVote, VotingStrategy,
+    },
+    pre_propose::PreProposeInfo,
+    proposal::{MultipleChoiceProposeMsg, SingleChoiceProposeMsg},
+    threshold::{PercentageThreshold, Threshold},
+    voting::Vote,
+};
+
+use crate::msg::{EpochResponse, ExecuteMsg, InstantiateMsg, QueryMsg, RewardsResponse};
+
+pub(crate) const STAKE_DENOM: &str = "ujuno";
+pub(crate) const REWARD_DENOM: &str = "uatom";
+pub(crate) const EPOCH_BLOCKS: u64 = 50;
+pub(crate) const VOTING_PERIOD_BLOCKS: u64 = 10;
+
+fn participation_rewards_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    ))
+}
+
+fn proposal_multiple_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(
+        ContractWrapper::new(
+            dao_proposal_multiple::contract::execute,
+            dao_proposal_multiple::contract::instantiate,
+            dao_proposal_multiple::contract::query,
+        )
+        .with_reply(dao_proposal_multiple::contract::reply),
+    )
+}
+
+pub(crate) struct Suite {
+    pub app: App,
+    pub core: Addr,
+    pub proposal_single: Addr,
+    pub proposal_multiple: Addr,
+    pub rewards: Addr,
+}
+
+impl Suite {
+    pub fn new() -> Self {
+        let mut app = App::default();
+
+        let core_id = app.store_code(dao_dao_contract());
+        let single_id = app.store_code(proposal_single_contract());
+        let multiple_id = app.store_code(proposal_multiple_contract());
+        let rewards_id = app.store_code(participation_rewards_contract());
+
+        let members = [("alice", 60), ("bob", 30), ("carol", 10)];
+        for (addr, amount) in members {
+            app.sudo(SudoMsg::Bank(BankSudo::Mint {
+                to_address: addr.to_string(),
+                amount: coins(amount, STAKE_DENOM),
+            }))
+            .unwrap();
+        }
+
+        let core = app
+            .instantiate_contract(
+                core_id,
+                Addr::unchecked("creator"),
+                &dao_interface::msg::InstantiateMsg {
+                    dao_uri: None,
+                    admin: None,
+                    name: "DAO".to_string(),
+                    description: "A DAO that rewards voting.".to_string(),
+                    image_url: None,
+                    automatically_add_cw20s: true,
+                    automatically_add_cw721s: true,
+                    voting_module_instantiate_info: ModuleInstantiateInfo {
+                        code_id: app.store_code(native_staked_balances_voting_contract()),
+                        msg: to_json_binary(&dao_voting_token_staked::msg::InstantiateMsg {
+                            token_info: dao_voting_token_staked::msg::TokenInfo::Existing {
+                                denom: STAKE_DENOM.to_string(),
+                            },
+                            unstaking_duration: None,
+                            active_threshold: None,
+                        })
+                        .unwrap(),
+                        admin: Some(Admin::CoreModule {}),
+                        funds: vec![],
+                        label: "voting module".to_string(),
+                    },
+                    proposal_modules_instantiate_info: vec![
+                        ModuleInstantiateInfo {
+                            code_id: single_id,
+                            msg: to_json_binary(&dao_proposal_single::msg::InstantiateMsg {
+                                threshold: Threshold::AbsolutePercentage {
+                                    percentage: PercentageThreshold::Majority {},
+                                },
+                                max_voting_period: Duration::Height(VOTING_PERIOD_BLOCKS),
+                                min_voting_period: None,
+                                only_members_execute: false,
+                                allow_revoting: false,
+                                pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+                                close_proposal_on_execution_failure: true,
+                                review_period: None,
+                                veto: None,
+                            })
+                            .unwrap(),
+                            admin: Some(Admin::CoreModule {}),
+                            funds: vec![],
+                            label: "proposal single".to_string(),
+                        },
+                        ModuleInstantiateInfo {
+                            code_id: multiple_id,
+                            msg: to_json_binary(&dao_proposal_multiple::msg::InstantiateMsg {
+                                voting_strategy: VotingStrategy::SingleChoice {
+                                    quorum: PercentageThreshold::Majority {},
+                                },
+                                max_voting_period: Duration::Height(VOTING_PERIOD_BLOCKS),
+                                min_voting_period: None,
+                                only_members_execute: false,
+                                allow_revoting: false,
+                                pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+                                close_proposal_on_execution_failure: true,
+                                veto: None,
+                            })
+                            .unwrap(),
+                            admin: Some(Admin::CoreModule {}),
+                            funds: vec![],
+                            label: "proposal multiple".to_string(),
+                        },
+                    ],
+                    initial_items: None,
+                },
+                &[],
+                "DAO",
+                None,
+            )
+            .unwrap();
+
+        let modules: Vec<ProposalModule> = app
+            .wrap()
+            .query_wasm_smart(
+                &core,
+                &dao_interface::msg::QueryMsg::ProposalModules {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        let module_with_code = |app: &App, code_id: u64| {
+            modules
+                .iter()
+                .find(|m| {
+                    app.wrap()
+                        .query_wasm_contract_info(&m.address)
+                        .unwrap()
+                        .code_id
+                        == code_id
+                })
+                .unwrap()
+                .address
+                .clone()
+        };
+        let proposal_single = module_with_code(&app, single_id);
+        let proposal_multiple = module_with_code(&app, multiple_id);
+
+        let voting_module: Addr = app
+            .wrap()
+            .query_wasm_smart(&core, &dao_interface::msg::QueryMsg::VotingModule {})
+            .unwrap();
+        for (addr, amount) in members {
+            app.execute_contract(
+                Addr::unchecked(addr),
+                voting_module.clone(),
+                &dao_voting_token_staked::msg::ExecuteMsg::Stake {},
+                &coins(amount, STAKE_DENOM),
+            )
+            .unwrap();
+        }
+        app.update_block(next_block);
+
+        let rewards = app
+            .instantiate_contract(
+                rewards_id,
+                core.clone(),
+                &InstantiateMsg {
+                    dao: core.to_string(),
+                    epoch_duration: Duration::Height(EPOCH_BLOCKS),
+                },
+                &[],
+                "participation rewards",
+                None,
+            )
+            .unwrap();
+
+        // Both proposal modules report votes and status changes with
+        // the same hook messages.
+        for module in [&proposal_single, &proposal_multiple] {
+            app.execute_contract(
+                core.clone(),
+                module.clone(),
+                &dao_proposal_single::msg::ExecuteMsg::AddVoteHook {
+                    address: rewards.to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+            app.execute_contract(
+                core.clone(),
+                module.clone(),
+                &dao_proposal_single::msg::ExecuteMsg::AddProposalHook {
+                    address: rewards.to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        }
+
+        Suite {
+            app,
+            core,
+            proposal_single,
+            proposal_multiple,
+            rewards,
+        }
+    }
+}
+
+impl Suite {
+    pub fn advance_blocks(&mut self, blocks: u64) {
+        self.app.update_block(|block| {
+            block.height += blocks;
+            block.time = block.time.plus_seconds(5 * blocks);
+        });
+    }
+
+    pub fn propose_single(&mut self) -> u64 {
+        self.app
+            .execute_contract(
+                Addr::unchecked("alice"),
+                self.proposal_single.clone(),
+                &dao_proposal_single::msg::ExecuteMsg::Propose(SingleChoiceProposeMsg {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    proposer: None,
+                    vote: None,
+                    schedule: None,
+                }),
+                &[],
+            )
+            .unwrap();
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.proposal_single,
+                &dao_proposal_single::msg::QueryMsg::ProposalCount {},
+            )
+            .unwrap()
+    }
+
+    pub fn propose_multiple(&mut self) -> u64 {
+        let option = |title: &str| MultipleChoiceOption {
+            title: title.to_string(),
+            description: "description".to_string(),
+            msgs: vec![],
+        };
+        self.app
+            .execute_contract(
+                Addr::unchecked("alice"),
+                self.proposal_multiple.clone(),
+                &dao_proposal_multiple::msg::ExecuteMsg::Propose(MultipleChoiceProposeMsg {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    choices: MultipleChoiceOptions {
+                        options: vec![option("a"), option("b")],
+                    },
+                    proposer: None,
+                    vote: None,
+                }),
+                &[],
+            )
+            .unwrap();
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.proposal_multiple,
+                &dao_proposal_multiple::msg::QueryMsg::ProposalCount {},
+            )
+            .unwrap()
+    }
+
+    pub fn vote_single(&mut self, voter: &str, proposal_id: u64, vote: Vote) {
+        self.app
+            .execute_contract(
+                Addr::unchecked(voter),
+                self.proposal_single.clone(),
+                &dao_proposal_single::msg::ExecuteMsg::Vote {
+                    proposal_id,
+                    vote,
+                    rationale: None,
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    pub fn vote_multiple(&mut self, voter: &str, proposal_id: u64, option_id: u32) {
+        self.app
+            .execute_contract(
+                Addr::unchecked(voter),
+                self.proposal_multiple.clone(),
+                &dao_proposal_multiple::msg::ExecuteMsg::Vote {
+                    proposal_id,
+                    vote: MultipleChoiceVote { option_id },
+                    rationale: None,
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    pub fn close_single(&mut self, proposal_id: u64) {
+        self.app
+            .execute_contract(
+                Addr::unchecked("alice"),
+                self.proposal_single.clone(),
+                &dao_proposal_single::msg::ExecuteMsg::Close { proposal_id },
+                &[],
+            )
+            .unwrap();
+    }
+
+    pub fn close_multiple(&mut self, proposal_id: u64) {
+        self.app
+            .execute_contract(
+                Addr::unchecked("alice"),
+                self.proposal_multiple.clone(),
+                &dao_proposal_multiple::msg::ExecuteMsg::Close { proposal_id },
+                &[],
+            )
+            .unwrap();
+    }
+
+    pub fn fund(&mut self, amount: u128, epoch: Option<u64>) -> anyhow::Result<AppResponse> {
+        self.app
+            .sudo(SudoMsg::Bank(BankSudo::Mint {
+                to_address: "funder".to_string(),
+                amount: coins(amount, REWARD_DENOM),
+            }))
+            .unwrap();
+        self.app.execute_contract(
+            Addr::unchecked("funder"),
+            self.rewards.clone(),
+            &ExecuteMsg::Fund { epoch },
+            &coins(amount, REWARD_DENOM),
+        )
+    }
+
+    pub fn claim(&mut self, sender: &str, epoch: u64) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.rewards.clone(),
+            &ExecuteMsg::Claim { epoch },
+            &[],
+        )
+    }
+
+    pub fn withdraw(&mut self, sender: &Addr, epoch: u64) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.rewards.clone(),
+            &ExecuteMsg::Withdraw { epoch },
+            &[],
+        )
+    }
+
+    pub fn current_epoch(&self) -> u64 {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.rewards, &QueryMsg::CurrentEpoch {})
+            .unwrap()
+    }
+
+    pub fn epoch(&self, epoch: u64) -> EpochResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.rewards, &QueryMsg::Epoch { epoch })
+            .unwrap()
+    }
+
+    pub fn rewards(&self, address: &str, epoch: u64) -> RewardsResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.rewards,
+                &QueryMsg::Rewards {
+                    epoch,
+                    address: address.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn balance(&self, address: impl Into<String>) -> Uint128 {
+        self.app
+            .wrap()
+            .query_balance(address, REWARD_DENOM)
+            .unwrap()
+            .amount
+    }
+}
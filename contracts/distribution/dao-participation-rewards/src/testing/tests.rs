@@ -0,0 +1,225 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw_denom::CheckedDenom;
+use cw_multi_test::Executor;
+use cw_utils::{Duration, Expiration};
+use dao_hooks::vote::VoteHookMsg;
+use dao_voting::voting::Vote;
+
+use crate::{
+    msg::{ExecuteMsg, InstantiateMsg},
+    state::Payout,
+    ContractError,
+};
+
+use super::suite::{Suite, EPOCH_BLOCKS, REWARD_DENOM, VOTING_PERIOD_BLOCKS};
+
+fn payout(amount: u128) -> Payout {
+    Payout {
+        denom: CheckedDenom::Native(REWARD_DENOM.to_string()),
+        amount: Uint128::new(amount),
+    }
+}
+
+#[test]
+fn test_participation_rewards() {
+    let mut suite = Suite::new();
+    suite.fund(1300, None).unwrap();
+
+    // alice's vote passes the proposal, so its status change is
+    // reported before her vote.
+    let passed = suite.propose_single();
+    suite.vote_single("bob", passed, Vote::Yes);
+    suite.vote_single("carol", passed, Vote::No);
+    suite.vote_single("alice", passed, Vote::Yes);
+
+    // Only bob votes, so the proposal fails quorum once it expires.
+    let rejected = suite.propose_multiple();
+    suite.vote_multiple("bob", rejected, 0);
+
+    // carol's vote counts towards the epoch the proposal closes in.
+    let open = suite.propose_single();
+    suite.vote_single("carol", open, Vote::Yes);
+
+    suite.advance_blocks(VOTING_PERIOD_BLOCKS + 1);
+    suite.close_multiple(rejected);
+
+    let epoch = suite.epoch(0);
+    assert_eq!(epoch.participation, Uint128::new(130));
+    assert_eq!(epoch.funds, vec![payout(1300)]);
+
+    let err: ContractError = suite.claim("alice", 0).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::EpochNotEnded { epoch: 0 });
+
+    suite.advance_blocks(EPOCH_BLOCKS);
+    assert_eq!(suite.current_epoch(), 1);
+    suite.close_single(open);
+    assert_eq!(suite.epoch(1).participation, Uint128::new(10));
+
+    let alice = suite.rewards("alice", 0);
+    assert_eq!(alice.participation, Uint128::new(60));
+    assert_eq!(alice.rewards, vec![payout(600)]);
+    assert!(!alice.claimed);
+    assert_eq!(suite.rewards("bob", 0).participation, Uint128::new(60));
+    assert_eq!(suite.rewards("carol", 0).participation, Uint128::new(10));
+
+    for (member, expected) in [("alice", 600), ("bob", 600), ("carol", 100)] {
+        suite.claim(member, 0).unwrap();
+        assert_eq!(suite.balance(member), Uint128::new(expected));
+    }
+    assert!(suite.rewards("alice", 0).claimed);
+
+    let err: ContractError = suite.claim("alice", 0).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::AlreadyClaimed {});
+    let err: ContractError = suite.claim("dave", 0).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::NothingToClaim {});
+}
+
+#[test]
+fn test_hooks_from_unknown_module() {
+    let mut suite = Suite::new();
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            Addr::unchecked("mallory"),
+            suite.rewards.clone(),
+            &ExecuteMsg::VoteHook(VoteHookMsg::NewVote {
+                proposal_id: 1,
+                voter: "mallory".to_string(),
+                vote: "yes".to_string(),
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::UnknownProposalModule {
+            module: Addr::unchecked("mallory")
+        }
+    );
+}
+
+#[test]
+fn test_withdraw() {
+    let mut suite = Suite::new();
+    suite.fund(100, Some(0)).unwrap();
+    suite.fund(50, Some(1)).unwrap();
+
+    let core = suite.core.clone();
+    let err: ContractError = suite.withdraw(&core, 0).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::EpochNotEnded { epoch: 0 });
+
+    suite.advance_blocks(EPOCH_BLOCKS);
+
+    // Rewards can not be added once voters may claim them.
+    let err: ContractError = suite.fund(100, Some(0)).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::EpochEnded { epoch: 0 });
+
+    let err: ContractError = suite
+        .withdraw(&Addr::unchecked("alice"), 0)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    suite.withdraw(&core, 0).unwrap();
+    assert_eq!(suite.balance(&core), Uint128::new(100));
+
+    let err: ContractError = suite.withdraw(&core, 0).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::NothingToWithdraw {});
+
+    // Rewards of an epoch with participation belong to the voters.
+    let proposal = suite.propose_single();
+    suite.vote_single("alice", proposal, Vote::Yes);
+    suite.advance_blocks(EPOCH_BLOCKS);
+    let err: ContractError = suite.withdraw(&core, 1).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::NothingToWithdraw {});
+    suite.claim("alice", 1).unwrap();
+    assert_eq!(suite.balance("alice"), Uint128::new(50));
+}
+
+#[test]
+fn test_withdraw_rounding_dust() {
+    let mut suite = Suite::new();
+    suite.fund(100, None).unwrap();
+
+    let proposal = suite.propose_single();
+    suite.vote_single("carol", proposal, Vote::No);
+    suite.vote_single("alice", proposal, Vote::Yes);
+    suite.advance_blocks(EPOCH_BLOCKS);
+
+    // Nothing may be withdrawn while voters could still claim it.
+    let core = suite.core.clone();
+    let err: ContractError = suite.withdraw(&core, 0).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::NothingToWithdraw {});
+    suite.claim("alice", 0).unwrap();
+    assert_eq!(suite.balance("alice"), Uint128::new(85));
+    let err: ContractError = suite.withdraw(&core, 0).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::NothingToWithdraw {});
+
+    suite.claim("carol", 0).unwrap();
+    assert_eq!(suite.balance("carol"), Uint128::new(14));
+    assert_eq!(suite.rewards("carol", 0).participation, Uint128::new(10));
+
+    suite.withdraw(&core, 0).unwrap();
+    assert_eq!(suite.balance(&core), Uint128::new(1));
+    assert_eq!(suite.balance(&suite.rewards), Uint128::zero());
+}
+
+#[test]
+fn test_votes_after_epoch_ended_are_ignored() {
+    let mut suite = Suite::new();
+    suite.fund(1000, None).unwrap();
+
+    // The proposal passes in the last block of the epoch, and keeps
+    // accepting votes into the next one.
+    let Expiration::AtHeight(end) = suite.epoch(0).ends_at else {
+        panic!("epochs are measured in blocks");
+    };
+    suite.app.update_block(|block| block.height = end - 1);
+    let proposal = suite.propose_single();
+    suite.vote_single("alice", proposal, Vote::Yes);
+
+    suite.advance_blocks(1);
+    assert_eq!(suite.current_epoch(), 1);
+    suite.claim("alice", 0).unwrap();
+    assert_eq!(suite.balance("alice"), Uint128::new(1000));
+
+    // bob's vote is accepted by the proposal module but does not add
+    // to the ended epoch's participation.
+    suite.vote_single("bob", proposal, Vote::Yes);
+    assert_eq!(suite.epoch(0).participation, Uint128::new(60));
+    assert_eq!(suite.epoch(1).participation, Uint128::zero());
+    let err: ContractError = suite.claim("bob", 0).unwrap_err().downcast().unwrap();
+    assert_eq!(err, ContractError::NothingToClaim {});
+    assert_eq!(suite.balance(&suite.rewards), Uint128::zero());
+}
+
+#[test]
+fn test_instantiate_invalid_epoch_duration() {
+    let mut suite = Suite::new();
+    let code_id = suite
+        .app
+        .wrap()
+        .query_wasm_contract_info(&suite.rewards)
+        .unwrap()
+        .code_id;
+    let err: ContractError = suite
+        .app
+        .instantiate_contract(
+            code_id,
+            suite.core.clone(),
+            &InstantiateMsg {
+                dao: suite.core.to_string(),
+                epoch_duration: Duration::Time(0),
+            },
+            &[],
+            "participation rewards",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidEpochDuration {});
+}
@@ -1,31 +1,38 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    ensure, from_json, to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order,
-    Response, StdError, StdResult, Uint128, Uint256,
+    ensure, from_json, to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env,
+    MessageInfo, Order, Response, StdError, StdResult, Storage, Uint128, Uint256,
 };
 use cw2::{get_contract_version, set_contract_version};
 use cw20::{Cw20ReceiveMsg, Denom};
 use cw_storage_plus::Bound;
-use cw_utils::{must_pay, nonpayable, Duration, Expiration};
+use cw_utils::{must_pay, nonpayable, Expiration};
 use dao_interface::voting::InfoResponse;
 use semver::Version;
 
 use std::ops::Add;
 
-use crate::helpers::{get_transfer_msg, validate_voting_power_contract};
+use crate::helpers::{
+    get_transfer_msg, validate_voting_power_contract, StakeTargetCache, VotingPowerCache,
+};
 use crate::hooks::{
     execute_membership_changed, execute_nft_stake_changed, execute_stake_changed,
     subscribe_distribution_to_hook, unsubscribe_distribution_from_hook,
 };
 use crate::msg::{
-    CreateMsg, DistributionPendingRewards, DistributionsResponse, ExecuteMsg, FundMsg,
-    InstantiateMsg, MigrateMsg, PendingRewardsResponse, QueryMsg, ReceiveCw20Msg,
+    BundleDenom, BundleResponse, CreateBundleMsg, CreateMsg, DistributionPendingRewards,
+    DistributionsResponse, ExecuteMsg, FundMsg, InstantiateMsg, MigrateMsg, PendingRewardsResponse,
+    QueryMsg, ReceiveCw20Msg,
 };
 use crate::rewards::{
     get_accrued_rewards_not_yet_accounted_for, get_active_total_earned_puvp, update_rewards,
+    update_rewards_for_distributions,
+};
+use crate::state::{
+    DistributionState, EmissionRate, Epoch, AUTO_COMPOUND, BUNDLES, BUNDLE_COUNT, COUNT,
+    DISTRIBUTIONS, USER_REWARDS,
 };
-use crate::state::{DistributionState, EmissionRate, Epoch, COUNT, DISTRIBUTIONS, USER_REWARDS};
 use crate::ContractError;
 
 pub(crate) const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -69,6 +76,9 @@ pub fn execute(
         ExecuteMsg::UpdateOwnership(action) => execute_update_owner(deps, info, env, action),
         ExecuteMsg::Receive(msg) => execute_receive_cw20(deps, env, info, msg),
         ExecuteMsg::Create(create_msg) => execute_create(deps, env, info, create_msg),
+        ExecuteMsg::CreateBundle(create_bundle_msg) => {
+            execute_create_bundle(deps, env, info, create_bundle_msg)
+        }
         ExecuteMsg::Update {
             id,
             emission_rate,
@@ -90,6 +100,13 @@ pub fn execute(
         ExecuteMsg::Fund(FundMsg { id }) => execute_fund_native(deps, env, info, id),
         ExecuteMsg::FundLatest {} => execute_fund_latest_native(deps, env, info),
         ExecuteMsg::Claim { id } => execute_claim(deps, env, info, id),
+        ExecuteMsg::ClaimAll {} => execute_claim_all(deps, env, info),
+        ExecuteMsg::SetAutoCompound { enabled } => execute_set_auto_compound(deps, info, enabled),
+        ExecuteMsg::Compound {
+            ids,
+            start_after,
+            limit,
+        } => execute_compound(deps, env, info, ids, start_after, limit),
         ExecuteMsg::Withdraw { id } => execute_withdraw(deps, info, env, id),
     }
 }
@@ -188,22 +205,15 @@ fn execute_create(
             last_updated_total_earned_puvp: Expiration::Never {},
         },
         vp_contract,
-        hook_caller: hook_caller.clone(),
+        hook_caller,
         funded_amount: Uint128::zero(),
         open_funding,
         withdraw_destination,
         historical_earned_puvp: Uint256::zero(),
+        bundle: None,
     };
 
-    // store the new distribution state, erroring if it already exists. this
-    // should never happen, but just in case.
-    DISTRIBUTIONS.update(deps.storage, id, |existing| match existing {
-        Some(_) => Err(ContractError::UnexpectedDuplicateDistributionId { id }),
-        None => Ok(distribution.clone()),
-    })?;
-
-    // update the registered hooks to include the new distribution
-    subscribe_distribution_to_hook(deps.storage, id, hook_caller.clone())?;
+    save_new_distribution(deps.storage, &distribution)?;
 
     let mut response = Response::new()
         .add_attribute("action", "create")
@@ -230,6 +240,130 @@ fn execute_create(
     Ok(response)
 }
 
+/// creates a bundle of distributions, one per denom, that share a voting power
+/// contract and hook caller. only the owner can do this. if native funds are
+/// provided, the distributions for their denoms are funded.
+fn execute_create_bundle(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: CreateBundleMsg,
+) -> Result<Response, ContractError> {
+    // only the owner can create a new bundle
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    if msg.denoms.is_empty() {
+        return Err(ContractError::EmptyBundle {});
+    }
+
+    let hook_caller = deps.api.addr_validate(&msg.hook_caller)?;
+    let vp_contract = validate_voting_power_contract(&deps, msg.vp_contract)?;
+
+    let withdraw_destination = match msg.withdraw_destination {
+        // if withdraw destination is specified, we validate it
+        Some(addr) => deps.api.addr_validate(&addr)?,
+        // otherwise default to the owner
+        None => info.sender.clone(),
+    };
+
+    let open_funding = msg.open_funding.unwrap_or(true);
+
+    // bundles were added after distributions, so the count may not exist yet
+    let bundle = BUNDLE_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+    BUNDLE_COUNT.save(deps.storage, &bundle)?;
+
+    let mut distributions: Vec<DistributionState> = vec![];
+    for BundleDenom {
+        denom,
+        emission_rate,
+    } in msg.denoms
+    {
+        let checked_denom = denom.into_checked(deps.as_ref())?;
+        if distributions.iter().any(|d| d.denom == checked_denom) {
+            return Err(ContractError::DuplicateBundleDenom {});
+        }
+
+        emission_rate.validate()?;
+
+        let id = COUNT.update(deps.storage, |count| -> StdResult<u64> { Ok(count + 1) })?;
+
+        let distribution = DistributionState {
+            id,
+            denom: checked_denom,
+            active_epoch: Epoch {
+                started_at: Expiration::Never {},
+                ends_at: Expiration::Never {},
+                emission_rate,
+                total_earned_puvp: Uint256::zero(),
+                last_updated_total_earned_puvp: Expiration::Never {},
+            },
+            vp_contract: vp_contract.clone(),
+            hook_caller: hook_caller.clone(),
+            funded_amount: Uint128::zero(),
+            open_funding,
+            withdraw_destination: withdraw_destination.clone(),
+            historical_earned_puvp: Uint256::zero(),
+            bundle: Some(bundle),
+        };
+
+        save_new_distribution(deps.storage, &distribution)?;
+
+        distributions.push(distribution);
+    }
+
+    let ids: Vec<u64> = distributions.iter().map(|d| d.id).collect();
+    BUNDLES.save(deps.storage, bundle, &ids)?;
+
+    // fund the distributions for each native denom provided. if funds are
+    // provided for a denom not in the bundle, return error.
+    for coin in &info.funds {
+        let distribution = distributions
+            .iter()
+            .find(|d| d.denom == Denom::Native(coin.denom.clone()))
+            .ok_or(ContractError::InvalidFunds {})?;
+
+        execute_fund(
+            deps.branch(),
+            env.clone(),
+            info.sender.clone(),
+            distribution.clone(),
+            coin.amount,
+        )?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "create_bundle")
+        .add_attribute("bundle", bundle.to_string())
+        .add_attribute(
+            "ids",
+            ids.iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        ))
+}
+
+/// stores a new distribution, erroring if it already exists, and subscribes it
+/// to its hook caller.
+fn save_new_distribution(
+    storage: &mut dyn Storage,
+    distribution: &DistributionState,
+) -> Result<(), ContractError> {
+    let id = distribution.id;
+
+    // store the new distribution state, erroring if it already exists. this
+    // should never happen, but just in case.
+    DISTRIBUTIONS.update(storage, id, |existing| match existing {
+        Some(_) => Err(ContractError::UnexpectedDuplicateDistributionId { id }),
+        None => Ok(distribution.clone()),
+    })?;
+
+    // update the registered hooks to include the new distribution
+    subscribe_distribution_to_hook(storage, id, distribution.hook_caller.clone())?;
+
+    Ok(())
+}
+
 /// updates the config for a distribution
 #[allow(clippy::too_many_arguments)]
 fn execute_update(
@@ -259,18 +393,36 @@ fn execute_update(
         distribution.transition_epoch(deps.as_ref(), emission_rate, &env.block)?;
     }
 
+    // distributions in a bundle share a voting power contract and hook caller,
+    // so updating either updates every distribution in the bundle.
+    let mut bundled = match distribution.bundle {
+        Some(bundle) => BUNDLES
+            .load(deps.storage, bundle)?
+            .into_iter()
+            .filter(|other| *other != id)
+            .map(|other| DISTRIBUTIONS.load(deps.storage, other))
+            .collect::<StdResult<Vec<_>>>()?,
+        None => vec![],
+    };
+
     if let Some(vp_contract) = vp_contract {
-        distribution.vp_contract = validate_voting_power_contract(&deps, vp_contract)?;
+        let vp_contract = validate_voting_power_contract(&deps, vp_contract)?;
+        for d in std::iter::once(&mut distribution).chain(bundled.iter_mut()) {
+            d.vp_contract = vp_contract.clone();
+        }
     }
 
     if let Some(hook_caller) = hook_caller {
-        // remove existing from registered hooks
-        unsubscribe_distribution_from_hook(deps.storage, id, distribution.hook_caller)?;
+        let hook_caller = deps.api.addr_validate(&hook_caller)?;
+        for d in std::iter::once(&mut distribution).chain(bundled.iter_mut()) {
+            // remove existing from registered hooks
+            unsubscribe_distribution_from_hook(deps.storage, d.id, d.hook_caller.clone())?;
 
-        distribution.hook_caller = deps.api.addr_validate(&hook_caller)?;
+            d.hook_caller = hook_caller.clone();
 
-        // add new to registered hooks
-        subscribe_distribution_to_hook(deps.storage, id, distribution.hook_caller.clone())?;
+            // add new to registered hooks
+            subscribe_distribution_to_hook(deps.storage, d.id, d.hook_caller.clone())?;
+        }
     }
 
     if let Some(open_funding) = open_funding {
@@ -282,6 +434,9 @@ fn execute_update(
     }
 
     DISTRIBUTIONS.save(deps.storage, id, &distribution)?;
+    for d in &bundled {
+        DISTRIBUTIONS.save(deps.storage, d.id, d)?;
+    }
 
     Ok(Response::new()
         .add_attribute("action", "update")
@@ -333,7 +488,9 @@ fn execute_fund(
     match distribution.active_epoch.emission_rate {
         EmissionRate::Paused {} => execute_fund_paused(deps, distribution, amount),
         EmissionRate::Immediate {} => execute_fund_immediate(deps, env, distribution, amount),
-        EmissionRate::Linear { .. } => execute_fund_linear(deps, env, distribution, amount),
+        EmissionRate::Linear { .. }
+        | EmissionRate::Piecewise { .. }
+        | EmissionRate::Decay { .. } => execute_fund_linear(deps, env, distribution, amount),
     }
 }
 
@@ -377,8 +534,9 @@ fn execute_fund_immediate(
         .add_attribute("amount_funded", amount))
 }
 
-/// funding a linear distribution requires some complex logic based on whether
-/// or not the distribution is continuous and whether or not it's expired.
+/// funding a linear (or piecewise or decaying) distribution requires some
+/// complex logic based on whether or not the distribution is continuous and
+/// whether or not it's expired.
 ///
 /// expired continuous distributions experience backfill with the new funds,
 /// whereas expired discontinuous distributions begin anew (and all past rewards
@@ -389,12 +547,7 @@ fn execute_fund_linear(
     mut distribution: DistributionState,
     amount: Uint128,
 ) -> Result<Response, ContractError> {
-    let continuous =
-        if let EmissionRate::Linear { continuous, .. } = distribution.active_epoch.emission_rate {
-            continuous
-        } else {
-            false
-        };
+    let continuous = distribution.active_epoch.emission_rate.is_continuous();
     let previously_funded = !distribution.funded_amount.is_zero();
     let was_expired = distribution.active_epoch.ends_at.is_expired(&env.block);
     let discontinuous_expired = !continuous && was_expired;
@@ -413,8 +566,12 @@ fn execute_fund_linear(
         // restarting, in case users haven't claimed yet, by adding the final
         // total rewards earned to the historical value.
         if discontinuous_expired && previously_funded {
-            let final_total_earned_puvp =
-                get_active_total_earned_puvp(deps.as_ref(), &env.block, &distribution)?;
+            let final_total_earned_puvp = get_active_total_earned_puvp(
+                deps.as_ref(),
+                &env.block,
+                &distribution,
+                &mut VotingPowerCache::default(),
+            )?;
             distribution.historical_earned_puvp = distribution
                 .historical_earned_puvp
                 .checked_add(final_total_earned_puvp)
@@ -426,14 +583,10 @@ fn execute_fund_linear(
 
         // reset all starting fields since a new distribution is starting
         distribution.funded_amount = amount;
-        distribution.active_epoch.started_at = match distribution.active_epoch.emission_rate {
-            EmissionRate::Paused {} => Expiration::Never {},
-            EmissionRate::Immediate {} => Expiration::Never {},
-            EmissionRate::Linear { duration, .. } => match duration {
-                Duration::Height(_) => Expiration::AtHeight(env.block.height),
-                Duration::Time(_) => Expiration::AtTime(env.block.time),
-            },
-        };
+        distribution.active_epoch.started_at = distribution
+            .active_epoch
+            .emission_rate
+            .get_started_at(&env.block);
         distribution.active_epoch.total_earned_puvp = Uint256::zero();
         distribution.active_epoch.last_updated_total_earned_puvp =
             distribution.active_epoch.started_at;
@@ -456,8 +609,12 @@ fn execute_fund_linear(
     // sufficient funding. ensure the total rewards earned puvp is up to date
     // based on the original start block and the newly updated end block.
     if continuous && previously_funded && was_expired {
-        distribution.active_epoch.total_earned_puvp =
-            get_active_total_earned_puvp(deps.as_ref(), &env.block, &distribution)?;
+        distribution.active_epoch.total_earned_puvp = get_active_total_earned_puvp(
+            deps.as_ref(),
+            &env.block,
+            &distribution,
+            &mut VotingPowerCache::default(),
+        )?;
         distribution.active_epoch.bump_last_updated(&env.block);
     }
 
@@ -503,18 +660,199 @@ fn execute_claim(
     // claimed rewards
     USER_REWARDS.save(deps.storage, info.sender.clone(), &user_reward_state)?;
 
-    let denom_str = distribution.get_denom_string();
+    let (msg, compounded) = get_claim_msg(
+        deps.as_ref(),
+        &mut StakeTargetCache::default(),
+        &info.sender,
+        claim_amount,
+        &distribution,
+    )?;
 
     Ok(Response::new()
-        .add_message(get_transfer_msg(
-            info.sender.clone(),
-            claim_amount,
-            distribution.denom,
-        )?)
+        .add_message(msg)
         .add_attribute("action", "claim")
         .add_attribute("id", id.to_string())
-        .add_attribute("denom", denom_str)
-        .add_attribute("amount_claimed", claim_amount))
+        .add_attribute("denom", distribution.get_denom_string())
+        .add_attribute("amount_claimed", claim_amount)
+        .add_attribute("compounded", compounded.to_string()))
+}
+
+/// claims the sender's rewards from every distribution in one transaction.
+fn execute_claim_all(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let ids = DISTRIBUTIONS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    // update every distribution for the sender at once. this updates the
+    // distribution states and the user reward state.
+    update_rewards_for_distributions(&mut deps, &env, &info.sender, &ids)?;
+
+    let mut user_reward_state = USER_REWARDS.load(deps.storage, info.sender.clone())?;
+
+    let mut response = Response::new().add_attribute("action", "claim_all");
+    let mut stake_targets = StakeTargetCache::default();
+
+    for id in ids {
+        // set the pending rewards to zero and get the amount to claim
+        let claim_amount = user_reward_state
+            .pending_rewards
+            .insert(id, Uint128::zero())
+            .unwrap_or_default();
+
+        if claim_amount.is_zero() {
+            continue;
+        }
+
+        let distribution = DISTRIBUTIONS.load(deps.storage, id)?;
+        let (msg, compounded) = get_claim_msg(
+            deps.as_ref(),
+            &mut stake_targets,
+            &info.sender,
+            claim_amount,
+            &distribution,
+        )?;
+
+        response = response
+            .add_message(msg)
+            .add_attribute("id", id.to_string())
+            .add_attribute("denom", distribution.get_denom_string())
+            .add_attribute("amount_claimed", claim_amount)
+            .add_attribute("compounded", compounded.to_string());
+    }
+
+    // if there are no rewards to claim, error out
+    if response.messages.is_empty() {
+        return Err(ContractError::NoRewardsClaimable {});
+    }
+
+    USER_REWARDS.save(deps.storage, info.sender, &user_reward_state)?;
+
+    Ok(response)
+}
+
+/// returns the message paying out a user's claimed rewards, and whether they
+/// are compounded. if the user enabled auto-compounding and the rewards are
+/// paid in the staking token of the distribution's voting power contract, they
+/// are staked on the user's behalf instead of sent to them.
+fn get_claim_msg(
+    deps: Deps,
+    stake_targets: &mut StakeTargetCache,
+    recipient: &Addr,
+    amount: Uint128,
+    distribution: &DistributionState,
+) -> StdResult<(CosmosMsg, bool)> {
+    if AUTO_COMPOUND.has(deps.storage, recipient.clone()) {
+        if let Some(target) = stake_targets.get(deps, distribution)? {
+            return Ok((target.get_stake_msg(recipient, amount)?, true));
+        }
+    }
+
+    Ok((
+        get_transfer_msg(recipient.clone(), amount, distribution.denom.clone())?,
+        false,
+    ))
+}
+
+fn execute_set_auto_compound(
+    deps: DepsMut,
+    info: MessageInfo,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    if enabled {
+        AUTO_COMPOUND.save(deps.storage, info.sender.clone(), &Empty {})?;
+    } else {
+        AUTO_COMPOUND.remove(deps.storage, info.sender.clone());
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_auto_compound")
+        .add_attribute("address", info.sender)
+        .add_attribute("enabled", enabled.to_string()))
+}
+
+/// compounds the pending rewards of a page of users who enabled
+/// auto-compounding from the given distributions. only rewards paid in the
+/// staking token of a distribution's voting power contract are compounded, so
+/// other rewards remain pending.
+fn execute_compound(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mut ids: Vec<u64>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let users = AUTO_COMPOUND
+        .keys(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    // find which of the given distributions can be compounded
+    ids.sort_unstable();
+    ids.dedup();
+    let mut stake_targets = StakeTargetCache::default();
+    let mut compoundable = vec![];
+    for id in ids {
+        let distribution = DISTRIBUTIONS
+            .load(deps.storage, id)
+            .map_err(|_| ContractError::DistributionNotFound { id })?;
+        if let Some(target) = stake_targets.get(deps.as_ref(), &distribution)? {
+            compoundable.push((id, target.clone()));
+        }
+    }
+    let ids: Vec<u64> = compoundable.iter().map(|(id, _)| *id).collect();
+
+    let mut response = Response::new()
+        .add_attribute("action", "compound")
+        .add_attribute("users", users.len().to_string());
+
+    if ids.is_empty() {
+        return Ok(response);
+    }
+
+    for user in users {
+        update_rewards_for_distributions(&mut deps, &env, &user, &ids)?;
+
+        let mut user_reward_state = USER_REWARDS.load(deps.storage, user.clone())?;
+        for (id, target) in &compoundable {
+            let amount = user_reward_state
+                .pending_rewards
+                .insert(*id, Uint128::zero())
+                .unwrap_or_default();
+
+            if amount.is_zero() {
+                continue;
+            }
+
+            response = response
+                .add_message(target.get_stake_msg(&user, amount)?)
+                .add_attribute("address", user.to_string())
+                .add_attribute("id", id.to_string())
+                .add_attribute("amount_compounded", amount);
+        }
+        USER_REWARDS.save(deps.storage, user, &user_reward_state)?;
+    }
+
+    Ok(response)
 }
 
 /// withdraws the undistributed rewards for a distribution. members can claim
@@ -616,6 +954,15 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Distributions { start_after, limit } => Ok(to_json_binary(
             &query_distributions(deps, start_after, limit)?,
         )?),
+        QueryMsg::Bundle { id } => Ok(to_json_binary(
+            &query_bundle(deps, id).map_err(|e| StdError::generic_err(e.to_string()))?,
+        )?),
+        QueryMsg::AutoCompound { address } => {
+            Ok(to_json_binary(&query_auto_compound(deps, address)?)?)
+        }
+        QueryMsg::AutoCompounders { start_after, limit } => Ok(to_json_binary(
+            &query_auto_compounders(deps, start_after, limit)?,
+        )?),
     }
 }
 
@@ -651,11 +998,14 @@ fn query_pending_rewards(
 
     let mut pending_rewards: Vec<DistributionPendingRewards> = vec![];
 
+    // distributions that share a voting power contract share its voting power
+    let mut voting_power = VotingPowerCache::default();
+
     // iterate over all distributions and calculate pending rewards for the user
     for (id, distribution) in distributions {
         // first we get the active epoch earned puvp value
         let active_total_earned_puvp =
-            get_active_total_earned_puvp(deps, &env.block, &distribution)
+            get_active_total_earned_puvp(deps, &env.block, &distribution, &mut voting_power)
                 .map_err(|e| StdError::generic_err(e.to_string()))?;
 
         // then we add that to the historical rewards earned puvp
@@ -675,6 +1025,7 @@ fn query_pending_rewards(
             total_earned_puvp,
             &distribution,
             &user_reward_state,
+            &mut voting_power,
         )?;
 
         pending_rewards.push(DistributionPendingRewards {
@@ -717,6 +1068,45 @@ fn query_distributions(
     Ok(DistributionsResponse { distributions })
 }
 
+fn query_bundle(deps: Deps, id: u64) -> Result<BundleResponse, ContractError> {
+    let ids = BUNDLES
+        .load(deps.storage, id)
+        .map_err(|_| ContractError::BundleNotFound { id })?;
+
+    let distributions = ids
+        .into_iter()
+        .map(|id| DISTRIBUTIONS.load(deps.storage, id))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(BundleResponse { id, distributions })
+}
+
+fn query_auto_compound(deps: Deps, address: String) -> StdResult<bool> {
+    let address = deps.api.addr_validate(&address)?;
+    Ok(AUTO_COMPOUND.has(deps.storage, address))
+}
+
+fn query_auto_compounders(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Vec<Addr>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+
+    AUTO_COMPOUND
+        .keys(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect()
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     let contract_version = get_contract_version(deps.storage)?;
@@ -52,6 +52,27 @@ pub enum ContractError {
     #[error("Invalid emission rate: {field} cannot be zero")]
     InvalidEmissionRateFieldZero { field: String },
 
+    #[error("Bundle not found with ID {id}")]
+    BundleNotFound { id: u64 },
+
+    #[error("A bundle must distribute at least one denom")]
+    EmptyBundle {},
+
+    #[error("A bundle cannot distribute the same denom twice")]
+    DuplicateBundleDenom {},
+
+    #[error("Invalid emission rate: the first segment must start at 0 and segments must be ordered by start")]
+    InvalidEmissionSegments {},
+
+    #[error("Invalid emission rate: cannot have more than {max} segments")]
+    TooManyEmissionSegments { max: u64 },
+
+    #[error("Invalid emission rate: decay factor must be between 0 and 1 exclusive")]
+    InvalidDecayFactor {},
+
+    #[error("Invalid emission rate: min_amount cannot exceed amount")]
+    InvalidDecayMinAmount {},
+
     #[error("There is no voting power registered, so no one will receive these funds")]
     NoVotingPowerNoRewards {},
 
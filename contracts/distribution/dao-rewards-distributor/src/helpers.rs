@@ -5,10 +5,12 @@ use cosmwasm_std::{
 use cw20::{Denom, Expiration};
 use cw_utils::Duration;
 use dao_interface::voting::{
-    Query as VotingQueryMsg, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+    DenomResponse, InfoResponse, Query as VotingQueryMsg, TotalPowerAtHeightResponse,
+    VotingPowerAtHeightResponse,
 };
+use std::collections::{hash_map::Entry, HashMap};
 
-use crate::ContractError;
+use crate::{state::DistributionState, ContractError};
 
 pub fn get_total_voting_power_at_block(
     deps: Deps,
@@ -36,6 +38,158 @@ pub fn get_voting_power_at_block(
     Ok(resp.power)
 }
 
+/// Voting power at a block, cached per voting power contract so that
+/// distributions sharing a voting power contract only query it once.
+#[derive(Default)]
+pub struct VotingPowerCache {
+    total: HashMap<Addr, Uint128>,
+    users: HashMap<(Addr, Addr), Uint128>,
+}
+
+impl VotingPowerCache {
+    pub fn total(
+        &mut self,
+        deps: Deps,
+        block: &BlockInfo,
+        vp_contract: &Addr,
+    ) -> StdResult<Uint128> {
+        if let Some(power) = self.total.get(vp_contract) {
+            return Ok(*power);
+        }
+        let power = get_total_voting_power_at_block(deps, block, vp_contract)?;
+        self.total.insert(vp_contract.clone(), power);
+        Ok(power)
+    }
+
+    pub fn user(
+        &mut self,
+        deps: Deps,
+        block: &BlockInfo,
+        vp_contract: &Addr,
+        addr: &Addr,
+    ) -> StdResult<Uint128> {
+        let key = (vp_contract.clone(), addr.clone());
+        if let Some(power) = self.users.get(&key) {
+            return Ok(*power);
+        }
+        let power = get_voting_power_at_block(deps, block, vp_contract, addr)?;
+        self.users.insert(key, power);
+        Ok(power)
+    }
+}
+
+/// Where rewards paid in a voting module's staking token are staked.
+#[derive(Clone)]
+pub enum StakeTarget {
+    /// A `dao-voting-token-staked` voting module.
+    Native { voting_module: Addr, denom: String },
+    /// The `cw20-stake` contract of a `dao-voting-cw20-staked` voting module.
+    Cw20 { staking_contract: Addr, token: Addr },
+}
+
+impl StakeTarget {
+    fn denom(&self) -> Denom {
+        match self {
+            StakeTarget::Native { denom, .. } => Denom::Native(denom.clone()),
+            StakeTarget::Cw20 { token, .. } => Denom::Cw20(token.clone()),
+        }
+    }
+
+    /// Returns the CosmosMsg staking `amount` of the staking token on behalf
+    /// of `recipient`.
+    pub fn get_stake_msg(&self, recipient: &Addr, amount: Uint128) -> StdResult<CosmosMsg> {
+        match self {
+            StakeTarget::Native {
+                voting_module,
+                denom,
+            } => Ok(WasmMsg::Execute {
+                contract_addr: voting_module.to_string(),
+                msg: to_json_binary(&dao_voting_token_staked::msg::ExecuteMsg::StakeFor {
+                    address: recipient.to_string(),
+                })?,
+                funds: coins(amount.u128(), denom),
+            }
+            .into()),
+            StakeTarget::Cw20 {
+                staking_contract,
+                token,
+            } => Ok(WasmMsg::Execute {
+                contract_addr: token.to_string(),
+                msg: to_json_binary(&cw20::Cw20ExecuteMsg::Send {
+                    contract: staking_contract.to_string(),
+                    amount,
+                    msg: to_json_binary(&cw20_stake::msg::ReceiveMsg::StakeFor {
+                        address: recipient.to_string(),
+                    })?,
+                })?,
+                funds: vec![],
+            }
+            .into()),
+        }
+    }
+}
+
+/// Stake targets, cached per voting power contract so that compounding many
+/// users or distributions only queries each voting power contract once.
+#[derive(Default)]
+pub struct StakeTargetCache(HashMap<Addr, Option<StakeTarget>>);
+
+impl StakeTargetCache {
+    /// Returns where a distribution's rewards are compounded, or None if the
+    /// distribution does not pay out the staking token of its voting power
+    /// contract.
+    pub fn get(
+        &mut self,
+        deps: Deps,
+        distribution: &DistributionState,
+    ) -> StdResult<Option<&StakeTarget>> {
+        let target = match self.0.entry(distribution.vp_contract.clone()) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                entry.insert(query_stake_target(deps, &distribution.vp_contract)?)
+            }
+        };
+        Ok(target
+            .as_ref()
+            .filter(|target| target.denom() == distribution.denom))
+    }
+}
+
+/// Gets where members of a voting module stake. Voting modules that members
+/// do not stake tokens with have no stake target.
+fn query_stake_target(deps: Deps, vp_contract: &Addr) -> StdResult<Option<StakeTarget>> {
+    let info: InfoResponse = deps
+        .querier
+        .query_wasm_smart(vp_contract, &VotingQueryMsg::Info {})?;
+    match info.info.contract.as_str() {
+        "crates.io:dao-voting-token-staked" => {
+            let denom: DenomResponse = deps.querier.query_wasm_smart(
+                vp_contract,
+                &dao_voting_token_staked::msg::QueryMsg::Denom {},
+            )?;
+            Ok(Some(StakeTarget::Native {
+                voting_module: vp_contract.clone(),
+                denom: denom.denom,
+            }))
+        }
+        "crates.io:dao-voting-cw20-staked" => {
+            let token: Addr = deps.querier.query_wasm_smart(
+                vp_contract,
+                &dao_voting_cw20_staked::msg::QueryMsg::TokenContract {},
+            )?;
+            let staking_contract: Addr = deps.querier.query_wasm_smart(
+                vp_contract,
+                &dao_voting_cw20_staked::msg::QueryMsg::StakingContract {},
+            )?;
+            Ok(Some(StakeTarget::Cw20 {
+                staking_contract,
+                token,
+            }))
+        }
+        _ => Ok(None),
+    }
+}
+
 /// Returns the appropriate CosmosMsg for transferring the reward token.
 pub fn get_transfer_msg(recipient: Addr, amount: Uint128, denom: Denom) -> StdResult<CosmosMsg> {
     match denom {
@@ -2,7 +2,7 @@ use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult, S
 use cw4::MemberChangedHookMsg;
 use dao_hooks::{nft_stake::NftStakeChangedHookMsg, stake::StakeChangedHookMsg};
 
-use crate::{rewards::update_rewards, state::REGISTERED_HOOKS, ContractError};
+use crate::{rewards::update_rewards_for_distributions, state::REGISTERED_HOOKS, ContractError};
 
 /// Register a hook caller contract for a given distribution ID.
 pub(crate) fn subscribe_distribution_to_hook(
@@ -84,9 +84,7 @@ pub(crate) fn execute_membership_changed(
     // Get the addresses of members whose voting power has changed.
     for member in msg.diffs {
         let addr = deps.api.addr_validate(&member.key)?;
-        for id in hooked_distribution_ids.clone() {
-            update_rewards(&mut deps, &env, &addr, id)?;
-        }
+        update_rewards_for_distributions(&mut deps, &env, &addr, &hooked_distribution_ids)?;
     }
 
     Ok(Response::new().add_attribute("action", "membership_changed"))
@@ -119,9 +117,7 @@ pub(crate) fn update_for_stake(
 ) -> Result<Response, ContractError> {
     // update rewards for every distribution ID that the hook caller is
     // registered for
-    for id in hooked_distribution_ids {
-        update_rewards(&mut deps, &env, &addr, id)?;
-    }
+    update_rewards_for_distributions(&mut deps, &env, &addr, &hooked_distribution_ids)?;
     Ok(Response::new().add_attribute("action", "stake"))
 }
 
@@ -133,8 +129,6 @@ pub(crate) fn execute_unstake(
 ) -> Result<Response, ContractError> {
     // update rewards for every distribution ID that the hook caller is
     // registered for
-    for id in hooked_distribution_ids {
-        update_rewards(&mut deps, &env, &addr, id)?;
-    }
+    update_rewards_for_distributions(&mut deps, &env, &addr, &hooked_distribution_ids)?;
     Ok(Response::new().add_attribute("action", "unstake"))
 }
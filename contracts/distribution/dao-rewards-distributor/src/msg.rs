@@ -33,6 +33,10 @@ pub enum ExecuteMsg {
     StakeChangeHook(StakeChangedHookMsg),
     /// registers a new distribution
     Create(CreateMsg),
+    /// registers a bundle of distributions, one per denom, that share a voting
+    /// power contract and hook caller. if native funds are provided, the
+    /// distributions for their denoms are funded.
+    CreateBundle(CreateBundleMsg),
     /// updates the config for a distribution
     Update {
         /// distribution ID to update
@@ -58,6 +62,21 @@ pub enum ExecuteMsg {
     FundLatest {},
     /// Claims rewards for the sender.
     Claim { id: u64 },
+    /// Claims rewards for the sender from every distribution.
+    ClaimAll {},
+    /// Sets whether rewards claimed by the sender that are paid in their
+    /// voting module's staking token are staked on their behalf instead of
+    /// sent to them.
+    SetAutoCompound { enabled: bool },
+    /// Compounds the pending rewards of users who enabled auto-compounding
+    /// from the distributions with the given IDs, in order of address.
+    /// Distributions whose rewards cannot be compounded are skipped.
+    /// Callable by anyone.
+    Compound {
+        ids: Vec<u64>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     /// withdraws the undistributed rewards for a distribution. members can
     /// claim whatever they earned until this point. this is effectively an
     /// inverse to fund and does not affect any already-distributed rewards.
@@ -81,6 +100,29 @@ pub struct CreateMsg {
     pub withdraw_destination: Option<String>,
 }
 
+#[cw_serde]
+pub struct CreateBundleMsg {
+    /// denoms to distribute, each with its own emission rate
+    pub denoms: Vec<BundleDenom>,
+    /// address to query the voting power
+    pub vp_contract: String,
+    /// address that will update the reward split when the voting power
+    /// distribution changes
+    pub hook_caller: String,
+    /// whether or not non-owners can fund the distributions. defaults to true.
+    pub open_funding: Option<bool>,
+    /// destination address for reward clawbacks. defaults to owner.
+    pub withdraw_destination: Option<String>,
+}
+
+#[cw_serde]
+pub struct BundleDenom {
+    /// denom to distribute
+    pub denom: UncheckedDenom,
+    /// reward emission rate
+    pub emission_rate: EmissionRate,
+}
+
 #[cw_serde]
 pub struct FundMsg {
     /// distribution ID to fund
@@ -130,6 +172,18 @@ pub enum QueryMsg {
         start_after: Option<u64>,
         limit: Option<u32>,
     },
+    /// Returns the state of the distributions in the given bundle.
+    #[returns(BundleResponse)]
+    Bundle { id: u64 },
+    /// Returns whether the given address has enabled auto-compounding.
+    #[returns(bool)]
+    AutoCompound { address: String },
+    /// Returns the addresses that have enabled auto-compounding.
+    #[returns(Vec<::cosmwasm_std::Addr>)]
+    AutoCompounders {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -137,6 +191,13 @@ pub struct DistributionsResponse {
     pub distributions: Vec<DistributionState>,
 }
 
+#[cw_serde]
+pub struct BundleResponse {
+    /// bundle ID
+    pub id: u64,
+    pub distributions: Vec<DistributionState>,
+}
+
 #[cw_serde]
 pub struct PendingRewardsResponse {
     pub pending_rewards: Vec<DistributionPendingRewards>,
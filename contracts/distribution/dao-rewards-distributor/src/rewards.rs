@@ -2,10 +2,7 @@ use cosmwasm_std::{Addr, BlockInfo, Deps, DepsMut, Env, StdResult, Uint128, Uint
 use cw20::Expiration;
 
 use crate::{
-    helpers::{
-        get_total_voting_power_at_block, get_voting_power_at_block, scale_factor, DurationExt,
-        ExpirationExt,
-    },
+    helpers::{scale_factor, DurationExt, ExpirationExt, VotingPowerCache},
     state::{DistributionState, EmissionRate, UserRewardState, DISTRIBUTIONS, USER_REWARDS},
     ContractError,
 };
@@ -18,62 +15,85 @@ pub fn update_rewards(
     addr: &Addr,
     distribution_id: u64,
 ) -> Result<(), ContractError> {
-    let mut distribution = DISTRIBUTIONS
-        .load(deps.storage, distribution_id)
-        .map_err(|_| ContractError::DistributionNotFound {
-            id: distribution_id,
-        })?;
+    update_rewards_for_distributions(deps, env, addr, &[distribution_id])
+}
 
+/// updates the user reward state for several distributions at once. the user
+/// reward state is loaded and saved once, and voting power is queried once per
+/// voting power contract, no matter how many distributions share it.
+pub fn update_rewards_for_distributions(
+    deps: &mut DepsMut,
+    env: &Env,
+    addr: &Addr,
+    distribution_ids: &[u64],
+) -> Result<(), ContractError> {
     // user may not have a reward state set yet if that is their first time
     // claiming, so we default to an empty state
     let mut user_reward_state = USER_REWARDS
         .may_load(deps.storage, addr.clone())?
         .unwrap_or_default();
 
-    // first update the active epoch earned puvp value up to the current block
-    distribution.active_epoch.total_earned_puvp =
-        get_active_total_earned_puvp(deps.as_ref(), &env.block, &distribution)?;
-    distribution.active_epoch.bump_last_updated(&env.block);
-
-    // then calculate the total applicable puvp, which is the sum of historical
-    // rewards earned puvp and the active epoch total earned puvp we just
-    // updated above based on the current block
-    let total_applicable_puvp = distribution
-        .active_epoch
-        .total_earned_puvp
-        .checked_add(distribution.historical_earned_puvp)?;
-
-    let unaccounted_for_rewards = get_accrued_rewards_not_yet_accounted_for(
-        deps.as_ref(),
-        env,
-        addr,
-        total_applicable_puvp,
-        &distribution,
-        &user_reward_state,
-    )?;
-
-    // get the pre-existing pending reward amount for the distribution
-    let previous_pending_reward_amount = user_reward_state
-        .pending_rewards
-        .get(&distribution.id)
-        .cloned()
-        .unwrap_or_default();
-
-    let amount_sum = unaccounted_for_rewards.checked_add(previous_pending_reward_amount)?;
-
-    // get the amount of newly earned rewards for the distribution
-    user_reward_state
-        .pending_rewards
-        .insert(distribution_id, amount_sum);
-
-    // update the accounted for amount to that of the total applicable puvp
-    user_reward_state
-        .accounted_for_rewards_puvp
-        .insert(distribution_id, total_applicable_puvp);
+    let mut voting_power = VotingPowerCache::default();
+
+    for &distribution_id in distribution_ids {
+        let mut distribution = DISTRIBUTIONS
+            .load(deps.storage, distribution_id)
+            .map_err(|_| ContractError::DistributionNotFound {
+                id: distribution_id,
+            })?;
+
+        // first update the active epoch earned puvp value up to the current
+        // block
+        distribution.active_epoch.total_earned_puvp = get_active_total_earned_puvp(
+            deps.as_ref(),
+            &env.block,
+            &distribution,
+            &mut voting_power,
+        )?;
+        distribution.active_epoch.bump_last_updated(&env.block);
+
+        // then calculate the total applicable puvp, which is the sum of
+        // historical rewards earned puvp and the active epoch total earned puvp
+        // we just updated above based on the current block
+        let total_applicable_puvp = distribution
+            .active_epoch
+            .total_earned_puvp
+            .checked_add(distribution.historical_earned_puvp)?;
+
+        let unaccounted_for_rewards = get_accrued_rewards_not_yet_accounted_for(
+            deps.as_ref(),
+            env,
+            addr,
+            total_applicable_puvp,
+            &distribution,
+            &user_reward_state,
+            &mut voting_power,
+        )?;
+
+        // get the pre-existing pending reward amount for the distribution
+        let previous_pending_reward_amount = user_reward_state
+            .pending_rewards
+            .get(&distribution.id)
+            .cloned()
+            .unwrap_or_default();
+
+        let amount_sum = unaccounted_for_rewards.checked_add(previous_pending_reward_amount)?;
+
+        // get the amount of newly earned rewards for the distribution
+        user_reward_state
+            .pending_rewards
+            .insert(distribution_id, amount_sum);
+
+        // update the accounted for amount to that of the total applicable puvp
+        user_reward_state
+            .accounted_for_rewards_puvp
+            .insert(distribution_id, total_applicable_puvp);
+
+        DISTRIBUTIONS.save(deps.storage, distribution_id, &distribution)?;
+    }
 
     // reflect the updated state changes
     USER_REWARDS.save(deps.storage, addr.clone(), &user_reward_state)?;
-    DISTRIBUTIONS.save(deps.storage, distribution_id, &distribution)?;
 
     Ok(())
 }
@@ -83,14 +103,15 @@ pub fn get_active_total_earned_puvp(
     deps: Deps,
     block: &BlockInfo,
     distribution: &DistributionState,
+    voting_power: &mut VotingPowerCache,
 ) -> Result<Uint256, ContractError> {
-    match distribution.active_epoch.emission_rate {
+    match &distribution.active_epoch.emission_rate {
         EmissionRate::Paused {} => Ok(Uint256::zero()),
         // this is updated manually during funding, so just return it here.
         EmissionRate::Immediate {} => Ok(distribution.active_epoch.total_earned_puvp),
-        EmissionRate::Linear {
-            amount, duration, ..
-        } => {
+        emission_rate @ (EmissionRate::Linear { .. }
+        | EmissionRate::Piecewise { .. }
+        | EmissionRate::Decay { .. }) => {
             let curr = distribution.active_epoch.total_earned_puvp;
 
             let last_time_rewards_distributed =
@@ -114,25 +135,50 @@ pub fn get_active_total_earned_puvp(
                 return Ok(curr);
             }
 
-            let total_power =
-                get_total_voting_power_at_block(deps, block, &distribution.vp_contract)?;
+            let total_power = voting_power.total(deps, block, &distribution.vp_contract)?;
 
             // if no voting power is registered, no one should receive rewards.
             if total_power.is_zero() {
                 Ok(curr)
             } else {
-                // count intervals of the rewards emission that have passed
-                // since the last update which need to be distributed
-                let complete_distribution_periods =
-                    new_reward_distribution_duration.checked_div(&duration)?;
+                let new_rewards = match emission_rate {
+                    EmissionRate::Linear {
+                        amount, duration, ..
+                    } => {
+                        // count intervals of the rewards emission that have
+                        // passed since the last update which need to be
+                        // distributed
+                        let complete_distribution_periods =
+                            new_reward_distribution_duration.checked_div(duration)?;
+
+                        amount.full_mul(complete_distribution_periods)
+                    }
+                    // segment rates change over time, so measure the rewards
+                    // distributed from the start of the epoch until both the
+                    // last update and now. the difference is exactly what was
+                    // distributed in between, even if the last update was in
+                    // the middle of a duration.
+                    _ => {
+                        let started_at = &distribution.active_epoch.started_at;
+                        let distributed = emission_rate.get_segment_rewards(
+                            last_time_rewards_distributed.duration_since(started_at)?,
+                        )?;
+                        let previously_distributed = emission_rate.get_segment_rewards(
+                            distribution
+                                .active_epoch
+                                .last_updated_total_earned_puvp
+                                .duration_since(started_at)?,
+                        )?;
+
+                        distributed.checked_sub(previously_distributed)?.into()
+                    }
+                };
 
                 // It is impossible for this to overflow as total rewards can
                 // never exceed max value of Uint128 as total tokens in
                 // existence cannot exceed Uint128 (because the bank module Coin
                 // type uses Uint128).
-                let new_rewards_distributed = amount
-                    .full_mul(complete_distribution_periods)
-                    .checked_mul(scale_factor())?;
+                let new_rewards_distributed = new_rewards.checked_mul(scale_factor())?;
 
                 // the new rewards per unit voting power that have been
                 // distributed since the last update
@@ -152,10 +198,12 @@ pub fn get_accrued_rewards_not_yet_accounted_for(
     total_earned_puvp: Uint256,
     distribution: &DistributionState,
     user_reward_state: &UserRewardState,
+    voting_power: &mut VotingPowerCache,
 ) -> StdResult<Uint128> {
     // get the user's voting power at the current height
-    let voting_power: Uint256 =
-        get_voting_power_at_block(deps, &env.block, &distribution.vp_contract, addr)?.into();
+    let voting_power: Uint256 = voting_power
+        .user(deps, &env.block, &distribution.vp_contract, addr)?
+        .into();
 
     // get previous reward per unit voting power accounted for
     let user_last_reward_puvp = user_reward_state
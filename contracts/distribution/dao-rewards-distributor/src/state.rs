@@ -1,7 +1,7 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    ensure, Addr, BlockInfo, Decimal, Deps, StdError, StdResult, Timestamp, Uint128, Uint256,
-    Uint64,
+    ensure, Addr, BlockInfo, Decimal, Deps, Empty, StdError, StdResult, Timestamp, Uint128,
+    Uint256, Uint64,
 };
 use cw20::{Denom, Expiration};
 use cw_storage_plus::{Item, Map};
@@ -9,7 +9,9 @@ use cw_utils::Duration;
 use std::{cmp::min, collections::HashMap};
 
 use crate::{
-    helpers::{get_total_voting_power_at_block, scale_factor, DurationExt, ExpirationExt},
+    helpers::{
+        get_total_voting_power_at_block, scale_factor, DurationExt, ExpirationExt, VotingPowerCache,
+    },
     rewards::get_active_total_earned_puvp,
     ContractError,
 };
@@ -26,6 +28,19 @@ pub const REGISTERED_HOOKS: Map<Addr, Vec<u64>> = Map::new("rh");
 /// The number of distributions that have been created.
 pub const COUNT: Item<u64> = Item::new("count");
 
+/// map bundle ID to the IDs of the distributions in it
+pub const BUNDLES: Map<u64, Vec<u64>> = Map::new("b");
+
+/// The number of bundles that have been created.
+pub const BUNDLE_COUNT: Item<u64> = Item::new("bundle_count");
+
+/// users who compound rewards paid in their voting module's staking token back
+/// into stake instead of receiving them
+pub const AUTO_COMPOUND: Map<Addr, Empty> = Map::new("ac");
+
+/// The maximum number of segments in a piecewise or decaying emission rate.
+pub const MAX_EMISSION_SEGMENTS: usize = 100;
+
 #[cw_serde]
 #[derive(Default)]
 pub struct UserRewardState {
@@ -59,6 +74,49 @@ pub enum EmissionRate {
         /// voting power.
         continuous: bool,
     },
+    /// rewards are distributed at a rate that changes over time. each
+    /// segment sets the amount distributed per duration from its start until
+    /// the next segment starts. the last segment continues until all funding
+    /// has been distributed.
+    Piecewise {
+        /// duration of time to distribute each segment's amount
+        duration: Duration,
+        /// segments ordered by start. the first segment must start at 0.
+        segments: Vec<EmissionSegment>,
+        /// whether or not reward distribution is continuous. see `Linear`.
+        continuous: bool,
+    },
+    /// rewards are distributed at a rate that decays over time. every `step`
+    /// durations, the amount distributed per duration is multiplied by
+    /// `factor` (rounding down), until it reaches `min_amount`. a halving
+    /// schedule is a factor of 0.5, and a smooth exponential decay is a step
+    /// of 1.
+    Decay {
+        /// initial amount of tokens to distribute per amount of time
+        amount: Uint128,
+        /// duration of time to distribute amount
+        duration: Duration,
+        /// the amount is multiplied by this every step. must be between 0 and
+        /// 1 exclusive.
+        factor: Decimal,
+        /// number of durations between each decay
+        step: u64,
+        /// the amount never decays below this, so that all funding is
+        /// eventually distributed
+        min_amount: Uint128,
+        /// whether or not reward distribution is continuous. see `Linear`.
+        continuous: bool,
+    },
+}
+
+/// a segment of a piecewise emission rate
+#[cw_serde]
+pub struct EmissionSegment {
+    /// number of durations after the distribution starts when this segment
+    /// begins
+    pub start: u64,
+    /// amount of tokens to distribute per duration during this segment
+    pub amount: Uint128,
 }
 
 impl EmissionRate {
@@ -82,9 +140,145 @@ impl EmissionRate {
                 }
                 Ok(())
             }
+            EmissionRate::Piecewise {
+                duration, segments, ..
+            } => {
+                if duration.is_zero() {
+                    return Err(ContractError::InvalidEmissionRateFieldZero {
+                        field: "duration".to_string(),
+                    });
+                }
+                if segments.is_empty() {
+                    return Err(ContractError::InvalidEmissionRateFieldZero {
+                        field: "segments".to_string(),
+                    });
+                }
+                if segments.len() > MAX_EMISSION_SEGMENTS {
+                    return Err(ContractError::TooManyEmissionSegments {
+                        max: MAX_EMISSION_SEGMENTS as u64,
+                    });
+                }
+                if segments[0].start != 0 || segments.windows(2).any(|w| w[0].start >= w[1].start) {
+                    return Err(ContractError::InvalidEmissionSegments {});
+                }
+                if segments.iter().any(|segment| segment.amount.is_zero()) {
+                    return Err(ContractError::InvalidEmissionRateFieldZero {
+                        field: "amount".to_string(),
+                    });
+                }
+                Ok(())
+            }
+            EmissionRate::Decay {
+                amount,
+                duration,
+                factor,
+                step,
+                min_amount,
+                ..
+            } => {
+                if duration.is_zero() {
+                    return Err(ContractError::InvalidEmissionRateFieldZero {
+                        field: "duration".to_string(),
+                    });
+                }
+                if *step == 0 {
+                    return Err(ContractError::InvalidEmissionRateFieldZero {
+                        field: "step".to_string(),
+                    });
+                }
+                if min_amount.is_zero() {
+                    return Err(ContractError::InvalidEmissionRateFieldZero {
+                        field: "min_amount".to_string(),
+                    });
+                }
+                if amount < min_amount {
+                    return Err(ContractError::InvalidDecayMinAmount {});
+                }
+                if factor.is_zero() || *factor >= Decimal::one() {
+                    return Err(ContractError::InvalidDecayFactor {});
+                }
+                // the number of segments is only known once the decay
+                // schedule has been computed.
+                self.get_segments()?;
+                Ok(())
+            }
         }
     }
 
+    /// get the segments of a piecewise or decaying emission rate and the
+    /// duration each segment's amount is distributed over. a decaying
+    /// emission rate has a segment for each step until the amount reaches
+    /// its minimum.
+    pub fn get_segments(&self) -> Result<Option<(Duration, Vec<EmissionSegment>)>, ContractError> {
+        match self {
+            EmissionRate::Paused {} | EmissionRate::Immediate {} | EmissionRate::Linear { .. } => {
+                Ok(None)
+            }
+            EmissionRate::Piecewise {
+                duration, segments, ..
+            } => Ok(Some((*duration, segments.clone()))),
+            EmissionRate::Decay {
+                amount,
+                duration,
+                factor,
+                step,
+                min_amount,
+                ..
+            } => {
+                let mut segments = vec![EmissionSegment {
+                    start: 0,
+                    amount: *amount,
+                }];
+                let mut last = segments[0].clone();
+                while last.amount > *min_amount {
+                    if segments.len() == MAX_EMISSION_SEGMENTS {
+                        return Err(ContractError::TooManyEmissionSegments {
+                            max: MAX_EMISSION_SEGMENTS as u64,
+                        });
+                    }
+                    last = EmissionSegment {
+                        start: last
+                            .start
+                            .checked_add(*step)
+                            .ok_or_else(|| StdError::generic_err("decay schedule is too long"))?,
+                        amount: last
+                            .amount
+                            .checked_mul_floor(*factor)
+                            .map_err(|e| StdError::generic_err(e.to_string()))?
+                            .max(*min_amount),
+                    };
+                    segments.push(last.clone());
+                }
+                Ok(Some((*duration, segments)))
+            }
+        }
+    }
+
+    /// get the rewards distributed by a piecewise or decaying emission rate
+    /// during the first `elapsed` of its epoch. only complete durations are
+    /// counted. since this is always measured from the start of the epoch,
+    /// the rewards distributed between any two times is exact.
+    pub fn get_segment_rewards(&self, elapsed: Duration) -> Result<Uint128, ContractError> {
+        let Some((duration, segments)) = self.get_segments()? else {
+            return Ok(Uint128::zero());
+        };
+        let periods = elapsed.checked_div(&duration)?.u128();
+
+        let mut rewards = Uint128::zero();
+        for (i, segment) in segments.iter().enumerate() {
+            let start = segment.start as u128;
+            if periods <= start {
+                break;
+            }
+            let end = segments
+                .get(i + 1)
+                .map_or(periods, |next| periods.min(next.start as u128));
+            rewards = rewards.checked_add(segment.amount.checked_mul((end - start).into())?)?;
+        }
+
+        Ok(rewards)
+    }
+
     /// find the duration of the funded period given funded amount. e.g. if the
     /// funded amount is twice the emission rate amount, the funded period
     /// should be twice the emission rate duration, since the funded amount
@@ -123,6 +317,72 @@ impl EmissionRate {
 
                 Ok(Some(funded_duration))
             }
+            // if rewards follow segments, find the number of complete
+            // durations the segments take to distribute the funded amount
+            EmissionRate::Piecewise { .. } | EmissionRate::Decay { .. } => {
+                let Some((duration, segments)) = self
+                    .get_segments()
+                    .map_err(|e| StdError::generic_err(e.to_string()))?
+                else {
+                    return Ok(None);
+                };
+
+                let mut remaining = funded_amount;
+                let mut periods = Uint128::zero();
+                for (i, segment) in segments.iter().enumerate() {
+                    let affordable = remaining.checked_div(segment.amount)?;
+                    let length = segments
+                        .get(i + 1)
+                        .map(|next| Uint128::from(next.start - segment.start));
+                    match length {
+                        // the segment is fully funded, so move on to the next
+                        Some(length) if affordable >= length => {
+                            remaining -= segment.amount.checked_mul(length)?;
+                            periods += length;
+                        }
+                        _ => {
+                            periods += affordable;
+                            break;
+                        }
+                    }
+                }
+
+                let funded_duration = match duration {
+                    Duration::Height(h) => {
+                        Duration::Height(Uint64::try_from(periods.checked_mul(h.into())?)?.u64())
+                    }
+                    Duration::Time(t) => {
+                        Duration::Time(Uint64::try_from(periods.checked_mul(t.into())?)?.u64())
+                    }
+                };
+
+                Ok(Some(funded_duration))
+            }
+        }
+    }
+
+    /// whether or not reward distribution is continuous. only linear,
+    /// piecewise, and decaying emission rates may be continuous.
+    pub fn is_continuous(&self) -> bool {
+        match self {
+            EmissionRate::Paused {} | EmissionRate::Immediate {} => false,
+            EmissionRate::Linear { continuous, .. }
+            | EmissionRate::Piecewise { continuous, .. }
+            | EmissionRate::Decay { continuous, .. } => *continuous,
+        }
+    }
+
+    /// get the start of an epoch with this emission rate that begins at the
+    /// given block. paused and immediate emission rates have no start.
+    pub fn get_started_at(&self, block: &BlockInfo) -> Expiration {
+        match self {
+            EmissionRate::Paused {} | EmissionRate::Immediate {} => Expiration::Never {},
+            EmissionRate::Linear { duration, .. }
+            | EmissionRate::Piecewise { duration, .. }
+            | EmissionRate::Decay { duration, .. } => match duration {
+                Duration::Height(_) => Expiration::AtHeight(block.height),
+                Duration::Time(_) => Expiration::AtTime(block.time),
+            },
         }
     }
 }
@@ -194,6 +454,10 @@ pub struct DistributionState {
     /// changes in the emission rate. each time emission rate is changed, this
     /// value is increased by the `active_epoch`'s rewards earned puvp.
     pub historical_earned_puvp: Uint256,
+    /// the bundle this distribution belongs to, if any. distributions in a
+    /// bundle share a voting power contract and hook caller.
+    #[serde(default)]
+    pub bundle: Option<u64>,
 }
 
 impl DistributionState {
@@ -231,7 +495,7 @@ impl DistributionState {
 
     /// get rewards to be distributed until the given expiration
     pub fn get_rewards_until(&self, expiration: Expiration) -> Result<Uint128, ContractError> {
-        match self.active_epoch.emission_rate {
+        match &self.active_epoch.emission_rate {
             EmissionRate::Paused {} => Ok(Uint128::zero()),
             EmissionRate::Immediate {} => Ok(self.funded_amount),
             EmissionRate::Linear {
@@ -246,10 +510,22 @@ impl DistributionState {
 
                 // count total intervals of the rewards emission that will pass
                 // based on the start and end times.
-                let complete_distribution_periods = epoch_duration.checked_div(&duration)?;
+                let complete_distribution_periods = epoch_duration.checked_div(duration)?;
 
                 Ok(amount.checked_mul(complete_distribution_periods)?)
             }
+            EmissionRate::Piecewise { .. } | EmissionRate::Decay { .. } => {
+                // if not yet started, return 0.
+                if let Expiration::Never {} = self.active_epoch.started_at {
+                    return Ok(Uint128::zero());
+                }
+
+                let epoch_duration = expiration.duration_since(&self.active_epoch.started_at)?;
+
+                self.active_epoch
+                    .emission_rate
+                    .get_segment_rewards(epoch_duration)
+            }
         }
     }
 
@@ -293,8 +569,12 @@ impl DistributionState {
         // 1. finish current epoch by updating rewards and setting end to the
         //    last time rewards were distributed (which is either the end date
         //    or the current block)
-        self.active_epoch.total_earned_puvp =
-            get_active_total_earned_puvp(deps, current_block, self)?;
+        self.active_epoch.total_earned_puvp = get_active_total_earned_puvp(
+            deps,
+            current_block,
+            self,
+            &mut VotingPowerCache::default(),
+        )?;
         self.active_epoch.ends_at = self.get_latest_reward_distribution_time(current_block);
 
         // 2. add current epoch rewards earned to historical rewards
@@ -339,14 +619,7 @@ impl DistributionState {
             None => Expiration::Never {},
         };
 
-        let new_started_at = match new_emission_rate {
-            EmissionRate::Paused {} => Expiration::Never {},
-            EmissionRate::Immediate {} => Expiration::Never {},
-            EmissionRate::Linear { duration, .. } => match duration {
-                Duration::Height(_) => Expiration::AtHeight(current_block.height),
-                Duration::Time(_) => Expiration::AtTime(current_block.time),
-            },
-        };
+        let new_started_at = new_emission_rate.get_started_at(current_block);
 
         self.active_epoch = Epoch {
             emission_rate: new_emission_rate.clone(),
@@ -7,12 +7,12 @@ use cw4::{Member, MemberListResponse};
 use cw_multi_test::{App, BankSudo, Executor, SudoMsg};
 use cw_ownable::Action;
 use cw_utils::Duration;
-use dao_interface::voting::InfoResponse;
+use dao_interface::voting::{InfoResponse, Query as VotingQueryMsg, VotingPowerAtHeightResponse};
 
 use crate::{
     msg::{
-        CreateMsg, DistributionsResponse, ExecuteMsg, FundMsg, InstantiateMsg,
-        PendingRewardsResponse, QueryMsg, ReceiveCw20Msg,
+        BundleDenom, BundleResponse, CreateBundleMsg, CreateMsg, DistributionsResponse, ExecuteMsg,
+        FundMsg, InstantiateMsg, PendingRewardsResponse, QueryMsg, ReceiveCw20Msg,
     },
     state::{DistributionState, EmissionRate},
     testing::cw20_setup::instantiate_cw20,
@@ -407,6 +407,40 @@ impl Suite {
         resp
     }
 
+    pub fn get_bundle(&mut self, id: u64) -> BundleResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.distribution_contract.clone(), &QueryMsg::Bundle { id })
+            .unwrap()
+    }
+
+    pub fn get_auto_compound(&self, address: &str) -> bool {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.distribution_contract.clone(),
+                &QueryMsg::AutoCompound {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn get_voting_power(&self, address: &str) -> u128 {
+        let resp: VotingPowerAtHeightResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                self.voting_power_addr.clone(),
+                &VotingQueryMsg::VotingPowerAtHeight {
+                    address: address.to_string(),
+                    height: None,
+                },
+            )
+            .unwrap();
+        resp.power.u128()
+    }
+
     pub fn get_undistributed_rewards(&mut self, id: u64) -> Uint128 {
         let undistributed_rewards: Uint128 = self
             .app
@@ -457,6 +491,9 @@ impl Suite {
             EmissionRate::Paused {} => panic!("expected non-paused emission rate"),
             EmissionRate::Immediate {} => panic!("expected non-immediate emission rate"),
             EmissionRate::Linear { amount, .. } => assert_eq!(amount, Uint128::new(expected)),
+            EmissionRate::Piecewise { .. } | EmissionRate::Decay { .. } => {
+                panic!("expected linear emission rate")
+            }
         }
     }
 
@@ -472,6 +509,9 @@ impl Suite {
                 },
                 expected
             ),
+            EmissionRate::Piecewise { .. } | EmissionRate::Decay { .. } => {
+                panic!("expected linear emission rate")
+            }
         }
     }
 
@@ -717,6 +757,63 @@ impl Suite {
             .unwrap();
     }
 
+    pub fn claim_all_rewards(&mut self, address: &str) {
+        self.app
+            .execute_contract(
+                Addr::unchecked(address),
+                self.distribution_contract.clone(),
+                &ExecuteMsg::ClaimAll {},
+                &[],
+            )
+            .unwrap();
+    }
+
+    pub fn set_auto_compound(&mut self, address: &str, enabled: bool) {
+        self.app
+            .execute_contract(
+                Addr::unchecked(address),
+                self.distribution_contract.clone(),
+                &ExecuteMsg::SetAutoCompound { enabled },
+                &[],
+            )
+            .unwrap();
+    }
+
+    pub fn compound(&mut self, sender: &str, ids: Vec<u64>) -> Result<(), ContractError> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.distribution_contract.clone(),
+                &ExecuteMsg::Compound {
+                    ids,
+                    start_after: None,
+                    limit: None,
+                },
+                &[],
+            )
+            .map(|_| ())
+            .map_err(|e| e.downcast().unwrap())
+    }
+
+    /// creates a bundle with the suite's voting power contract and hook caller
+    pub fn create_bundle(&mut self, denoms: Vec<BundleDenom>, funds: &[Coin]) {
+        let msg = ExecuteMsg::CreateBundle(CreateBundleMsg {
+            denoms,
+            vp_contract: self.voting_power_addr.to_string(),
+            hook_caller: self.staking_addr.to_string(),
+            open_funding: None,
+            withdraw_destination: None,
+        });
+        self.app
+            .execute_contract(
+                Addr::unchecked(OWNER),
+                self.distribution_contract.clone(),
+                &msg,
+                funds,
+            )
+            .unwrap();
+    }
+
     #[allow(dead_code)]
     pub fn stake_cw20_tokens(&mut self, amount: u128, sender: &str) {
         let msg = cw20::Cw20ExecuteMsg::Send {
@@ -801,6 +898,27 @@ impl Suite {
             .unwrap();
     }
 
+    pub fn set_emission_rate(&mut self, id: u64, emission_rate: EmissionRate) {
+        let msg: ExecuteMsg = ExecuteMsg::Update {
+            id,
+            emission_rate: Some(emission_rate),
+            vp_contract: None,
+            hook_caller: None,
+            open_funding: None,
+            withdraw_destination: None,
+        };
+
+        let _resp = self
+            .app
+            .execute_contract(
+                Addr::unchecked(OWNER),
+                self.distribution_contract.clone(),
+                &msg,
+                &[],
+            )
+            .unwrap();
+    }
+
     pub fn set_immediate_emission(&mut self, id: u64) {
         let msg: ExecuteMsg = ExecuteMsg::Update {
             id,
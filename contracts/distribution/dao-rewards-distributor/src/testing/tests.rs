@@ -1,7 +1,7 @@
 use std::borrow::BorrowMut;
 
 use cosmwasm_std::testing::{mock_dependencies, mock_env};
-use cosmwasm_std::{coin, coins, to_json_binary, Addr, Timestamp};
+use cosmwasm_std::{coin, coins, to_json_binary, Addr, Decimal, Timestamp};
 use cosmwasm_std::{Uint128, Uint256};
 use cw2::ContractVersion;
 use cw20::{Cw20Coin, Expiration, UncheckedDenom};
@@ -12,8 +12,11 @@ use cw_utils::Duration;
 use dao_interface::voting::InfoResponse;
 
 use crate::contract::{CONTRACT_NAME, CONTRACT_VERSION};
-use crate::msg::{CreateMsg, FundMsg, InstantiateMsg, MigrateMsg};
-use crate::state::{EmissionRate, Epoch};
+use crate::msg::{
+    BundleDenom, BundleResponse, CreateBundleMsg, CreateMsg, FundMsg, InstantiateMsg, MigrateMsg,
+    QueryMsg,
+};
+use crate::state::{EmissionRate, EmissionSegment, Epoch, MAX_EMISSION_SEGMENTS};
 use crate::testing::native_setup::setup_native_token_test;
 use crate::ContractError;
 use crate::{
@@ -2440,6 +2443,202 @@ fn test_validate_emission_rate_duration_time() {
     suite.update_emission_rate(1, Duration::Time(0), 100, true);
 }
 
+#[test]
+fn test_validate_segmented_emission_rates() {
+    let segment = |start: u64, amount: u128| EmissionSegment {
+        start,
+        amount: Uint128::new(amount),
+    };
+    let piecewise = |segments: Vec<EmissionSegment>| EmissionRate::Piecewise {
+        duration: Duration::Height(10),
+        segments,
+        continuous: true,
+    };
+
+    piecewise(vec![segment(0, 100), segment(10, 50)])
+        .validate()
+        .unwrap();
+    assert_eq!(
+        piecewise(vec![]).validate().unwrap_err(),
+        ContractError::InvalidEmissionRateFieldZero {
+            field: "segments".to_string()
+        }
+    );
+    assert_eq!(
+        piecewise(vec![segment(1, 100)]).validate().unwrap_err(),
+        ContractError::InvalidEmissionSegments {}
+    );
+    assert_eq!(
+        piecewise(vec![segment(0, 100), segment(10, 50), segment(10, 25)])
+            .validate()
+            .unwrap_err(),
+        ContractError::InvalidEmissionSegments {}
+    );
+    assert_eq!(
+        piecewise(vec![segment(0, 100), segment(10, 0)])
+            .validate()
+            .unwrap_err(),
+        ContractError::InvalidEmissionRateFieldZero {
+            field: "amount".to_string()
+        }
+    );
+    assert_eq!(
+        piecewise(
+            (0..=MAX_EMISSION_SEGMENTS as u64)
+                .map(|i| segment(i, 1))
+                .collect()
+        )
+        .validate()
+        .unwrap_err(),
+        ContractError::TooManyEmissionSegments {
+            max: MAX_EMISSION_SEGMENTS as u64
+        }
+    );
+
+    let decay = |amount: u128, factor: Decimal, min_amount: u128| EmissionRate::Decay {
+        amount: Uint128::new(amount),
+        duration: Duration::Time(60),
+        factor,
+        step: 100,
+        min_amount: Uint128::new(min_amount),
+        continuous: false,
+    };
+
+    decay(1_000, Decimal::percent(50), 1).validate().unwrap();
+    assert_eq!(
+        decay(1_000, Decimal::one(), 1).validate().unwrap_err(),
+        ContractError::InvalidDecayFactor {}
+    );
+    assert_eq!(
+        decay(1_000, Decimal::zero(), 1).validate().unwrap_err(),
+        ContractError::InvalidDecayFactor {}
+    );
+    assert_eq!(
+        decay(1_000, Decimal::percent(50), 0)
+            .validate()
+            .unwrap_err(),
+        ContractError::InvalidEmissionRateFieldZero {
+            field: "min_amount".to_string()
+        }
+    );
+    assert_eq!(
+        decay(1, Decimal::percent(50), 2).validate().unwrap_err(),
+        ContractError::InvalidDecayMinAmount {}
+    );
+    // decaying by 1% a step takes more than the maximum number of segments to
+    // reach the minimum.
+    assert_eq!(
+        decay(1_000, Decimal::percent(99), 1)
+            .validate()
+            .unwrap_err(),
+        ContractError::TooManyEmissionSegments {
+            max: MAX_EMISSION_SEGMENTS as u64
+        }
+    );
+
+    // halving
+    let (_, segments) = decay(1_000, Decimal::percent(50), 100)
+        .get_segments()
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        segments,
+        vec![
+            segment(0, 1_000),
+            segment(100, 500),
+            segment(200, 250),
+            segment(300, 125),
+            segment(400, 100),
+        ]
+    );
+}
+
+#[test]
+fn test_piecewise_and_decay_emission() {
+    let mut suite = SuiteBuilder::base(super::suite::DaoType::Native).build();
+
+    // front-load rewards: 2_000 per 10 blocks for the first 100_000 blocks,
+    // then 1_000 for the next 100_000 blocks, then 500 until funding runs out.
+    suite.set_emission_rate(
+        1,
+        EmissionRate::Piecewise {
+            duration: Duration::Height(10),
+            segments: vec![
+                EmissionSegment {
+                    start: 0,
+                    amount: Uint128::new(2_000),
+                },
+                EmissionSegment {
+                    start: 10_000,
+                    amount: Uint128::new(1_000),
+                },
+                EmissionSegment {
+                    start: 20_000,
+                    amount: Uint128::new(500),
+                },
+            ],
+            continuous: true,
+        },
+    );
+
+    // 20_000_000 + 10_000_000 in the first two segments, and the remaining
+    // 70_000_000 over 140_000 durations of the last.
+    suite.assert_started_at(Expiration::AtHeight(0));
+    suite.assert_ends_at(Expiration::AtHeight(1_600_000));
+
+    suite.skip_blocks(100_000);
+    suite.assert_pending_rewards(ADDR1, 1, 10_000_000);
+    suite.assert_pending_rewards(ADDR2, 1, 5_000_000);
+    suite.assert_pending_rewards(ADDR3, 1, 5_000_000);
+    suite.assert_undistributed_rewards(1, 80_000_000);
+
+    // claiming in the middle of a duration does not lose any rewards.
+    suite.skip_blocks(5);
+    suite.claim_rewards(ADDR1, 1);
+    suite.skip_blocks(99_995);
+    suite.assert_pending_rewards(ADDR1, 1, 5_000_000);
+    suite.assert_pending_rewards(ADDR2, 1, 7_500_000);
+    suite.assert_pending_rewards(ADDR3, 1, 7_500_000);
+    suite.assert_undistributed_rewards(1, 70_000_000);
+
+    // switch to a halving schedule in the middle of a duration. the
+    // remaining 70_000_000 is distributed starting at 1_000 per 10 blocks,
+    // halving every 1_000 durations until 100 per 10 blocks.
+    suite.skip_blocks(5);
+    suite.set_emission_rate(
+        1,
+        EmissionRate::Decay {
+            amount: Uint128::new(1_000),
+            duration: Duration::Height(10),
+            factor: Decimal::percent(50),
+            step: 1_000,
+            min_amount: Uint128::new(100),
+            continuous: true,
+        },
+    );
+
+    // 1_875_000 over the first 4_000 durations, and the remaining 68_125_000
+    // over 681_250 durations at the minimum.
+    suite.assert_started_at(Expiration::AtHeight(200_005));
+    suite.assert_ends_at(Expiration::AtHeight(200_005 + 6_852_500));
+    suite.assert_pending_rewards(ADDR1, 1, 5_000_000);
+    suite.assert_undistributed_rewards(1, 70_000_000);
+
+    // first two steps
+    suite.skip_blocks(20_000);
+    suite.assert_pending_rewards(ADDR1, 1, 5_750_000);
+    suite.assert_pending_rewards(ADDR2, 1, 7_875_000);
+    suite.assert_pending_rewards(ADDR3, 1, 7_875_000);
+    suite.assert_undistributed_rewards(1, 68_500_000);
+
+    // everything is distributed by the end.
+    suite.skip_blocks(6_852_500);
+    suite.assert_pending_rewards(ADDR1, 1, 40_000_000);
+    suite.assert_pending_rewards(ADDR2, 1, 25_000_000);
+    suite.assert_pending_rewards(ADDR3, 1, 25_000_000);
+    suite.assert_undistributed_rewards(1, 0);
+}
+
 #[test]
 fn test_query_info() {
     let mut suite = SuiteBuilder::base(super::suite::DaoType::Native).build();
@@ -2899,3 +3098,270 @@ fn test_migrate() {
         }
     );
 }
+
+#[test]
+fn test_bundle_claim_all() {
+    let mut suite = SuiteBuilder::base(super::suite::DaoType::Native).build();
+    let third_denom = "uthird";
+
+    suite.mint_native(coin(1_000_000, ALT_DENOM), OWNER);
+    suite.mint_native(coin(1_000_000, third_denom), OWNER);
+
+    // distributions 2 and 3 are bundled together
+    suite.create_bundle(
+        vec![
+            BundleDenom {
+                denom: UncheckedDenom::Native(ALT_DENOM.to_string()),
+                emission_rate: EmissionRate::Linear {
+                    amount: Uint128::new(500),
+                    duration: Duration::Height(10),
+                    continuous: true,
+                },
+            },
+            BundleDenom {
+                denom: UncheckedDenom::Native(third_denom.to_string()),
+                emission_rate: EmissionRate::Linear {
+                    amount: Uint128::new(200),
+                    duration: Duration::Height(10),
+                    continuous: true,
+                },
+            },
+        ],
+        &[coin(1_000_000, ALT_DENOM), coin(1_000_000, third_denom)],
+    );
+
+    let bundle = suite.get_bundle(1);
+    assert_eq!(bundle.id, 1);
+    assert_eq!(
+        bundle
+            .distributions
+            .iter()
+            .map(|d| (d.id, d.bundle, d.funded_amount.u128()))
+            .collect::<Vec<_>>(),
+        vec![(2, Some(1), 1_000_000), (3, Some(1), 1_000_000)]
+    );
+    assert_eq!(suite.get_distribution(1).bundle, None);
+
+    suite.skip_blocks(10_000);
+
+    suite.assert_pending_rewards(ADDR1, 1, 500_000);
+    suite.assert_pending_rewards(ADDR1, 2, 250_000);
+    suite.assert_pending_rewards(ADDR1, 3, 100_000);
+
+    // claiming all pays out every distribution, bundled or not
+    let denom_balance = suite.get_balance_native(ADDR1, DENOM);
+    suite.claim_all_rewards(ADDR1);
+    suite.assert_native_balance(ADDR1, DENOM, denom_balance + 500_000);
+    suite.assert_native_balance(ADDR1, ALT_DENOM, 250_000);
+    suite.assert_native_balance(ADDR1, third_denom, 100_000);
+    suite.assert_pending_rewards(ADDR1, 1, 0);
+    suite.assert_pending_rewards(ADDR1, 2, 0);
+    suite.assert_pending_rewards(ADDR1, 3, 0);
+
+    // nothing left to claim
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            Addr::unchecked(ADDR1),
+            suite.distribution_contract.clone(),
+            &ExecuteMsg::ClaimAll {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoRewardsClaimable {});
+
+    // a stake change updates every distribution the hook caller is
+    // registered for
+    suite.unstake_native_tokens(ADDR2, 50);
+    suite.skip_blocks(3_000);
+
+    suite.assert_pending_rewards(ADDR1, 1, 200_000);
+    suite.assert_pending_rewards(ADDR1, 2, 100_000);
+    suite.assert_pending_rewards(ADDR1, 3, 40_000);
+    suite.assert_pending_rewards(ADDR2, 1, 250_000);
+    suite.assert_pending_rewards(ADDR2, 2, 125_000);
+    suite.assert_pending_rewards(ADDR2, 3, 50_000);
+    suite.assert_pending_rewards(ADDR3, 1, 350_000);
+    suite.assert_pending_rewards(ADDR3, 2, 175_000);
+    suite.assert_pending_rewards(ADDR3, 3, 70_000);
+
+    // updating the hook caller of one distribution in a bundle updates them
+    // all, but leaves other distributions alone
+    suite.update_hook_caller(2, "new_hook_caller");
+    assert_eq!(
+        suite.get_distribution(3).hook_caller,
+        Addr::unchecked("new_hook_caller")
+    );
+    assert_eq!(suite.get_distribution(1).hook_caller, suite.staking_addr);
+}
+
+#[test]
+fn test_create_bundle_invalid() {
+    let mut suite = SuiteBuilder::base(super::suite::DaoType::Native).build();
+
+    let bundle_denom = |denom: &str| BundleDenom {
+        denom: UncheckedDenom::Native(denom.to_string()),
+        emission_rate: EmissionRate::Linear {
+            amount: Uint128::new(1_000),
+            duration: Duration::Height(10),
+            continuous: true,
+        },
+    };
+    let vp_contract = suite.voting_power_addr.to_string();
+    let hook_caller = suite.staking_addr.to_string();
+    let create_bundle = |denoms: Vec<BundleDenom>| {
+        ExecuteMsg::CreateBundle(CreateBundleMsg {
+            denoms,
+            vp_contract: vp_contract.clone(),
+            hook_caller: hook_caller.clone(),
+            open_funding: None,
+            withdraw_destination: None,
+        })
+    };
+
+    let msg = create_bundle(vec![]);
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            suite.distribution_contract.clone(),
+            &msg,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::EmptyBundle {});
+
+    let msg = create_bundle(vec![bundle_denom(ALT_DENOM), bundle_denom(ALT_DENOM)]);
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            suite.distribution_contract.clone(),
+            &msg,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::DuplicateBundleDenom {});
+
+    // funds must be for a denom in the bundle
+    suite.mint_native(coin(100, DENOM), OWNER);
+    let msg = create_bundle(vec![bundle_denom(ALT_DENOM)]);
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            suite.distribution_contract.clone(),
+            &msg,
+            &coins(100, DENOM),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidFunds {});
+
+    // only the owner can create bundles
+    let err: ContractError = suite
+        .app
+        .execute_contract(
+            Addr::unchecked(ADDR1),
+            suite.distribution_contract.clone(),
+            &msg,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Ownable(OwnershipError::NotOwner));
+
+    let err = suite
+        .app
+        .wrap()
+        .query_wasm_smart::<BundleResponse>(
+            suite.distribution_contract.clone(),
+            &QueryMsg::Bundle { id: 1 },
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("Bundle not found with ID 1"));
+}
+
+#[test]
+fn test_auto_compound() {
+    let mut suite = SuiteBuilder::base(super::suite::DaoType::Native).build();
+
+    // rewards in a denom other than the staking token are never compounded
+    suite.mint_native(coin(100_000_000, ALT_DENOM), OWNER);
+    suite.create(
+        RewardsConfig {
+            amount: 1_000,
+            denom: UncheckedDenom::Native(ALT_DENOM.to_string()),
+            duration: Duration::Height(10),
+            destination: None,
+            continuous: true,
+        },
+        suite.staking_addr.to_string().as_ref(),
+        Some(Uint128::new(100_000_000)),
+    );
+
+    suite.set_auto_compound(ADDR1, true);
+    suite.set_auto_compound(ADDR2, true);
+    assert!(suite.get_auto_compound(ADDR1));
+    assert!(!suite.get_auto_compound(ADDR3));
+
+    let compounders: Vec<Addr> = suite
+        .app
+        .wrap()
+        .query_wasm_smart(
+            suite.distribution_contract.clone(),
+            &QueryMsg::AutoCompounders {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        compounders,
+        vec![Addr::unchecked(ADDR1), Addr::unchecked(ADDR2)]
+    );
+
+    suite.skip_blocks(10_000);
+
+    suite.assert_pending_rewards(ADDR1, 1, 500_000);
+    suite.assert_pending_rewards(ADDR2, 1, 250_000);
+    suite.assert_pending_rewards(ADDR3, 1, 250_000);
+
+    // claimed staking token rewards are staked instead of sent, while other
+    // rewards are sent
+    suite.claim_all_rewards(ADDR1);
+    suite.assert_native_balance(ADDR1, DENOM, 0);
+    suite.assert_native_balance(ADDR1, ALT_DENOM, 500_000);
+
+    // compounding a distribution that does not exist fails
+    assert_eq!(
+        suite.compound(ADDR3, vec![1, 3]).unwrap_err(),
+        ContractError::DistributionNotFound { id: 3 }
+    );
+
+    // anyone can compound the rewards of users who enabled it. distributions
+    // that cannot be compounded are skipped.
+    suite.compound(ADDR3, vec![1, 2]).unwrap();
+    suite.assert_pending_rewards(ADDR2, 1, 0);
+    suite.assert_pending_rewards(ADDR2, 2, 250_000);
+    suite.assert_pending_rewards(ADDR3, 1, 250_000);
+
+    suite.skip_blocks(1);
+    assert_eq!(suite.get_voting_power(ADDR1), 100 + 500_000);
+    assert_eq!(suite.get_voting_power(ADDR2), 50 + 250_000);
+    assert_eq!(suite.get_voting_power(ADDR3), 50);
+
+    // once disabled, rewards are sent again
+    suite.set_auto_compound(ADDR2, false);
+    assert!(!suite.get_auto_compound(ADDR2));
+    suite.claim_rewards(ADDR2, 2);
+    suite.assert_native_balance(ADDR2, ALT_DENOM, 250_000);
+}
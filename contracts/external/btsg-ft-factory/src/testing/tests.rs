@@ -83,6 +83,7 @@ fn test_issue_fantoken() -> anyhow::Result<()> {
                 allow_revoting: false,
                 pre_propose_info: dao_voting::pre_propose::PreProposeInfo::AnyoneMayPropose {},
                 close_proposal_on_execution_failure: true,
+                review_period: None,
                 veto: None,
             })?,
             admin: Some(Admin::CoreModule {}),
@@ -185,6 +186,7 @@ fn test_initial_fantoken_balances() -> anyhow::Result<()> {
                 allow_revoting: false,
                 pre_propose_info: dao_voting::pre_propose::PreProposeInfo::AnyoneMayPropose {},
                 close_proposal_on_execution_failure: true,
+                review_period: None,
                 veto: None,
             })?,
             admin: Some(Admin::CoreModule {}),
@@ -292,6 +294,7 @@ fn test_fantoken_minter_and_authority_set_to_dao() -> anyhow::Result<()> {
                 allow_revoting: false,
                 pre_propose_info: dao_voting::pre_propose::PreProposeInfo::AnyoneMayPropose {},
                 close_proposal_on_execution_failure: true,
+                review_period: None,
                 veto: None,
             })?,
             admin: Some(Admin::CoreModule {}),
@@ -449,6 +452,7 @@ fn test_fantoken_can_be_staked() -> anyhow::Result<()> {
                 allow_revoting: false,
                 pre_propose_info: dao_voting::pre_propose::PreProposeInfo::AnyoneMayPropose {},
                 close_proposal_on_execution_failure: true,
+                review_period: None,
                 veto: None,
             })?,
             admin: Some(Admin::CoreModule {}),
@@ -1,7 +1,7 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{ensure, Decimal, StdResult, Uint128};
+use cosmwasm_std::{ensure, Decimal, StdError, StdResult, Timestamp, Uint128};
 use cw_curves::{
-    curves::{Constant, Linear, SquareRoot},
+    curves::{Constant, Exponential, Linear, PiecewiseLinear, Sigmoid, SquareRoot},
     utils::decimal,
     Curve, DecimalPlaces,
 };
@@ -73,6 +73,49 @@ impl HatchConfig {
     }
 }
 
+#[cw_serde]
+pub struct VestingConfig {
+    /// The number of seconds after the Hatch phase ends before any tokens vest
+    pub cliff_seconds: u64,
+    /// The number of seconds after the Hatch phase ends over which tokens vest linearly
+    pub duration_seconds: u64,
+}
+
+impl Copy for VestingConfig {}
+
+impl VestingConfig {
+    /// Validate the vesting config
+    pub fn validate(&self) -> Result<(), ContractError> {
+        ensure!(
+            self.duration_seconds > 0,
+            ContractError::VestingPhaseConfigError(
+                "Vesting duration must be greater than zero.".to_string()
+            )
+        );
+
+        ensure!(
+            self.cliff_seconds <= self.duration_seconds,
+            ContractError::VestingPhaseConfigError(
+                "Vesting cliff must not be longer than the vesting duration.".to_string()
+            )
+        );
+
+        Ok(())
+    }
+
+    /// Returns how much of `total` has vested at `now`, for vesting that started at `start`
+    pub fn vested(&self, total: Uint128, start: Timestamp, now: Timestamp) -> Uint128 {
+        let elapsed = now.seconds().saturating_sub(start.seconds());
+        if elapsed < self.cliff_seconds {
+            Uint128::zero()
+        } else if elapsed >= self.duration_seconds {
+            total
+        } else {
+            total.multiply_ratio(elapsed, self.duration_seconds)
+        }
+    }
+}
+
 #[cw_serde]
 pub struct OpenConfig {
     /// Percentage of capital put into the Reserve Pool during the Open phase
@@ -117,9 +160,9 @@ impl ClosedConfig {
 pub struct CommonsPhaseConfig {
     /// The Hatch phase where initial contributors (Hatchers) participate in a hatch sale.
     pub hatch: HatchConfig,
-    /// TODO Vest tokens after hatch phase
     /// The Vesting phase where tokens minted during the Hatch phase are locked (burning is disabled) to combat early speculation/arbitrage.
-    /// pub vesting: VestingConfig,
+    /// Vesting starts when the Hatch phase ends. If not set, tokens minted during the Hatch phase are not locked.
+    pub vesting: Option<VestingConfig>,
     /// The Open phase where anyone can mint tokens by contributing the reserve token into the curve and becoming members of the Commons.
     pub open: OpenConfig,
     /// The Closed phase where the Commons is closed to new members.
@@ -172,6 +215,9 @@ impl CommonsPhaseConfig {
     /// Validate that the commons configuration is valid
     pub fn validate(&self) -> Result<(), ContractError> {
         self.hatch.validate()?;
+        if let Some(vesting) = &self.vesting {
+            vesting.validate()?;
+        }
         self.open.validate()?;
         self.closed.validate()?;
 
@@ -181,8 +227,6 @@ impl CommonsPhaseConfig {
 
 pub type CurveFn = Box<dyn Fn(DecimalPlaces) -> Box<dyn Curve>>;
 
-// TODO Curve type validation?
-// TODO add S-curve and taylor series
 #[cw_serde]
 pub enum CurveType {
     /// Constant always returns `value * 10^-scale` as spot price
@@ -191,6 +235,31 @@ pub enum CurveType {
     Linear { slope: Uint128, scale: u32 },
     /// SquareRoot returns `slope * 10^-scale * supply^0.5` as spot price
     SquareRoot { slope: Uint128, scale: u32 },
+    /// Exponential returns `value * 10^-scale * e^(growth * 10^-scale * supply)` as spot price
+    Exponential {
+        value: Uint128,
+        growth: Uint128,
+        scale: u32,
+    },
+    /// Sigmoid returns `max_price / (1 + e^(-steepness * (supply - midpoint)))` as spot
+    /// price, where `max_price`, `midpoint` and `steepness` are multiplied by `10^-scale`.
+    /// The price rises fastest at the midpoint and levels off towards the max price.
+    /// Parameters for which the price or reserve can not be calculated at zero supply
+    /// are rejected.
+    Sigmoid {
+        max_price: Uint128,
+        midpoint: Uint128,
+        steepness: Uint128,
+        scale: u32,
+    },
+    /// PiecewiseLinear interpolates the spot price between `(supply, price)` points,
+    /// each multiplied by `10^-scale`, and keeps the last price beyond the last point.
+    /// There may be at most 100 points, which must start at zero supply, increase in supply,
+    /// and never decrease in price.
+    PiecewiseLinear {
+        points: Vec<(Uint128, Uint128)>,
+        scale: u32,
+    },
 }
 
 impl CurveType {
@@ -213,6 +282,62 @@ impl CurveType {
                     move |places| -> Box<dyn Curve> { Box::new(SquareRoot::new(slope, places)) };
                 Ok(Box::new(calc))
             }
+            CurveType::Exponential {
+                value,
+                growth,
+                scale,
+            } => {
+                let value = decimal(value, scale)?;
+                let growth = decimal(growth, scale)?;
+                if growth.is_zero() {
+                    return Err(StdError::generic_err(
+                        "Exponential curve growth must be positive",
+                    ));
+                }
+                let calc = move |places| -> Box<dyn Curve> {
+                    Box::new(Exponential::new(value, growth, places))
+                };
+                Ok(Box::new(calc))
+            }
+            CurveType::Sigmoid {
+                max_price,
+                midpoint,
+                steepness,
+                scale,
+            } => {
+                let max_price = decimal(max_price, scale)?;
+                let midpoint = decimal(midpoint, scale)?;
+                let steepness = decimal(steepness, scale)?;
+                if max_price.is_zero() || steepness.is_zero() {
+                    return Err(StdError::generic_err(
+                        "Sigmoid curve max price and steepness must be positive",
+                    ));
+                }
+                // Supply 0 is the same in any decimal places
+                let start = Sigmoid::new(max_price, midpoint, steepness, DecimalPlaces::new(0, 0));
+                if start.spot_price(Uint128::zero()).is_err()
+                    || start.reserve(Uint128::zero()).is_err()
+                {
+                    return Err(StdError::generic_err(
+                        "Sigmoid curve can not be evaluated at zero supply",
+                    ));
+                }
+                let calc = move |places| -> Box<dyn Curve> {
+                    Box::new(Sigmoid::new(max_price, midpoint, steepness, places))
+                };
+                Ok(Box::new(calc))
+            }
+            CurveType::PiecewiseLinear { points, scale } => {
+                let points = points
+                    .into_iter()
+                    .map(|(supply, price)| Ok((decimal(supply, scale)?, decimal(price, scale)?)))
+                    .collect::<StdResult<Vec<_>>>()?;
+                PiecewiseLinear::validate(&points)?;
+                let calc = move |places| -> Box<dyn Curve> {
+                    Box::new(PiecewiseLinear::new(points.clone(), places))
+                };
+                Ok(Box::new(calc))
+            }
         }
     }
 }
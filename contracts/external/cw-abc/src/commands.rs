@@ -1,22 +1,35 @@
 use cosmwasm_std::{
-    to_json_binary, Addr, BankMsg, Coin, CosmosMsg, DepsMut, Env, MessageInfo, QuerierWrapper,
-    Response, StdResult, Storage, Uint128, WasmMsg,
+    to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, DepsMut, Env, MessageInfo,
+    QuerierWrapper, Response, StdResult, Storage, Timestamp, Uint128, WasmMsg,
 };
 use cw_tokenfactory_issuer::msg::ExecuteMsg as IssuerExecuteMsg;
 use cw_utils::must_pay;
 use std::ops::Deref;
 
 use crate::abc::{CommonsPhase, CurveType, HatchConfig, MinMax};
-use crate::helpers::{calculate_buy_quote, calculate_sell_quote};
+use crate::helpers::{
+    assert_deadline, assert_min_out, calculate_buy_quote, calculate_hatcher_vesting,
+    calculate_sell_quote, record_price_observation, record_trade,
+};
 use crate::msg::{HatcherAllowlistEntryMsg, UpdatePhaseConfigMsg};
 use crate::state::{
-    hatcher_allowlist, HatcherAllowlistConfig, HatcherAllowlistConfigType, CURVE_STATE, CURVE_TYPE,
-    DONATIONS, FUNDING_POOL_FORWARDING, HATCHERS, HATCHER_DAO_PRIORITY_QUEUE, IS_PAUSED,
-    MAX_SUPPLY, PHASE, PHASE_CONFIG, SUPPLY_DENOM, TOKEN_ISSUER_CONTRACT,
+    hatcher_allowlist, HatcherAllowlistConfig, HatcherAllowlistConfigType, Trade, TradeSide,
+    CURVE_STATE, CURVE_TYPE, DONATIONS, FUNDING_POOL_FORWARDING, HATCHERS, HATCHER_CLAIMED,
+    HATCHER_DAO_PRIORITY_QUEUE, HATCHER_MINTED, IS_PAUSED, MAX_SUPPLY, PHASE, PHASE_CONFIG,
+    SUPPLY_DENOM, TOKEN_ISSUER_CONTRACT, VESTING_START,
 };
 use crate::ContractError;
 
-pub fn buy(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+pub fn buy(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    deadline: Option<Timestamp>,
+    max_price: Option<Decimal>,
+    min_out: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    assert_deadline(env.block.time, deadline)?;
+
     let curve_type = CURVE_TYPE.load(deps.storage)?;
     let mut curve_state = CURVE_STATE.load(deps.storage)?;
 
@@ -29,6 +42,19 @@ pub fn buy(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, Cont
     // Calculate the curve state from the buy
     let buy_quote = calculate_buy_quote(payment, &curve_type, &curve_state, &phase, &phase_config)?;
 
+    // Check the buy against the bounds set by the buyer
+    assert_min_out(buy_quote.amount, min_out)?;
+    if let Some(max_price) = max_price {
+        match buy_quote.price {
+            Some(price) if price <= max_price => {}
+            _ => return Err(ContractError::MaxPriceExceeded { max_price }),
+        }
+    }
+
+    // Tokens minted during the hatch phase are held by this contract while they vest,
+    // and released to the hatcher with `ClaimVested {}`
+    let escrow = phase == CommonsPhase::Hatch && phase_config.vesting.is_some();
+
     // Validate phase
     match &phase {
         CommonsPhase::Hatch => {
@@ -56,6 +82,13 @@ pub fn buy(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, Cont
                 });
             }
 
+            // Track the tokens escrowed for the hatcher, which vest after the hatch phase
+            if escrow {
+                HATCHER_MINTED.update(deps.storage, &info.sender, |amount| -> StdResult<_> {
+                    Ok(amount.unwrap_or_default().checked_add(buy_quote.amount)?)
+                })?;
+            }
+
             // Check if the initial_raise max has been met
             if buy_quote.new_reserve >= hatch_config.initial_raise.max {
                 // Transition to the Open phase
@@ -65,6 +98,9 @@ pub fn buy(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, Cont
                 hatcher_allowlist().clear(deps.storage);
 
                 PHASE.save(deps.storage, &phase)?;
+
+                // Start vesting the tokens minted during the hatch phase
+                VESTING_START.save(deps.storage, &env.block.time)?;
             }
         }
         CommonsPhase::Open => {}
@@ -80,12 +116,18 @@ pub fn buy(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, Cont
         }
     }
 
-    // Mint tokens for sender by calling mint on the cw-tokenfactory-issuer contract
+    // Mint tokens for sender, or into escrow for vesting hatch phase tokens, by calling mint
+    // on the cw-tokenfactory-issuer contract
+    let mint_to = if escrow {
+        &env.contract.address
+    } else {
+        &info.sender
+    };
     let issuer_addr = TOKEN_ISSUER_CONTRACT.load(deps.storage)?;
     let mut msgs: Vec<CosmosMsg> = vec![CosmosMsg::Wasm(WasmMsg::Execute {
         contract_addr: issuer_addr.to_string(),
         msg: to_json_binary(&IssuerExecuteMsg::Mint {
-            to_address: info.sender.to_string(),
+            to_address: mint_to.to_string(),
             amount: buy_quote.amount,
         })?,
         funds: vec![],
@@ -112,6 +154,23 @@ pub fn buy(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, Cont
 
     CURVE_STATE.save(deps.storage, &curve_state)?;
 
+    // Record the new spot price and the trade
+    record_price_observation(deps.storage, &curve_type, &curve_state, env.block.time)?;
+    record_trade(
+        deps.storage,
+        Trade {
+            id: 0,
+            side: TradeSide::Buy,
+            trader: info.sender.clone(),
+            reserve_in: payment,
+            reserve_out: Uint128::zero(),
+            supply_delta: buy_quote.amount,
+            fee: buy_quote.funded,
+            time: env.block.time,
+            height: env.block.height,
+        },
+    )?;
+
     Ok(Response::new()
         .add_messages(msgs)
         .add_attribute("action", "buy")
@@ -124,7 +183,16 @@ pub fn buy(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, Cont
 }
 
 /// Sell tokens on the bonding curve
-pub fn sell(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+pub fn sell(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    deadline: Option<Timestamp>,
+    min_out: Option<Uint128>,
+    min_price: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    assert_deadline(env.block.time, deadline)?;
+
     let curve_type = CURVE_TYPE.load(deps.storage)?;
     let supply_denom = SUPPLY_DENOM.load(deps.storage)?;
     let burn_amount = must_pay(&info, &supply_denom)?;
@@ -144,6 +212,15 @@ pub fn sell(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, Con
         &phase_config,
     )?;
 
+    // Check the sell against the bounds set by the seller
+    assert_min_out(sell_quote.amount, min_out)?;
+    if let Some(min_price) = min_price {
+        match sell_quote.price {
+            Some(price) if price >= min_price => {}
+            _ => return Err(ContractError::MinPriceNotMet { min_price }),
+        }
+    }
+
     let mut send_msgs: Vec<CosmosMsg> = vec![CosmosMsg::Bank(BankMsg::Send {
         to_address: info.sender.to_string(),
         amount: vec![Coin {
@@ -195,6 +272,23 @@ pub fn sell(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, Con
     curve_state.supply = sell_quote.new_supply;
     CURVE_STATE.save(deps.storage, &curve_state)?;
 
+    // Record the new spot price and the trade
+    record_price_observation(deps.storage, &curve_type, &curve_state, env.block.time)?;
+    record_trade(
+        deps.storage,
+        Trade {
+            id: 0,
+            side: TradeSide::Sell,
+            trader: info.sender.clone(),
+            reserve_in: Uint128::zero(),
+            reserve_out: sell_quote.amount,
+            supply_delta: burn_amount,
+            fee: sell_quote.funded,
+            time: env.block.time,
+            height: env.block.height,
+        },
+    )?;
+
     Ok(Response::new()
         .add_messages(burn_msgs)
         .add_messages(send_msgs)
@@ -207,17 +301,50 @@ pub fn sell(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, Con
         .add_attribute("funded", sell_quote.funded))
 }
 
+/// Send the vested supply tokens minted to a hatcher during the hatch phase
+pub fn claim_vested(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let vesting = calculate_hatcher_vesting(deps.as_ref(), &info.sender, env.block.time)?;
+    if vesting.claimable.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+
+    HATCHER_CLAIMED.save(
+        deps.storage,
+        &info.sender,
+        &vesting.claimed.checked_add(vesting.claimable)?,
+    )?;
+
+    let supply_denom = SUPPLY_DENOM.load(deps.storage)?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin {
+                amount: vesting.claimable,
+                denom: supply_denom,
+            }],
+        })
+        .add_attribute("action", "claim_vested")
+        .add_attribute("from", info.sender)
+        .add_attribute("amount", vesting.claimable))
+}
+
 /// Transitions the bonding curve to a closed phase where only sells are allowed
-pub fn close(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+pub fn close(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
     cw_ownable::assert_owner(deps.storage, &info.sender)?;
 
     PHASE.save(deps.storage, &CommonsPhase::Closed)?;
 
+    // If closed during the hatch phase, start vesting the tokens minted during it
+    if !VESTING_START.exists(deps.storage) {
+        VESTING_START.save(deps.storage, &env.block.time)?;
+    }
+
     Ok(Response::new().add_attribute("action", "close"))
 }
 
 /// Send a donation to the funding pool
-pub fn donate(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+pub fn donate(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
     let mut curve_state = CURVE_STATE.load(deps.storage)?;
 
     let payment = must_pay(&info, &curve_state.reserve_denom)?;
@@ -246,6 +373,10 @@ pub fn donate(deps: DepsMut, _env: Env, info: MessageInfo) -> Result<Response, C
             }
         })?;
 
+    // Donations don't move the price, but are recorded so the price history is complete
+    let curve_type = CURVE_TYPE.load(deps.storage)?;
+    record_price_observation(deps.storage, &curve_type, &curve_state, env.block.time)?;
+
     Ok(Response::new()
         .add_attribute("action", "donate")
         .add_attribute("donor", info.sender)
@@ -600,13 +731,19 @@ pub fn update_phase_config(
 /// TODO: what other limitations do we want to put on this?
 pub fn update_curve(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     curve_type: CurveType,
 ) -> Result<Response, ContractError> {
     cw_ownable::assert_owner(deps.storage, &info.sender)?;
 
+    curve_type.to_curve_fn()?;
     CURVE_TYPE.save(deps.storage, &curve_type)?;
 
+    // The new curve changes the spot price
+    let curve_state = CURVE_STATE.load(deps.storage)?;
+    record_price_observation(deps.storage, &curve_type, &curve_state, env.block.time)?;
+
     Ok(Response::new().add_attribute("action", "close"))
 }
 
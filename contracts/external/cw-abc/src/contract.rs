@@ -13,10 +13,11 @@ use cw_utils::parse_reply_instantiate_data;
 
 use crate::abc::{CommonsPhase, CurveFn};
 use crate::error::ContractError;
+use crate::helpers::record_price_observation;
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 use crate::state::{
     CurveState, CURVE_STATE, CURVE_TYPE, FUNDING_POOL_FORWARDING, IS_PAUSED, MAX_SUPPLY, PHASE,
-    PHASE_CONFIG, SUPPLY_DENOM, TEMP_SUPPLY, TOKEN_ISSUER_CONTRACT,
+    PHASE_CONFIG, PRICE_OBSERVATIONS, SUPPLY_DENOM, TEMP_SUPPLY, TOKEN_ISSUER_CONTRACT,
 };
 use crate::{commands, queries};
 
@@ -65,7 +66,8 @@ pub fn instantiate(
         MAX_SUPPLY.save(deps.storage, &max_supply)?;
     }
 
-    // Save the curve type
+    // Validate and save the curve type
+    curve_type.to_curve_fn()?;
     CURVE_TYPE.save(deps.storage, &curve_type)?;
 
     PHASE_CONFIG.save(deps.storage, &phase_config)?;
@@ -101,6 +103,10 @@ pub fn instantiate(
     // Save the curve state
     CURVE_STATE.save(deps.storage, &curve_state)?;
 
+    // Record the starting spot price, so time-weighted average prices cover the whole
+    // life of the curve
+    record_price_observation(deps.storage, &curve_type, &curve_state, env.block.time)?;
+
     // Set the paused state
     IS_PAUSED.save(deps.storage, &false)?;
 
@@ -135,9 +141,18 @@ pub fn execute(
     }
 
     match msg {
-        ExecuteMsg::Buy {} => commands::buy(deps, env, info),
-        ExecuteMsg::Sell {} => commands::sell(deps, env, info),
-        ExecuteMsg::Close {} => commands::close(deps, info),
+        ExecuteMsg::Buy {
+            deadline,
+            max_price,
+            min_out,
+        } => commands::buy(deps, env, info, deadline, max_price, min_out),
+        ExecuteMsg::Sell {
+            deadline,
+            min_out,
+            min_price,
+        } => commands::sell(deps, env, info, deadline, min_out, min_price),
+        ExecuteMsg::ClaimVested {} => commands::claim_vested(deps, env, info),
+        ExecuteMsg::Close {} => commands::close(deps, env, info),
         ExecuteMsg::Donate {} => commands::donate(deps, env, info),
         ExecuteMsg::Withdraw { amount } => commands::withdraw(deps, env, info, amount),
         ExecuteMsg::UpdateFundingPoolForwarding { address } => {
@@ -146,7 +161,9 @@ pub fn execute(
         ExecuteMsg::UpdateMaxSupply { max_supply } => {
             commands::update_max_supply(deps, info, max_supply)
         }
-        ExecuteMsg::UpdateCurve { curve_type } => commands::update_curve(deps, info, curve_type),
+        ExecuteMsg::UpdateCurve { curve_type } => {
+            commands::update_curve(deps, env, info, curve_type)
+        }
         ExecuteMsg::UpdateHatchAllowlist { to_add, to_remove } => {
             commands::update_hatch_allowlist(deps, env, info, to_add, to_remove)
         }
@@ -172,7 +189,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 /// We pull out logic here, so we can import this from another contract and set a different Curve.
 /// This contacts sets a curve with an enum in [`InstantiateMsg`] and stored in state, but you may want
 /// to use custom math not included - make this easily reusable
-pub fn do_query(deps: Deps, _env: Env, msg: QueryMsg, curve_fn: CurveFn) -> StdResult<Binary> {
+pub fn do_query(deps: Deps, env: Env, msg: QueryMsg, curve_fn: CurveFn) -> StdResult<Binary> {
     match msg {
         // custom queries
         QueryMsg::CurveInfo {} => to_json_binary(&queries::query_curve_info(deps, curve_fn)?),
@@ -188,6 +205,9 @@ pub fn do_query(deps: Deps, _env: Env, msg: QueryMsg, curve_fn: CurveFn) -> StdR
             to_json_binary(&queries::query_hatchers(deps, start_after, limit)?)
         }
         QueryMsg::Hatcher { addr } => to_json_binary(&queries::query_hatcher(deps, addr)?),
+        QueryMsg::HatcherVesting { addr } => {
+            to_json_binary(&queries::query_hatcher_vesting(deps, env, addr)?)
+        }
         QueryMsg::HatcherAllowlist {
             start_after,
             limit,
@@ -209,14 +229,27 @@ pub fn do_query(deps: Deps, _env: Env, msg: QueryMsg, curve_fn: CurveFn) -> StdR
             to_json_binary(&queries::query_sell_quote(deps, payment)?)
         }
         QueryMsg::SupplyDenom {} => to_json_binary(&SUPPLY_DENOM.load(deps.storage)?),
+        QueryMsg::Trades { start_after, limit } => {
+            to_json_binary(&queries::query_trades(deps, start_after, limit)?)
+        }
+        QueryMsg::Twap { start, end } => {
+            to_json_binary(&queries::query_twap(deps, env, start, end)?)
+        }
         QueryMsg::DumpState {} => to_json_binary(&queries::query_dump_state(deps, curve_fn)?),
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     // Set contract to version to latest
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // Contracts instantiated before price observations were added start recording them now
+    if PRICE_OBSERVATIONS.is_empty(deps.storage) {
+        let curve_type = CURVE_TYPE.load(deps.storage)?;
+        let curve_state = CURVE_STATE.load(deps.storage)?;
+        record_price_observation(deps.storage, &curve_type, &curve_state, env.block.time)?;
+    }
     Ok(Response::default())
 }
 
@@ -1,4 +1,6 @@
-use cosmwasm_std::{CheckedMultiplyFractionError, OverflowError, StdError, Uint128};
+use cosmwasm_std::{
+    CheckedMultiplyFractionError, Decimal, OverflowError, StdError, Timestamp, Uint128,
+};
 use cw_utils::{ParseReplyError, PaymentError};
 use thiserror::Error;
 
@@ -34,6 +36,9 @@ pub enum ContractError {
     #[error("Contribution must be less than or equal to {max} and greater than or equal to {min}")]
     ContributionLimit { min: Uint128, max: Uint128 },
 
+    #[error("The deadline of {deadline} has passed")]
+    DeadlineExceeded { deadline: Timestamp },
+
     #[error("Hatch phase config error {0}")]
     HatchPhaseConfigError(String),
 
@@ -46,9 +51,21 @@ pub enum ContractError {
     #[error("Invalid phase, expected {expected:?}, actual {actual:?}")]
     InvalidPhase { expected: String, actual: String },
 
+    #[error("The average price would exceed the maximum price of {max_price}")]
+    MaxPriceExceeded { max_price: Decimal },
+
+    #[error("Only {amount} tokens would be received, less than the minimum of {min_out}")]
+    MinOutNotMet { amount: Uint128, min_out: Uint128 },
+
+    #[error("The average price would be less than the minimum price of {min_price}")]
+    MinPriceNotMet { min_price: Decimal },
+
     #[error("Invalid sell amount")]
     MismatchedSellAmount {},
 
+    #[error("No vested hatch phase tokens to claim")]
+    NothingToClaim {},
+
     #[error("Open phase config error {0}")]
     OpenPhaseConfigError(String),
 
@@ -64,6 +81,9 @@ pub enum ContractError {
     #[error("Got a submessage reply with unknown id: {id}")]
     UnknownReplyId { id: u64 },
 
+    #[error("Vesting phase config error {0}")]
+    VestingPhaseConfigError(String),
+
     #[error("Contract is paused")]
     Paused {},
 }
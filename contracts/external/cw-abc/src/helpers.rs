@@ -1,9 +1,17 @@
-use cosmwasm_std::{Decimal, Deps, StdResult, Uint128};
+use cosmwasm_std::{
+    Addr, Decimal, Decimal256, Deps, Order, StdError, StdResult, Storage, Timestamp, Uint128,
+};
+use cw_curves::DecimalPlaces;
+use cw_storage_plus::Bound;
 
 use crate::{
     abc::{CommonsPhase, CommonsPhaseConfig, CurveType},
-    msg::{HatcherAllowlistEntryMsg, QuoteResponse},
-    state::{CurveState, HatcherAllowlistConfig, HatcherAllowlistEntry},
+    msg::{HatcherAllowlistEntryMsg, HatcherVestingResponse, QuoteResponse},
+    state::{
+        CurveState, HatcherAllowlistConfig, HatcherAllowlistEntry, PriceObservation, Trade,
+        HATCHER_CLAIMED, HATCHER_MINTED, PHASE_CONFIG, PRICE_OBSERVATIONS, TRADES, TRADE_COUNT,
+        VESTING_START,
+    },
     ContractError,
 };
 
@@ -38,6 +46,7 @@ pub fn calculate_buy_quote(
         funded,
         amount: minted,
         new_supply,
+        price: calculate_average_price(payment, minted, curve_state.decimals)?,
     })
 }
 
@@ -77,6 +86,82 @@ pub fn calculate_sell_quote(
         funded,
         amount: reserved,
         new_supply,
+        price: calculate_average_price(reserved, payment, curve_state.decimals)?,
+    })
+}
+
+/// Calculate the average price of a trade in reserve tokens per supply token,
+/// normalized by their decimal places like the spot price.
+/// Returns None if no supply tokens are traded.
+pub fn calculate_average_price(
+    reserve: Uint128,
+    supply: Uint128,
+    decimals: DecimalPlaces,
+) -> Result<Option<Decimal>, ContractError> {
+    if supply.is_zero() {
+        return Ok(None);
+    }
+
+    let supply_factor = Uint128::new(10).checked_pow(decimals.supply)?;
+    let reserve_factor = Uint128::new(10).checked_pow(decimals.reserve)?;
+    let price = Decimal256::checked_from_ratio(
+        reserve.full_mul(supply_factor),
+        supply.full_mul(reserve_factor),
+    )
+    .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+    Ok(Some(
+        Decimal::try_from(price).map_err(|e| StdError::generic_err(e.to_string()))?,
+    ))
+}
+
+/// Check that a trade has not passed its deadline
+pub fn assert_deadline(now: Timestamp, deadline: Option<Timestamp>) -> Result<(), ContractError> {
+    match deadline {
+        Some(deadline) if now > deadline => Err(ContractError::DeadlineExceeded { deadline }),
+        _ => Ok(()),
+    }
+}
+
+/// Check that a trade returns at least `min_out` tokens
+pub fn assert_min_out(amount: Uint128, min_out: Option<Uint128>) -> Result<(), ContractError> {
+    match min_out {
+        Some(min_out) if amount < min_out => Err(ContractError::MinOutNotMet { amount, min_out }),
+        _ => Ok(()),
+    }
+}
+
+/// Calculate how many of the supply tokens minted to a hatcher during the hatch phase
+/// have vested at `now`
+pub fn calculate_hatcher_vesting(
+    deps: Deps,
+    hatcher: &Addr,
+    now: Timestamp,
+) -> StdResult<HatcherVestingResponse> {
+    let minted = HATCHER_MINTED
+        .may_load(deps.storage, hatcher)?
+        .unwrap_or_default();
+    let vesting_start = VESTING_START.may_load(deps.storage)?;
+
+    let claimed = HATCHER_CLAIMED
+        .may_load(deps.storage, hatcher)?
+        .unwrap_or_default();
+
+    let vested = match (PHASE_CONFIG.load(deps.storage)?.vesting, vesting_start) {
+        // Without a vesting config, hatch phase tokens are minted directly to the hatcher
+        (None, _) => minted,
+        // Vesting starts once the hatch phase ends
+        (Some(_), None) => Uint128::zero(),
+        (Some(vesting), Some(start)) => vesting.vested(minted, start, now),
+    };
+
+    Ok(HatcherVestingResponse {
+        minted,
+        vested,
+        claimed,
+        claimable: vested - claimed,
+        locked: minted - vested,
+        vesting_start,
     })
 }
 
@@ -107,3 +192,58 @@ impl HatcherAllowlistEntryMsg {
         })
     }
 }
+
+/// Record the spot price of the curve after a change to it, accumulating the previous
+/// spot price over the time it was in effect
+pub fn record_price_observation(
+    storage: &mut dyn Storage,
+    curve_type: &CurveType,
+    curve_state: &CurveState,
+    now: Timestamp,
+) -> StdResult<()> {
+    let curve_fn = curve_type.to_curve_fn()?;
+    let spot_price = curve_fn(curve_state.decimals).spot_price(curve_state.supply)?;
+    let cumulative_price = calculate_cumulative_price(storage, now)?.unwrap_or_default();
+
+    PRICE_OBSERVATIONS.save(
+        storage,
+        now.seconds(),
+        &PriceObservation {
+            spot_price,
+            cumulative_price,
+        },
+    )
+}
+
+/// Calculate the cumulative price at `time` from the latest observation before it.
+/// Returns None if there are no observations before `time`.
+pub fn calculate_cumulative_price(
+    storage: &dyn Storage,
+    time: Timestamp,
+) -> StdResult<Option<Decimal256>> {
+    let now = time.seconds();
+    let latest = PRICE_OBSERVATIONS
+        .range(
+            storage,
+            None,
+            Some(Bound::inclusive(now)),
+            Order::Descending,
+        )
+        .next()
+        .transpose()?;
+
+    let Some((observed, observation)) = latest else {
+        return Ok(None);
+    };
+    let elapsed = Decimal256::from_ratio(now - observed, 1u64);
+    let accumulated = Decimal256::from(observation.spot_price).checked_mul(elapsed)?;
+
+    Ok(Some(observation.cumulative_price.checked_add(accumulated)?))
+}
+
+/// Record a buy or sell in the trade history
+pub fn record_trade(storage: &mut dyn Storage, trade: Trade) -> StdResult<()> {
+    let id = TRADE_COUNT.may_load(storage)?.unwrap_or_default();
+    TRADES.save(storage, id, &Trade { id, ..trade })?;
+    TRADE_COUNT.save(storage, &(id + 1))
+}
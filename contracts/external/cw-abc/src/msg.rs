@@ -1,9 +1,9 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Decimal, Uint128};
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
 
 use crate::{
     abc::{CommonsPhase, CommonsPhaseConfig, CurveType, MinMax, ReserveToken, SupplyToken},
-    state::{HatcherAllowlistConfigType, HatcherAllowlistEntry},
+    state::{HatcherAllowlistConfigType, HatcherAllowlistEntry, Trade},
 };
 
 #[cw_serde]
@@ -59,12 +59,35 @@ pub enum UpdatePhaseConfigMsg {
 pub enum ExecuteMsg {
     /// Buy will attempt to purchase as many supply tokens as possible.
     /// You must send only reserve tokens.
+    /// The optional bounds protect against the price moving before the buy executes,
+    /// and can be taken from the `BuyQuote` query.
     #[cw_orch(payable)]
-    Buy {},
+    Buy {
+        /// Fail if the buy executes after this time.
+        deadline: Option<Timestamp>,
+        /// Fail if the average price paid per supply token, including fees,
+        /// is greater than this.
+        max_price: Option<Decimal>,
+        /// Fail if fewer supply tokens than this would be minted.
+        min_out: Option<Uint128>,
+    },
     /// Sell burns supply tokens in return for the reserve token.
     /// You must send only supply tokens.
+    /// The optional bounds protect against the price moving before the sell executes,
+    /// and can be taken from the `SellQuote` query.
     #[cw_orch(payable)]
-    Sell {},
+    Sell {
+        /// Fail if the sell executes after this time.
+        deadline: Option<Timestamp>,
+        /// Fail if fewer reserve tokens than this would be returned.
+        min_out: Option<Uint128>,
+        /// Fail if the average price received per supply token, after fees,
+        /// is less than this.
+        min_price: Option<Decimal>,
+    },
+    /// Claim the vested supply tokens minted to the sender during the hatch phase,
+    /// which are held by this contract until they vest.
+    ClaimVested {},
     /// Donate will donate tokens to the funding pool.
     /// You must send only reserve tokens.
     #[cw_orch(payable)]
@@ -146,6 +169,11 @@ pub enum QueryMsg {
     /// Returns the contribution of a hatcher
     #[returns(Uint128)]
     Hatcher { addr: String },
+    /// Returns how many of the supply tokens minted to a hatcher during the hatch
+    /// phase have vested and how many are still locked
+    /// Returns [`HatcherVestingResponse`]
+    #[returns(HatcherVestingResponse)]
+    HatcherVesting { addr: String },
     /// Lists the hatcher allowlist
     /// Returns [`HatcherAllowlistResponse`]
     #[returns(HatcherAllowlistResponse)]
@@ -179,6 +207,21 @@ pub enum QueryMsg {
     /// Returns the dumped state
     #[returns(DumpStateResponse)]
     DumpState {},
+    /// Returns the time-weighted average spot price between `start` and `end`
+    /// (defaults to now), which can't be before the contract was instantiated
+    /// Returns [`TwapResponse`]
+    #[returns(TwapResponse)]
+    Twap {
+        start: Timestamp,
+        end: Option<Timestamp>,
+    },
+    /// Lists the buys and sells against the curve, most recent first
+    /// Returns [`TradesResponse`]
+    #[returns(TradesResponse)]
+    Trades {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -251,12 +294,51 @@ pub struct HatchersResponse {
     pub hatchers: Vec<(Addr, Uint128)>,
 }
 
+#[cw_serde]
+pub struct HatcherVestingResponse {
+    /// The supply tokens minted to the hatcher during the hatch phase and held by the contract
+    pub minted: Uint128,
+    /// The portion of the minted tokens that has vested
+    pub vested: Uint128,
+    /// The portion of the vested tokens that has been claimed
+    pub claimed: Uint128,
+    /// The portion of the vested tokens that can be claimed with `ClaimVested {}`
+    pub claimable: Uint128,
+    /// The portion of the minted tokens that is still locked and cannot be claimed
+    pub locked: Uint128,
+    /// When vesting started, or None if the hatch phase has not ended
+    pub vesting_start: Option<Timestamp>,
+}
+
 #[cw_serde]
 pub struct QuoteResponse {
     pub new_reserve: Uint128,
     pub funded: Uint128,
+    /// The supply tokens minted by a buy, or the reserve tokens returned by a sell.
+    /// This is the amount checked against `min_out`.
     pub amount: Uint128,
     pub new_supply: Uint128,
+    /// The average price per supply token, in reserve tokens and including fees,
+    /// normalized by decimals like the spot price. This is the price checked against
+    /// a buy's `max_price` or a sell's `min_price`, and is None if a buy would not mint
+    /// any tokens.
+    pub price: Option<Decimal>,
+}
+
+#[cw_serde]
+pub struct TwapResponse {
+    /// The time-weighted average of the spot price over the window
+    pub average_price: Decimal,
+    /// The start of the window
+    pub start: Timestamp,
+    /// The end of the window
+    pub end: Timestamp,
+}
+
+#[cw_serde]
+pub struct TradesResponse {
+    /// The trades, most recent first
+    pub trades: Vec<Trade>,
 }
 
 #[cw_serde]
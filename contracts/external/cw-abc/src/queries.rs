@@ -1,14 +1,21 @@
 use crate::abc::CurveFn;
-use crate::helpers::{calculate_buy_quote, calculate_sell_quote};
+use crate::helpers::{
+    calculate_buy_quote, calculate_cumulative_price, calculate_hatcher_vesting,
+    calculate_sell_quote,
+};
 use crate::msg::{
     CommonsPhaseConfigResponse, CurveInfoResponse, DenomResponse, DonationsResponse,
-    DumpStateResponse, HatcherAllowlistResponse, HatchersResponse, QuoteResponse,
+    DumpStateResponse, HatcherAllowlistResponse, HatcherVestingResponse, HatchersResponse,
+    QuoteResponse, TradesResponse, TwapResponse,
 };
 use crate::state::{
     hatcher_allowlist, CurveState, HatcherAllowlistConfigType, HatcherAllowlistEntry, CURVE_STATE,
     CURVE_TYPE, DONATIONS, HATCHERS, IS_PAUSED, MAX_SUPPLY, PHASE, PHASE_CONFIG, SUPPLY_DENOM,
+    TRADES,
+};
+use cosmwasm_std::{
+    Decimal, Decimal256, Deps, Env, Order, QuerierWrapper, StdError, StdResult, Timestamp, Uint128,
 };
-use cosmwasm_std::{Deps, Order, QuerierWrapper, StdError, StdResult, Uint128};
 use cw_storage_plus::Bound;
 use std::ops::Deref;
 
@@ -95,6 +102,17 @@ pub fn query_hatcher(deps: Deps, addr: String) -> StdResult<Uint128> {
     HATCHERS.load(deps.storage, &addr)
 }
 
+/// Query the vesting of the tokens minted to a hatcher during the hatch phase
+pub fn query_hatcher_vesting(
+    deps: Deps,
+    env: Env,
+    addr: String,
+) -> StdResult<HatcherVestingResponse> {
+    let addr = deps.api.addr_validate(&addr)?;
+
+    calculate_hatcher_vesting(deps, &addr, env.block.time)
+}
+
 /// Query hatcher allowlist
 pub fn query_hatcher_allowlist(
     deps: Deps,
@@ -180,3 +198,62 @@ pub fn query_dump_state(deps: Deps, curve_fn: CurveFn) -> StdResult<DumpStateRes
         max_supply: MAX_SUPPLY.may_load(deps.storage)?,
     })
 }
+
+/// Get the time-weighted average spot price over a window
+pub fn query_twap(
+    deps: Deps,
+    env: Env,
+    start: Timestamp,
+    end: Option<Timestamp>,
+) -> StdResult<TwapResponse> {
+    let end = end.unwrap_or(env.block.time);
+    if end > env.block.time {
+        return Err(StdError::generic_err(
+            "TWAP window cannot end in the future",
+        ));
+    }
+    if end.seconds() <= start.seconds() {
+        return Err(StdError::generic_err(
+            "TWAP window must end at least a second after it starts",
+        ));
+    }
+
+    let start_cumulative = calculate_cumulative_price(deps.storage, start)?
+        .ok_or_else(|| StdError::generic_err("No price observations before the TWAP window"))?;
+    let end_cumulative = calculate_cumulative_price(deps.storage, end)?
+        .ok_or_else(|| StdError::generic_err("No price observations before the TWAP window"))?;
+
+    let elapsed = Decimal256::from_ratio(end.seconds() - start.seconds(), 1u64);
+    let average_price = end_cumulative
+        .checked_sub(start_cumulative)?
+        .checked_div(elapsed)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+    Ok(TwapResponse {
+        average_price: Decimal::try_from(average_price)
+            .map_err(|e| StdError::generic_err(e.to_string()))?,
+        start,
+        end,
+    })
+}
+
+/// List the trade history, most recent first
+pub fn query_trades(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<TradesResponse> {
+    let trades = cw_paginate_storage::paginate_map_values(
+        Deps {
+            storage: deps.storage,
+            api: deps.api,
+            querier: QuerierWrapper::new(deps.querier.deref()),
+        },
+        &TRADES,
+        start_after,
+        limit,
+        Order::Descending,
+    )?;
+
+    Ok(TradesResponse { trades })
+}
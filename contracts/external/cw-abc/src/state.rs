@@ -2,7 +2,7 @@ use std::fmt::{self, Display};
 
 use crate::abc::{CommonsPhase, CommonsPhaseConfig, CurveType, MinMax, SupplyToken};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128, Uint64};
+use cosmwasm_std::{Addr, Decimal, Decimal256, Timestamp, Uint128, Uint64};
 use cw_curves::DecimalPlaces;
 use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
@@ -35,6 +35,43 @@ impl CurveState {
     }
 }
 
+/// A spot price observation, recorded whenever the curve is traded against or donated to
+#[cw_serde]
+pub struct PriceObservation {
+    /// The spot price after the trade or donation
+    pub spot_price: Decimal,
+    /// The sum of each previous spot price multiplied by the seconds it was in effect
+    pub cumulative_price: Decimal256,
+}
+
+#[cw_serde]
+pub enum TradeSide {
+    Buy,
+    Sell,
+}
+
+/// A buy or sell against the curve
+#[cw_serde]
+pub struct Trade {
+    /// The sequential id of the trade
+    pub id: u64,
+    pub side: TradeSide,
+    /// The buyer or seller
+    pub trader: Addr,
+    /// The reserve tokens paid by a buyer, including the entry fee
+    pub reserve_in: Uint128,
+    /// The reserve tokens returned to a seller, after the exit fee
+    pub reserve_out: Uint128,
+    /// The supply tokens minted by a buy or burned by a sell
+    pub supply_delta: Uint128,
+    /// The reserve tokens sent to the funding pool
+    pub fee: Uint128,
+    /// The block time of the trade
+    pub time: Timestamp,
+    /// The block height of the trade
+    pub height: u64,
+}
+
 /// The configuration for a member of the hatcher allowlist
 #[cw_serde]
 pub struct HatcherAllowlistConfig {
@@ -129,6 +166,26 @@ pub const MAX_SUPPLY: Item<Uint128> = Item::new("max_supply");
 /// to ascertain the amount contributed by a user
 pub static HATCHERS: Map<&Addr, Uint128> = Map::new("hatchers");
 
+/// Keep track of the supply tokens minted to each hatcher during the hatch phase, which
+/// are held by the contract and vest according to the vesting config once the hatch phase ends
+pub static HATCHER_MINTED: Map<&Addr, Uint128> = Map::new("hatcher_minted");
+
+/// Keep track of the vested hatch phase tokens each hatcher has claimed
+pub static HATCHER_CLAIMED: Map<&Addr, Uint128> = Map::new("hatcher_claimed");
+
+/// The time the hatch phase ended and vesting of hatch phase tokens started
+pub static VESTING_START: Item<Timestamp> = Item::new("vesting_start");
+
+/// Spot price observations keyed by block time in seconds, used to calculate time-weighted
+/// average prices
+pub const PRICE_OBSERVATIONS: Map<u64, PriceObservation> = Map::new("price_observations");
+
+/// The history of buys and sells keyed by trade id
+pub const TRADES: Map<u64, Trade> = Map::new("trades");
+
+/// The number of trades recorded, used as the id of the next trade
+pub const TRADE_COUNT: Item<u64> = Item::new("trade_count");
+
 /// Keep track of the donated amounts per user
 pub static DONATIONS: Map<&Addr, Uint128> = Map::new("donations");
 
@@ -1,13 +1,13 @@
 use anyhow::Result;
-use cosmwasm_std::{coin, coins, to_json_binary, Decimal, Uint128, Uint64};
+use cosmwasm_std::{coin, coins, to_json_binary, Decimal, Timestamp, Uint128, Uint64};
 use cw_abc::msg::{ExecuteMsgFns, QueryMsgFns};
 use cw_abc::msg::{HatcherAllowlistConfigMsg, HatcherAllowlistEntryMsg, InstantiateMsg};
 use cw_abc::{
     abc::{
         ClosedConfig, CommonsPhase, CommonsPhaseConfig, CurveType, HatchConfig, MinMax, OpenConfig,
-        ReserveToken, SupplyToken,
+        ReserveToken, SupplyToken, VestingConfig,
     },
-    state::HatcherAllowlistConfigType,
+    state::{HatcherAllowlistConfigType, TradeSide},
 };
 use cw_orch::prelude::*;
 use cw_orch_osmosis_test_tube::osmosis_test_tube::{Account, SigningAccount};
@@ -94,6 +94,7 @@ fn default_instantiate(
                 },
                 entry_fee: Decimal::percent(10),
             },
+            vesting: None,
             open: OpenConfig {
                 entry_fee: Decimal::percent(5),
                 exit_fee: Decimal::percent(5),
@@ -228,9 +229,9 @@ fn test_pause() -> Result<()> {
     assert!(abc.is_paused()?);
 
     // Can't execute when paused
-    let result = abc
-        .call_as(&accounts.buyer)
-        .buy(&[coin(100, TEST_RESERVE_DENOM)]);
+    let result =
+        abc.call_as(&accounts.buyer)
+            .buy(None, None, None, &[coin(100, TEST_RESERVE_DENOM)]);
     assert_that!(result.unwrap_err().to_string()).contains("Contract is paused");
 
     // Creator can unpause
@@ -254,7 +255,7 @@ fn test_buy_in_hatch_phase() -> Result<()> {
 
     let buy_amount = 1000u128;
     abc.call_as(&accounts.buyer)
-        .buy(&[coin(buy_amount, TEST_RESERVE_DENOM)])?;
+        .buy(None, None, None, &[coin(buy_amount, TEST_RESERVE_DENOM)])?;
 
     let curve_info = abc.curve_info()?;
     assert!(curve_info.supply > Uint128::zero());
@@ -281,7 +282,7 @@ fn test_transition_to_open_phase() -> Result<()> {
 
     let buy_amount = 1000000u128; // Max raise amount
     abc.call_as(&accounts.buyer)
-        .buy(&[coin(buy_amount, TEST_RESERVE_DENOM)])?;
+        .buy(None, None, None, &[coin(buy_amount, TEST_RESERVE_DENOM)])?;
 
     let phase = abc.phase()?;
     assert_eq!(phase, CommonsPhase::Open);
@@ -290,8 +291,12 @@ fn test_transition_to_open_phase() -> Result<()> {
 
     // Now try to sell
     let sell_amount = Uint128::new(1000);
-    abc.call_as(&accounts.buyer)
-        .sell(&[coin(sell_amount.u128(), supply_denom)])?;
+    abc.call_as(&accounts.buyer).sell(
+        None,
+        None,
+        None,
+        &[coin(sell_amount.u128(), supply_denom)],
+    )?;
 
     Ok(())
 }
@@ -310,19 +315,162 @@ fn test_sell_in_hatch_phase() -> Result<()> {
 
     let buy_amount = 1000u128;
     abc.call_as(&accounts.buyer)
-        .buy(&[coin(buy_amount, TEST_RESERVE_DENOM)])?;
+        .buy(None, None, None, &[coin(buy_amount, TEST_RESERVE_DENOM)])?;
 
     let supply_denom = abc.supply_denom()?;
 
     let sell_amount = Uint128::new(100);
-    let result = abc
-        .call_as(&accounts.buyer)
-        .sell(&[coin(sell_amount.u128(), supply_denom)]);
+    let result = abc.call_as(&accounts.buyer).sell(
+        None,
+        None,
+        None,
+        &[coin(sell_amount.u128(), supply_denom)],
+    );
     assert_that!(result.unwrap_err().to_string()).contains("commons is locked");
 
     Ok(())
 }
 
+#[test]
+fn test_slippage_bounds() -> Result<()> {
+    let (_, abc, token_issuer_code_id, accounts) = setup()?;
+
+    let curve_type = CurveType::Linear {
+        slope: Uint128::new(1),
+        scale: 1,
+    };
+    let msg = default_instantiate(token_issuer_code_id, curve_type, None);
+    abc.call_as(&accounts.creator)
+        .instantiate(&msg, None, None)?;
+
+    // Move to the open phase
+    abc.call_as(&accounts.buyer)
+        .buy(None, None, None, &[coin(1000000, TEST_RESERVE_DENOM)])?;
+
+    // Buys fail if the quote no longer holds
+    let quote = abc.buy_quote(Uint128::new(1000))?;
+    let price = quote.price.unwrap();
+    let result = abc.call_as(&accounts.buyer).buy(
+        None,
+        None,
+        Some(quote.amount + Uint128::one()),
+        &[coin(1000, TEST_RESERVE_DENOM)],
+    );
+    assert_that!(result.unwrap_err().to_string()).contains("less than the minimum");
+    let result = abc.call_as(&accounts.buyer).buy(
+        None,
+        Some(price - Decimal::raw(1)),
+        None,
+        &[coin(1000, TEST_RESERVE_DENOM)],
+    );
+    assert_that!(result.unwrap_err().to_string()).contains("exceed the maximum price");
+    let result = abc.call_as(&accounts.buyer).buy(
+        Some(Timestamp::from_seconds(0)),
+        None,
+        None,
+        &[coin(1000, TEST_RESERVE_DENOM)],
+    );
+    assert_that!(result.unwrap_err().to_string()).contains("deadline");
+
+    // The bounds from the quote are met exactly
+    abc.call_as(&accounts.buyer).buy(
+        None,
+        Some(price),
+        Some(quote.amount),
+        &[coin(1000, TEST_RESERVE_DENOM)],
+    )?;
+    assert_eq!(abc.curve_info()?.supply, quote.new_supply);
+
+    // Sells fail if the quote no longer holds
+    let supply_denom = abc.supply_denom()?;
+    let quote = abc.sell_quote(Uint128::new(1000))?;
+    let price = quote.price.unwrap();
+    let result = abc.call_as(&accounts.buyer).sell(
+        None,
+        Some(quote.amount + Uint128::one()),
+        None,
+        &[coin(1000, &supply_denom)],
+    );
+    assert_that!(result.unwrap_err().to_string()).contains("less than the minimum");
+    let result = abc.call_as(&accounts.buyer).sell(
+        None,
+        None,
+        Some(price + Decimal::raw(1)),
+        &[coin(1000, &supply_denom)],
+    );
+    assert_that!(result.unwrap_err().to_string()).contains("less than the minimum price");
+
+    // The bounds from the quote are met exactly
+    abc.call_as(&accounts.buyer).sell(
+        Some(Timestamp::from_seconds(u64::MAX / 1_000_000_000)),
+        Some(quote.amount),
+        Some(price),
+        &[coin(1000, &supply_denom)],
+    )?;
+    assert_eq!(abc.curve_info()?.supply, quote.new_supply);
+
+    Ok(())
+}
+
+#[test]
+fn test_twap_and_trade_history() -> Result<()> {
+    let (chain, abc, token_issuer_code_id, accounts) = setup()?;
+
+    let curve_type = CurveType::Linear {
+        slope: Uint128::new(1),
+        scale: 1,
+    };
+    let msg = default_instantiate(token_issuer_code_id, curve_type, None);
+    abc.call_as(&accounts.creator)
+        .instantiate(&msg, None, None)?;
+
+    // Buy enough to move to the open phase
+    abc.call_as(&accounts.buyer)
+        .buy(None, None, None, &[coin(1000000, TEST_RESERVE_DENOM)])?;
+    let buy_price = abc.curve_info()?.spot_price;
+    chain.wait_seconds(100)?;
+
+    let supply_denom = abc.supply_denom()?;
+    abc.call_as(&accounts.buyer)
+        .sell(None, None, None, &[coin(1000, supply_denom)])?;
+    let sell_price = abc.curve_info()?.spot_price;
+    chain.wait_seconds(100)?;
+
+    // Trades are listed most recent first
+    let trades = abc.trades(None, None)?.trades;
+    assert_eq!(trades.len(), 2);
+    let (sell, buy) = (&trades[0], &trades[1]);
+    assert_eq!(sell.id, 1);
+    assert_eq!(sell.side, TradeSide::Sell);
+    assert_eq!(sell.supply_delta, Uint128::new(1000));
+    assert!(sell.reserve_out > Uint128::zero());
+    assert_eq!(buy.id, 0);
+    assert_eq!(buy.side, TradeSide::Buy);
+    assert_eq!(buy.trader.as_str(), accounts.buyer.address());
+    assert_eq!(buy.reserve_in, Uint128::new(1000000));
+    // Hatch phase entry fee of 10%
+    assert_eq!(buy.fee, Uint128::new(100000));
+    assert_eq!(abc.trades(None, Some(1))?.trades, vec![buy.clone()]);
+
+    // The price held between trades is the average over that window
+    let twap = abc.twap(Some(sell.time), buy.time)?;
+    assert_eq!(twap.average_price, buy_price);
+    let twap = abc.twap(None, sell.time)?;
+    assert_eq!(twap.average_price, sell_price);
+
+    // A window spanning both trades averages the two prices
+    let twap = abc.twap(None, buy.time)?;
+    assert!(twap.average_price < buy_price && twap.average_price > sell_price);
+
+    // Windows must be covered by observations and not end in the future
+    let result = abc.twap(None, Timestamp::from_seconds(0));
+    assert_that!(result.unwrap_err().to_string()).contains("No price observations");
+    let result = abc.twap(Some(sell.time.plus_days(1)), sell.time);
+    assert_that!(result.unwrap_err().to_string()).contains("future");
+
+    Ok(())
+}
+
 #[test]
 fn test_update_curve_parameters() -> Result<()> {
     let (_, abc, token_issuer_code_id, accounts) = setup()?;
@@ -348,6 +496,102 @@ fn test_update_curve_parameters() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_additional_curve_types() -> Result<()> {
+    let curve_types = vec![
+        CurveType::Exponential {
+            value: Uint128::new(1),
+            growth: Uint128::new(1),
+            scale: 1,
+        },
+        CurveType::Sigmoid {
+            max_price: Uint128::new(20),
+            midpoint: Uint128::new(100),
+            steepness: Uint128::new(1),
+            scale: 1,
+        },
+        // The spot price at zero supply is far too small to represent
+        CurveType::Sigmoid {
+            max_price: Uint128::new(20_000),
+            midpoint: Uint128::new(10_000_000_000),
+            steepness: Uint128::new(1),
+            scale: 4,
+        },
+        CurveType::PiecewiseLinear {
+            points: vec![
+                (Uint128::zero(), Uint128::new(1)),
+                (Uint128::new(100), Uint128::new(2)),
+            ],
+            scale: 1,
+        },
+    ];
+
+    for curve_type in curve_types {
+        let (_, abc, token_issuer_code_id, accounts) = setup()?;
+        let msg = default_instantiate(token_issuer_code_id, curve_type, None);
+        abc.call_as(&accounts.creator)
+            .instantiate(&msg, None, None)?;
+
+        abc.call_as(&accounts.buyer).buy(
+            None,
+            None,
+            None,
+            &[coin(1000u128, TEST_RESERVE_DENOM)],
+        )?;
+
+        let curve_info = abc.curve_info()?;
+        assert!(curve_info.supply > Uint128::zero());
+        assert!(curve_info.spot_price > Decimal::zero());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_curve_types() -> Result<()> {
+    let (_, abc, token_issuer_code_id, accounts) = setup()?;
+
+    // Piecewise linear points must start at zero supply
+    let curve_type = CurveType::PiecewiseLinear {
+        points: vec![(Uint128::new(1), Uint128::new(1))],
+        scale: 1,
+    };
+    let msg = default_instantiate(token_issuer_code_id, curve_type, None);
+    let result = abc.call_as(&accounts.creator).instantiate(&msg, None, None);
+    assert_that!(result.unwrap_err().to_string()).contains("must start at zero supply");
+
+    let curve_type = CurveType::Linear {
+        slope: Uint128::new(1),
+        scale: 1,
+    };
+    let msg = default_instantiate(token_issuer_code_id, curve_type, None);
+    abc.call_as(&accounts.creator)
+        .instantiate(&msg, None, None)?;
+
+    // Exponential growth must be positive
+    let result = abc
+        .call_as(&accounts.creator)
+        .update_curve(CurveType::Exponential {
+            value: Uint128::new(1),
+            growth: Uint128::zero(),
+            scale: 1,
+        });
+    assert_that!(result.unwrap_err().to_string()).contains("growth must be positive");
+
+    // Sigmoid curves must be able to price zero supply
+    let result = abc
+        .call_as(&accounts.creator)
+        .update_curve(CurveType::Sigmoid {
+            max_price: Uint128::new(1),
+            midpoint: Uint128::new(10u128.pow(27)),
+            steepness: Uint128::new(1_000),
+            scale: 0,
+        });
+    assert_that!(result.unwrap_err().to_string()).contains("can not be evaluated at zero supply");
+
+    Ok(())
+}
+
 #[test]
 fn test_query_functions() -> Result<()> {
     let (_, abc, token_issuer_code_id, accounts) = setup()?;
@@ -397,23 +641,23 @@ fn test_contribution_limits() -> Result<()> {
         .instantiate(&msg, None, None)?;
 
     // Test minimum contribution limit
-    let result = abc
-        .call_as(&accounts.buyer)
-        .buy(&[coin(99, TEST_RESERVE_DENOM)]);
+    let result =
+        abc.call_as(&accounts.buyer)
+            .buy(None, None, None, &[coin(99, TEST_RESERVE_DENOM)]);
     assert_that!(result.unwrap_err().to_string())
         .contains("Contribution must be less than or equal to");
 
     // Test maximum contribution limit
-    let result = abc
-        .call_as(&accounts.buyer)
-        .buy(&[coin(1001, TEST_RESERVE_DENOM)]);
+    let result =
+        abc.call_as(&accounts.buyer)
+            .buy(None, None, None, &[coin(1001, TEST_RESERVE_DENOM)]);
     assert_that!(result.unwrap_err().to_string())
         .contains("Contribution must be less than or equal to");
 
     // Test valid contribution
-    let result = abc
-        .call_as(&accounts.buyer)
-        .buy(&[coin(500, TEST_RESERVE_DENOM)]);
+    let result =
+        abc.call_as(&accounts.buyer)
+            .buy(None, None, None, &[coin(500, TEST_RESERVE_DENOM)]);
     assert!(result.is_ok());
 
     Ok(())
@@ -436,12 +680,12 @@ fn test_max_supply() -> Result<()> {
 
     // Buy tokens up to max supply
     abc.call_as(&accounts.buyer)
-        .buy(&[coin(10000, TEST_RESERVE_DENOM)])?;
+        .buy(None, None, None, &[coin(10000, TEST_RESERVE_DENOM)])?;
 
     // Attempt to buy more tokens
-    let result = abc
-        .call_as(&accounts.buyer)
-        .buy(&[coin(100, TEST_RESERVE_DENOM)]);
+    let result =
+        abc.call_as(&accounts.buyer)
+            .buy(None, None, None, &[coin(100, TEST_RESERVE_DENOM)]);
     assert_that!(result.unwrap_err().to_string()).contains("Cannot mint more tokens");
 
     // Verify max supply
@@ -466,7 +710,7 @@ fn test_closing_curve() -> Result<()> {
 
     // Buy tokens to transition to open phase
     abc.call_as(&accounts.buyer)
-        .buy(&[coin(1000000, TEST_RESERVE_DENOM)])?;
+        .buy(None, None, None, &[coin(1000000, TEST_RESERVE_DENOM)])?;
 
     // Close the curve
     abc.call_as(&accounts.creator).close()?;
@@ -475,9 +719,9 @@ fn test_closing_curve() -> Result<()> {
     assert_eq!(abc.phase()?, CommonsPhase::Closed);
 
     // Attempt to buy tokens (should fail)
-    let result = abc
-        .call_as(&accounts.buyer)
-        .buy(&[coin(500, TEST_RESERVE_DENOM)]);
+    let result =
+        abc.call_as(&accounts.buyer)
+            .buy(None, None, None, &[coin(500, TEST_RESERVE_DENOM)]);
     assert_that!(result.unwrap_err().to_string())
         .contains("commons is closed to new contributions");
 
@@ -485,12 +729,123 @@ fn test_closing_curve() -> Result<()> {
     let curve_denom = abc.supply_denom()?;
     let result = abc
         .call_as(&accounts.buyer)
-        .sell(&[coin(1000, &curve_denom)]);
+        .sell(None, None, None, &[coin(1000, &curve_denom)]);
     assert!(result.is_ok());
 
     Ok(())
 }
 
+#[test]
+fn test_hatcher_vesting() -> Result<()> {
+    let (chain, abc, token_issuer_code_id, accounts) = setup()?;
+
+    let curve_type = CurveType::Linear {
+        slope: Uint128::new(1),
+        scale: 1,
+    };
+    let mut msg = default_instantiate(token_issuer_code_id, curve_type, None);
+    msg.phase_config.vesting = Some(VestingConfig {
+        cliff_seconds: 100,
+        duration_seconds: 1000,
+    });
+
+    // Vesting cliff must not exceed the duration
+    let mut invalid = msg.clone();
+    invalid.phase_config.vesting = Some(VestingConfig {
+        cliff_seconds: 1001,
+        duration_seconds: 1000,
+    });
+    let result = abc
+        .call_as(&accounts.creator)
+        .instantiate(&invalid, None, None);
+    assert_that!(result.unwrap_err().to_string()).contains("Vesting phase config error");
+
+    abc.call_as(&accounts.creator)
+        .instantiate(&msg, None, None)?;
+
+    // Hatch buy, still in the hatch phase so vesting has not started
+    abc.call_as(&accounts.buyer)
+        .buy(None, None, None, &[coin(1000, TEST_RESERVE_DENOM)])?;
+    let vesting = abc.hatcher_vesting(accounts.buyer.address())?;
+    assert!(vesting.minted > Uint128::zero());
+    assert_eq!(vesting.locked, vesting.minted);
+    assert_eq!(vesting.vesting_start, None);
+
+    // Buy enough to transition to the open phase, within the contribution limit
+    abc.call_as(&accounts.buyer)
+        .buy(None, None, None, &[coin(999000, TEST_RESERVE_DENOM)])?;
+    assert_eq!(abc.phase()?, CommonsPhase::Open);
+    let vesting = abc.hatcher_vesting(accounts.buyer.address())?;
+    assert!(vesting.vesting_start.is_some());
+    assert_eq!(vesting.vested, Uint128::zero());
+    let minted = vesting.minted;
+
+    // Hatch phase tokens are held by the contract, not the hatcher
+    let supply_denom = abc.supply_denom()?;
+    let balance = chain.query_balance(&accounts.buyer.address(), &supply_denom)?;
+    assert_eq!(balance, Uint128::zero());
+    let balance = chain.query_balance(&abc.address()?.to_string(), &supply_denom)?;
+    assert_eq!(balance, minted);
+
+    // So they can't be transferred to another address and sold from there
+    let result = chain
+        .call_as(&accounts.buyer)
+        .bank_send(accounts.donor.address(), coins(1000, &supply_denom));
+    assert!(result.is_err());
+    let result = abc
+        .call_as(&accounts.donor)
+        .sell(None, None, None, &[coin(1000, &supply_denom)]);
+    assert!(result.is_err());
+
+    // Nothing can be claimed before the cliff
+    let result = abc.call_as(&accounts.buyer).claim_vested();
+    assert_that!(result.unwrap_err().to_string()).contains("No vested hatch phase tokens");
+
+    // Open phase buys are not locked and can be sold
+    abc.call_as(&accounts.buyer)
+        .buy(None, None, None, &[coin(10000, TEST_RESERVE_DENOM)])?;
+    let vesting = abc.hatcher_vesting(accounts.buyer.address())?;
+    assert_eq!(vesting.minted, minted);
+    let open_minted = chain.query_balance(&accounts.buyer.address(), &supply_denom)?;
+    abc.call_as(&accounts.buyer).sell(
+        None,
+        None,
+        None,
+        &[coin(open_minted.u128(), &supply_denom)],
+    )?;
+
+    // Halfway through vesting, about half of the hatch tokens can be claimed and sold
+    chain.wait_seconds(500)?;
+    let vesting = abc.hatcher_vesting(accounts.buyer.address())?;
+    assert!(vesting.vested >= minted.multiply_ratio(1u128, 2u128));
+    assert!(vesting.locked > Uint128::zero());
+    assert_eq!(vesting.claimable, vesting.vested);
+    abc.call_as(&accounts.buyer).claim_vested()?;
+    let balance = chain.query_balance(&accounts.buyer.address(), &supply_denom)?;
+    assert_eq!(balance, vesting.vested);
+    abc.call_as(&accounts.buyer)
+        .sell(None, None, None, &[coin(balance.u128(), &supply_denom)])?;
+
+    // Claiming again releases nothing until more tokens vest
+    let vesting = abc.hatcher_vesting(accounts.buyer.address())?;
+    assert_eq!(vesting.claimable, Uint128::zero());
+    let result = abc.call_as(&accounts.buyer).claim_vested();
+    assert!(result.is_err());
+
+    // Everything is vested and claimable after the duration
+    chain.wait_seconds(500)?;
+    let vesting = abc.hatcher_vesting(accounts.buyer.address())?;
+    assert_eq!(vesting.vested, minted);
+    assert_eq!(vesting.locked, Uint128::zero());
+    abc.call_as(&accounts.buyer).claim_vested()?;
+    let vesting = abc.hatcher_vesting(accounts.buyer.address())?;
+    assert_eq!(vesting.claimed, minted);
+    let balance = chain.query_balance(&abc.address()?.to_string(), &supply_denom)?;
+    assert_eq!(balance, Uint128::zero());
+
+    Ok(())
+}
+
 #[test]
 fn test_dao_hatcher_functionality() -> Result<()> {
     let (mut chain, abc, token_issuer_code_id, accounts) = setup()?;
@@ -588,7 +943,7 @@ fn test_dao_hatcher_functionality() -> Result<()> {
     // Check contribution limit (should be 50, the highest priority DAO's limit)
     let err = abc
         .call_as(&accounts.buyer)
-        .buy(&[coin(51, TEST_RESERVE_DENOM)])
+        .buy(None, None, None, &[coin(51, TEST_RESERVE_DENOM)])
         .unwrap_err();
     assert_that!(err.to_string()).contains("Contribution must be less than or equal to");
 
@@ -598,7 +953,7 @@ fn test_dao_hatcher_functionality() -> Result<()> {
 
     // Check new contribution limit (should be 1000, the next highest priority DAO's limit)
     abc.call_as(&accounts.buyer)
-        .buy(&[coin(1000, TEST_RESERVE_DENOM)])?;
+        .buy(None, None, None, &[coin(1000, TEST_RESERVE_DENOM)])?;
 
     // Add an individual address to the allowlist
     let individual = chain.init_account(coins(1_000_000_000_000, TEST_RESERVE_DENOM))?;
@@ -620,17 +975,17 @@ fn test_dao_hatcher_functionality() -> Result<()> {
 
     // Check that the individual address limit takes precedence
     abc.call_as(&individual)
-        .buy(&[coin(2000, TEST_RESERVE_DENOM)])?;
+        .buy(None, None, None, &[coin(2000, TEST_RESERVE_DENOM)])?;
 
     // Non-DAO member cannot buy tokens
-    let result = abc
-        .call_as(&accounts.creator)
-        .buy(&[coin(50, TEST_RESERVE_DENOM)]);
+    let result =
+        abc.call_as(&accounts.creator)
+            .buy(None, None, None, &[coin(50, TEST_RESERVE_DENOM)]);
     assert_that!(result.unwrap_err().to_string()).contains("not in the hatcher allowlist");
 
     // DAO member without priority can buy tokens within their limit
     abc.call_as(&accounts.donor)
-        .buy(&[coin(100, TEST_RESERVE_DENOM)])?;
+        .buy(None, None, None, &[coin(100, TEST_RESERVE_DENOM)])?;
 
     Ok(())
 }
@@ -680,6 +1035,7 @@ pub fn test_arena_token() -> Result<()> {
                 },
                 entry_fee: Decimal::zero(),
             },
+            vesting: None,
             open: OpenConfig {
                 entry_fee: Decimal::from_atomics(999965u128, 6)?,
                 exit_fee: Decimal::zero(),
@@ -714,7 +1070,7 @@ pub fn test_arena_token() -> Result<()> {
 
     // Founder allocation
     abc.call_as(&accounts.creator)
-        .buy(&coins(17_888_544, TEST_RESERVE_DENOM))?;
+        .buy(None, None, None, &coins(17_888_544, TEST_RESERVE_DENOM))?;
 
     // Get denom
     let supply_denom = abc.supply_denom()?;
@@ -725,7 +1081,7 @@ pub fn test_arena_token() -> Result<()> {
 
     // Arena Gladiators + Treasury
     abc.call_as(&dao)
-        .buy(&coins(75_063_057, TEST_RESERVE_DENOM))?;
+        .buy(None, None, None, &coins(75_063_057, TEST_RESERVE_DENOM))?;
 
     // Check we're in the open phase now
     let phase_config = abc.phase_config()?;
@@ -736,8 +1092,12 @@ pub fn test_arena_token() -> Result<()> {
     assert_eq!(curve_info.supply, Uint128::new(600_000_002_000));
 
     // User buys the rest
-    abc.call_as(&buyer)
-        .buy(&coins(3_058_525_685_714, TEST_RESERVE_DENOM))?;
+    abc.call_as(&buyer).buy(
+        None,
+        None,
+        None,
+        &coins(3_058_525_685_714, TEST_RESERVE_DENOM),
+    )?;
 
     // Check the supply
     let curve_info = abc.curve_info()?;
@@ -747,15 +1107,19 @@ pub fn test_arena_token() -> Result<()> {
     assert_eq!(curve_info.funding, Uint128::new(3_058_418_637_315));
 
     // Max supply reached
-    let result = abc.call_as(&buyer).buy(&coins(1, TEST_RESERVE_DENOM));
+    let result = abc
+        .call_as(&buyer)
+        .buy(None, None, None, &coins(1, TEST_RESERVE_DENOM));
     assert_that!(result.unwrap_err().to_string()).contains("Cannot mint more tokens");
 
     // Sell some into the curve
     abc.call_as(&dao)
-        .sell(&coins(1_000_000, supply_denom.clone()))?;
+        .sell(None, None, None, &coins(1_000_000, supply_denom.clone()))?;
 
     // Buying a very small amount results in rounding issues
-    let result = abc.call_as(&buyer).buy(&coins(1000, TEST_RESERVE_DENOM));
+    let result = abc
+        .call_as(&buyer)
+        .buy(None, None, None, &coins(1000, TEST_RESERVE_DENOM));
     assert!(result.is_err());
 
     Ok(())
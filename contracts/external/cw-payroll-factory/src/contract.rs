@@ -2,16 +2,16 @@
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     from_json, to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order,
-    Reply, Response, StdResult, SubMsg, WasmMsg,
+    Reply, Response, StdError, StdResult, SubMsg, Uint128, WasmMsg,
 };
 use cosmwasm_std::{Addr, Coin};
 
 use cw2::set_contract_version;
 use cw20::Cw20ExecuteMsg;
 use cw20::Cw20ReceiveMsg;
-use cw_denom::CheckedDenom;
+use cw_denom::{CheckedDenom, UncheckedDenom};
 use cw_storage_plus::Bound;
-use cw_utils::{nonpayable, parse_reply_instantiate_data};
+use cw_utils::{nonpayable, parse_reply_instantiate_data, PaymentError};
 use cw_vesting::msg::{
     InstantiateMsg as PayrollInstantiateMsg, QueryMsg as PayrollQueryMsg,
     ReceiveMsg as PayrollReceiveMsg,
@@ -19,10 +19,13 @@ use cw_vesting::msg::{
 use cw_vesting::vesting::Vest;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, ReceiveMsg};
+use crate::msg::{
+    ExecuteMsg, InstantiateMsg, MigrateMsg, Payroll, PayrollContract, PayrollTemplate, QueryMsg,
+    ReceiveMsg,
+};
 use crate::state::{
-    vesting_contracts, VestingContract, INSTANTIATE_ALLOWLIST, TMP_INSTANTIATOR_INFO,
-    VESTING_CODE_ID,
+    vesting_contracts, VestingContract, INSTANTIATE_ALLOWLIST, PAYROLL_TEMPLATES,
+    TMP_INSTANTIATOR_INFO, TMP_PENDING_INSTANTIATIONS, VESTING_CODE_ID,
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:cw-payroll-factory";
@@ -75,6 +78,15 @@ pub fn execute(
             instantiate_msg,
             label,
         } => execute_instantiate_native_payroll_contract(deps, info, instantiate_msg, label),
+        ExecuteMsg::InstantiateNativePayrollContracts { contracts } => {
+            execute_instantiate_native_payroll_contracts(deps, info, contracts)
+        }
+        ExecuteMsg::SetPayrollTemplate { name, template } => {
+            execute_set_payroll_template(deps, info, name, template)
+        }
+        ExecuteMsg::RemovePayrollTemplate { name } => {
+            execute_remove_payroll_template(deps, info, name)
+        }
         ExecuteMsg::UpdateOwnership(action) => execute_update_owner(deps, info, env, action),
         ExecuteMsg::UpdateCodeId { vesting_code_id } => {
             execute_update_code_id(deps, info, vesting_code_id)
@@ -142,9 +154,7 @@ pub fn execute_receive_cw20(
         return Err(ContractError::Reentrancy);
     }
 
-    // Save instantiator info for use in reply (cw20 sender in this case)
     let sender = deps.api.addr_validate(&receive_msg.sender)?;
-    TMP_INSTANTIATOR_INFO.save(deps.storage, &sender)?;
 
     match msg {
         ReceiveMsg::InstantiatePayrollContract {
@@ -159,6 +169,40 @@ pub fn execute_receive_cw20(
             }
             instantiate_contract(deps, sender, None, instantiate_msg, label)
         }
+        ReceiveMsg::InstantiatePayrollContracts { contracts } => {
+            let contracts = resolve_payroll_contracts(deps.as_ref(), contracts)?;
+
+            let mut expected = Uint128::zero();
+            for (instantiate_msg, _) in &contracts {
+                match instantiate_msg.denom {
+                    UncheckedDenom::Cw20(ref addr) if *addr == info.sender.as_str() => {
+                        expected = expected
+                            .checked_add(instantiate_msg.total)
+                            .map_err(StdError::from)?;
+                    }
+                    ref denom => {
+                        return Err(ContractError::UnfundedDenom {
+                            denom: denom_name(denom),
+                        })
+                    }
+                }
+            }
+            if receive_msg.amount != expected {
+                return Err(ContractError::WrongFundAmount {
+                    sent: receive_msg.amount,
+                    expected,
+                });
+            }
+
+            instantiate_contracts(
+                deps,
+                sender,
+                contracts
+                    .into_iter()
+                    .map(|(instantiate_msg, label)| (instantiate_msg, label, vec![]))
+                    .collect(),
+            )
+        }
     }
 }
 
@@ -168,12 +212,108 @@ pub fn execute_instantiate_native_payroll_contract(
     instantiate_msg: PayrollInstantiateMsg,
     label: String,
 ) -> Result<Response, ContractError> {
-    // Save instantiator info for use in reply
-    TMP_INSTANTIATOR_INFO.save(deps.storage, &info.sender)?;
-
     instantiate_contract(deps, info.sender, Some(info.funds), instantiate_msg, label)
 }
 
+pub fn execute_instantiate_native_payroll_contracts(
+    deps: DepsMut,
+    info: MessageInfo,
+    contracts: Vec<PayrollContract>,
+) -> Result<Response, ContractError> {
+    let contracts = resolve_payroll_contracts(deps.as_ref(), contracts)?;
+
+    // Each vesting contract is sent its total, and the sum of those
+    // must be exactly the funds sent.
+    let mut expected: Vec<Coin> = vec![];
+    let contracts = contracts
+        .into_iter()
+        .map(|(instantiate_msg, label)| {
+            let denom = match instantiate_msg.denom {
+                UncheckedDenom::Native(ref denom) => denom.clone(),
+                ref denom => {
+                    return Err(ContractError::UnfundedDenom {
+                        denom: denom_name(denom),
+                    })
+                }
+            };
+            match expected.iter_mut().find(|c| c.denom == denom) {
+                Some(coin) => {
+                    coin.amount = coin
+                        .amount
+                        .checked_add(instantiate_msg.total)
+                        .map_err(StdError::from)?
+                }
+                None => expected.push(Coin {
+                    denom: denom.clone(),
+                    amount: instantiate_msg.total,
+                }),
+            }
+            let funds = vec![Coin {
+                denom,
+                amount: instantiate_msg.total,
+            }];
+            Ok((instantiate_msg, label, funds))
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+
+    for coin in &info.funds {
+        if !expected.iter().any(|c| c.denom == coin.denom) {
+            return Err(PaymentError::ExtraDenom(coin.denom.clone()).into());
+        }
+    }
+    for coin in expected {
+        let sent = info
+            .funds
+            .iter()
+            .find(|c| c.denom == coin.denom)
+            .map(|c| c.amount)
+            .unwrap_or_default();
+        if sent != coin.amount {
+            return Err(ContractError::WrongFundAmount {
+                sent,
+                expected: coin.amount,
+            });
+        }
+    }
+
+    instantiate_contracts(deps, info.sender, contracts)
+}
+
+fn denom_name(denom: &UncheckedDenom) -> String {
+    match denom {
+        UncheckedDenom::Native(denom) => denom.clone(),
+        UncheckedDenom::Cw20(addr) => addr.clone(),
+    }
+}
+
+/// Resolves each payroll of a batch into a vesting contract
+/// instantiate message and its label.
+fn resolve_payroll_contracts(
+    deps: Deps,
+    contracts: Vec<PayrollContract>,
+) -> Result<Vec<(PayrollInstantiateMsg, String)>, ContractError> {
+    if contracts.is_empty() {
+        return Err(ContractError::EmptyBatch);
+    }
+    contracts
+        .into_iter()
+        .map(|PayrollContract { payroll, label }| {
+            let instantiate_msg = match payroll {
+                Payroll::Custom(instantiate_msg) => instantiate_msg,
+                Payroll::Template(payroll) => {
+                    let template = PAYROLL_TEMPLATES
+                        .may_load(deps.storage, &payroll.template)?
+                        .ok_or_else(|| ContractError::NoSuchTemplate {
+                            name: payroll.template.clone(),
+                        })?;
+                    payroll.into_instantiate_msg(template)
+                }
+            };
+            Ok((instantiate_msg, label))
+        })
+        .collect()
+}
+
 /// `sender` here refers to the initiator of the vesting, not the
 /// literal sender of the message. Practically speaking, this means
 /// that it should be set to the sender of the cw20's being vested,
@@ -184,6 +324,21 @@ pub fn instantiate_contract(
     funds: Option<Vec<Coin>>,
     instantiate_msg: PayrollInstantiateMsg,
     label: String,
+) -> Result<Response, ContractError> {
+    instantiate_contracts(
+        deps,
+        sender,
+        vec![(instantiate_msg, label, funds.unwrap_or_default())],
+    )
+}
+
+/// Instantiates a vesting contract for each of `contracts`, given as
+/// (instantiate message, label, funds). See `instantiate_contract`
+/// for the meaning of `sender`.
+pub fn instantiate_contracts(
+    deps: DepsMut,
+    sender: Addr,
+    contracts: Vec<(PayrollInstantiateMsg, String, Vec<Coin>)>,
 ) -> Result<Response, ContractError> {
     // Check sender is contract owner if set - or an allowlisted address
     cw_ownable::assert_owner(deps.storage, &sender).or_else(|e| {
@@ -194,22 +349,68 @@ pub fn instantiate_contract(
         }
     })?;
 
-    let code_id = VESTING_CODE_ID.load(deps.storage)?;
+    // Save instantiator info for use in replies
+    TMP_INSTANTIATOR_INFO.save(deps.storage, &sender)?;
+    TMP_PENDING_INSTANTIATIONS.save(deps.storage, &(contracts.len() as u64))?;
 
-    // Instantiate the specified contract with owner as the admin.
-    let instantiate = WasmMsg::Instantiate {
-        admin: instantiate_msg.owner.clone(),
-        code_id,
-        msg: to_json_binary(&instantiate_msg)?,
-        funds: funds.unwrap_or_default(),
-        label,
-    };
+    let code_id = VESTING_CODE_ID.load(deps.storage)?;
 
-    let msg = SubMsg::reply_on_success(instantiate, INSTANTIATE_CONTRACT_REPLY_ID);
+    // Instantiate the specified contracts with owner as the admin.
+    let msgs = contracts
+        .into_iter()
+        .map(|(instantiate_msg, label, funds)| {
+            let instantiate = WasmMsg::Instantiate {
+                admin: instantiate_msg.owner.clone(),
+                code_id,
+                msg: to_json_binary(&instantiate_msg)?,
+                funds,
+                label,
+            };
+            Ok(SubMsg::reply_on_success(
+                instantiate,
+                INSTANTIATE_CONTRACT_REPLY_ID,
+            ))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
 
     Ok(Response::default()
         .add_attribute("action", "instantiate_cw_vesting")
-        .add_submessage(msg))
+        .add_submessages(msgs))
+}
+
+pub fn execute_set_payroll_template(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+    template: PayrollTemplate,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    if let Some(ref owner) = template.owner {
+        deps.api.addr_validate(owner)?;
+    }
+    template.denom.clone().into_checked(deps.as_ref())?;
+
+    PAYROLL_TEMPLATES.save(deps.storage, &name, &template)?;
+    Ok(Response::default()
+        .add_attribute("action", "set_payroll_template")
+        .add_attribute("name", name))
+}
+
+pub fn execute_remove_payroll_template(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    if !PAYROLL_TEMPLATES.has(deps.storage, &name) {
+        return Err(ContractError::NoSuchTemplate { name });
+    }
+    PAYROLL_TEMPLATES.remove(deps.storage, &name);
+    Ok(Response::default()
+        .add_attribute("action", "remove_payroll_template")
+        .add_attribute("name", name))
 }
 
 pub fn execute_update_owner(
@@ -354,6 +555,18 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         }
         QueryMsg::Ownership {} => to_json_binary(&cw_ownable::get_ownership(deps.storage)?),
         QueryMsg::CodeId {} => to_json_binary(&VESTING_CODE_ID.load(deps.storage)?),
+        QueryMsg::PayrollTemplate { name } => {
+            to_json_binary(&PAYROLL_TEMPLATES.may_load(deps.storage, &name)?)
+        }
+        QueryMsg::ListPayrollTemplates { start_after, limit } => {
+            to_json_binary(&cw_paginate_storage::paginate_map(
+                deps,
+                &PAYROLL_TEMPLATES,
+                start_after.as_deref(),
+                limit,
+                Order::Ascending,
+            )?)
+        }
         QueryMsg::InstantiateAllowlist { start_after, limit } => {
             let start_after = start_after
                 .map(|x| deps.api.addr_validate(&x))
@@ -395,8 +608,18 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
                 },
             )?;
 
-            // Clear tmp instatiator info
-            TMP_INSTANTIATOR_INFO.remove(deps.storage);
+            // Clear tmp instatiator info once the last contract of
+            // the batch has been instantiated
+            let pending = TMP_PENDING_INSTANTIATIONS
+                .may_load(deps.storage)?
+                .unwrap_or(1)
+                .saturating_sub(1);
+            if pending == 0 {
+                TMP_INSTANTIATOR_INFO.remove(deps.storage);
+                TMP_PENDING_INSTANTIATIONS.remove(deps.storage);
+            } else {
+                TMP_PENDING_INSTANTIATIONS.save(deps.storage, &pending)?;
+            }
 
             // If cw20, fire off fund message!
             let msgs: Vec<CosmosMsg> = match vest.denom {
@@ -25,4 +25,13 @@ pub enum ContractError {
 
     #[error("vesting contract vests ({expected}) tokens, funded with ({sent})")]
     WrongFundAmount { sent: Uint128, expected: Uint128 },
+
+    #[error("vesting contract vests ({denom}), which was not sent")]
+    UnfundedDenom { denom: String },
+
+    #[error("no payroll template named ({name})")]
+    NoSuchTemplate { name: String },
+
+    #[error("must instantiate at least one vesting contract")]
+    EmptyBatch,
 }
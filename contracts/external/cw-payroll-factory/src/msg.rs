@@ -1,7 +1,9 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Timestamp, Uint128};
 use cw20::Cw20ReceiveMsg;
+use cw_denom::UncheckedDenom;
 use cw_ownable::cw_ownable_execute;
-use cw_vesting::msg::InstantiateMsg as PayrollInstantiateMsg;
+use cw_vesting::{msg::InstantiateMsg as PayrollInstantiateMsg, vesting::Schedule};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -23,6 +25,22 @@ pub enum ExecuteMsg {
         label: String,
     },
 
+    /// Instantiates a vesting contract for each of `contracts`,
+    /// funded by the native tokens sent with this message. The funds
+    /// sent must be exactly the total of each vest.
+    #[cw_orch(payable)]
+    InstantiateNativePayrollContracts { contracts: Vec<PayrollContract> },
+
+    /// Callable only by the current owner. Stores a payroll template
+    /// under `name`, replacing any existing template with that name.
+    SetPayrollTemplate {
+        name: String,
+        template: PayrollTemplate,
+    },
+    /// Callable only by the current owner. Removes the payroll
+    /// template stored under `name`.
+    RemovePayrollTemplate { name: String },
+
     /// Callable only by the current owner. Updates the code ID used
     /// while instantiating vesting contracts.
     UpdateCodeId { vesting_code_id: u64 },
@@ -42,6 +60,69 @@ pub enum ReceiveMsg {
         instantiate_msg: PayrollInstantiateMsg,
         label: String,
     },
+    /// Funds a vesting contract for each of `contracts`. The amount
+    /// sent must be exactly the total of each vest, all of which must
+    /// vest the cw20 token sent.
+    InstantiatePayrollContracts { contracts: Vec<PayrollContract> },
+}
+
+/// A vesting contract to instantiate as part of a batch.
+#[cw_serde]
+pub struct PayrollContract {
+    pub payroll: Payroll,
+    pub label: String,
+}
+
+#[cw_serde]
+pub enum Payroll {
+    /// Instantiates the vesting contract with this message.
+    Custom(PayrollInstantiateMsg),
+    /// Instantiates the vesting contract with the settings of a
+    /// stored payroll template.
+    Template(TemplatePayroll),
+}
+
+/// The fields of a vesting contract's instantiate message that vary
+/// between recipients. The rest are taken from the template.
+#[cw_serde]
+pub struct TemplatePayroll {
+    /// The name of the payroll template to use.
+    pub template: String,
+    pub recipient: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub total: Uint128,
+    pub start_time: Option<Timestamp>,
+}
+
+/// Reusable vesting contract settings. See
+/// `cw_vesting::msg::InstantiateMsg` for the meaning of each field.
+#[cw_serde]
+pub struct PayrollTemplate {
+    pub owner: Option<String>,
+    pub denom: UncheckedDenom,
+    pub schedule: Schedule,
+    pub vesting_duration_seconds: u64,
+    pub unbonding_duration_seconds: u64,
+}
+
+impl TemplatePayroll {
+    /// Combines this payroll with its template into a vesting
+    /// contract instantiate message.
+    pub fn into_instantiate_msg(self, template: PayrollTemplate) -> PayrollInstantiateMsg {
+        PayrollInstantiateMsg {
+            owner: template.owner,
+            recipient: self.recipient,
+            title: self.title,
+            description: self.description,
+            total: self.total,
+            denom: template.denom,
+            schedule: template.schedule,
+            start_time: self.start_time,
+            vesting_duration_seconds: template.vesting_duration_seconds,
+            unbonding_duration_seconds: template.unbonding_duration_seconds,
+        }
+    }
 }
 
 #[cw_serde]
@@ -95,6 +176,16 @@ pub enum QueryMsg {
     #[returns(::std::primitive::u64)]
     CodeId {},
 
+    /// Returns the payroll template stored under `name`, if any.
+    #[returns(Option<crate::msg::PayrollTemplate>)]
+    PayrollTemplate { name: String },
+    /// Returns the stored payroll templates and their names.
+    #[returns(Vec<(String, crate::msg::PayrollTemplate)>)]
+    ListPayrollTemplates {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
     /// Returns the allowlist
     /// Addresses allowed to instantiate vesting contracts
     #[returns(Option<Vec<::cosmwasm_std::Addr>>)]
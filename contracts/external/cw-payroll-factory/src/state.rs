@@ -2,10 +2,16 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Empty};
 use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
+use crate::msg::PayrollTemplate;
+
 /// Temporarily holds the address of the instantiator for use in submessages
 pub const TMP_INSTANTIATOR_INFO: Item<Addr> = Item::new("tmp_instantiator_info");
+/// Temporarily holds the number of vesting contracts whose
+/// instantiation replies have not yet been handled.
+pub const TMP_PENDING_INSTANTIATIONS: Item<u64> = Item::new("tmp_pending_instantiations");
 pub const VESTING_CODE_ID: Item<u64> = Item::new("pci");
 pub const INSTANTIATE_ALLOWLIST: Map<&Addr, Empty> = Map::new("instantiate_allowlist");
+pub const PAYROLL_TEMPLATES: Map<&str, PayrollTemplate> = Map::new("payroll_templates");
 
 #[cw_serde]
 pub struct VestingContract {
@@ -9,7 +9,10 @@ use cw_vesting::{
 };
 
 use crate::{
-    msg::{ExecuteMsg, InstantiateMsg, QueryMsg, ReceiveMsg},
+    msg::{
+        ExecuteMsg, InstantiateMsg, Payroll, PayrollContract, PayrollTemplate, QueryMsg,
+        ReceiveMsg, TemplatePayroll,
+    },
     state::VestingContract,
     ContractError,
 };
@@ -747,3 +750,437 @@ pub fn test_instantiate_allowlist() {
         ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
     );
 }
+
+fn payroll_template(denom: UncheckedDenom) -> PayrollTemplate {
+    PayrollTemplate {
+        owner: Some(ALICE.to_string()),
+        denom,
+        schedule: Schedule::SaturatingLinear,
+        vesting_duration_seconds: 200,
+        unbonding_duration_seconds: 2592000, // 30 days
+    }
+}
+
+fn template_payroll(recipient: &str, total: u128) -> PayrollContract {
+    PayrollContract {
+        payroll: Payroll::Template(TemplatePayroll {
+            template: "contributor".to_string(),
+            recipient: recipient.to_string(),
+            title: "title".to_string(),
+            description: None,
+            total: Uint128::new(total),
+            start_time: None,
+        }),
+        label: format!("Payroll {recipient}"),
+    }
+}
+
+#[test]
+pub fn test_payroll_templates() {
+    let mut app = App::default();
+    let code_id = app.store_code(factory_contract());
+    let cw_vesting_code_id = app.store_code(cw_vesting_contract());
+    let factory_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("CREATOR"),
+            &InstantiateMsg {
+                owner: Some(ALICE.to_string()),
+                vesting_code_id: cw_vesting_code_id,
+                instantiate_allowlist: None,
+            },
+            &[],
+            "cw-admin-factory",
+            None,
+        )
+        .unwrap();
+
+    let template = payroll_template(UncheckedDenom::Native(NATIVE_DENOM.to_string()));
+    let set_template = ExecuteMsg::SetPayrollTemplate {
+        name: "contributor".to_string(),
+        template: template.clone(),
+    };
+
+    // Only the owner may set templates.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(BOB),
+            factory_addr.clone(),
+            &set_template,
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Ownable(OwnershipError::NotOwner));
+
+    app.execute_contract(
+        Addr::unchecked(ALICE),
+        factory_addr.clone(),
+        &set_template,
+        &[],
+    )
+    .unwrap();
+
+    let stored: Option<PayrollTemplate> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::PayrollTemplate {
+                name: "contributor".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(stored, Some(template.clone()));
+
+    let templates: Vec<(String, PayrollTemplate)> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::ListPayrollTemplates {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(templates, vec![("contributor".to_string(), template)]);
+
+    app.execute_contract(
+        Addr::unchecked(ALICE),
+        factory_addr.clone(),
+        &ExecuteMsg::RemovePayrollTemplate {
+            name: "contributor".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Templates that don't exist can't be removed or used.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ALICE),
+            factory_addr.clone(),
+            &ExecuteMsg::RemovePayrollTemplate {
+                name: "contributor".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::NoSuchTemplate {
+            name: "contributor".to_string()
+        }
+    );
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ALICE),
+            factory_addr,
+            &ExecuteMsg::InstantiateNativePayrollContracts {
+                contracts: vec![template_payroll(BOB, 100)],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::NoSuchTemplate {
+            name: "contributor".to_string()
+        }
+    );
+}
+
+#[test]
+pub fn test_instantiate_native_payroll_contracts() {
+    let mut app = App::default();
+    let code_id = app.store_code(factory_contract());
+    let cw_vesting_code_id = app.store_code(cw_vesting_contract());
+    let factory_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("CREATOR"),
+            &InstantiateMsg {
+                owner: Some(ALICE.to_string()),
+                vesting_code_id: cw_vesting_code_id,
+                instantiate_allowlist: None,
+            },
+            &[],
+            "cw-admin-factory",
+            None,
+        )
+        .unwrap();
+    app.sudo(SudoMsg::Bank({
+        BankSudo::Mint {
+            to_address: ALICE.to_string(),
+            amount: coins(INITIAL_BALANCE, NATIVE_DENOM),
+        }
+    }))
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ALICE),
+        factory_addr.clone(),
+        &ExecuteMsg::SetPayrollTemplate {
+            name: "contributor".to_string(),
+            template: payroll_template(UncheckedDenom::Native(NATIVE_DENOM.to_string())),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let custom = PayrollContract {
+        payroll: Payroll::Custom(PayrollInstantiateMsg {
+            owner: None,
+            recipient: "carol".to_string(),
+            title: "title".to_string(),
+            description: None,
+            total: Uint128::new(300),
+            denom: UncheckedDenom::Native(NATIVE_DENOM.to_string()),
+            schedule: Schedule::SaturatingLinear,
+            vesting_duration_seconds: 100,
+            unbonding_duration_seconds: 2592000, // 30 days
+            start_time: None,
+        }),
+        label: "Payroll carol".to_string(),
+    };
+    let batch = ExecuteMsg::InstantiateNativePayrollContracts {
+        contracts: vec![
+            template_payroll(BOB, 100),
+            template_payroll("dave", 200),
+            custom,
+        ],
+    };
+
+    // The funds sent must be exactly the total of each vest.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ALICE),
+            factory_addr.clone(),
+            &batch,
+            &coins(500, NATIVE_DENOM),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::WrongFundAmount {
+            sent: Uint128::new(500),
+            expected: Uint128::new(600)
+        }
+    );
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ALICE),
+            factory_addr.clone(),
+            &ExecuteMsg::InstantiateNativePayrollContracts { contracts: vec![] },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::EmptyBatch);
+
+    app.execute_contract(
+        Addr::unchecked(ALICE),
+        factory_addr.clone(),
+        &batch,
+        &coins(600, NATIVE_DENOM),
+    )
+    .unwrap();
+
+    let contracts: Vec<VestingContract> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr.clone(),
+            &QueryMsg::ListVestingContractsByInstantiator {
+                instantiator: ALICE.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(contracts.len(), 3);
+
+    for (recipient, total, owner) in [
+        (BOB, 100, Some(ALICE.to_string())),
+        ("dave", 200, Some(ALICE.to_string())),
+        ("carol", 300, None),
+    ] {
+        let contracts: Vec<VestingContract> = app
+            .wrap()
+            .query_wasm_smart(
+                factory_addr.clone(),
+                &QueryMsg::ListVestingContractsByRecipient {
+                    recipient: recipient.to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(contracts.len(), 1);
+
+        let vest: Vest = app
+            .wrap()
+            .query_wasm_smart(&contracts[0].contract, &PayrollQueryMsg::Info {})
+            .unwrap();
+        assert_eq!(vest.status, Status::Funded);
+        assert_eq!(vest.total(), Uint128::new(total));
+        let contract_info = app
+            .wrap()
+            .query_wasm_contract_info(&contracts[0].contract)
+            .unwrap();
+        assert_eq!(contract_info.admin, owner);
+    }
+}
+
+#[test]
+pub fn test_instantiate_cw20_payroll_contracts() {
+    let mut app = App::default();
+    let code_id = app.store_code(factory_contract());
+    let cw20_code_id = app.store_code(cw20_contract());
+    let cw_vesting_code_id = app.store_code(cw_vesting_contract());
+
+    let cw20_addr = app
+        .instantiate_contract(
+            cw20_code_id,
+            Addr::unchecked(ALICE),
+            &cw20_base::msg::InstantiateMsg {
+                name: "cw20 token".to_string(),
+                symbol: "cwtwenty".to_string(),
+                decimals: 6,
+                initial_balances: vec![Cw20Coin {
+                    address: ALICE.to_string(),
+                    amount: Uint128::new(INITIAL_BALANCE),
+                }],
+                mint: None,
+                marketing: None,
+            },
+            &[],
+            "cw20-base",
+            None,
+        )
+        .unwrap();
+    let factory_addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("CREATOR"),
+            &InstantiateMsg {
+                owner: Some(ALICE.to_string()),
+                vesting_code_id: cw_vesting_code_id,
+                instantiate_allowlist: None,
+            },
+            &[],
+            "cw-admin-factory",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ALICE),
+        factory_addr.clone(),
+        &ExecuteMsg::SetPayrollTemplate {
+            name: "contributor".to_string(),
+            template: payroll_template(UncheckedDenom::Cw20(cw20_addr.to_string())),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let send = |amount: u128, contracts: Vec<PayrollContract>| Cw20ExecuteMsg::Send {
+        contract: factory_addr.to_string(),
+        amount: Uint128::new(amount),
+        msg: to_json_binary(&ReceiveMsg::InstantiatePayrollContracts { contracts }).unwrap(),
+    };
+
+    // Every vest must be of the cw20 sent.
+    let native = PayrollContract {
+        payroll: Payroll::Custom(PayrollInstantiateMsg {
+            owner: None,
+            recipient: "carol".to_string(),
+            title: "title".to_string(),
+            description: None,
+            total: Uint128::new(300),
+            denom: UncheckedDenom::Native(NATIVE_DENOM.to_string()),
+            schedule: Schedule::SaturatingLinear,
+            vesting_duration_seconds: 100,
+            unbonding_duration_seconds: 2592000, // 30 days
+            start_time: None,
+        }),
+        label: "Payroll carol".to_string(),
+    };
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ALICE),
+            cw20_addr.clone(),
+            &send(400, vec![template_payroll(BOB, 100), native]),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::UnfundedDenom {
+            denom: NATIVE_DENOM.to_string()
+        }
+    );
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ALICE),
+            cw20_addr.clone(),
+            &send(
+                100,
+                vec![template_payroll(BOB, 100), template_payroll("dave", 200)],
+            ),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::WrongFundAmount {
+            sent: Uint128::new(100),
+            expected: Uint128::new(300)
+        }
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ALICE),
+        cw20_addr,
+        &send(
+            300,
+            vec![template_payroll(BOB, 100), template_payroll("dave", 200)],
+        ),
+        &[],
+    )
+    .unwrap();
+
+    let contracts: Vec<VestingContract> = app
+        .wrap()
+        .query_wasm_smart(
+            factory_addr,
+            &QueryMsg::ListVestingContractsByInstantiator {
+                instantiator: ALICE.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(contracts.len(), 2);
+    for contract in contracts {
+        let vest: Vest = app
+            .wrap()
+            .query_wasm_smart(contract.contract, &PayrollQueryMsg::Info {})
+            .unwrap();
+        assert_eq!(vest.status, Status::Funded);
+    }
+}
@@ -4,13 +4,14 @@ use cosmwasm_std::{
     to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
 };
 use cw2::set_contract_version;
-use cw_storage_plus::Item;
-use cw_utils::must_pay;
 
 use crate::{
     error::ContractError,
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, StatusResponse},
-    state::{CheckedCounterparty, CheckedTokenInfo, COUNTERPARTY_ONE, COUNTERPARTY_TWO},
+    state::{
+        CheckedCounterparty, CheckedPromise, CheckedTokenInfo, LegacyCounterparty, COMPLETE,
+        COUNTERPARTIES, DEADLINE, LEGACY_COUNTERPARTY_ONE, LEGACY_COUNTERPARTY_TWO,
+    },
 };
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:cw-token-swap";
@@ -19,214 +20,293 @@ pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     _info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    let counterparty_one = msg.counterparty_one.into_checked(deps.as_ref())?;
-    let counterparty_two = msg.counterparty_two.into_checked(deps.as_ref())?;
+    if msg.counterparties.len() < 2 {
+        return Err(ContractError::TooFewCounterparties {});
+    }
+    let counterparties = msg
+        .counterparties
+        .into_iter()
+        .map(|counterparty| counterparty.into_checked(deps.as_ref()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (i, counterparty) in counterparties.iter().enumerate() {
+        if counterparties[..i]
+            .iter()
+            .any(|other| other.address == counterparty.address)
+        {
+            return Err(ContractError::NonDistinctCounterparties {});
+        }
+    }
 
-    if counterparty_one.address == counterparty_two.address {
-        return Err(ContractError::NonDistinctCounterparties {});
+    let mut nfts = vec![];
+    for counterparty in &counterparties {
+        for promise in &counterparty.promises {
+            if promise.recipient == counterparty.address
+                || !counterparties
+                    .iter()
+                    .any(|other| other.address == promise.recipient)
+            {
+                return Err(ContractError::InvalidRecipient {});
+            }
+            // An NFT promised twice could only ever be provided once,
+            // so the escrow would never complete.
+            if let CheckedTokenInfo::Cw721 {
+                contract_addr,
+                token_id,
+            } = &promise.token
+            {
+                if nfts.contains(&(contract_addr, token_id)) {
+                    return Err(ContractError::DuplicateNft {});
+                }
+                nfts.push((contract_addr, token_id));
+            }
+        }
+    }
+
+    if let Some(deadline) = &msg.deadline {
+        if deadline.is_expired(&env.block) {
+            return Err(ContractError::Expired {});
+        }
     }
 
-    COUNTERPARTY_ONE.save(deps.storage, &counterparty_one)?;
-    COUNTERPARTY_TWO.save(deps.storage, &counterparty_two)?;
+    COUNTERPARTIES.save(deps.storage, &counterparties)?;
+    DEADLINE.save(deps.storage, &msg.deadline)?;
+    COMPLETE.save(deps.storage, &false)?;
 
     Ok(Response::new()
         .add_attribute("method", "instantiate")
-        .add_attribute("counterparty_one", counterparty_one.address)
-        .add_attribute("counterparty_two", counterparty_two.address))
+        .add_attributes(
+            counterparties
+                .iter()
+                .map(|counterparty| ("counterparty", counterparty.address.to_string())),
+        ))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Receive(msg) => execute_receive(deps, info.sender, msg),
-        ExecuteMsg::Fund {} => execute_fund(deps, info),
-        ExecuteMsg::Withdraw {} => execute_withdraw(deps, info),
+        ExecuteMsg::Receive(msg) => execute_receive(deps, env, info.sender, msg),
+        ExecuteMsg::ReceiveNft(msg) => execute_receive_nft(deps, env, info.sender, msg),
+        ExecuteMsg::Fund {} => execute_fund(deps, env, info),
+        ExecuteMsg::Withdraw {} => execute_withdraw(deps, env, info),
     }
 }
 
-struct CounterpartyResponse<'a> {
-    pub counterparty: CheckedCounterparty,
-    pub other_counterparty: CheckedCounterparty,
-    pub storage: Item<'a, CheckedCounterparty>,
-}
-
-fn get_counterparty<'a>(
+/// Loads the escrow's counterparties and the index of SENDER among
+/// them.
+fn get_counterparty(
     deps: Deps,
     sender: &Addr,
-) -> Result<CounterpartyResponse<'a>, ContractError> {
-    let counterparty_one = COUNTERPARTY_ONE.load(deps.storage)?;
-    let counterparty_two = COUNTERPARTY_TWO.load(deps.storage)?;
-
-    let (counterparty, other_counterparty, storage) = if *sender == counterparty_one.address {
-        (counterparty_one, counterparty_two, COUNTERPARTY_ONE)
-    } else if *sender == counterparty_two.address {
-        (counterparty_two, counterparty_one, COUNTERPARTY_TWO)
-    } else {
+) -> Result<(Vec<CheckedCounterparty>, usize), ContractError> {
+    let counterparties = COUNTERPARTIES.load(deps.storage)?;
+    let index = counterparties
+        .iter()
+        .position(|counterparty| counterparty.address == *sender)
         // Contract may only be funded by a counterparty.
-        return Err(ContractError::Unauthorized {});
-    };
+        .ok_or(ContractError::Unauthorized {})?;
+    Ok((counterparties, index))
+}
 
-    Ok(CounterpartyResponse {
-        counterparty,
-        other_counterparty,
-        storage,
-    })
+fn check_deadline(deps: Deps, env: &Env) -> Result<(), ContractError> {
+    match DEADLINE.load(deps.storage)? {
+        Some(deadline) if deadline.is_expired(&env.block) => Err(ContractError::Expired {}),
+        _ => Ok(()),
+    }
 }
 
-/// Accepts funding from COUNTERPARTY for the escrow. Distributes
-/// escrow funds if both counterparties have funded the contract.
+/// Marks the promises of COUNTERPARTY selected by MATCHES as
+/// provided. MATCHES returns the amount of a token that is being
+/// provided, and PAID must equal the total of the unprovided
+/// promises it selects.
 ///
 /// NOTE: The caller must verify that the denom of PAID is correct.
-fn do_fund(
-    deps: DepsMut,
-    counterparty: CheckedCounterparty,
+fn provide(
+    counterparty: &mut CheckedCounterparty,
     paid: Uint128,
-    expected: Uint128,
-    other_counterparty: CheckedCounterparty,
-    storage: Item<CheckedCounterparty>,
-) -> Result<Response, ContractError> {
-    if counterparty.provided {
-        return Err(ContractError::AlreadyProvided {});
+    matches: impl Fn(&CheckedTokenInfo) -> Option<Uint128>,
+) -> Result<(), ContractError> {
+    let mut promised = false;
+    let mut expected = Uint128::zero();
+    for promise in counterparty.promises.iter_mut() {
+        if let Some(amount) = matches(&promise.token) {
+            promised = true;
+            if !promise.provided {
+                expected += amount;
+                promise.provided = true;
+            }
+        }
     }
 
+    if !promised {
+        // Must fund with the promised tokens.
+        return Err(ContractError::InvalidFunds {});
+    }
+    if expected.is_zero() {
+        return Err(ContractError::AlreadyProvided {});
+    }
     if paid != expected {
         return Err(ContractError::InvalidAmount {
             expected,
             actual: paid,
         });
     }
+    Ok(())
+}
 
-    let mut counterparty = counterparty;
-    counterparty.provided = true;
-    storage.save(deps.storage, &counterparty)?;
-
-    let messages = if counterparty.provided && other_counterparty.provided {
-        vec![
-            counterparty
-                .promise
-                .into_send_message(&other_counterparty.address)?,
-            other_counterparty
-                .promise
-                .into_send_message(&counterparty.address)?,
-        ]
+/// Saves funding from the counterparty at INDEX. Distributes escrow
+/// funds if every counterparty has provided all of their promises.
+fn do_fund(
+    deps: DepsMut,
+    counterparties: Vec<CheckedCounterparty>,
+    index: usize,
+) -> Result<Response, ContractError> {
+    let complete = counterparties
+        .iter()
+        .all(|counterparty| counterparty.provided());
+
+    let messages = if complete {
+        counterparties
+            .iter()
+            .flat_map(|counterparty| &counterparty.promises)
+            .map(|promise| promise.token.clone().into_send_message(&promise.recipient))
+            .collect::<StdResult<Vec<_>>>()?
     } else {
         vec![]
     };
 
+    COUNTERPARTIES.save(deps.storage, &counterparties)?;
+    COMPLETE.save(deps.storage, &complete)?;
+
     Ok(Response::new()
         .add_attribute("method", "fund_escrow")
-        .add_attribute("counterparty", counterparty.address)
+        .add_attribute("counterparty", counterparties[index].address.to_string())
         .add_messages(messages))
 }
 
 pub fn execute_receive(
     deps: DepsMut,
+    env: Env,
     token_contract: Addr,
     msg: cw20::Cw20ReceiveMsg,
 ) -> Result<Response, ContractError> {
     let sender = deps.api.addr_validate(&msg.sender)?;
-
-    let CounterpartyResponse {
-        counterparty,
-        other_counterparty,
-        storage,
-    } = get_counterparty(deps.as_ref(), &sender)?;
-
-    let (expected_payment, paid) = if let CheckedTokenInfo::Cw20 {
-        contract_addr,
-        amount,
-    } = &counterparty.promise
-    {
-        if *contract_addr != token_contract {
-            // Must fund with the promised tokens.
-            return Err(ContractError::InvalidFunds {});
-        }
-
-        (*amount, msg.amount)
-    } else {
-        return Err(ContractError::InvalidFunds {});
-    };
-
-    do_fund(
-        deps,
-        counterparty,
-        paid,
-        expected_payment,
-        other_counterparty,
-        storage,
-    )
+    let (mut counterparties, index) = get_counterparty(deps.as_ref(), &sender)?;
+    check_deadline(deps.as_ref(), &env)?;
+
+    provide(
+        &mut counterparties[index],
+        msg.amount,
+        |token| match token {
+            CheckedTokenInfo::Cw20 {
+                contract_addr,
+                amount,
+            } if *contract_addr == token_contract => Some(*amount),
+            _ => None,
+        },
+    )?;
+
+    do_fund(deps, counterparties, index)
 }
 
-pub fn execute_fund(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
-    let CounterpartyResponse {
-        counterparty,
-        other_counterparty,
-        storage,
-    } = get_counterparty(deps.as_ref(), &info.sender)?;
+pub fn execute_receive_nft(
+    deps: DepsMut,
+    env: Env,
+    nft_contract: Addr,
+    msg: cw721::Cw721ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let sender = deps.api.addr_validate(&msg.sender)?;
+    let (mut counterparties, index) = get_counterparty(deps.as_ref(), &sender)?;
+    check_deadline(deps.as_ref(), &env)?;
+
+    provide(
+        &mut counterparties[index],
+        Uint128::one(),
+        |token| match token {
+            CheckedTokenInfo::Cw721 {
+                contract_addr,
+                token_id,
+            } if *contract_addr == nft_contract && *token_id == msg.token_id => {
+                Some(Uint128::one())
+            }
+            _ => None,
+        },
+    )?;
+
+    do_fund(deps, counterparties, index)
+}
 
-    let (expected_payment, paid) =
-        if let CheckedTokenInfo::Native { amount, denom } = &counterparty.promise {
-            let paid = must_pay(&info, denom).map_err(|_| ContractError::InvalidFunds {})?;
+pub fn execute_fund(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let (mut counterparties, index) = get_counterparty(deps.as_ref(), &info.sender)?;
+    check_deadline(deps.as_ref(), &env)?;
 
-            (*amount, paid)
-        } else {
-            return Err(ContractError::InvalidFunds {});
-        };
+    if info.funds.is_empty() {
+        return Err(ContractError::InvalidFunds {});
+    }
+    for coin in &info.funds {
+        provide(
+            &mut counterparties[index],
+            coin.amount,
+            |token| match token {
+                CheckedTokenInfo::Native { denom, amount } if *denom == coin.denom => Some(*amount),
+                _ => None,
+            },
+        )?;
+    }
 
-    do_fund(
-        deps,
-        counterparty,
-        paid,
-        expected_payment,
-        other_counterparty,
-        storage,
-    )
+    do_fund(deps, counterparties, index)
 }
 
-pub fn execute_withdraw(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
-    let CounterpartyResponse {
-        counterparty,
-        other_counterparty,
-        storage,
-    } = get_counterparty(deps.as_ref(), &info.sender)?;
+pub fn execute_withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let (mut counterparties, index) = get_counterparty(deps.as_ref(), &info.sender)?;
+    let counterparty = &mut counterparties[index];
 
-    if !counterparty.provided {
+    if !counterparty.promises.iter().any(|promise| promise.provided) {
         return Err(ContractError::NoProvision {});
     }
 
     // The escrow contract completes itself in the same transaction
-    // that the second counterparty sends its funds. If that has
+    // that the last counterparty sends its funds. If that has
     // happens no more withdrawals are allowed. This check isn't
     // strictly needed because the contract won't have enough balance
     // anyhow, but we may as well error nicely.
-    if counterparty.provided && other_counterparty.provided {
+    if COMPLETE.load(deps.storage)? {
         return Err(ContractError::Complete {});
     }
 
-    let message = counterparty
-        .promise
-        .clone()
-        .into_send_message(&counterparty.address)?;
+    // With a deadline, counterparties may rely on provided funds
+    // staying in escrow until it passes.
+    if let Some(deadline) = DEADLINE.load(deps.storage)? {
+        if !deadline.is_expired(&env.block) {
+            return Err(ContractError::NotExpired {});
+        }
+    }
 
-    let mut counterparty = counterparty;
-    counterparty.provided = false;
-    storage.save(deps.storage, &counterparty)?;
+    let mut messages = vec![];
+    for promise in counterparty.promises.iter_mut().filter(|p| p.provided) {
+        messages.push(promise.token.clone().into_send_message(&info.sender)?);
+        promise.provided = false;
+    }
+    COUNTERPARTIES.save(deps.storage, &counterparties)?;
 
     Ok(Response::new()
         .add_attribute("method", "withdraw")
-        .add_attribute("counterparty", counterparty.address)
-        .add_message(message))
+        .add_attribute("counterparty", info.sender)
+        .add_messages(messages))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -237,17 +317,41 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
 }
 
 pub fn query_status(deps: Deps) -> StdResult<Binary> {
-    let counterparty_one = COUNTERPARTY_ONE.load(deps.storage)?;
-    let counterparty_two = COUNTERPARTY_TWO.load(deps.storage)?;
-
     to_json_binary(&StatusResponse {
-        counterparty_one,
-        counterparty_two,
+        counterparties: COUNTERPARTIES.load(deps.storage)?,
+        deadline: DEADLINE.load(deps.storage)?,
+        complete: COMPLETE.load(deps.storage)?,
     })
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    // Escrows instantiated before counterparties could promise more
+    // than one token stored exactly two counterparties, each sending
+    // their promise to the other.
+    if let Some(one) = LEGACY_COUNTERPARTY_ONE.may_load(deps.storage)? {
+        let two = LEGACY_COUNTERPARTY_TWO.load(deps.storage)?;
+        let complete = one.provided && two.provided;
+        let upgrade = |counterparty: LegacyCounterparty, recipient: &Addr| CheckedCounterparty {
+            address: counterparty.address,
+            promises: vec![CheckedPromise {
+                recipient: recipient.clone(),
+                token: counterparty.promise,
+                provided: counterparty.provided,
+            }],
+        };
+        let counterparties = vec![
+            upgrade(one.clone(), &two.address),
+            upgrade(two, &one.address),
+        ];
+
+        COUNTERPARTIES.save(deps.storage, &counterparties)?;
+        DEADLINE.save(deps.storage, &None)?;
+        COMPLETE.save(deps.storage, &complete)?;
+        LEGACY_COUNTERPARTY_ONE.remove(deps.storage);
+        LEGACY_COUNTERPARTY_TWO.remove(deps.storage);
+    }
+
     // Set contract to version to latest
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
@@ -13,6 +13,18 @@ pub enum ContractError {
     #[error("Counterparties must have different addresses")]
     NonDistinctCounterparties {},
 
+    #[error("An escrow must have at least two counterparties")]
+    TooFewCounterparties {},
+
+    #[error("Counterparties must promise at least one token")]
+    NoPromises {},
+
+    #[error("Promised tokens must be sent to another counterparty")]
+    InvalidRecipient {},
+
+    #[error("An NFT may only be promised once")]
+    DuplicateNft {},
+
     #[error("Can not provide funds more than once")]
     AlreadyProvided {},
 
@@ -22,6 +34,12 @@ pub enum ContractError {
     #[error("Must provide funds before withdrawing")]
     NoProvision {},
 
+    #[error("The escrow's deadline has passed")]
+    Expired {},
+
+    #[error("Can not withdraw before the escrow's deadline")]
+    NotExpired {},
+
     #[error("Can not create an escrow for zero tokens")]
     ZeroTokens {},
 
@@ -1,9 +1,10 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Uint128;
+use cw_utils::Expiration;
 
 use crate::state::CheckedCounterparty;
 
-/// Information about the token being used on one side of the escrow.
+/// Information about a token being promised in the escrow.
 #[cw_serde]
 pub enum TokenInfo {
     /// A native token.
@@ -13,6 +14,21 @@ pub enum TokenInfo {
         contract_addr: String,
         amount: Uint128,
     },
+    /// A cw721 NFT.
+    Cw721 {
+        contract_addr: String,
+        token_id: String,
+    },
+}
+
+/// A token a counterparty promises to send to another counterparty.
+#[cw_serde]
+pub struct Promise {
+    /// The counterparty the token is sent to when the escrow
+    /// completes.
+    pub recipient: String,
+    /// The token being promised.
+    pub token: TokenInfo,
 }
 
 /// Information about a counterparty in this escrow transaction and
@@ -22,13 +38,18 @@ pub struct Counterparty {
     /// The address of the counterparty.
     pub address: String,
     /// The funds they have promised to provide.
-    pub promise: TokenInfo,
+    pub promises: Vec<Promise>,
 }
 
 #[cw_serde]
 pub struct InstantiateMsg {
-    pub counterparty_one: Counterparty,
-    pub counterparty_two: Counterparty,
+    /// The counterparties to the escrow. There must be at least two.
+    pub counterparties: Vec<Counterparty>,
+    /// An optional deadline. Provided funds may only be withdrawn
+    /// once it has passed, and may no longer be provided after it.
+    /// If unset, funds may be withdrawn at any time before the escrow
+    /// completes.
+    pub deadline: Option<Expiration>,
 }
 
 #[cw_serde]
@@ -36,11 +57,15 @@ pub struct InstantiateMsg {
 pub enum ExecuteMsg {
     /// Used to provide cw20 tokens to satisfy a funds promise.
     Receive(cw20::Cw20ReceiveMsg),
-    /// Provides native tokens to satisfy a funds promise.
+    /// Used to provide a cw721 NFT to satisfy a funds promise.
+    ReceiveNft(cw721::Cw721ReceiveMsg),
+    /// Provides native tokens to satisfy funds promises. Sending a
+    /// denom provides every unprovided promise of that denom, so the
+    /// amount sent must be their total.
     #[cw_orch(payable)]
     Fund {},
-    /// Withdraws provided funds. Only allowed if the other
-    /// counterparty has yet to provide their promised funds.
+    /// Withdraws provided funds. Only allowed before the escrow
+    /// completes, and after the deadline if there is one.
     Withdraw {},
 }
 
@@ -54,8 +79,10 @@ pub enum QueryMsg {
 
 #[cw_serde]
 pub struct StatusResponse {
-    pub counterparty_one: CheckedCounterparty,
-    pub counterparty_two: CheckedCounterparty,
+    pub counterparties: Vec<CheckedCounterparty>,
+    pub deadline: Option<Expiration>,
+    /// Whether every promise has been provided and sent.
+    pub complete: bool,
 }
 
 #[cw_serde]
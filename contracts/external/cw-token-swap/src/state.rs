@@ -3,9 +3,10 @@ use cosmwasm_std::{
     to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Deps, StdError, Uint128, WasmMsg,
 };
 use cw_storage_plus::Item;
+use cw_utils::Expiration;
 
 use crate::{
-    msg::{Counterparty, TokenInfo},
+    msg::{Counterparty, Promise, TokenInfo},
     ContractError,
 };
 
@@ -19,24 +20,74 @@ pub enum CheckedTokenInfo {
         contract_addr: Addr,
         amount: Uint128,
     },
+    Cw721 {
+        contract_addr: Addr,
+        token_id: String,
+    },
+}
+
+#[cw_serde]
+pub struct CheckedPromise {
+    pub recipient: Addr,
+    pub token: CheckedTokenInfo,
+    pub provided: bool,
 }
 
 #[cw_serde]
 pub struct CheckedCounterparty {
+    pub address: Addr,
+    pub promises: Vec<CheckedPromise>,
+}
+
+/// A counterparty of an escrow instantiated before counterparties
+/// could promise more than one token. Its promise is sent to the
+/// other counterparty.
+#[cw_serde]
+pub struct LegacyCounterparty {
     pub address: Addr,
     pub promise: CheckedTokenInfo,
     pub provided: bool,
 }
 
-pub const COUNTERPARTY_ONE: Item<CheckedCounterparty> = Item::new("counterparty_one");
-pub const COUNTERPARTY_TWO: Item<CheckedCounterparty> = Item::new("counterparty_two");
+pub const COUNTERPARTIES: Item<Vec<CheckedCounterparty>> = Item::new("counterparties");
+/// When, if ever, counterparties may withdraw from an incomplete
+/// escrow. Funds may not be provided afterwards.
+pub const DEADLINE: Item<Option<Expiration>> = Item::new("deadline");
+/// Whether the escrow has completed and every promise was sent.
+pub const COMPLETE: Item<bool> = Item::new("complete");
+
+pub const LEGACY_COUNTERPARTY_ONE: Item<LegacyCounterparty> = Item::new("counterparty_one");
+pub const LEGACY_COUNTERPARTY_TWO: Item<LegacyCounterparty> = Item::new("counterparty_two");
 
 impl Counterparty {
     pub fn into_checked(self, deps: Deps) -> Result<CheckedCounterparty, ContractError> {
+        if self.promises.is_empty() {
+            return Err(ContractError::NoPromises {});
+        }
         Ok(CheckedCounterparty {
             address: deps.api.addr_validate(&self.address)?,
+            promises: self
+                .promises
+                .into_iter()
+                .map(|promise| promise.into_checked(deps))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl CheckedCounterparty {
+    /// Whether every promise of the counterparty has been provided.
+    pub fn provided(&self) -> bool {
+        self.promises.iter().all(|promise| promise.provided)
+    }
+}
+
+impl Promise {
+    pub fn into_checked(self, deps: Deps) -> Result<CheckedPromise, ContractError> {
+        Ok(CheckedPromise {
+            recipient: deps.api.addr_validate(&self.recipient)?,
+            token: self.token.into_checked(deps)?,
             provided: false,
-            promise: self.promise.into_checked(deps)?,
         })
     }
 }
@@ -70,6 +121,21 @@ impl TokenInfo {
                     })
                 }
             }
+            TokenInfo::Cw721 {
+                contract_addr,
+                token_id,
+            } => {
+                let contract_addr = deps.api.addr_validate(&contract_addr)?;
+                // Make sure we are dealing with a cw721.
+                let _: cw721::ContractInfoResponse = deps.querier.query_wasm_smart(
+                    contract_addr.clone(),
+                    &cw721::Cw721QueryMsg::ContractInfo {},
+                )?;
+                Ok(CheckedTokenInfo::Cw721 {
+                    contract_addr,
+                    token_id,
+                })
+            }
         }
     }
 }
@@ -94,6 +160,18 @@ impl CheckedTokenInfo {
                 funds: vec![],
             }
             .into(),
+            Self::Cw721 {
+                contract_addr,
+                token_id,
+            } => WasmMsg::Execute {
+                contract_addr: contract_addr.into_string(),
+                msg: to_json_binary(&cw721::Cw721ExecuteMsg::TransferNft {
+                    recipient: recipient.to_string(),
+                    token_id,
+                })?,
+                funds: vec![],
+            }
+            .into(),
         })
     }
 }
@@ -122,6 +200,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_into_spend_message_cw721() {
+        let info = CheckedTokenInfo::Cw721 {
+            contract_addr: Addr::unchecked("ekez_nft"),
+            token_id: "1".to_string(),
+        };
+        let message = info.into_send_message(&Addr::unchecked("ekez")).unwrap();
+
+        assert_eq!(
+            message,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                funds: vec![],
+                contract_addr: "ekez_nft".to_string(),
+                msg: to_json_binary(&cw721::Cw721ExecuteMsg::TransferNft {
+                    recipient: "ekez".to_string(),
+                    token_id: "1".to_string(),
+                })
+                .unwrap()
+            })
+        );
+    }
+
     #[test]
     fn test_into_spend_message_cw20() {
         let info = CheckedTokenInfo::Cw20 {
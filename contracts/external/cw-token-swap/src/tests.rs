@@ -1,21 +1,28 @@
 use cosmwasm_std::{
+    coins,
     testing::{mock_dependencies, mock_env},
     to_json_binary, Addr, Coin, Empty, Uint128,
 };
 use cw20::Cw20Coin;
 use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor, SudoMsg};
+use cw_utils::Expiration;
 
 use crate::{
     contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
     msg::{
-        Counterparty, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, StatusResponse, TokenInfo,
+        Counterparty, ExecuteMsg, InstantiateMsg, MigrateMsg, Promise, QueryMsg, StatusResponse,
+        TokenInfo,
+    },
+    state::{
+        CheckedCounterparty, CheckedPromise, CheckedTokenInfo, LegacyCounterparty, COMPLETE,
+        COUNTERPARTIES, DEADLINE, LEGACY_COUNTERPARTY_ONE, LEGACY_COUNTERPARTY_TWO,
     },
-    state::{CheckedCounterparty, CheckedTokenInfo},
     ContractError,
 };
 
 const DAO1: &str = "dao1";
 const DAO2: &str = "dao2";
+const DAO3: &str = "dao3";
 
 fn escrow_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
@@ -35,6 +42,55 @@ fn cw20_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+fn cw721_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cw721_base::entry::execute,
+        cw721_base::entry::instantiate,
+        cw721_base::entry::query,
+    );
+    Box::new(contract)
+}
+
+/// An escrow swapping ONE's promise for TWO's.
+fn swap(one: (&str, TokenInfo), two: (&str, TokenInfo)) -> InstantiateMsg {
+    InstantiateMsg {
+        counterparties: vec![
+            Counterparty {
+                address: one.0.to_string(),
+                promises: vec![Promise {
+                    recipient: two.0.to_string(),
+                    token: one.1,
+                }],
+            },
+            Counterparty {
+                address: two.0.to_string(),
+                promises: vec![Promise {
+                    recipient: one.0.to_string(),
+                    token: two.1,
+                }],
+            },
+        ],
+        deadline: None,
+    }
+}
+
+/// The status of a counterparty sending a single token to RECIPIENT.
+fn checked(
+    address: &str,
+    recipient: &str,
+    token: CheckedTokenInfo,
+    provided: bool,
+) -> CheckedCounterparty {
+    CheckedCounterparty {
+        address: Addr::unchecked(address),
+        promises: vec![CheckedPromise {
+            recipient: Addr::unchecked(recipient),
+            token,
+            provided,
+        }],
+    }
+}
+
 #[test]
 fn test_simple_escrow() {
     let mut app = App::default();
@@ -67,22 +123,22 @@ fn test_simple_escrow() {
         .instantiate_contract(
             escrow_code,
             Addr::unchecked(DAO1),
-            &InstantiateMsg {
-                counterparty_one: Counterparty {
-                    address: DAO1.to_string(),
-                    promise: TokenInfo::Native {
+            &swap(
+                (
+                    DAO1,
+                    TokenInfo::Native {
                         denom: "ujuno".to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-                counterparty_two: Counterparty {
-                    address: DAO2.to_string(),
-                    promise: TokenInfo::Cw20 {
+                ),
+                (
+                    DAO2,
+                    TokenInfo::Cw20 {
                         contract_addr: cw20.to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-            },
+                ),
+            ),
             &[],
             "escrow",
             None,
@@ -168,22 +224,22 @@ fn test_withdraw() {
         .instantiate_contract(
             escrow_code,
             Addr::unchecked(DAO1),
-            &InstantiateMsg {
-                counterparty_one: Counterparty {
-                    address: DAO1.to_string(),
-                    promise: TokenInfo::Native {
+            &swap(
+                (
+                    DAO1,
+                    TokenInfo::Native {
                         denom: "ujuno".to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-                counterparty_two: Counterparty {
-                    address: DAO2.to_string(),
-                    promise: TokenInfo::Cw20 {
+                ),
+                (
+                    DAO2,
+                    TokenInfo::Cw20 {
                         contract_addr: cw20.to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-            },
+                ),
+            ),
             &[],
             "escrow",
             None,
@@ -262,22 +318,28 @@ fn test_withdraw() {
     assert_eq!(
         status,
         StatusResponse {
-            counterparty_one: CheckedCounterparty {
-                address: Addr::unchecked(DAO1),
-                promise: CheckedTokenInfo::Native {
-                    denom: "ujuno".to_string(),
-                    amount: Uint128::new(100)
-                },
-                provided: true,
-            },
-            counterparty_two: CheckedCounterparty {
-                address: Addr::unchecked(DAO2),
-                promise: CheckedTokenInfo::Cw20 {
-                    contract_addr: cw20.clone(),
-                    amount: Uint128::new(100)
-                },
-                provided: false,
-            }
+            counterparties: vec![
+                checked(
+                    DAO1,
+                    DAO2,
+                    CheckedTokenInfo::Native {
+                        denom: "ujuno".to_string(),
+                        amount: Uint128::new(100)
+                    },
+                    true
+                ),
+                checked(
+                    DAO2,
+                    DAO1,
+                    CheckedTokenInfo::Cw20 {
+                        contract_addr: cw20.clone(),
+                        amount: Uint128::new(100)
+                    },
+                    false
+                ),
+            ],
+            deadline: None,
+            complete: false,
         }
     );
 
@@ -300,22 +362,28 @@ fn test_withdraw() {
     assert_eq!(
         status,
         StatusResponse {
-            counterparty_one: CheckedCounterparty {
-                address: Addr::unchecked(DAO1),
-                promise: CheckedTokenInfo::Native {
-                    denom: "ujuno".to_string(),
-                    amount: Uint128::new(100)
-                },
-                provided: false,
-            },
-            counterparty_two: CheckedCounterparty {
-                address: Addr::unchecked(DAO2),
-                promise: CheckedTokenInfo::Cw20 {
-                    contract_addr: cw20,
-                    amount: Uint128::new(100)
-                },
-                provided: false,
-            }
+            counterparties: vec![
+                checked(
+                    DAO1,
+                    DAO2,
+                    CheckedTokenInfo::Native {
+                        denom: "ujuno".to_string(),
+                        amount: Uint128::new(100)
+                    },
+                    false
+                ),
+                checked(
+                    DAO2,
+                    DAO1,
+                    CheckedTokenInfo::Cw20 {
+                        contract_addr: cw20,
+                        amount: Uint128::new(100)
+                    },
+                    false
+                ),
+            ],
+            deadline: None,
+            complete: false,
         }
     )
 }
@@ -352,22 +420,22 @@ fn test_withdraw_post_completion() {
         .instantiate_contract(
             escrow_code,
             Addr::unchecked(DAO1),
-            &InstantiateMsg {
-                counterparty_one: Counterparty {
-                    address: DAO1.to_string(),
-                    promise: TokenInfo::Native {
+            &swap(
+                (
+                    DAO1,
+                    TokenInfo::Native {
                         denom: "ujuno".to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-                counterparty_two: Counterparty {
-                    address: DAO2.to_string(),
-                    promise: TokenInfo::Cw20 {
+                ),
+                (
+                    DAO2,
+                    TokenInfo::Cw20 {
                         contract_addr: cw20.to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-            },
+                ),
+            ),
             &[],
             "escrow",
             None,
@@ -461,22 +529,22 @@ fn test_invalid_instantiate() {
         .instantiate_contract(
             escrow_code,
             Addr::unchecked(DAO1),
-            &InstantiateMsg {
-                counterparty_one: Counterparty {
-                    address: DAO1.to_string(),
-                    promise: TokenInfo::Native {
+            &swap(
+                (
+                    DAO1,
+                    TokenInfo::Native {
                         denom: "ujuno".to_string(),
                         amount: Uint128::new(0),
                     },
-                },
-                counterparty_two: Counterparty {
-                    address: DAO2.to_string(),
-                    promise: TokenInfo::Cw20 {
+                ),
+                (
+                    DAO2,
+                    TokenInfo::Cw20 {
                         contract_addr: cw20.to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-            },
+                ),
+            ),
             &[],
             "escrow",
             None,
@@ -492,22 +560,22 @@ fn test_invalid_instantiate() {
         .instantiate_contract(
             escrow_code,
             Addr::unchecked(DAO1),
-            &InstantiateMsg {
-                counterparty_one: Counterparty {
-                    address: DAO1.to_string(),
-                    promise: TokenInfo::Native {
+            &swap(
+                (
+                    DAO1,
+                    TokenInfo::Native {
                         denom: "ujuno".to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-                counterparty_two: Counterparty {
-                    address: DAO2.to_string(),
-                    promise: TokenInfo::Cw20 {
+                ),
+                (
+                    DAO2,
+                    TokenInfo::Cw20 {
                         contract_addr: cw20.to_string(),
                         amount: Uint128::new(0),
                     },
-                },
-            },
+                ),
+            ),
             &[],
             "escrow",
             None,
@@ -530,22 +598,22 @@ fn test_non_distincy_counterparties() {
         .instantiate_contract(
             escrow_code,
             Addr::unchecked(DAO1),
-            &InstantiateMsg {
-                counterparty_one: Counterparty {
-                    address: DAO1.to_string(),
-                    promise: TokenInfo::Native {
+            &swap(
+                (
+                    DAO1,
+                    TokenInfo::Native {
                         denom: "ujuno".to_string(),
                         amount: Uint128::new(110),
                     },
-                },
-                counterparty_two: Counterparty {
-                    address: DAO1.to_string(),
-                    promise: TokenInfo::Native {
+                ),
+                (
+                    DAO1,
+                    TokenInfo::Native {
                         denom: "ujuno".to_string(),
                         amount: Uint128::new(10),
                     },
-                },
-            },
+                ),
+            ),
             &[],
             "escrow",
             None,
@@ -589,22 +657,22 @@ fn test_fund_non_counterparty() {
         .instantiate_contract(
             escrow_code,
             Addr::unchecked(DAO1),
-            &InstantiateMsg {
-                counterparty_one: Counterparty {
-                    address: DAO1.to_string(),
-                    promise: TokenInfo::Native {
+            &swap(
+                (
+                    DAO1,
+                    TokenInfo::Native {
                         denom: "ujuno".to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-                counterparty_two: Counterparty {
-                    address: DAO2.to_string(),
-                    promise: TokenInfo::Cw20 {
+                ),
+                (
+                    DAO2,
+                    TokenInfo::Cw20 {
                         contract_addr: cw20.to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-            },
+                ),
+            ),
             &[],
             "escrow",
             None,
@@ -686,22 +754,22 @@ fn test_fund_twice() {
         .instantiate_contract(
             escrow_code,
             Addr::unchecked(DAO1),
-            &InstantiateMsg {
-                counterparty_one: Counterparty {
-                    address: DAO1.to_string(),
-                    promise: TokenInfo::Native {
+            &swap(
+                (
+                    DAO1,
+                    TokenInfo::Native {
                         denom: "ujuno".to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-                counterparty_two: Counterparty {
-                    address: DAO2.to_string(),
-                    promise: TokenInfo::Cw20 {
+                ),
+                (
+                    DAO2,
+                    TokenInfo::Cw20 {
                         contract_addr: cw20.to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-            },
+                ),
+            ),
             &[],
             "escrow",
             None,
@@ -806,22 +874,22 @@ fn test_fund_invalid_amount() {
         .instantiate_contract(
             escrow_code,
             Addr::unchecked(DAO1),
-            &InstantiateMsg {
-                counterparty_one: Counterparty {
-                    address: DAO1.to_string(),
-                    promise: TokenInfo::Native {
+            &swap(
+                (
+                    DAO1,
+                    TokenInfo::Native {
                         denom: "ujuno".to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-                counterparty_two: Counterparty {
-                    address: DAO2.to_string(),
-                    promise: TokenInfo::Cw20 {
+                ),
+                (
+                    DAO2,
+                    TokenInfo::Cw20 {
                         contract_addr: cw20.to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-            },
+                ),
+            ),
             &[],
             "escrow",
             None,
@@ -889,22 +957,22 @@ fn test_fund_invalid_denom() {
         .instantiate_contract(
             escrow_code,
             Addr::unchecked(DAO1),
-            &InstantiateMsg {
-                counterparty_one: Counterparty {
-                    address: DAO1.to_string(),
-                    promise: TokenInfo::Native {
+            &swap(
+                (
+                    DAO1,
+                    TokenInfo::Native {
                         denom: "ujuno".to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-                counterparty_two: Counterparty {
-                    address: DAO2.to_string(),
-                    promise: TokenInfo::Native {
+                ),
+                (
+                    DAO2,
+                    TokenInfo::Native {
                         denom: "uekez".to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-            },
+                ),
+            ),
             &[],
             "escrow",
             None,
@@ -992,22 +1060,22 @@ fn test_fund_invalid_cw20() {
         .instantiate_contract(
             escrow_code,
             Addr::unchecked(DAO1),
-            &InstantiateMsg {
-                counterparty_one: Counterparty {
-                    address: DAO1.to_string(),
-                    promise: TokenInfo::Native {
+            &swap(
+                (
+                    DAO1,
+                    TokenInfo::Native {
                         denom: "ujuno".to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-                counterparty_two: Counterparty {
-                    address: DAO2.to_string(),
-                    promise: TokenInfo::Cw20 {
+                ),
+                (
+                    DAO2,
+                    TokenInfo::Cw20 {
                         contract_addr: cw20.to_string(),
                         amount: Uint128::new(100),
                     },
-                },
-            },
+                ),
+            ),
             &[],
             "escrow",
             None,
@@ -1061,3 +1129,449 @@ pub fn test_migrate_update_version() {
     assert_eq!(version.version, CONTRACT_VERSION);
     assert_eq!(version.contract, CONTRACT_NAME);
 }
+
+#[test]
+pub fn test_migrate_legacy_escrow() {
+    let mut deps = mock_dependencies();
+    let native = CheckedTokenInfo::Native {
+        denom: "ujuno".to_string(),
+        amount: Uint128::new(100),
+    };
+    let cw20 = CheckedTokenInfo::Cw20 {
+        contract_addr: Addr::unchecked("coin"),
+        amount: Uint128::new(100),
+    };
+    LEGACY_COUNTERPARTY_ONE
+        .save(
+            &mut deps.storage,
+            &LegacyCounterparty {
+                address: Addr::unchecked(DAO1),
+                promise: native.clone(),
+                provided: true,
+            },
+        )
+        .unwrap();
+    LEGACY_COUNTERPARTY_TWO
+        .save(
+            &mut deps.storage,
+            &LegacyCounterparty {
+                address: Addr::unchecked(DAO2),
+                promise: cw20.clone(),
+                provided: false,
+            },
+        )
+        .unwrap();
+
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    assert_eq!(
+        COUNTERPARTIES.load(&deps.storage).unwrap(),
+        vec![
+            checked(DAO1, DAO2, native, true),
+            checked(DAO2, DAO1, cw20, false),
+        ]
+    );
+    assert_eq!(DEADLINE.load(&deps.storage).unwrap(), None);
+    assert!(!COMPLETE.load(&deps.storage).unwrap());
+    assert!(LEGACY_COUNTERPARTY_ONE
+        .may_load(&deps.storage)
+        .unwrap()
+        .is_none());
+    assert!(LEGACY_COUNTERPARTY_TWO
+        .may_load(&deps.storage)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn test_multi_party_escrow() {
+    let mut app = App::default();
+
+    let cw20_code = app.store_code(cw20_contract());
+    let cw721_code = app.store_code(cw721_contract());
+    let escrow_code = app.store_code(escrow_contract());
+
+    let cw20 = app
+        .instantiate_contract(
+            cw20_code,
+            Addr::unchecked(DAO2),
+            &cw20_base::msg::InstantiateMsg {
+                name: "coin coin".to_string(),
+                symbol: "coin".to_string(),
+                decimals: 6,
+                initial_balances: vec![Cw20Coin {
+                    address: DAO2.to_string(),
+                    amount: Uint128::new(100),
+                }],
+                mint: None,
+                marketing: None,
+            },
+            &[],
+            "coin",
+            None,
+        )
+        .unwrap();
+    let cw721 = app
+        .instantiate_contract(
+            cw721_code,
+            Addr::unchecked(DAO1),
+            &cw721_base::msg::InstantiateMsg {
+                name: "nft".to_string(),
+                symbol: "nft".to_string(),
+                minter: DAO1.to_string(),
+            },
+            &[],
+            "nft",
+            None,
+        )
+        .unwrap();
+    app.execute_contract(
+        Addr::unchecked(DAO1),
+        cw721.clone(),
+        &cw721_base::msg::ExecuteMsg::<Empty, Empty>::Mint {
+            token_id: "1".to_string(),
+            owner: DAO1.to_string(),
+            token_uri: None,
+            extension: Empty {},
+        },
+        &[],
+    )
+    .unwrap();
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: DAO1.to_string(),
+        amount: coins(100, "ujuno"),
+    }))
+    .unwrap();
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: DAO3.to_string(),
+        amount: coins(100, "uekez"),
+    }))
+    .unwrap();
+
+    let promise = |recipient: &str, token: TokenInfo| Promise {
+        recipient: recipient.to_string(),
+        token,
+    };
+    let escrow = app
+        .instantiate_contract(
+            escrow_code,
+            Addr::unchecked(DAO1),
+            &InstantiateMsg {
+                counterparties: vec![
+                    Counterparty {
+                        address: DAO1.to_string(),
+                        promises: vec![
+                            promise(
+                                DAO2,
+                                TokenInfo::Native {
+                                    denom: "ujuno".to_string(),
+                                    amount: Uint128::new(100),
+                                },
+                            ),
+                            promise(
+                                DAO3,
+                                TokenInfo::Cw721 {
+                                    contract_addr: cw721.to_string(),
+                                    token_id: "1".to_string(),
+                                },
+                            ),
+                        ],
+                    },
+                    Counterparty {
+                        address: DAO2.to_string(),
+                        promises: vec![promise(
+                            DAO1,
+                            TokenInfo::Cw20 {
+                                contract_addr: cw20.to_string(),
+                                amount: Uint128::new(100),
+                            },
+                        )],
+                    },
+                    Counterparty {
+                        address: DAO3.to_string(),
+                        promises: vec![
+                            promise(
+                                DAO1,
+                                TokenInfo::Native {
+                                    denom: "uekez".to_string(),
+                                    amount: Uint128::new(60),
+                                },
+                            ),
+                            promise(
+                                DAO2,
+                                TokenInfo::Native {
+                                    denom: "uekez".to_string(),
+                                    amount: Uint128::new(40),
+                                },
+                            ),
+                        ],
+                    },
+                ],
+                deadline: None,
+            },
+            &[],
+            "escrow",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(DAO1),
+        escrow.clone(),
+        &ExecuteMsg::Fund {},
+        &coins(100, "ujuno"),
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(DAO1),
+        cw721.clone(),
+        &cw721::Cw721ExecuteMsg::SendNft {
+            contract: escrow.to_string(),
+            token_id: "1".to_string(),
+            msg: to_json_binary("").unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(DAO2),
+        cw20.clone(),
+        &cw20::Cw20ExecuteMsg::Send {
+            contract: escrow.to_string(),
+            amount: Uint128::new(100),
+            msg: to_json_binary("").unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Nothing is paid out until every promise is met.
+    let status: StatusResponse = app
+        .wrap()
+        .query_wasm_smart(escrow.clone(), &QueryMsg::Status {})
+        .unwrap();
+    assert!(!status.complete);
+    assert!(status.counterparties[0].provided());
+    assert!(status.counterparties[1].provided());
+    assert!(!status.counterparties[2].provided());
+    assert_eq!(
+        app.wrap().query_balance(DAO2, "ujuno").unwrap().amount,
+        Uint128::zero()
+    );
+
+    // Both of DAO3's uekez promises are provided at once.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(DAO3),
+            escrow.clone(),
+            &ExecuteMsg::Fund {},
+            &coins(60, "uekez"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::InvalidAmount {
+            expected: Uint128::new(100),
+            actual: Uint128::new(60),
+        }
+    );
+    app.execute_contract(
+        Addr::unchecked(DAO3),
+        escrow.clone(),
+        &ExecuteMsg::Fund {},
+        &coins(100, "uekez"),
+    )
+    .unwrap();
+
+    let status: StatusResponse = app
+        .wrap()
+        .query_wasm_smart(escrow, &QueryMsg::Status {})
+        .unwrap();
+    assert!(status.complete);
+
+    assert_eq!(
+        app.wrap().query_balance(DAO2, "ujuno").unwrap().amount,
+        Uint128::new(100)
+    );
+    let owner: cw721::OwnerOfResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw721,
+            &cw721::Cw721QueryMsg::OwnerOf {
+                token_id: "1".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(owner.owner, DAO3);
+    let dao1_balance: cw20::BalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw20,
+            &cw20::Cw20QueryMsg::Balance {
+                address: DAO1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(dao1_balance.balance, Uint128::new(100));
+    assert_eq!(
+        app.wrap().query_balance(DAO1, "uekez").unwrap().amount,
+        Uint128::new(60)
+    );
+    assert_eq!(
+        app.wrap().query_balance(DAO2, "uekez").unwrap().amount,
+        Uint128::new(40)
+    );
+}
+
+#[test]
+fn test_deadline() {
+    let mut app = App::default();
+    let escrow_code = app.store_code(escrow_contract());
+
+    let mut msg = swap(
+        (
+            DAO1,
+            TokenInfo::Native {
+                denom: "ujuno".to_string(),
+                amount: Uint128::new(100),
+            },
+        ),
+        (
+            DAO2,
+            TokenInfo::Native {
+                denom: "uekez".to_string(),
+                amount: Uint128::new(100),
+            },
+        ),
+    );
+    msg.deadline = Some(Expiration::AtHeight(app.block_info().height + 10));
+    let escrow = app
+        .instantiate_contract(
+            escrow_code,
+            Addr::unchecked(DAO1),
+            &msg,
+            &[],
+            "escrow",
+            None,
+        )
+        .unwrap();
+
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: DAO1.to_string(),
+        amount: coins(100, "ujuno"),
+    }))
+    .unwrap();
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: DAO2.to_string(),
+        amount: coins(100, "uekez"),
+    }))
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(DAO1),
+        escrow.clone(),
+        &ExecuteMsg::Fund {},
+        &coins(100, "ujuno"),
+    )
+    .unwrap();
+
+    // Provided funds are locked until the deadline.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(DAO1),
+            escrow.clone(),
+            &ExecuteMsg::Withdraw {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotExpired {});
+
+    app.update_block(|block| block.height += 10);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(DAO2),
+            escrow.clone(),
+            &ExecuteMsg::Fund {},
+            &coins(100, "uekez"),
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Expired {});
+
+    app.execute_contract(Addr::unchecked(DAO1), escrow, &ExecuteMsg::Withdraw {}, &[])
+        .unwrap();
+    assert_eq!(
+        app.wrap().query_balance(DAO1, "ujuno").unwrap().amount,
+        Uint128::new(100)
+    );
+}
+
+#[test]
+fn test_invalid_multi_party_instantiate() {
+    let mut app = App::default();
+    let escrow_code = app.store_code(escrow_contract());
+
+    let native = |amount: u128| TokenInfo::Native {
+        denom: "ujuno".to_string(),
+        amount: Uint128::new(amount),
+    };
+    let counterparty = |address: &str, recipients: &[&str]| Counterparty {
+        address: address.to_string(),
+        promises: recipients
+            .iter()
+            .map(|recipient| Promise {
+                recipient: recipient.to_string(),
+                token: native(100),
+            })
+            .collect(),
+    };
+    let mut instantiate = |counterparties: Vec<Counterparty>, deadline: Option<Expiration>| {
+        app.instantiate_contract(
+            escrow_code,
+            Addr::unchecked(DAO1),
+            &InstantiateMsg {
+                counterparties,
+                deadline,
+            },
+            &[],
+            "escrow",
+            None,
+        )
+        .unwrap_err()
+        .downcast::<ContractError>()
+        .unwrap()
+    };
+
+    let err = instantiate(vec![counterparty(DAO1, &[DAO2])], None);
+    assert_eq!(err, ContractError::TooFewCounterparties {});
+
+    let err = instantiate(
+        vec![counterparty(DAO1, &[DAO2]), counterparty(DAO2, &[])],
+        None,
+    );
+    assert_eq!(err, ContractError::NoPromises {});
+
+    // Recipients must be other counterparties.
+    let err = instantiate(
+        vec![counterparty(DAO1, &[DAO3]), counterparty(DAO2, &[DAO1])],
+        None,
+    );
+    assert_eq!(err, ContractError::InvalidRecipient {});
+    let err = instantiate(
+        vec![counterparty(DAO1, &[DAO1]), counterparty(DAO2, &[DAO1])],
+        None,
+    );
+    assert_eq!(err, ContractError::InvalidRecipient {});
+
+    let err = instantiate(
+        vec![counterparty(DAO1, &[DAO2]), counterparty(DAO2, &[DAO1])],
+        Some(Expiration::AtHeight(0)),
+    );
+    assert_eq!(err, ContractError::Expired {});
+}
@@ -105,6 +105,19 @@ pub fn execute(
         ExecuteMsg::SetMinterAllowance { address, allowance } => {
             execute::set_minter(deps, info, address, allowance)
         }
+        ExecuteMsg::SetMinterRateLimit {
+            address,
+            max,
+            window_seconds,
+        } => execute::set_minter_rate_limit(deps, env, info, address, max, window_seconds),
+        ExecuteMsg::SetGuardian { address } => execute::set_guardian(deps, info, address),
+        ExecuteMsg::FreezeMinter { address, status } => {
+            execute::freeze_minter(deps, info, address, status)
+        }
+        ExecuteMsg::SetTransferRules { rules } => execute::set_transfer_rules(deps, info, rules),
+        ExecuteMsg::SetHolderTier { address, tier } => {
+            execute::set_holder_tier(deps, info, address, tier)
+        }
         ExecuteMsg::SetBurnerAllowance { address, allowance } => {
             execute::set_burner(deps, info, address, allowance)
         }
@@ -120,16 +133,16 @@ pub fn execute(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
     match msg {
         SudoMsg::BlockBeforeSend { from, to, amount } => {
-            hooks::beforesend_hook(deps, from, to, amount)
+            hooks::beforesend_hook(deps, env, from, to, amount)
         }
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Allowlist { start_after, limit } => {
             to_json_binary(&queries::query_allowlist(deps, start_after, limit)?)
@@ -137,6 +150,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::BeforeSendHookInfo {} => {
             to_json_binary(&queries::query_before_send_hook_features(deps)?)
         }
+        QueryMsg::BlockBeforeSend { from, to, amount } => to_json_binary(
+            &queries::query_block_before_send(deps, env, from, to, amount)?,
+        ),
         QueryMsg::BurnAllowance { address } => {
             to_json_binary(&queries::query_burn_allowance(deps, address)?)
         }
@@ -147,18 +163,23 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Denylist { start_after, limit } => {
             to_json_binary(&queries::query_denylist(deps, start_after, limit)?)
         }
+        QueryMsg::HolderRestrictions { address } => {
+            to_json_binary(&queries::query_holder_restrictions(deps, env, address)?)
+        }
         QueryMsg::IsAllowed { address } => {
             to_json_binary(&queries::query_is_allowed(deps, address)?)
         }
         QueryMsg::IsDenied { address } => to_json_binary(&queries::query_is_denied(deps, address)?),
         QueryMsg::IsFrozen {} => to_json_binary(&queries::query_is_frozen(deps)?),
         QueryMsg::Ownership {} => to_json_binary(&queries::query_owner(deps)?),
+        QueryMsg::Guardian {} => to_json_binary(&queries::query_guardian(deps)?),
         QueryMsg::MintAllowance { address } => {
-            to_json_binary(&queries::query_mint_allowance(deps, address)?)
+            to_json_binary(&queries::query_mint_allowance(deps, env, address)?)
         }
         QueryMsg::MintAllowances { start_after, limit } => {
             to_json_binary(&queries::query_mint_allowances(deps, start_after, limit)?)
         }
+        QueryMsg::TransferRules {} => to_json_binary(&queries::query_transfer_rules(deps)?),
     }
 }
 
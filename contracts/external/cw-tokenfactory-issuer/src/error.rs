@@ -41,6 +41,37 @@ pub enum ContractError {
         allowance: Uint128,
     },
 
+    #[error("Mint rate limit exceeded: attempted to mint {amount}, but only {available} may be minted until the limit refills")]
+    MintRateLimited { amount: Uint128, available: Uint128 },
+
+    #[error("The minter '{address}' is frozen")]
+    MinterFrozen { address: String },
+
+    #[error("Rate limit window must be non-zero")]
+    ZeroRateLimitWindow {},
+
+    #[error("Transfer to '{address}' would exceed the max balance of {max_balance}")]
+    MaxBalanceExceeded {
+        address: String,
+        max_balance: Uint128,
+    },
+
+    #[error("Transfer of {amount} exceeds the max transfer of {max_transfer}")]
+    MaxTransferExceeded {
+        amount: Uint128,
+        max_transfer: Uint128,
+    },
+
+    #[error("The address '{address}' may not send its {locked} locked up tokens")]
+    MintLockup { address: String, locked: Uint128 },
+
+    #[error("The address '{address}' has tier {tier}, but tier {min_tier} is required to receive tokens")]
+    RecipientTierTooLow {
+        address: String,
+        tier: u8,
+        min_tier: u8,
+    },
+
     #[error("Unauthorized")]
     Unauthorized {},
 
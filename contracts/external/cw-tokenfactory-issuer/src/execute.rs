@@ -1,4 +1,6 @@
-use cosmwasm_std::{coins, BankMsg, CosmosMsg, DepsMut, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{
+    coins, BankMsg, CosmosMsg, DepsMut, Env, MessageInfo, Response, StdResult, Uint128,
+};
 
 use cw_tokenfactory_types::msg::{msg_burn, msg_change_admin, msg_mint};
 #[cfg(feature = "osmosis_tokenfactory")]
@@ -11,7 +13,11 @@ use crate::helpers::{check_before_send_hook_features_enabled, check_is_not_froze
 
 #[cfg(feature = "osmosis_tokenfactory")]
 use crate::state::{BeforeSendHookInfo, BEFORE_SEND_HOOK_INFO};
-use crate::state::{ALLOWLIST, BURNER_ALLOWANCES, DENOM, DENYLIST, IS_FROZEN, MINTER_ALLOWANCES};
+use crate::state::{
+    Lockup, MintRateLimit, TransferRules, ALLOWLIST, BURNER_ALLOWANCES, DENOM, DENYLIST,
+    FROZEN_MINTERS, GUARDIAN, HOLDER_TIERS, IS_FROZEN, LOCKUPS, MINTER_ALLOWANCES,
+    MINTER_RATE_LIMITS, TRANSFER_RULES,
+};
 
 /// Mints new tokens. To mint new tokens, the address calling this method must
 /// have an allowance of tokens to mint. This allowance is set by the contract through
@@ -31,6 +37,16 @@ pub fn mint(
         return Err(ContractError::ZeroAmount {});
     }
 
+    // Frozen minters may not mint
+    if FROZEN_MINTERS
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or(false)
+    {
+        return Err(ContractError::MinterFrozen {
+            address: info.sender.to_string(),
+        });
+    }
+
     // Decrease minter allowance
     let allowance = MINTER_ALLOWANCES
         .may_load(deps.storage, &info.sender)?
@@ -48,12 +64,37 @@ pub fn mint(
         MINTER_ALLOWANCES.save(deps.storage, &info.sender, &updated_allowance)?;
     }
 
+    // Decrease the amount available under the minter's rate limit, if any
+    if let Some(mut rate_limit) = MINTER_RATE_LIMITS.may_load(deps.storage, &info.sender)? {
+        rate_limit.refill(env.block.time);
+        let available = rate_limit.available;
+        rate_limit.available = available
+            .checked_sub(amount)
+            .map_err(|_| ContractError::MintRateLimited { amount, available })?;
+        MINTER_RATE_LIMITS.save(deps.storage, &info.sender, &rate_limit)?;
+    }
+
     // Get token denom from contract
     let denom = DENOM.load(deps.storage)?;
 
     // Check token is not frozen, or if from or to address is on allowlist
     check_is_not_frozen(deps.as_ref(), info.sender.as_str(), &to_address, &denom)?;
 
+    // Lock up the minted tokens if a holding period is configured
+    if let Some(lockup) = TRANSFER_RULES
+        .may_load(deps.storage)?
+        .and_then(|rules| rules.mint_lockup_seconds)
+    {
+        let recipient = deps.api.addr_validate(&to_address)?;
+        let until = env.block.time.plus_seconds(lockup);
+        LOCKUPS.update(deps.storage, &recipient, |lockups| -> StdResult<_> {
+            let mut lockups = lockups.unwrap_or_default();
+            lockups.retain(|lockup| lockup.until > env.block.time);
+            lockups.push(Lockup { amount, until });
+            Ok(lockups)
+        })?;
+    }
+
     // Create tokenfactory MsgMint which mints coins to the contract address
     let mint_tokens_msg = msg_mint(
         env.contract.address.to_string(),
@@ -319,6 +360,118 @@ pub fn set_minter(
         .add_attribute("allowance", allowance))
 }
 
+/// Sets a rolling-window rate limit on minting for a minter, in addition
+/// to its mint allowance. To remove a rate limit, set `max` to zero.
+///
+/// If the minter already has a rate limit, the amount it may currently
+/// mint is carried over, up to the new `max`. Otherwise it may mint up
+/// to `max` immediately.
+///
+/// Must be the contract owner to call this method.
+pub fn set_minter_rate_limit(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    max: Uint128,
+    window_seconds: u64,
+) -> Result<Response, ContractError> {
+    // Only allow current contract owner to set rate limits
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    // Validate that minter is a valid address
+    let address = deps.api.addr_validate(&address)?;
+
+    // Remove the rate limit if max is set to 0
+    if max.is_zero() {
+        MINTER_RATE_LIMITS.remove(deps.storage, &address);
+    } else {
+        if window_seconds == 0 {
+            return Err(ContractError::ZeroRateLimitWindow {});
+        }
+        let available = MINTER_RATE_LIMITS
+            .may_load(deps.storage, &address)?
+            .map_or(max, |limit| limit.available_at(env.block.time).min(max));
+        MINTER_RATE_LIMITS.save(
+            deps.storage,
+            &address,
+            &MintRateLimit {
+                max,
+                window_seconds,
+                available,
+                updated_at: env.block.time,
+            },
+        )?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_minter_rate_limit")
+        .add_attribute("minter", address)
+        .add_attribute("max", max)
+        .add_attribute("window_seconds", window_seconds.to_string()))
+}
+
+/// Sets the guardian, who may freeze individual minters. To remove the
+/// guardian, set `address` to `None`.
+///
+/// Must be the contract owner to call this method.
+pub fn set_guardian(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: Option<String>,
+) -> Result<Response, ContractError> {
+    // Only allow current contract owner to set the guardian
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    match address {
+        Some(ref address) => {
+            let guardian = deps.api.addr_validate(address)?;
+            GUARDIAN.save(deps.storage, &guardian)?;
+        }
+        None => GUARDIAN.remove(deps.storage),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_guardian")
+        .add_attribute("guardian", address.unwrap_or_else(|| "none".to_string())))
+}
+
+/// Freezes / unfreezes a single minter. A frozen minter may not mint,
+/// regardless of its allowance. This acts as a circuit breaker for a
+/// compromised minter, such as a bridge, without freezing transfers of
+/// the token.
+///
+/// The contract owner or the guardian may freeze a minter. Only the
+/// contract owner may unfreeze one.
+pub fn freeze_minter(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    status: bool,
+) -> Result<Response, ContractError> {
+    let is_guardian = GUARDIAN
+        .may_load(deps.storage)?
+        .map_or(false, |guardian| guardian == info.sender);
+    if !(status && is_guardian) {
+        cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    }
+
+    // Validate that minter is a valid address
+    let address = deps.api.addr_validate(&address)?;
+
+    // Remove key from state if unfrozen
+    if status {
+        FROZEN_MINTERS.save(deps.storage, &address, &status)?;
+    } else {
+        FROZEN_MINTERS.remove(deps.storage, &address);
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "freeze_minter")
+        .add_attribute("minter", address)
+        .add_attribute("status", status.to_string()))
+}
+
 /// Freezes / unfreezes token transfers, meaning that address will not be
 /// able to send tokens until the token is unfrozen. This feature is dependent
 /// on the BeforeSendHook.
@@ -401,6 +554,61 @@ pub fn deny(
         .add_attribute("status", status.to_string()))
 }
 
+/// Sets the rules restricting token transfers, which are enforced by the
+/// BeforeSendHook. Setting `rules` to `None` removes all of them.
+///
+/// See `TransferRules` for the available rules.
+///
+/// Must be the contract owner to call this method.
+pub fn set_transfer_rules(
+    deps: DepsMut,
+    info: MessageInfo,
+    rules: Option<TransferRules>,
+) -> Result<Response, ContractError> {
+    check_before_send_hook_features_enabled(deps.as_ref())?;
+
+    // Only allow current contract owner to call this method
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    match rules {
+        Some(rules) => TRANSFER_RULES.save(deps.storage, &rules)?,
+        None => TRANSFER_RULES.remove(deps.storage),
+    }
+
+    Ok(Response::new().add_attribute("action", "set_transfer_rules"))
+}
+
+/// Sets the recipient tier of a holder. When `TransferRules` has a
+/// `min_recipient_tier`, only holders with at least that tier may receive
+/// tokens. Holders without a tier are tier 0.
+///
+/// Must be the contract owner to call this method.
+pub fn set_holder_tier(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+    tier: u8,
+) -> Result<Response, ContractError> {
+    check_before_send_hook_features_enabled(deps.as_ref())?;
+
+    // Only allow current contract owner to call this method
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let address = deps.api.addr_validate(&address)?;
+
+    // Remove key from state if tier is 0 to reduce space usage
+    if tier == 0 {
+        HOLDER_TIERS.remove(deps.storage, &address);
+    } else {
+        HOLDER_TIERS.save(deps.storage, &address, &tier)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_holder_tier")
+        .add_attribute("address", address)
+        .add_attribute("tier", tier.to_string()))
+}
+
 /// Relevant only when the token is frozen. Addresses on the allowlist can
 /// transfer tokens as well as have tokens sent to them. This feature is
 /// dependent on the BeforeSendHook.
@@ -1,6 +1,16 @@
-use crate::state::{ALLOWLIST, BEFORE_SEND_HOOK_INFO, DENOM, DENYLIST, IS_FROZEN};
+use crate::state::{
+    Lockup, ALLOWLIST, BEFORE_SEND_HOOK_INFO, DENOM, DENYLIST, HOLDER_TIERS, IS_FROZEN, LOCKUPS,
+    TRANSFER_RULES,
+};
 use crate::ContractError;
-use cosmwasm_std::Deps;
+use cosmwasm_std::{Addr, Coin, Deps, Env};
+
+/// The canonical address of the tokenfactory module account, the first 20
+/// bytes of `sha256("tokenfactory")`. Tokens burned with `burn_from` are
+/// sent to it before being burned.
+const TOKENFACTORY_MODULE_ADDRESS: [u8; 20] = [
+    46, 100, 67, 204, 160, 134, 112, 177, 166, 176, 206, 218, 18, 224, 54, 154, 127, 246, 190, 90,
+];
 
 /// Checks wether the BeforeSendHookFeatures gated features are enabled
 pub fn check_before_send_hook_features_enabled(deps: Deps) -> Result<(), ContractError> {
@@ -61,3 +71,91 @@ pub fn check_is_not_frozen(
 
     Ok(())
 }
+
+/// Checks wether the given address is the tokenfactory module account
+fn is_tokenfactory_module(deps: Deps, address: &Addr) -> bool {
+    deps.api
+        .addr_canonicalize(address.as_str())
+        .map(|canonical| canonical.as_slice() == TOKENFACTORY_MODULE_ADDRESS)
+        .unwrap_or(false)
+}
+
+/// Checks that a transfer satisfies the configured transfer rules. Transfers
+/// to or from an address on the allowlist, transfers to or from the
+/// tokenfactory module account, transfers to this contract, and transfers of
+/// other denoms are exempt. Transfers from this contract, such as mints, are
+/// only subject to the recipient rules.
+pub fn check_transfer_rules(
+    deps: Deps,
+    env: &Env,
+    from_address: &str,
+    to_address: &str,
+    coin: &Coin,
+) -> Result<(), ContractError> {
+    let Some(rules) = TRANSFER_RULES.may_load(deps.storage)? else {
+        return Ok(());
+    };
+    if coin.denom != DENOM.load(deps.storage)? {
+        return Ok(());
+    }
+
+    let from = deps.api.addr_validate(from_address)?;
+    let to = deps.api.addr_validate(to_address)?;
+    if to == env.contract.address
+        || is_tokenfactory_module(deps, &to)
+        || is_tokenfactory_module(deps, &from)
+        || ALLOWLIST.may_load(deps.storage, &from)?.unwrap_or(false)
+        || ALLOWLIST.may_load(deps.storage, &to)?.unwrap_or(false)
+    {
+        return Ok(());
+    }
+
+    if from != env.contract.address {
+        if let Some(max_transfer) = rules.max_transfer {
+            if coin.amount > max_transfer {
+                return Err(ContractError::MaxTransferExceeded {
+                    amount: coin.amount,
+                    max_transfer,
+                });
+            }
+        }
+        let lockups = LOCKUPS.may_load(deps.storage, &from)?.unwrap_or_default();
+        let locked = Lockup::locked_at(&lockups, env.block.time);
+        if !locked.is_zero() {
+            // The hook is called before the transfer, so the amount being
+            // sent is still included in the sender's balance.
+            let balance = deps.querier.query_balance(&from, &coin.denom)?.amount;
+            if balance.saturating_sub(locked) < coin.amount {
+                return Err(ContractError::MintLockup {
+                    address: from.into_string(),
+                    locked,
+                });
+            }
+        }
+    }
+
+    if let Some(min_tier) = rules.min_recipient_tier {
+        let tier = HOLDER_TIERS.may_load(deps.storage, &to)?.unwrap_or(0);
+        if tier < min_tier {
+            return Err(ContractError::RecipientTierTooLow {
+                address: to.into_string(),
+                tier,
+                min_tier,
+            });
+        }
+    }
+
+    if let Some(max_balance) = rules.max_balance {
+        // The hook is called before the transfer, so the amount being
+        // sent is not yet included in the recipient's balance.
+        let balance = deps.querier.query_balance(&to, &coin.denom)?.amount;
+        if balance.saturating_add(coin.amount) > max_balance {
+            return Err(ContractError::MaxBalanceExceeded {
+                address: to.into_string(),
+                max_balance,
+            });
+        }
+    }
+
+    Ok(())
+}
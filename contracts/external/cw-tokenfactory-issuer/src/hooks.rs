@@ -1,7 +1,7 @@
-use cosmwasm_std::{Coin, DepsMut, Response};
+use cosmwasm_std::{Coin, Deps, DepsMut, Env, Response};
 
 use crate::error::ContractError;
-use crate::helpers::{check_is_not_denied, check_is_not_frozen};
+use crate::helpers::{check_is_not_denied, check_is_not_frozen, check_transfer_rules};
 
 /// The before send hook is called before every token transfer on chains that
 /// support MsgSetBeforeSendHook.
@@ -9,17 +9,33 @@ use crate::helpers::{check_is_not_denied, check_is_not_frozen};
 /// It is called by the bank module.
 pub fn beforesend_hook(
     deps: DepsMut,
+    env: Env,
     from: String,
     to: String,
     coin: Coin,
 ) -> Result<Response, ContractError> {
+    check_before_send(deps.as_ref(), &env, from, to, coin)?;
+
+    Ok(Response::new().add_attribute("action", "before_send"))
+}
+
+/// Runs every check of the before send hook without modifying state. Used
+/// by the hook itself as well as the `BlockBeforeSend` dry-run query.
+pub fn check_before_send(
+    deps: Deps,
+    env: &Env,
+    from: String,
+    to: String,
+    coin: Coin,
+) -> Result<(), ContractError> {
     // Assert that denom of this contract is not frozen
     // If it is frozen, check whether either 'from' or 'to' address is allowed
-    check_is_not_frozen(deps.as_ref(), &from, &to, &coin.denom)?;
+    check_is_not_frozen(deps, &from, &to, &coin.denom)?;
 
     // Assert that neither 'from' or 'to' address is denylist
-    check_is_not_denied(deps.as_ref(), from)?;
-    check_is_not_denied(deps.as_ref(), to)?;
+    check_is_not_denied(deps, from.clone())?;
+    check_is_not_denied(deps, to.clone())?;
 
-    Ok(Response::new().add_attribute("action", "before_send"))
+    // Assert that the transfer satisfies the owner's transfer rules
+    check_transfer_rules(deps, env, &from, &to, &coin)
 }
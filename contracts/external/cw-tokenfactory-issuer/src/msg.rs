@@ -1,7 +1,8 @@
 #[allow(unused_imports)]
 use crate::state::BeforeSendHookInfo;
+pub use crate::state::TransferRules;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Coin, Uint128};
+use cosmwasm_std::{Addr, Coin, Timestamp, Uint128};
 
 #[cfg(any(feature = "osmosis_tokenfactory", feature = "cosmwasm_tokenfactory"))]
 pub use dao_interface::token::{DenomUnit, Metadata};
@@ -84,9 +85,50 @@ pub enum ExecuteMsg {
     #[cfg(any(feature = "osmosis_tokenfactory", feature = "cosmwasm_tokenfactory"))]
     SetDenomMetadata { metadata: Metadata },
 
+    /// Sets the recipient tier of a holder. Holders without a tier are
+    /// tier 0, and setting the tier to 0 removes it. See
+    /// `TransferRules::min_recipient_tier`.
+    ///
+    /// This method can only be called by the contract owner.
+    SetHolderTier { address: String, tier: u8 },
+
     /// Grant/revoke mint allowance.
     SetMinterAllowance { address: String, allowance: Uint128 },
 
+    /// Limits a minter to minting at most `max` tokens in any window
+    /// of `window_seconds`, in addition to its mint allowance. The
+    /// amount available to mint refills linearly over the window.
+    /// Setting `max` to zero removes the rate limit.
+    ///
+    /// This method can only be called by the contract owner.
+    SetMinterRateLimit {
+        address: String,
+        max: Uint128,
+        window_seconds: u64,
+    },
+
+    /// Sets or removes the guardian, who may freeze individual
+    /// minters with `FreezeMinter`.
+    ///
+    /// This method can only be called by the contract owner.
+    SetGuardian { address: Option<String> },
+
+    /// Freezes / unfreezes a single minter, preventing it from minting
+    /// regardless of its allowance. Unlike `Freeze`, this does not
+    /// affect token transfers.
+    ///
+    /// The owner and the guardian may freeze minters. Only the owner
+    /// may unfreeze them.
+    FreezeMinter { address: String, status: bool },
+
+    /// Sets the rules restricting token transfers, such as a max balance
+    /// per holder or a lockup after minting. Setting `rules` to `None`
+    /// removes all of them. Token Factory's BeforeSendHook listener must
+    /// be set to this contract in order for this feature to work.
+    ///
+    /// This method can only be called by the contract owner.
+    SetTransferRules { rules: Option<TransferRules> },
+
     /// Updates the admin of the Token Factory token.
     /// Normally this is the cw-tokenfactory-issuer contract itself.
     /// This is intended to be used only if you seek to transfer ownership
@@ -134,8 +176,9 @@ pub enum QueryMsg {
         limit: Option<u32>,
     },
 
-    /// Returns the mint allowance of the specified user. Response: AllowanceResponse
-    #[returns(AllowanceResponse)]
+    /// Returns the mint allowance of the specified user, its rate limit, and
+    /// whether it is frozen. Response: MintAllowanceResponse
+    #[returns(MintAllowanceResponse)]
     MintAllowance { address: String },
 
     /// Enumerates over all mint allowances. Response: AllowancesResponse
@@ -145,6 +188,10 @@ pub enum QueryMsg {
         limit: Option<u32>,
     },
 
+    /// Returns the guardian, who may freeze individual minters. Response: GuardianResponse
+    #[returns(GuardianResponse)]
+    Guardian {},
+
     /// Returns wether the user is on denylist or not. Response: StatusResponse
     #[returns(StatusResponse)]
     IsDenied { address: String },
@@ -177,6 +224,24 @@ pub enum QueryMsg {
     /// Response: BeforeSendHookInfo
     #[returns(BeforeSendHookInfo)]
     BeforeSendHookInfo {},
+
+    /// Returns the rules restricting token transfers. Response: Option<TransferRules>
+    #[returns(Option<TransferRules>)]
+    TransferRules {},
+
+    /// Returns the recipient tier of a holder and its locked up tokens.
+    /// Response: HolderRestrictionsResponse
+    #[returns(HolderRestrictionsResponse)]
+    HolderRestrictions { address: String },
+
+    /// Dry-runs the BeforeSendHook for a transfer, reporting whether it
+    /// would be allowed and, if not, why. Response: BlockBeforeSendResponse
+    #[returns(BlockBeforeSendResponse)]
+    BlockBeforeSend {
+        from: String,
+        to: String,
+        amount: Coin,
+    },
 }
 
 /// SudoMsg is only exposed for internal Cosmos SDK modules to call.
@@ -219,6 +284,51 @@ pub struct AllowanceResponse {
     pub allowance: Uint128,
 }
 
+/// Returns the mint allowance for a particular address, along with its
+/// rate limit and whether it has been frozen.
+#[cw_serde]
+pub struct MintAllowanceResponse {
+    pub allowance: Uint128,
+    pub rate_limit: Option<RateLimitResponse>,
+    pub frozen: bool,
+}
+
+/// A minter's rate limit and the amount it may currently mint under it.
+#[cw_serde]
+pub struct RateLimitResponse {
+    pub max: Uint128,
+    pub window_seconds: u64,
+    pub available: Uint128,
+}
+
+/// Returns the guardian, if one is set.
+#[cw_serde]
+pub struct GuardianResponse {
+    pub guardian: Option<Addr>,
+}
+
+/// Returns the transfer restrictions on a particular holder.
+#[cw_serde]
+pub struct HolderRestrictionsResponse {
+    /// The holder's recipient tier.
+    pub tier: u8,
+    /// The tokens minted to the holder during a lockup that it may not
+    /// yet send. The holder may send the rest of its balance.
+    pub locked: Uint128,
+    /// The time at which all of the holder's locked up tokens unlock,
+    /// if any are locked up.
+    pub locked_until: Option<Timestamp>,
+}
+
+/// Returns whether a transfer would be allowed by the BeforeSendHook.
+#[cw_serde]
+pub struct BlockBeforeSendResponse {
+    pub allowed: bool,
+    /// The error the BeforeSendHook would fail with, naming the rule that
+    /// rejects the transfer.
+    pub reason: Option<String>,
+}
+
 /// Information about a particular account and its mint / burn allowances.
 /// Used in list queries.
 #[cw_serde]
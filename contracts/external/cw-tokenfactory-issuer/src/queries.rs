@@ -1,13 +1,17 @@
-use cosmwasm_std::{Addr, Deps, Order, StdResult, Uint128};
+use cosmwasm_std::{Addr, Coin, Deps, Env, Order, StdResult, Uint128};
 use cw_storage_plus::{Bound, Map};
 
+use crate::hooks::check_before_send;
 use crate::msg::{
-    AllowanceInfo, AllowanceResponse, AllowancesResponse, AllowlistResponse, DenomResponse,
-    DenylistResponse, IsFrozenResponse, StatusInfo, StatusResponse,
+    AllowanceInfo, AllowanceResponse, AllowancesResponse, AllowlistResponse,
+    BlockBeforeSendResponse, DenomResponse, DenylistResponse, GuardianResponse,
+    HolderRestrictionsResponse, IsFrozenResponse, MintAllowanceResponse, RateLimitResponse,
+    StatusInfo, StatusResponse,
 };
 use crate::state::{
-    BeforeSendHookInfo, ALLOWLIST, BEFORE_SEND_HOOK_INFO, BURNER_ALLOWANCES, DENOM, DENYLIST,
-    IS_FROZEN, MINTER_ALLOWANCES,
+    BeforeSendHookInfo, Lockup, TransferRules, ALLOWLIST, BEFORE_SEND_HOOK_INFO, BURNER_ALLOWANCES,
+    DENOM, DENYLIST, FROZEN_MINTERS, GUARDIAN, HOLDER_TIERS, IS_FROZEN, LOCKUPS, MINTER_ALLOWANCES,
+    MINTER_RATE_LIMITS, TRANSFER_RULES,
 };
 
 // Default settings for pagination
@@ -31,12 +35,39 @@ pub fn query_owner(deps: Deps) -> StdResult<cw_ownable::Ownership<::cosmwasm_std
     cw_ownable::get_ownership(deps.storage)
 }
 
-/// Returns the mint allowance of the specified user. Response: AllowanceResponse
-pub fn query_mint_allowance(deps: Deps, address: String) -> StdResult<AllowanceResponse> {
+/// Returns the guardian, who may freeze individual minters. Response: GuardianResponse
+pub fn query_guardian(deps: Deps) -> StdResult<GuardianResponse> {
+    Ok(GuardianResponse {
+        guardian: GUARDIAN.may_load(deps.storage)?,
+    })
+}
+
+/// Returns the mint allowance of the specified user, its rate limit, and
+/// whether it is frozen. Response: MintAllowanceResponse
+pub fn query_mint_allowance(
+    deps: Deps,
+    env: Env,
+    address: String,
+) -> StdResult<MintAllowanceResponse> {
+    let address = deps.api.addr_validate(&address)?;
     let allowance = MINTER_ALLOWANCES
-        .may_load(deps.storage, &deps.api.addr_validate(&address)?)?
+        .may_load(deps.storage, &address)?
         .unwrap_or_else(Uint128::zero);
-    Ok(AllowanceResponse { allowance })
+    let rate_limit = MINTER_RATE_LIMITS
+        .may_load(deps.storage, &address)?
+        .map(|limit| RateLimitResponse {
+            max: limit.max,
+            window_seconds: limit.window_seconds,
+            available: limit.available_at(env.block.time),
+        });
+    let frozen = FROZEN_MINTERS
+        .may_load(deps.storage, &address)?
+        .unwrap_or(false);
+    Ok(MintAllowanceResponse {
+        allowance,
+        rate_limit,
+        frozen,
+    })
 }
 
 /// Returns the allowance of the specified address. Response: AllowanceResponse
@@ -123,6 +154,58 @@ pub fn query_before_send_hook_features(deps: Deps) -> StdResult<BeforeSendHookIn
     BEFORE_SEND_HOOK_INFO.load(deps.storage)
 }
 
+/// Returns the rules restricting token transfers. Response: Option<TransferRules>
+pub fn query_transfer_rules(deps: Deps) -> StdResult<Option<TransferRules>> {
+    TRANSFER_RULES.may_load(deps.storage)
+}
+
+/// Returns the recipient tier of a holder and its locked up tokens.
+/// Response: HolderRestrictionsResponse
+pub fn query_holder_restrictions(
+    deps: Deps,
+    env: Env,
+    address: String,
+) -> StdResult<HolderRestrictionsResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let tier = HOLDER_TIERS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    let lockups = LOCKUPS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    let locked = Lockup::locked_at(&lockups, env.block.time);
+    let locked_until = lockups
+        .iter()
+        .map(|lockup| lockup.until)
+        .filter(|until| *until > env.block.time)
+        .max();
+    Ok(HolderRestrictionsResponse {
+        tier,
+        locked,
+        locked_until,
+    })
+}
+
+/// Dry-runs the BeforeSendHook for a transfer. Response: BlockBeforeSendResponse
+pub fn query_block_before_send(
+    deps: Deps,
+    env: Env,
+    from: String,
+    to: String,
+    amount: Coin,
+) -> StdResult<BlockBeforeSendResponse> {
+    Ok(match check_before_send(deps, &env, from, to, amount) {
+        Ok(()) => BlockBeforeSendResponse {
+            allowed: true,
+            reason: None,
+        },
+        Err(err) => BlockBeforeSendResponse {
+            allowed: false,
+            reason: Some(err.to_string()),
+        },
+    })
+}
+
 /// A helper function used in list queries
 pub fn query_status_map(
     deps: Deps,
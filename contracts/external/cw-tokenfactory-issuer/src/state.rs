@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
 
 /// Holds the Token Factory denom managed by this contract
@@ -31,3 +31,105 @@ pub const BURNER_ALLOWANCES: Map<&Addr, Uint128> = Map::new("burner_allowances")
 
 /// Allowances for minting
 pub const MINTER_ALLOWANCES: Map<&Addr, Uint128> = Map::new("minter_allowances");
+
+/// Rolling-window rate limits for minting
+pub const MINTER_RATE_LIMITS: Map<&Addr, MintRateLimit> = Map::new("minter_rate_limits");
+
+/// Minters that may not mint until unfrozen, regardless of their allowance
+pub const FROZEN_MINTERS: Map<&Addr, bool> = Map::new("frozen_minters");
+
+/// An address that may freeze individual minters in addition to the owner
+pub const GUARDIAN: Item<Addr> = Item::new("guardian");
+
+/// Limits a minter to minting at most `max` tokens in any window of
+/// `window_seconds`. The amount available to mint refills linearly
+/// over the window, up to `max`.
+#[cw_serde]
+pub struct MintRateLimit {
+    /// The most that may be minted within any window.
+    pub max: Uint128,
+    /// The length of the window in seconds.
+    pub window_seconds: u64,
+    /// The amount that could be minted at `updated_at`.
+    pub available: Uint128,
+    pub updated_at: Timestamp,
+}
+
+impl MintRateLimit {
+    fn window_nanos(&self) -> Uint128 {
+        Uint128::from(self.window_seconds) * Uint128::from(1_000_000_000u64)
+    }
+
+    /// Returns the amount that may be minted at time `t`.
+    pub fn available_at(&self, t: Timestamp) -> Uint128 {
+        let window = self.window_nanos();
+        let elapsed = Uint128::from(t.nanos().saturating_sub(self.updated_at.nanos())).min(window);
+        let refill = self.max.multiply_ratio(elapsed, window);
+        self.available.saturating_add(refill).min(self.max)
+    }
+
+    /// Credits the amount refilled by time `t` to `available`.
+    /// `updated_at` only advances by the time it took to refill the
+    /// credited amount, so the fraction of a token refilled since is
+    /// carried over rather than lost to rounding.
+    pub fn refill(&mut self, t: Timestamp) {
+        let available = self.available_at(t);
+        if available >= self.max {
+            self.updated_at = t;
+        } else {
+            // Rounded up so that no more is credited than was refilled.
+            let credited = self
+                .window_nanos()
+                .mul_ceil((available - self.available, self.max));
+            self.updated_at = self.updated_at.plus_nanos(credited.u128() as u64);
+        }
+        self.available = available;
+    }
+}
+
+/// Rules restricting token transfers, enforced by the before send hook
+/// in addition to the denylist and freeze
+pub const TRANSFER_RULES: Item<TransferRules> = Item::new("transfer_rules");
+
+/// Recipient tiers of holders. Holders without a tier are tier 0.
+pub const HOLDER_TIERS: Map<&Addr, u8> = Map::new("holder_tiers");
+
+/// Tokens minted to a holder during a lockup, which the holder may not
+/// send until `until`.
+#[cw_serde]
+pub struct Lockup {
+    pub amount: Uint128,
+    pub until: Timestamp,
+}
+
+impl Lockup {
+    /// Returns the total amount of `lockups` still locked at `t`.
+    pub fn locked_at(lockups: &[Lockup], t: Timestamp) -> Uint128 {
+        lockups
+            .iter()
+            .filter(|lockup| lockup.until > t)
+            .fold(Uint128::zero(), |locked, lockup| {
+                locked.saturating_add(lockup.amount)
+            })
+    }
+}
+
+/// The tokens each holder was minted during a lockup. Expired lockups
+/// are removed when the holder is next minted tokens.
+pub const LOCKUPS: Map<&Addr, Vec<Lockup>> = Map::new("lockups");
+
+/// Restrictions on token transfers. Rules that are `None` are not
+/// enforced. Transfers to or from addresses on the allowlist are exempt.
+#[cw_serde]
+#[derive(Default)]
+pub struct TransferRules {
+    /// The most tokens a holder may have after receiving a transfer.
+    pub max_balance: Option<Uint128>,
+    /// The most tokens that may be sent in a single transfer.
+    pub max_transfer: Option<Uint128>,
+    /// The number of seconds after being minted tokens during which a
+    /// holder may not send tokens.
+    pub mint_lockup_seconds: Option<u64>,
+    /// The minimum tier a holder must have to receive tokens.
+    pub min_recipient_tier: Option<u8>,
+}
@@ -0,0 +1,250 @@
+use cosmwasm_std::Uint128;
+use cw_tokenfactory_issuer::ContractError;
+use osmosis_test_tube::Account;
+
+use crate::test_env::{TestEnv, TokenfactoryIssuer};
+
+const DAY: u64 = 24 * 60 * 60;
+
+#[test]
+fn set_minter_rate_limit_by_non_owner_should_fail() {
+    let env = TestEnv::default();
+    let non_owner = &env.test_accs[1];
+
+    let err = env
+        .cw_tokenfactory_issuer
+        .set_minter_rate_limit(&non_owner.address(), 1000, DAY, non_owner)
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        TokenfactoryIssuer::execute_error(ContractError::Ownership(
+            cw_ownable::OwnershipError::NotOwner
+        ))
+    );
+}
+
+#[test]
+fn set_minter_rate_limit_with_zero_window_should_fail() {
+    let env = TestEnv::default();
+    let owner = &env.test_accs[0];
+    let minter = &env.test_accs[1];
+
+    let err = env
+        .cw_tokenfactory_issuer
+        .set_minter_rate_limit(&minter.address(), 1000, 0, owner)
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        TokenfactoryIssuer::execute_error(ContractError::ZeroRateLimitWindow {})
+    );
+}
+
+#[test]
+fn mint_over_rate_limit_should_fail_until_refilled() {
+    let env = TestEnv::default();
+    let owner = &env.test_accs[0];
+    let minter = &env.test_accs[1];
+
+    env.cw_tokenfactory_issuer
+        .set_minter(&minter.address(), 1_000_000, owner)
+        .unwrap();
+    env.cw_tokenfactory_issuer
+        .set_minter_rate_limit(&minter.address(), 1000, DAY, owner)
+        .unwrap();
+
+    let rate_limit = env
+        .cw_tokenfactory_issuer
+        .query_mint_allowance(&minter.address())
+        .unwrap()
+        .rate_limit
+        .unwrap();
+    assert_eq!(rate_limit.max, Uint128::new(1000));
+    assert_eq!(rate_limit.window_seconds, DAY);
+    assert_eq!(rate_limit.available, Uint128::new(1000));
+
+    env.cw_tokenfactory_issuer
+        .mint(&minter.address(), 600, minter)
+        .unwrap();
+
+    // Only 400 remain in the current window
+    let err = env
+        .cw_tokenfactory_issuer
+        .mint(&minter.address(), 600, minter)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TokenfactoryIssuer::execute_error(ContractError::MintRateLimited {
+            amount: Uint128::new(600),
+            available: Uint128::new(400),
+        })
+    );
+
+    // Half a window later, half of the max has been refilled
+    env.app().increase_time(DAY / 2);
+    env.cw_tokenfactory_issuer
+        .mint(&minter.address(), 800, minter)
+        .unwrap();
+
+    // The refill is capped at the max
+    env.app().increase_time(10 * DAY);
+    let response = env
+        .cw_tokenfactory_issuer
+        .query_mint_allowance(&minter.address())
+        .unwrap();
+    assert_eq!(response.rate_limit.unwrap().available, Uint128::new(1000));
+
+    // Minting still decrements the static allowance
+    assert_eq!(response.allowance, Uint128::new(1_000_000 - 1400));
+}
+
+#[test]
+fn frequent_small_mints_should_not_lose_refills() {
+    let env = TestEnv::default();
+    let owner = &env.test_accs[0];
+    let minter = &env.test_accs[1];
+
+    env.cw_tokenfactory_issuer
+        .set_minter(&minter.address(), 1_000_000, owner)
+        .unwrap();
+    env.cw_tokenfactory_issuer
+        .set_minter_rate_limit(&minter.address(), 1000, DAY, owner)
+        .unwrap();
+    env.cw_tokenfactory_issuer
+        .mint(&minter.address(), 980, minter)
+        .unwrap();
+
+    // Less than one token is refilled between each of these mints
+    for _ in 0..20 {
+        env.app().increase_time(60);
+        env.cw_tokenfactory_issuer
+            .mint(&minter.address(), 1, minter)
+            .unwrap();
+    }
+
+    // Over 20 minutes, at least 13 tokens have been refilled
+    env.cw_tokenfactory_issuer
+        .mint(&minter.address(), 13, minter)
+        .unwrap();
+}
+
+#[test]
+fn set_minter_rate_limit_to_0_should_remove_it() {
+    let env = TestEnv::default();
+    let owner = &env.test_accs[0];
+    let minter = &env.test_accs[1];
+
+    env.cw_tokenfactory_issuer
+        .set_minter(&minter.address(), 1_000_000, owner)
+        .unwrap();
+    env.cw_tokenfactory_issuer
+        .set_minter_rate_limit(&minter.address(), 1000, DAY, owner)
+        .unwrap();
+    env.cw_tokenfactory_issuer
+        .set_minter_rate_limit(&minter.address(), 0, DAY, owner)
+        .unwrap();
+
+    assert_eq!(
+        env.cw_tokenfactory_issuer
+            .query_mint_allowance(&minter.address())
+            .unwrap()
+            .rate_limit,
+        None
+    );
+    env.cw_tokenfactory_issuer
+        .mint(&minter.address(), 5000, minter)
+        .unwrap();
+}
+
+#[test]
+fn guardian_can_freeze_but_not_unfreeze_minter() {
+    let env = TestEnv::default();
+    let owner = &env.test_accs[0];
+    let minter = &env.test_accs[1];
+    let guardian = &env.test_accs[2];
+
+    env.cw_tokenfactory_issuer
+        .set_minter(&minter.address(), 1_000_000, owner)
+        .unwrap();
+
+    // Guardian can't freeze before being set
+    let err = env
+        .cw_tokenfactory_issuer
+        .freeze_minter(&minter.address(), true, guardian)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TokenfactoryIssuer::execute_error(ContractError::Ownership(
+            cw_ownable::OwnershipError::NotOwner
+        ))
+    );
+
+    env.cw_tokenfactory_issuer
+        .set_guardian(Some(&guardian.address()), owner)
+        .unwrap();
+    assert_eq!(
+        env.cw_tokenfactory_issuer
+            .query_guardian()
+            .unwrap()
+            .guardian
+            .unwrap()
+            .as_str(),
+        guardian.address()
+    );
+
+    env.cw_tokenfactory_issuer
+        .freeze_minter(&minter.address(), true, guardian)
+        .unwrap();
+    assert!(
+        env.cw_tokenfactory_issuer
+            .query_mint_allowance(&minter.address())
+            .unwrap()
+            .frozen
+    );
+
+    let err = env
+        .cw_tokenfactory_issuer
+        .mint(&minter.address(), 100, minter)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TokenfactoryIssuer::execute_error(ContractError::MinterFrozen {
+            address: minter.address(),
+        })
+    );
+
+    // Only the owner may unfreeze
+    let err = env
+        .cw_tokenfactory_issuer
+        .freeze_minter(&minter.address(), false, guardian)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TokenfactoryIssuer::execute_error(ContractError::Ownership(
+            cw_ownable::OwnershipError::NotOwner
+        ))
+    );
+
+    env.cw_tokenfactory_issuer
+        .freeze_minter(&minter.address(), false, owner)
+        .unwrap();
+    env.cw_tokenfactory_issuer
+        .mint(&minter.address(), 100, minter)
+        .unwrap();
+
+    // Removing the guardian revokes its ability to freeze
+    env.cw_tokenfactory_issuer
+        .set_guardian(None, owner)
+        .unwrap();
+    assert_eq!(
+        env.cw_tokenfactory_issuer
+            .query_guardian()
+            .unwrap()
+            .guardian,
+        None
+    );
+    env.cw_tokenfactory_issuer
+        .freeze_minter(&minter.address(), true, guardian)
+        .unwrap_err();
+}
@@ -8,5 +8,7 @@ mod force_transfer;
 mod freeze;
 mod instantiate;
 mod mint;
+mod minter_rate_limit;
 mod set_before_send_hook;
 mod tokenfactory_admin;
+mod transfer_rules;
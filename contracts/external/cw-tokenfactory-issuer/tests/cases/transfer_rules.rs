@@ -0,0 +1,299 @@
+#![cfg(feature = "osmosis_tokenfactory")]
+
+use cosmwasm_std::{coin, coins, Uint128};
+use cw_tokenfactory_issuer::{msg::TransferRules, ContractError};
+use osmosis_test_tube::{Account, RunnerError};
+
+use crate::test_env::{TestEnv, TokenfactoryIssuer};
+
+fn hook_error(denom: &str, err: ContractError) -> RunnerError {
+    RunnerError::ExecuteError {
+        msg: format!("failed to execute message; message index: 0: failed to call before send hook for denom {denom}: {err}: execute wasm contract failed"),
+    }
+}
+
+/// Sets up an issuer with the before send hook enabled and mints 10000
+/// tokens to the first test account, returning the denom.
+fn setup(env: &TestEnv) -> String {
+    let owner = &env.test_accs[0];
+    env.cw_tokenfactory_issuer
+        .set_before_send_hook(env.cw_tokenfactory_issuer.contract_addr.clone(), owner)
+        .unwrap();
+    env.cw_tokenfactory_issuer
+        .set_minter(&owner.address(), 1_000_000, owner)
+        .unwrap();
+    env.cw_tokenfactory_issuer
+        .mint(&owner.address(), 10000, owner)
+        .unwrap();
+    env.cw_tokenfactory_issuer.query_denom().unwrap().denom
+}
+
+#[test]
+fn set_transfer_rules_by_non_owner_should_fail() {
+    let env = TestEnv::default();
+    setup(&env);
+    let non_owner = &env.test_accs[1];
+
+    let err = env
+        .cw_tokenfactory_issuer
+        .set_transfer_rules(Some(TransferRules::default()), non_owner)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TokenfactoryIssuer::execute_error(ContractError::Ownership(
+            cw_ownable::OwnershipError::NotOwner
+        ))
+    );
+
+    let err = env
+        .cw_tokenfactory_issuer
+        .set_holder_tier(&non_owner.address(), 1, non_owner)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TokenfactoryIssuer::execute_error(ContractError::Ownership(
+            cw_ownable::OwnershipError::NotOwner
+        ))
+    );
+}
+
+#[test]
+fn max_transfer_and_max_balance_are_enforced() {
+    let env = TestEnv::default();
+    let denom = setup(&env);
+    let owner = &env.test_accs[0];
+    let holder = &env.test_accs[1];
+
+    let rules = TransferRules {
+        max_balance: Some(Uint128::new(1500)),
+        max_transfer: Some(Uint128::new(1000)),
+        ..Default::default()
+    };
+    env.cw_tokenfactory_issuer
+        .set_transfer_rules(Some(rules.clone()), owner)
+        .unwrap();
+    assert_eq!(
+        env.cw_tokenfactory_issuer.query_transfer_rules().unwrap(),
+        Some(rules)
+    );
+
+    let err = env
+        .send_tokens(holder.address(), coins(1001, &denom), owner)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        hook_error(
+            &denom,
+            ContractError::MaxTransferExceeded {
+                amount: Uint128::new(1001),
+                max_transfer: Uint128::new(1000),
+            }
+        )
+    );
+
+    env.send_tokens(holder.address(), coins(1000, &denom), owner)
+        .unwrap();
+    let err = env
+        .send_tokens(holder.address(), coins(501, &denom), owner)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        hook_error(
+            &denom,
+            ContractError::MaxBalanceExceeded {
+                address: holder.address(),
+                max_balance: Uint128::new(1500),
+            }
+        )
+    );
+    env.send_tokens(holder.address(), coins(500, &denom), owner)
+        .unwrap();
+
+    // Removing the rules lifts the restrictions
+    env.cw_tokenfactory_issuer
+        .set_transfer_rules(None, owner)
+        .unwrap();
+    env.send_tokens(holder.address(), coins(5000, &denom), owner)
+        .unwrap();
+}
+
+#[test]
+fn minted_tokens_are_locked_up() {
+    let env = TestEnv::default();
+    let denom = setup(&env);
+    let owner = &env.test_accs[0];
+    let holder = &env.test_accs[1];
+
+    env.cw_tokenfactory_issuer
+        .set_transfer_rules(
+            Some(TransferRules {
+                mint_lockup_seconds: Some(3600),
+                ..Default::default()
+            }),
+            owner,
+        )
+        .unwrap();
+    env.cw_tokenfactory_issuer
+        .mint(&holder.address(), 1000, owner)
+        .unwrap();
+    env.send_tokens(holder.address(), coins(500, &denom), owner)
+        .unwrap();
+
+    let restrictions = env
+        .cw_tokenfactory_issuer
+        .query_holder_restrictions(&holder.address())
+        .unwrap();
+    assert_eq!(restrictions.locked, Uint128::new(1000));
+    assert!(restrictions.locked_until.is_some());
+
+    // Only the tokens that were not minted during the lockup may be sent
+    let err = env
+        .send_tokens(owner.address(), coins(501, &denom), holder)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        hook_error(
+            &denom,
+            ContractError::MintLockup {
+                address: holder.address(),
+                locked: Uint128::new(1000),
+            }
+        )
+    );
+    env.send_tokens(owner.address(), coins(500, &denom), holder)
+        .unwrap();
+
+    // Locked up tokens may still be burned
+    env.cw_tokenfactory_issuer
+        .set_burner(&owner.address(), 100, owner)
+        .unwrap();
+    env.cw_tokenfactory_issuer
+        .burn(&holder.address(), 100, owner)
+        .unwrap();
+
+    env.app().increase_time(3600);
+    let restrictions = env
+        .cw_tokenfactory_issuer
+        .query_holder_restrictions(&holder.address())
+        .unwrap();
+    assert_eq!(restrictions.locked, Uint128::zero());
+    assert_eq!(restrictions.locked_until, None);
+    env.send_tokens(owner.address(), coins(900, &denom), holder)
+        .unwrap();
+}
+
+#[test]
+fn recipients_below_min_tier_are_rejected() {
+    let env = TestEnv::default();
+    let denom = setup(&env);
+    let owner = &env.test_accs[0];
+    let holder = &env.test_accs[1];
+    let allowlistee = &env.test_accs[2];
+
+    env.cw_tokenfactory_issuer
+        .set_transfer_rules(
+            Some(TransferRules {
+                min_recipient_tier: Some(2),
+                ..Default::default()
+            }),
+            owner,
+        )
+        .unwrap();
+    env.cw_tokenfactory_issuer
+        .set_holder_tier(&holder.address(), 1, owner)
+        .unwrap();
+
+    let err = env
+        .send_tokens(holder.address(), coins(100, &denom), owner)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        hook_error(
+            &denom,
+            ContractError::RecipientTierTooLow {
+                address: holder.address(),
+                tier: 1,
+                min_tier: 2,
+            }
+        )
+    );
+
+    env.cw_tokenfactory_issuer
+        .set_holder_tier(&holder.address(), 2, owner)
+        .unwrap();
+    assert_eq!(
+        env.cw_tokenfactory_issuer
+            .query_holder_restrictions(&holder.address())
+            .unwrap()
+            .tier,
+        2
+    );
+    env.send_tokens(holder.address(), coins(100, &denom), owner)
+        .unwrap();
+
+    // Allowlisted addresses are exempt
+    env.cw_tokenfactory_issuer
+        .allow(&allowlistee.address(), true, owner)
+        .unwrap();
+    env.send_tokens(allowlistee.address(), coins(100, &denom), owner)
+        .unwrap();
+}
+
+#[test]
+fn block_before_send_reports_rejecting_rule() {
+    let env = TestEnv::default();
+    let denom = setup(&env);
+    let owner = &env.test_accs[0];
+    let holder = &env.test_accs[1];
+
+    env.cw_tokenfactory_issuer
+        .set_transfer_rules(
+            Some(TransferRules {
+                max_transfer: Some(Uint128::new(1000)),
+                ..Default::default()
+            }),
+            owner,
+        )
+        .unwrap();
+
+    let response = env
+        .cw_tokenfactory_issuer
+        .query_block_before_send(&owner.address(), &holder.address(), coin(1000, &denom))
+        .unwrap();
+    assert!(response.allowed);
+    assert_eq!(response.reason, None);
+
+    let response = env
+        .cw_tokenfactory_issuer
+        .query_block_before_send(&owner.address(), &holder.address(), coin(1001, &denom))
+        .unwrap();
+    assert!(!response.allowed);
+    assert_eq!(
+        response.reason,
+        Some(
+            ContractError::MaxTransferExceeded {
+                amount: Uint128::new(1001),
+                max_transfer: Uint128::new(1000),
+            }
+            .to_string()
+        )
+    );
+
+    // Denylisted addresses are reported too
+    env.cw_tokenfactory_issuer
+        .deny(&holder.address(), true, owner)
+        .unwrap();
+    let response = env
+        .cw_tokenfactory_issuer
+        .query_block_before_send(&owner.address(), &holder.address(), coin(10, &denom))
+        .unwrap();
+    assert_eq!(
+        response.reason,
+        Some(
+            ContractError::Denied {
+                address: holder.address()
+            }
+            .to_string()
+        )
+    );
+}
@@ -9,11 +9,14 @@ use cosmwasm_std::{Addr, Coin};
 #[cfg(any(feature = "osmosis_tokenfactory", feature = "cosmwasm_tokenfactory"))]
 use cw_tokenfactory_issuer::msg::Metadata;
 
-use cw_tokenfactory_issuer::msg::{AllowlistResponse, DenylistResponse, MigrateMsg};
+use cw_tokenfactory_issuer::msg::{
+    AllowlistResponse, BlockBeforeSendResponse, DenylistResponse, HolderRestrictionsResponse,
+    MigrateMsg, TransferRules,
+};
 use cw_tokenfactory_issuer::{
     msg::{
-        AllowanceResponse, AllowancesResponse, DenomResponse, ExecuteMsg, InstantiateMsg,
-        IsFrozenResponse, QueryMsg, StatusResponse,
+        AllowanceResponse, AllowancesResponse, DenomResponse, ExecuteMsg, GuardianResponse,
+        InstantiateMsg, IsFrozenResponse, MintAllowanceResponse, QueryMsg, StatusResponse,
     },
     ContractError,
 };
@@ -319,6 +322,78 @@ impl TokenfactoryIssuer {
         self.execute(&ExecuteMsg::Freeze { status }, &[], signer)
     }
 
+    pub fn set_minter_rate_limit(
+        &self,
+        address: &str,
+        max: u128,
+        window_seconds: u64,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgExecuteContractResponse> {
+        self.execute(
+            &ExecuteMsg::SetMinterRateLimit {
+                address: address.to_string(),
+                max: max.into(),
+                window_seconds,
+            },
+            &[],
+            signer,
+        )
+    }
+
+    pub fn set_guardian(
+        &self,
+        address: Option<&str>,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgExecuteContractResponse> {
+        self.execute(
+            &ExecuteMsg::SetGuardian {
+                address: address.map(str::to_string),
+            },
+            &[],
+            signer,
+        )
+    }
+
+    pub fn freeze_minter(
+        &self,
+        address: &str,
+        status: bool,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgExecuteContractResponse> {
+        self.execute(
+            &ExecuteMsg::FreezeMinter {
+                address: address.to_string(),
+                status,
+            },
+            &[],
+            signer,
+        )
+    }
+
+    pub fn set_transfer_rules(
+        &self,
+        rules: Option<TransferRules>,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgExecuteContractResponse> {
+        self.execute(&ExecuteMsg::SetTransferRules { rules }, &[], signer)
+    }
+
+    pub fn set_holder_tier(
+        &self,
+        address: &str,
+        tier: u8,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgExecuteContractResponse> {
+        self.execute(
+            &ExecuteMsg::SetHolderTier {
+                address: address.to_string(),
+                tier,
+            },
+            &[],
+            signer,
+        )
+    }
+
     pub fn deny(
         &self,
         address: &str,
@@ -364,6 +439,36 @@ impl TokenfactoryIssuer {
         self.query(&QueryMsg::Denom {})
     }
 
+    pub fn query_transfer_rules(&self) -> Result<Option<TransferRules>, RunnerError> {
+        self.query(&QueryMsg::TransferRules {})
+    }
+
+    pub fn query_holder_restrictions(
+        &self,
+        address: &str,
+    ) -> Result<HolderRestrictionsResponse, RunnerError> {
+        self.query(&QueryMsg::HolderRestrictions {
+            address: address.to_string(),
+        })
+    }
+
+    pub fn query_block_before_send(
+        &self,
+        from: &str,
+        to: &str,
+        amount: Coin,
+    ) -> Result<BlockBeforeSendResponse, RunnerError> {
+        self.query(&QueryMsg::BlockBeforeSend {
+            from: from.to_string(),
+            to: to.to_string(),
+            amount,
+        })
+    }
+
+    pub fn query_guardian(&self) -> Result<GuardianResponse, RunnerError> {
+        self.query(&QueryMsg::Guardian {})
+    }
+
     pub fn query_is_frozen(&self) -> Result<IsFrozenResponse, RunnerError> {
         self.query(&QueryMsg::IsFrozen {})
     }
@@ -384,7 +489,10 @@ impl TokenfactoryIssuer {
         self.query(&QueryMsg::Ownership {})
     }
 
-    pub fn query_mint_allowance(&self, address: &str) -> Result<AllowanceResponse, RunnerError> {
+    pub fn query_mint_allowance(
+        &self,
+        address: &str,
+    ) -> Result<MintAllowanceResponse, RunnerError> {
         self.query(&QueryMsg::MintAllowance {
             address: address.to_string(),
         })
@@ -17,7 +17,7 @@ use cw_utils::{must_pay, nonpayable};
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, ReceiveMsg};
 use crate::state::{PAYMENT, UNBONDING_DURATION_SECONDS};
-use crate::vesting::{Status, VestInit};
+use crate::vesting::{Schedule, Status, VestInit};
 
 const CONTRACT_NAME: &str = "crates.io:cw-vesting";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -36,7 +36,11 @@ pub fn instantiate(
     let recipient = deps.api.addr_validate(&msg.recipient)?;
     let start_time = msg.start_time.unwrap_or(env.block.time);
 
-    if start_time.plus_seconds(msg.vesting_duration_seconds) <= env.block.time {
+    if let Schedule::Milestones(_) = msg.schedule {
+        if msg.owner.is_none() {
+            return Err(ContractError::MilestonesWithoutOwner);
+        }
+    } else if start_time.plus_seconds(msg.vesting_duration_seconds) <= env.block.time {
         return Err(ContractError::Instavest);
     }
 
@@ -110,6 +114,7 @@ pub fn execute(
         ExecuteMsg::WithdrawCanceledPayment { amount } => {
             execute_withdraw_canceled_payment(deps, env, amount)
         }
+        ExecuteMsg::UnlockMilestone { id } => execute_unlock_milestone(deps, env, info, id),
         ExecuteMsg::UpdateOwnership(action) => execute_update_owner(deps, info, env, action),
         #[cfg(feature = "staking")]
         ExecuteMsg::Delegate { validator, amount } => {
@@ -223,18 +228,41 @@ pub fn execute_withdraw_canceled_payment(
         .add_message(msg))
 }
 
+pub fn execute_unlock_milestone(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let amount = PAYMENT.unlock_milestone(deps.storage, env.block.time, id)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "unlock_milestone")
+        .add_attribute("id", id.to_string())
+        .add_attribute("amount", amount))
+}
+
 pub fn execute_update_owner(
     deps: DepsMut,
     info: MessageInfo,
     env: Env,
     action: cw_ownable::Action,
 ) -> Result<Response, ContractError> {
-    if let Status::Canceled { owner_withdrawable } = PAYMENT.get_vest(deps.storage)?.status {
+    let vest = PAYMENT.get_vest(deps.storage)?;
+    if let Status::Canceled { owner_withdrawable } = vest.status {
         if action == cw_ownable::Action::RenounceOwnership && !owner_withdrawable.is_zero() {
             // Ownership cannot be removed if there are withdrawable
             // funds as this would lock those funds in the contract.
             return Err(ContractError::Cancelled);
         }
+    } else if action == cw_ownable::Action::RenounceOwnership
+        && vest.milestones.iter().any(|m| m.unlocked.is_none())
+    {
+        // Only the owner may unlock milestones, so renouncing
+        // ownership would lock their tranches in the contract.
+        return Err(ContractError::LockedMilestones);
     }
     let ownership = cw_ownable::update_ownership(deps, &env.block, &info.sender, action)?;
     Ok(Response::default().add_attributes(ownership.into_attributes()))
@@ -478,6 +506,11 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 .get_vest(deps.storage)?
                 .vested(t.unwrap_or(env.block.time)),
         ),
+        QueryMsg::NextUnlock { t } => to_json_binary(
+            &PAYMENT
+                .get_vest(deps.storage)?
+                .next_unlock(t.unwrap_or(env.block.time)),
+        ),
         QueryMsg::TotalToVest {} => to_json_binary(&PAYMENT.get_vest(deps.storage)?.total()),
         QueryMsg::VestDuration {} => to_json_binary(&PAYMENT.duration(deps.storage)?),
     }
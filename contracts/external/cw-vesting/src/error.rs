@@ -40,6 +40,35 @@ pub enum ContractError {
     #[error("can not vest a constant amount, specifiy two or more points")]
     ConstantVest,
 
+    #[error(
+        "cliff must be > 1 and <= the vesting duration ({duration_seconds}). got ({cliff_seconds})"
+    )]
+    InvalidCliff {
+        cliff_seconds: u64,
+        duration_seconds: u64,
+    },
+
+    #[error("vesting period must be > 1 and <= the vesting duration ({duration_seconds}). got ({period_seconds})")]
+    InvalidPeriod {
+        period_seconds: u64,
+        duration_seconds: u64,
+    },
+
+    #[error("periodic vest has ({periods}) periods, more than the maximum ({max})")]
+    TooManyPeriods { periods: u64, max: u64 },
+
+    #[error("milestone vests must have an owner to unlock them")]
+    MilestonesWithoutOwner,
+
+    #[error("no milestone with id ({id})")]
+    NoSuchMilestone { id: u64 },
+
+    #[error("milestone ({id}) has already been unlocked")]
+    MilestoneUnlocked { id: u64 },
+
+    #[error("ownership can not be renounced while milestones are locked")]
+    LockedMilestones,
+
     #[error("payment is cancelled")]
     Cancelled,
 
@@ -5,7 +5,7 @@ use cw_denom::UncheckedDenom;
 use cw_ownable::cw_ownable_execute;
 use cw_stake_tracker::StakeTrackerQuery;
 
-use crate::vesting::Schedule;
+use crate::vesting::{Schedule, UnlockEvent};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -27,8 +27,10 @@ pub struct InstantiateMsg {
     pub denom: UncheckedDenom,
 
     /// The vesting schedule, can be either `SaturatingLinear` vesting
-    /// (which vests evenly over time), or `PiecewiseLinear` which can
-    /// represent a more complicated vesting schedule.
+    /// (which vests evenly over time), `PiecewiseLinear` which can
+    /// represent a more complicated vesting schedule, `CliffLinear`
+    /// or `Periodic` for cliffs and step unlocks, or `Milestones`
+    /// whose tranches are unlocked by the owner.
     pub schedule: Schedule,
     /// The time to start vesting, or None to start vesting when the
     /// contract is instantiated. `start_time` may be in the past,
@@ -45,6 +47,8 @@ pub struct InstantiateMsg {
     ///
     /// See `suite_tests/tests.rs`
     /// `test_almost_instavest_in_the_future` for an example of this.
+    ///
+    /// Ignored by `Milestones` schedules.
     pub vesting_duration_seconds: u64,
 
     /// The unbonding duration for the chain this contract is deployed
@@ -147,6 +151,10 @@ pub enum ExecuteMsg {
         /// The validator to claim rewards for.
         validator: String,
     },
+    /// Unlocks a milestone of a `Milestones` vest, vesting its
+    /// tranche. `id` is the milestone's index in the schedule. Only
+    /// callable by the owner.
+    UnlockMilestone { id: u64 },
     /// If the owner cancels a payment and there are not enough liquid
     /// tokens to settle the owner may become entitled to some number
     /// of staked tokens. They may then unbond those tokens and then
@@ -217,6 +225,11 @@ pub enum QueryMsg {
     /// current time is used.
     #[returns(::cosmwasm_std::Uint128)]
     Vested { t: Option<Timestamp> },
+    /// Gets the next time after `t` that more tokens will vest, or
+    /// `None` if nothing more will vest. If `t` is `None`, the
+    /// current time is used.
+    #[returns(Option<UnlockEvent>)]
+    NextUnlock { t: Option<Timestamp> },
     /// Gets the total amount that will ever vest, `max(vested(t))`.
     ///
     /// Note that if the contract is canceled at time c, this value
@@ -225,7 +238,8 @@ pub enum QueryMsg {
     #[returns(::cosmwasm_std::Uint128)]
     TotalToVest {},
     /// Gets the amount of time between the vest starting, and it
-    /// completing. Returns `None` if the vest has been cancelled.
+    /// completing. Returns `None` if the vest has been cancelled, or
+    /// has a `Milestones` schedule.
     #[returns(Option<::cosmwasm_std::Uint64>)]
     VestDuration {},
     /// Queries information about the contract's understanding of it's
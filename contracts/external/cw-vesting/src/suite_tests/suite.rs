@@ -6,7 +6,7 @@ use dao_testing::contracts::cw_vesting_contract;
 
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
-    vesting::{Schedule, Vest},
+    vesting::{Schedule, UnlockEvent, Vest},
     StakeTrackerQuery,
 };
 
@@ -202,6 +202,17 @@ impl Suite {
             .map(|_| ())
     }
 
+    pub fn unlock_milestone<S: Into<String>>(&mut self, sender: S, id: u64) -> anyhow::Result<()> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.vesting.clone(),
+                &ExecuteMsg::UnlockMilestone { id },
+                &[],
+            )
+            .map(|_| ())
+    }
+
     pub fn delegate(&mut self, amount: Uint128) -> anyhow::Result<()> {
         self.app
             .execute_contract(
@@ -381,6 +392,13 @@ impl Suite {
             .unwrap()
     }
 
+    pub fn query_next_unlock(&self, t: Option<Timestamp>) -> Option<UnlockEvent> {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.vesting, &QueryMsg::NextUnlock { t })
+            .unwrap()
+    }
+
     pub fn query_total_to_vest(&self) -> Uint128 {
         self.app
             .wrap()
@@ -3,7 +3,7 @@ use cw_multi_test::App;
 use cw_ownable::OwnershipError;
 
 use crate::{
-    vesting::{Schedule, Status},
+    vesting::{MilestoneInit, Schedule, Status, UnlockEvent},
     ContractError,
 };
 
@@ -680,3 +680,109 @@ fn test_piecewise_and_queries() {
     let duration = suite.query_duration();
     assert_eq!(duration, None);
 }
+
+/// Periodic vests unlock in tranches, and report the next one.
+#[test]
+fn test_periodic_next_unlock() {
+    let mut suite = SuiteBuilder::default()
+        .with_curve(Schedule::Periodic {
+            period_seconds: 60 * 60 * 24,
+            cliff_seconds: Some(60 * 60 * 24 * 2),
+        })
+        .build();
+    let start = suite.time();
+
+    assert_eq!(
+        suite.query_next_unlock(None),
+        Some(UnlockEvent::Time {
+            time: start.plus_seconds(60 * 60 * 24 * 2),
+            amount: Uint128::new(28_571_428)
+        })
+    );
+
+    suite.a_day_passes();
+    assert_eq!(suite.query_distributable(), Uint128::zero());
+
+    suite.a_day_passes();
+    assert_eq!(suite.query_distributable(), Uint128::new(28_571_428));
+    assert_eq!(
+        suite.query_next_unlock(None),
+        Some(UnlockEvent::Time {
+            time: start.plus_seconds(60 * 60 * 24 * 3),
+            amount: Uint128::new(14_285_714)
+        })
+    );
+
+    suite.a_week_passes();
+    assert_eq!(suite.query_vested(None), suite.total);
+    assert_eq!(suite.query_next_unlock(None), None);
+}
+
+/// Milestone tranches vest when the owner unlocks them.
+#[test]
+fn test_milestones() {
+    let mut suite = SuiteBuilder::default()
+        .with_curve(Schedule::Milestones(vec![
+            MilestoneInit {
+                description: "testnet".to_string(),
+                amount: Uint128::new(40_000_000),
+            },
+            MilestoneInit {
+                description: "mainnet".to_string(),
+                amount: Uint128::new(60_000_000),
+            },
+        ]))
+        .build();
+
+    assert_eq!(suite.query_duration(), None);
+    assert_eq!(
+        suite.query_next_unlock(None),
+        Some(UnlockEvent::Milestone {
+            id: 0,
+            amount: Uint128::new(40_000_000)
+        })
+    );
+
+    // Time passing vests nothing.
+    suite.a_week_passes();
+    assert_eq!(suite.query_vested(None), Uint128::zero());
+
+    let res = suite.unlock_milestone(suite.receiver.clone(), 0);
+    is_error!(res, "Caller is not the contract's current owner");
+
+    suite.unlock_milestone("owner", 0).unwrap();
+    let res = suite.unlock_milestone("owner", 0);
+    is_error!(res, "milestone (0) has already been unlocked");
+
+    assert_eq!(suite.query_distributable(), Uint128::new(40_000_000));
+    assert_eq!(
+        suite.query_next_unlock(None),
+        Some(UnlockEvent::Milestone {
+            id: 1,
+            amount: Uint128::new(60_000_000)
+        })
+    );
+    suite.distribute(suite.receiver.clone(), None).unwrap();
+    assert_eq!(
+        suite.query_receiver_vesting_token_balance(),
+        Uint128::new(40_000_000)
+    );
+
+    suite.unlock_milestone("owner", 1).unwrap();
+    assert_eq!(suite.query_vested(None), suite.total);
+    assert_eq!(suite.query_next_unlock(None), None);
+    let vest = suite.query_vest();
+    assert_eq!(vest.milestones[1].unlocked, Some(suite.time()));
+}
+
+#[test]
+#[should_panic(expected = "milestone vests must have an owner to unlock them")]
+fn test_milestones_require_owner() {
+    let mut builder =
+        SuiteBuilder::default().with_curve(Schedule::Milestones(vec![MilestoneInit {
+            description: "launch".to_string(),
+            amount: Uint128::new(100_000_000),
+        }]));
+    builder.instantiate.owner = None;
+    builder.build();
+}
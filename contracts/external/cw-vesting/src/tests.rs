@@ -11,7 +11,7 @@ use dao_testing::contracts::cw20_base_contract;
 use crate::contract::{execute, execute_receive_cw20};
 use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, ReceiveMsg};
 use crate::state::PAYMENT;
-use crate::vesting::{Schedule, Status, Vest, VestInit};
+use crate::vesting::{MilestoneInit, Schedule, Status, Vest, VestInit};
 use crate::ContractError;
 
 const ALICE: &str = "alice";
@@ -704,6 +704,58 @@ fn test_update_owner() {
     assert_eq!(err, ContractError::Cancelled);
 }
 
+/// Ownership can not be renounced while there are milestones for the
+/// owner to unlock.
+#[test]
+fn test_renounce_with_locked_milestones() {
+    let env = mock_env;
+    let mut deps = mock_dependencies();
+    PAYMENT
+        .initialize(
+            deps.as_mut().storage,
+            VestInit {
+                total: Uint128::new(100),
+                schedule: Schedule::Milestones(vec![MilestoneInit {
+                    description: "launch".to_string(),
+                    amount: Uint128::new(100),
+                }]),
+                start_time: env().block.time,
+                duration_seconds: 0,
+                denom: CheckedDenom::Cw20(Addr::unchecked("cw20")),
+                recipient: Addr::unchecked("recipient"),
+                title: "title".to_string(),
+                description: None,
+            },
+        )
+        .unwrap();
+    let d = deps.as_mut();
+    cw_ownable::initialize_owner(d.storage, d.api, Some("owner")).unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        env(),
+        mock_info("owner", &[]),
+        ExecuteMsg::UpdateOwnership(Action::RenounceOwnership),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::LockedMilestones);
+
+    execute(
+        deps.as_mut(),
+        env(),
+        mock_info("owner", &[]),
+        ExecuteMsg::UnlockMilestone { id: 0 },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env(),
+        mock_info("owner", &[]),
+        ExecuteMsg::UpdateOwnership(Action::RenounceOwnership),
+    )
+    .unwrap();
+}
+
 #[test]
 #[should_panic(expected = "can not vest a constant amount, specifiy two or more points")]
 fn test_constant_piecewise_not_allowed() {
@@ -3,7 +3,9 @@ use std::cmp::min;
 use cosmwasm_schema::cw_serde;
 #[cfg(feature = "staking")]
 use cosmwasm_std::DistributionMsg;
-use cosmwasm_std::{Addr, Binary, CosmosMsg, StdResult, Storage, Timestamp, Uint128, Uint64};
+use cosmwasm_std::{
+    Addr, Binary, CosmosMsg, StdError, StdResult, Storage, Timestamp, Uint128, Uint64,
+};
 use cw_denom::CheckedDenom;
 use cw_storage_plus::Item;
 use wynd_utils::{Curve, PiecewiseLinear, SaturatingLinear};
@@ -36,6 +38,39 @@ pub struct Vest {
 
     pub title: String,
     pub description: Option<String>,
+
+    /// The tranches of a `Milestones` vest. Empty for vests with any
+    /// other schedule.
+    #[serde(default)]
+    pub milestones: Vec<Milestone>,
+}
+
+#[cw_serde]
+pub struct Milestone {
+    pub description: String,
+    pub amount: Uint128,
+    /// The time the owner unlocked this milestone, or `None` if it
+    /// is still locked.
+    pub unlocked: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct MilestoneInit {
+    /// A description of the work that unlocks this tranche.
+    pub description: String,
+    /// The number of tokens that vest when this milestone is
+    /// unlocked.
+    pub amount: Uint128,
+}
+
+/// The next time the amount vested will increase.
+#[cw_serde]
+pub enum UnlockEvent {
+    /// `amount` more tokens will have vested at `time`.
+    Time { time: Timestamp, amount: Uint128 },
+    /// `amount` more tokens will vest when the owner unlocks
+    /// milestone `id`.
+    Milestone { id: u64, amount: Uint128 },
 }
 
 #[cw_serde]
@@ -67,8 +102,33 @@ pub enum Schedule {
     ///
     /// <https://github.com/cosmorama/wynddao/pull/4>
     PiecewiseLinear(Vec<(u64, Uint128)>),
+    /// Nothing vests until `cliff_seconds` have passed. At the cliff,
+    /// the amount that would have vested linearally so far vests at
+    /// once, and the rest vests linearally until the vest
+    /// completes. `cliff_seconds` must be > 1 and no later than the
+    /// end of the vest.
+    CliffLinear { cliff_seconds: u64 },
+    /// Vests in equal tranches at the end of every `period_seconds`,
+    /// for example monthly or quarterly. If the vest's duration is
+    /// not a multiple of the period, the last tranche is smaller.
+    ///
+    /// If `cliff_seconds` is set, nothing vests until the cliff, at
+    /// which point all tranches up to the cliff vest at once.
+    Periodic {
+        period_seconds: u64,
+        cliff_seconds: Option<u64>,
+    },
+    /// Vests in tranches that the owner unlocks explicitly with
+    /// `UnlockMilestone`. The tranche amounts must sum to the total
+    /// vesting amount. The vest's duration is unused, and an owner
+    /// must be set.
+    Milestones(Vec<MilestoneInit>),
 }
 
+/// The maximum number of tranches in a `Periodic` schedule. Each
+/// tranche adds two points to the stored vesting curve.
+pub const MAX_PERIODS: u64 = 256;
+
 pub struct VestInit {
     pub total: Uint128,
     pub schedule: Schedule,
@@ -392,6 +452,31 @@ impl<'a> Payment<'a> {
         }
     }
 
+    /// Unlocks milestone `id` of a `Milestones` vest at time `t`,
+    /// vesting its tranche. Returns the number of tokens unlocked.
+    pub fn unlock_milestone(
+        &self,
+        storage: &mut dyn Storage,
+        t: Timestamp,
+        id: u64,
+    ) -> Result<Uint128, ContractError> {
+        let mut vest = self.vesting.load(storage)?;
+        if matches!(vest.status, Status::Canceled { .. }) {
+            return Err(ContractError::Cancelled);
+        }
+        let milestone = vest
+            .milestones
+            .get_mut(id as usize)
+            .ok_or(ContractError::NoSuchMilestone { id })?;
+        if milestone.unlocked.is_some() {
+            return Err(ContractError::MilestoneUnlocked { id });
+        }
+        milestone.unlocked = Some(t);
+        let amount = milestone.amount;
+        self.vesting.save(storage, &vest)?;
+        Ok(amount)
+    }
+
     /// Passes a query through to the vest's stake tracker which has
     /// information about bonded and unbonding token balances.
     pub fn query_stake(&self, storage: &dyn Storage, q: StakeTrackerQuery) -> StdResult<Binary> {
@@ -407,9 +492,13 @@ impl<'a> Payment<'a> {
 
 impl Vest {
     pub fn new(init: VestInit) -> Result<Self, ContractError> {
+        let milestones = match init.schedule {
+            Schedule::Milestones(ref milestones) => Schedule::milestones(milestones, init.total)?,
+            _ => vec![],
+        };
         if init.total.is_zero() {
             Err(ContractError::ZeroVest)
-        } else if init.duration_seconds == 0 {
+        } else if init.duration_seconds == 0 && milestones.is_empty() {
             Err(ContractError::Instavest)
         } else {
             Ok(Self {
@@ -424,6 +513,7 @@ impl Vest {
                 status: Status::Unfunded,
                 title: init.title,
                 description: init.description,
+                milestones,
             })
         }
     }
@@ -431,25 +521,82 @@ impl Vest {
     /// Gets the total number of tokens that will vest as part of this
     /// payment.
     pub fn total(&self) -> Uint128 {
-        Uint128::new(self.vested.range().1)
+        self.milestones
+            .iter()
+            .fold(Uint128::new(self.vested.range().1), |total, m| {
+                total + m.amount
+            })
     }
 
     /// Gets the number of tokens that have vested at `time`.
     pub fn vested(&self, t: Timestamp) -> Uint128 {
         let elapsed = t.seconds().saturating_sub(self.start_time.seconds());
-        self.vested.value(elapsed)
+        self.milestones
+            .iter()
+            .filter(|m| m.unlocked.map_or(false, |unlocked| unlocked <= t))
+            .fold(self.vested.value(elapsed), |vested, m| vested + m.amount)
+    }
+
+    /// Gets the next time the amount vested will increase after `t`,
+    /// or `None` if nothing more will vest. For linear schedules this
+    /// is the next second at which the amount vested changes.
+    pub fn next_unlock(&self, t: Timestamp) -> Option<UnlockEvent> {
+        if let Some((id, m)) = self
+            .milestones
+            .iter()
+            .enumerate()
+            .find(|(_, m)| m.unlocked.is_none())
+        {
+            return Some(UnlockEvent::Milestone {
+                id: id as u64,
+                amount: m.amount,
+            });
+        }
+
+        let end = match &self.vested {
+            Curve::Constant { .. } => return None,
+            Curve::SaturatingLinear(SaturatingLinear { max_x, .. }) => *max_x,
+            Curve::PiecewiseLinear(PiecewiseLinear { steps }) => steps[steps.len() - 1].0,
+        };
+        let elapsed = t.seconds().saturating_sub(self.start_time.seconds());
+        let current = self.vested.value(elapsed);
+        if elapsed >= end || self.vested.value(end) <= current {
+            return None;
+        }
+
+        // The curve never decreases, so binary search for the first
+        // second after `elapsed` with a larger value.
+        let (mut lo, mut hi) = (elapsed + 1, end);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.vested.value(mid) > current {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        Some(UnlockEvent::Time {
+            time: self.start_time.plus_seconds(lo),
+            amount: self.vested.value(lo) - current,
+        })
     }
 
-    /// Cancels the current vest. No additional tokens will vest after `t`.
+    /// Cancels the current vest. No additional tokens will vest after
+    /// `t`, and milestones that have not been unlocked are removed.
     pub fn cancel(&mut self, t: Timestamp, owner_withdrawable: Uint128) {
         debug_assert!(!matches!(self.status, Status::Canceled { .. }));
 
+        let elapsed = t.seconds().saturating_sub(self.start_time.seconds());
         self.status = Status::Canceled { owner_withdrawable };
-        self.vested = Curve::Constant { y: self.vested(t) };
+        self.vested = Curve::Constant {
+            y: self.vested.value(elapsed),
+        };
+        self.milestones
+            .retain(|m| m.unlocked.map_or(false, |unlocked| unlocked <= t));
     }
 
-    /// Gets the duration of the vest. For constant curves, `None` is
-    /// returned.
+    /// Gets the duration of the vest. For constant curves, which
+    /// includes cancelled and `Milestones` vests, `None` is returned.
     pub fn duration(&self) -> Option<Uint64> {
         let (start, end) = match &self.vested {
             Curve::Constant { .. } => return None,
@@ -473,6 +620,10 @@ impl Schedule {
     /// Piecewise curves must have at least two steps. One step would
     /// be a constant vest (why would you want this?).
     ///
+    /// `Milestones` schedules vest nothing over time, so their curve
+    /// is constantly zero. Their tranches are validated by
+    /// `Schedule::milestones`.
+    ///
     /// A schedule is valid if `total` is zero: nothing will ever be
     /// paid out. Consumers should consider validating that `total` is
     /// non-zero.
@@ -487,6 +638,28 @@ impl Schedule {
                 }
                 Curve::PiecewiseLinear(wynd_utils::PiecewiseLinear { steps })
             }
+            Schedule::CliffLinear { cliff_seconds } => {
+                check_cliff(cliff_seconds, duration_seconds)?;
+                let mut steps = vec![(1, Uint128::zero())];
+                if cliff_seconds > 2 {
+                    steps.push((cliff_seconds - 1, Uint128::zero()));
+                }
+                steps.push((
+                    cliff_seconds,
+                    total.multiply_ratio(cliff_seconds, duration_seconds),
+                ));
+                if cliff_seconds < duration_seconds {
+                    steps.push((duration_seconds, total));
+                }
+                Curve::PiecewiseLinear(wynd_utils::PiecewiseLinear { steps })
+            }
+            Schedule::Periodic {
+                period_seconds,
+                cliff_seconds,
+            } => Curve::PiecewiseLinear(wynd_utils::PiecewiseLinear {
+                steps: periodic_steps(total, duration_seconds, period_seconds, cliff_seconds)?,
+            }),
+            Schedule::Milestones(_) => return Ok(Curve::Constant { y: Uint128::zero() }),
         };
         c.validate_monotonic_increasing()?; // => max >= curve(t) \forall t
         let range = c.range();
@@ -498,4 +671,116 @@ impl Schedule {
         }
         Ok(c)
     }
+
+    /// Validates the tranches of a `Milestones` schedule, which must
+    /// be non-empty, each vest a non-zero amount, and sum to `total`.
+    pub fn milestones(
+        milestones: &[MilestoneInit],
+        total: Uint128,
+    ) -> Result<Vec<Milestone>, ContractError> {
+        if milestones.is_empty() {
+            return Err(ContractError::ConstantVest);
+        }
+        let mut sum = Uint128::zero();
+        for m in milestones {
+            if m.amount.is_zero() {
+                return Err(ContractError::ZeroVest);
+            }
+            sum = sum.checked_add(m.amount).map_err(StdError::from)?;
+        }
+        if sum != total {
+            return Err(ContractError::VestRange {
+                min: Uint128::zero(),
+                max: sum,
+            });
+        }
+        Ok(milestones
+            .iter()
+            .map(|m| Milestone {
+                description: m.description.clone(),
+                amount: m.amount,
+                unlocked: None,
+            })
+            .collect())
+    }
+}
+
+/// Cliffs are represented by a jump between two adjacent points on
+/// the vesting curve, the first of which is at `cliff_seconds - 1`.
+/// Curves start at one second rather than zero (see
+/// `Schedule::PiecewiseLinear`), so that point must be >= 1.
+fn check_cliff(cliff_seconds: u64, duration_seconds: u64) -> Result<(), ContractError> {
+    if cliff_seconds < 2 || cliff_seconds > duration_seconds {
+        Err(ContractError::InvalidCliff {
+            cliff_seconds,
+            duration_seconds,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Builds the points of a periodic vesting curve, starting at one
+/// second. Each tranche is a jump between a point one second before
+/// the end of its period and a point at the end of it.
+fn periodic_steps(
+    total: Uint128,
+    duration_seconds: u64,
+    period_seconds: u64,
+    cliff_seconds: Option<u64>,
+) -> Result<Vec<(u64, Uint128)>, ContractError> {
+    if period_seconds < 2 || period_seconds > duration_seconds {
+        return Err(ContractError::InvalidPeriod {
+            period_seconds,
+            duration_seconds,
+        });
+    }
+    let periods = (duration_seconds + period_seconds - 1) / period_seconds;
+    if periods > MAX_PERIODS {
+        return Err(ContractError::TooManyPeriods {
+            periods,
+            max: MAX_PERIODS,
+        });
+    }
+    let cliff = match cliff_seconds {
+        Some(cliff_seconds) => {
+            check_cliff(cliff_seconds, duration_seconds)?;
+            cliff_seconds
+        }
+        None => 0,
+    };
+
+    // The amount vested `t` seconds after the start, ignoring the
+    // cliff.
+    let vested = |t: u64| {
+        if t >= duration_seconds {
+            total
+        } else {
+            total.multiply_ratio(t - t % period_seconds, duration_seconds)
+        }
+    };
+
+    let mut unlocks: Vec<u64> = (1..periods)
+        .map(|p| p * period_seconds)
+        .filter(|t| *t > cliff)
+        .collect();
+    unlocks.push(duration_seconds);
+    if cliff != 0 && cliff != duration_seconds {
+        unlocks.insert(0, cliff);
+    }
+
+    let mut steps: Vec<(u64, Uint128)> = Vec::with_capacity(unlocks.len() * 2 + 1);
+    steps.push((1, Uint128::zero()));
+    for t in unlocks {
+        let before = if t <= cliff {
+            Uint128::zero()
+        } else {
+            vested(t - 1)
+        };
+        if steps[steps.len() - 1].0 != t - 1 {
+            steps.push((t - 1, before));
+        }
+        steps.push((t, vested(t)));
+    }
+    Ok(steps)
 }
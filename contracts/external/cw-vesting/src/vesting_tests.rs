@@ -1,10 +1,10 @@
-use cosmwasm_std::{testing::mock_dependencies, Addr, Timestamp, Uint128};
+use cosmwasm_std::{testing::mock_dependencies, Addr, Timestamp, Uint128, Uint64};
 use cw_denom::CheckedDenom;
 use wynd_utils::CurveError;
 
 use crate::{
     error::ContractError,
-    vesting::{Payment, Schedule, Status, Vest, VestInit},
+    vesting::{MilestoneInit, Payment, Schedule, Status, UnlockEvent, Vest, VestInit, MAX_PERIODS},
 };
 
 #[cfg(test)]
@@ -365,3 +365,310 @@ fn test_redelegate_should_increase_cardinality() {
                    // undelegation so this should not cause an
                    // overflow when we remove stake.
 }
+
+#[test]
+fn test_cliff_linear() {
+    let vest = Vest::new(VestInit {
+        schedule: Schedule::CliffLinear { cliff_seconds: 25 },
+        ..Default::default()
+    })
+    .unwrap();
+
+    // Nothing vests before the cliff, then the amount that would
+    // have vested linearally vests at once.
+    assert_eq!(vest.vested(Timestamp::from_seconds(24)), Uint128::zero());
+    assert_eq!(
+        vest.vested(Timestamp::from_seconds(25)),
+        Uint128::new(25_000_000)
+    );
+    assert_eq!(
+        vest.vested(Timestamp::from_seconds(50)),
+        Uint128::new(50_000_000)
+    );
+    assert_eq!(vest.total(), Uint128::new(100_000_000));
+
+    assert_eq!(
+        vest.next_unlock(Timestamp::from_seconds(0)),
+        Some(UnlockEvent::Time {
+            time: Timestamp::from_seconds(25),
+            amount: Uint128::new(25_000_000)
+        })
+    );
+    assert_eq!(
+        vest.next_unlock(Timestamp::from_seconds(25)),
+        Some(UnlockEvent::Time {
+            time: Timestamp::from_seconds(26),
+            amount: Uint128::new(1_000_000)
+        })
+    );
+    assert_eq!(vest.next_unlock(Timestamp::from_seconds(100)), None);
+
+    // A cliff at the end of the vest vests everything at once.
+    let vest = Vest::new(VestInit {
+        schedule: Schedule::CliffLinear { cliff_seconds: 100 },
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(vest.vested(Timestamp::from_seconds(99)), Uint128::zero());
+    assert_eq!(vest.vested(Timestamp::from_seconds(100)), vest.total());
+}
+
+#[test]
+fn test_periodic() {
+    let init = || VestInit {
+        total: Uint128::new(120),
+        schedule: Schedule::Periodic {
+            period_seconds: 30,
+            cliff_seconds: None,
+        },
+        duration_seconds: 360,
+        ..Default::default()
+    };
+
+    let vest = Vest::new(init()).unwrap();
+    for (t, vested) in [
+        (29, 0),
+        (30, 10),
+        (59, 10),
+        (60, 20),
+        (359, 110),
+        (360, 120),
+    ] {
+        assert_eq!(
+            vest.vested(Timestamp::from_seconds(t)),
+            Uint128::new(vested)
+        );
+    }
+    assert_eq!(
+        vest.next_unlock(Timestamp::from_seconds(45)),
+        Some(UnlockEvent::Time {
+            time: Timestamp::from_seconds(60),
+            amount: Uint128::new(10)
+        })
+    );
+    assert_eq!(vest.duration(), Some(Uint64::new(359)));
+
+    // With a cliff, the tranches before it vest at the cliff.
+    let vest = Vest::new(VestInit {
+        schedule: Schedule::Periodic {
+            period_seconds: 30,
+            cliff_seconds: Some(90),
+        },
+        ..init()
+    })
+    .unwrap();
+    for (t, vested) in [(60, 0), (89, 0), (90, 30), (119, 30), (120, 40)] {
+        assert_eq!(
+            vest.vested(Timestamp::from_seconds(t)),
+            Uint128::new(vested)
+        );
+    }
+    assert_eq!(
+        vest.next_unlock(Timestamp::from_seconds(0)),
+        Some(UnlockEvent::Time {
+            time: Timestamp::from_seconds(90),
+            amount: Uint128::new(30)
+        })
+    );
+
+    // The last tranche is shorter if the duration is not a multiple
+    // of the period, and cliffs need not fall on a period.
+    let vest = Vest::new(VestInit {
+        total: Uint128::new(100),
+        schedule: Schedule::Periodic {
+            period_seconds: 30,
+            cliff_seconds: Some(45),
+        },
+        duration_seconds: 100,
+        ..init()
+    })
+    .unwrap();
+    for (t, vested) in [(44, 0), (45, 30), (59, 30), (60, 60), (99, 90), (100, 100)] {
+        assert_eq!(
+            vest.vested(Timestamp::from_seconds(t)),
+            Uint128::new(vested)
+        );
+    }
+    assert_eq!(
+        vest.next_unlock(Timestamp::from_seconds(95)),
+        Some(UnlockEvent::Time {
+            time: Timestamp::from_seconds(100),
+            amount: Uint128::new(10)
+        })
+    );
+
+    // A one second final tranche.
+    let vest = Vest::new(VestInit {
+        total: Uint128::new(61),
+        duration_seconds: 61,
+        ..init()
+    })
+    .unwrap();
+    assert_eq!(vest.vested(Timestamp::from_seconds(60)), Uint128::new(60));
+    assert_eq!(vest.vested(Timestamp::from_seconds(61)), Uint128::new(61));
+}
+
+#[test]
+fn test_schedule_validation() {
+    let err = |schedule| {
+        Vest::new(VestInit {
+            schedule,
+            ..Default::default()
+        })
+        .unwrap_err()
+    };
+
+    assert_eq!(
+        err(Schedule::CliffLinear { cliff_seconds: 1 }),
+        ContractError::InvalidCliff {
+            cliff_seconds: 1,
+            duration_seconds: 100
+        }
+    );
+    assert_eq!(
+        err(Schedule::Periodic {
+            period_seconds: 10,
+            cliff_seconds: Some(101)
+        }),
+        ContractError::InvalidCliff {
+            cliff_seconds: 101,
+            duration_seconds: 100
+        }
+    );
+    assert_eq!(
+        err(Schedule::Periodic {
+            period_seconds: 1,
+            cliff_seconds: None
+        }),
+        ContractError::InvalidPeriod {
+            period_seconds: 1,
+            duration_seconds: 100
+        }
+    );
+    assert_eq!(
+        Vest::new(VestInit {
+            schedule: Schedule::Periodic {
+                period_seconds: 2,
+                cliff_seconds: None
+            },
+            duration_seconds: 1000,
+            ..Default::default()
+        })
+        .unwrap_err(),
+        ContractError::TooManyPeriods {
+            periods: 500,
+            max: MAX_PERIODS
+        }
+    );
+
+    assert_eq!(
+        err(Schedule::Milestones(vec![])),
+        ContractError::ConstantVest
+    );
+    assert_eq!(
+        err(Schedule::Milestones(vec![MilestoneInit {
+            description: "launch".to_string(),
+            amount: Uint128::zero(),
+        }])),
+        ContractError::ZeroVest
+    );
+    assert_eq!(
+        err(Schedule::Milestones(vec![MilestoneInit {
+            description: "launch".to_string(),
+            amount: Uint128::new(10),
+        }])),
+        ContractError::VestRange {
+            min: Uint128::zero(),
+            max: Uint128::new(10)
+        }
+    );
+}
+
+#[test]
+fn test_milestones() {
+    let storage = &mut mock_dependencies().storage;
+    let payment = Payment::new("vesting", "staked", "validator", "cardinality");
+
+    payment
+        .initialize(
+            storage,
+            VestInit {
+                schedule: Schedule::Milestones(vec![
+                    MilestoneInit {
+                        description: "testnet".to_string(),
+                        amount: Uint128::new(40_000_000),
+                    },
+                    MilestoneInit {
+                        description: "mainnet".to_string(),
+                        amount: Uint128::new(60_000_000),
+                    },
+                ]),
+                // Unused by milestone vests.
+                duration_seconds: 0,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    payment.set_funded(storage).unwrap();
+
+    let vest = payment.get_vest(storage).unwrap();
+    assert_eq!(vest.total(), Uint128::new(100_000_000));
+    assert_eq!(vest.duration(), None);
+    assert_eq!(
+        payment
+            .distributable(storage, &vest, Timestamp::from_seconds(50))
+            .unwrap(),
+        Uint128::zero()
+    );
+
+    // Milestones may be unlocked in any order.
+    let unlocked = payment
+        .unlock_milestone(storage, Timestamp::from_seconds(10), 1)
+        .unwrap();
+    assert_eq!(unlocked, Uint128::new(60_000_000));
+    assert_eq!(
+        payment.unlock_milestone(storage, Timestamp::from_seconds(10), 1),
+        Err(ContractError::MilestoneUnlocked { id: 1 })
+    );
+    assert_eq!(
+        payment.unlock_milestone(storage, Timestamp::from_seconds(10), 2),
+        Err(ContractError::NoSuchMilestone { id: 2 })
+    );
+
+    let vest = payment.get_vest(storage).unwrap();
+    assert_eq!(vest.vested(Timestamp::from_seconds(9)), Uint128::zero());
+    assert_eq!(
+        vest.vested(Timestamp::from_seconds(10)),
+        Uint128::new(60_000_000)
+    );
+    assert_eq!(
+        payment
+            .distributable(storage, &vest, Timestamp::from_seconds(10))
+            .unwrap(),
+        Uint128::new(60_000_000)
+    );
+    assert_eq!(
+        vest.next_unlock(Timestamp::from_seconds(10)),
+        Some(UnlockEvent::Milestone {
+            id: 0,
+            amount: Uint128::new(40_000_000)
+        })
+    );
+
+    // Canceling removes locked milestones.
+    payment
+        .cancel(
+            storage,
+            Timestamp::from_seconds(20),
+            &Addr::unchecked("owner"),
+        )
+        .unwrap();
+    let vest = payment.get_vest(storage).unwrap();
+    assert_eq!(vest.total(), Uint128::new(60_000_000));
+    assert_eq!(vest.milestones.len(), 1);
+    assert_eq!(vest.next_unlock(Timestamp::from_seconds(20)), None);
+    assert_eq!(
+        payment.unlock_milestone(storage, Timestamp::from_seconds(30), 0),
+        Err(ContractError::Cancelled)
+    );
+}
@@ -81,7 +81,11 @@ fn test_stake_unstake_new_denom() {
     // Buy tokens off of bonding curve
     cw_abc
         .execute(
-            &cw_abc::msg::ExecuteMsg::Buy {},
+            &cw_abc::msg::ExecuteMsg::Buy {
+                deadline: None,
+                max_price: None,
+                min_out: None,
+            },
             &coins(100000, RESERVE),
             &accounts[0],
         )
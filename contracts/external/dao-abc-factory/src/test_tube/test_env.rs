@@ -159,6 +159,7 @@ impl TestEnvBuilder {
                                         },
                                         entry_fee: Decimal::percent(10u64),
                                     },
+                                    vesting: None,
                                     open: OpenConfig {
                                         entry_fee: Decimal::percent(10u64),
                                         exit_fee: Decimal::percent(10u64),
@@ -270,6 +271,7 @@ impl TestEnvBuilder {
                                             },
                                             entry_fee: Decimal::percent(10u64),
                                         },
+                                        vesting: None,
                                         open: OpenConfig {
                                             entry_fee: Decimal::percent(10u64),
                                             exit_fee: Decimal::percent(10u64),
@@ -311,6 +313,7 @@ impl TestEnvBuilder {
                     allow_revoting: false,
                     only_members_execute: true,
                     close_proposal_on_execution_failure: false,
+                    review_period: None,
                     pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                     veto: None,
                 })
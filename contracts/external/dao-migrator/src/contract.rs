@@ -14,15 +14,17 @@ use dao_interface::{
 
 use crate::{
     error::ContractError,
-    msg::{ExecuteMsg, InstantiateMsg, MigrateV1ToV2, QueryMsg},
-    state::{CORE_ADDR, MODULES_ADDRS, TEST_STATE},
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    state::{CORE_ADDR, MIGRATION_REPORT, MODULES_ADDRS, PENDING_PLAN, TEST_STATE},
     types::{
-        CodeIdPair, MigrationMsgs, MigrationParams, ModulesAddrs, TestState, V1CodeIds, V2CodeIds,
+        CodeIdPair, MigrationMsgs, MigrationParams, MigrationPlan, MigrationReport,
+        ModuleMigrationReport, ModulesAddrs, PendingPlan, StateAssertion, TestState, V1CodeIds,
+        V2CodeIds,
     },
     utils::state_queries::{
         query_proposal_count_v1, query_proposal_count_v2, query_proposal_v1, query_proposal_v2,
-        query_single_voting_power_v1, query_single_voting_power_v2, query_total_voting_power_v1,
-        query_total_voting_power_v2,
+        query_single_voting_power_v1, query_single_voting_power_v2, query_smart_raw,
+        query_total_voting_power_v1, query_total_voting_power_v2,
     },
 };
 
@@ -30,6 +32,7 @@ pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-migrator";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub(crate) const V1_V2_REPLY_ID: u64 = 1;
+pub(crate) const MIGRATION_PLAN_REPLY_ID: u64 = 2;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -45,12 +48,7 @@ pub fn instantiate(
         Response::default().set_data(to_json_binary(&ModuleInstantiateCallback {
             msgs: vec![WasmMsg::Execute {
                 contract_addr: env.contract.address.to_string(),
-                msg: to_json_binary(&MigrateV1ToV2 {
-                    sub_daos: msg.sub_daos,
-                    migration_params: msg.migration_params,
-                    v1_code_ids: msg.v1_code_ids,
-                    v2_code_ids: msg.v2_code_ids,
-                })?,
+                msg: to_json_binary(&msg)?,
                 funds: vec![],
             }
             .into()],
@@ -65,15 +63,18 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    execute_migration_v1_v2(
-        deps,
-        env,
-        info,
-        msg.sub_daos,
-        msg.migration_params,
-        msg.v1_code_ids,
-        msg.v2_code_ids,
-    )
+    match msg {
+        ExecuteMsg::MigrateV1ToV2(msg) => execute_migration_v1_v2(
+            deps,
+            env,
+            info,
+            msg.sub_daos,
+            msg.migration_params,
+            msg.v1_code_ids,
+            msg.v2_code_ids,
+        ),
+        ExecuteMsg::MigrationPlan(plan) => execute_migration_plan(deps, env, info, plan),
+    }
 }
 
 fn execute_migration_v1_v2(
@@ -319,7 +320,10 @@ fn execute_migration_v1_v2(
     let proposal_hook_msg = SubMsg::reply_on_success(
         WasmMsg::Execute {
             contract_addr: info.sender.to_string(),
-            msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook { msgs })?,
+            msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
+                msgs,
+                proposal_id: None,
+            })?,
             funds: vec![],
         },
         V1_V2_REPLY_ID,
@@ -328,9 +332,138 @@ fn execute_migration_v1_v2(
     Ok(Response::default().add_submessage(proposal_hook_msg))
 }
 
+fn execute_migration_plan(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    plan: MigrationPlan,
+) -> Result<Response, ContractError> {
+    if info.sender != CORE_ADDR.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if plan.migrations.is_empty() {
+        return Err(ContractError::EmptyMigrationPlan);
+    }
+
+    let sub_daos: Vec<SubDao> = deps.querier.query_wasm_smart(
+        info.sender.clone(),
+        &dao_interface::msg::QueryMsg::ListSubDaos {
+            start_after: None,
+            limit: None,
+        },
+    )?;
+
+    let mut uniq = HashSet::new();
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    let mut migrations: Vec<ModuleMigrationReport> = vec![];
+
+    // Verify every module and build its migrate msg, in order.
+    for migration in plan.migrations {
+        let address = deps.api.addr_validate(&migration.address)?;
+        if address == env.contract.address {
+            return Err(ContractError::CantMigrateMigrator);
+        }
+        if !uniq.insert(address.clone()) {
+            return Err(ContractError::DuplicateModuleMigration {
+                address: address.into(),
+            });
+        }
+
+        let old_code_id =
+            if let Ok(contract_info) = deps.querier.query_wasm_contract_info(address.clone()) {
+                contract_info.code_id
+            } else {
+                return Err(ContractError::NoContractInfo {
+                    address: address.into(),
+                });
+            };
+
+        if migration
+            .from_code_id
+            .is_some_and(|from_code_id| from_code_id != old_code_id)
+        {
+            return Err(ContractError::CantMigrateModule {
+                code_id: old_code_id,
+            });
+        }
+
+        let migrate_msg: CosmosMsg = WasmMsg::Migrate {
+            contract_addr: address.to_string(),
+            new_code_id: migration.new_code_id,
+            msg: migration.msg,
+        }
+        .into();
+
+        // Modules of a SubDAO are migrated by the SubDAO, which executes
+        // the migration for us as we are its admin.
+        let dao = match migration.sub_dao {
+            Some(sub_dao) => {
+                let sub_dao = deps.api.addr_validate(&sub_dao)?;
+                if !sub_daos.iter().any(|s| s.addr == sub_dao.as_str()) {
+                    return Err(ContractError::SubDaoNotFound {
+                        address: sub_dao.into(),
+                    });
+                }
+
+                msgs.push(
+                    WasmMsg::Execute {
+                        contract_addr: sub_dao.to_string(),
+                        msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteAdminMsgs {
+                            msgs: vec![migrate_msg],
+                        })?,
+                        funds: vec![],
+                    }
+                    .into(),
+                );
+                sub_dao
+            }
+            None => {
+                msgs.push(migrate_msg);
+                info.sender.clone()
+            }
+        };
+
+        migrations.push(ModuleMigrationReport {
+            dao,
+            address,
+            old_code_id,
+            new_code_id: migration.new_code_id,
+        });
+    }
+
+    // Check the state before migration, and save what we need to test
+    // it after migration.
+    let snapshots = query_plan_state(deps.as_ref(), &env, &plan.assertions)?;
+    PENDING_PLAN.save(
+        deps.storage,
+        &PendingPlan {
+            assertions: plan.assertions,
+            snapshots,
+            migrations,
+        },
+    )?;
+
+    let proposal_hook_msg = SubMsg::reply_on_success(
+        WasmMsg::Execute {
+            contract_addr: info.sender.to_string(),
+            msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
+                msgs,
+                proposal_id: None,
+            })?,
+            funds: vec![],
+        },
+        MIGRATION_PLAN_REPLY_ID,
+    );
+
+    Ok(Response::default().add_submessage(proposal_hook_msg))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(_deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {}
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Report {} => to_json_binary(&MIGRATION_REPORT.may_load(deps.storage)?),
+    }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -344,33 +477,59 @@ pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> Result<Response, Contract
             // FINALLY remove the migrator from the core
             // Reason we do it now, is because we first need to test the state
             // and only then delete our module if everything worked out.
-            let remove_msg = WasmMsg::Execute {
-                contract_addr: core_addr.to_string(),
-                msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
-                    msgs: vec![WasmMsg::Execute {
-                        contract_addr: core_addr.to_string(),
-                        msg: to_json_binary(
-                            &dao_interface::msg::ExecuteMsg::UpdateProposalModules {
-                                to_add: vec![],
-                                to_disable: vec![env.contract.address.to_string()],
-                            },
-                        )?,
-                        funds: vec![],
-                    }
-                    .into()],
-                })?,
-                funds: vec![],
-            };
-
             Ok(Response::default()
-                .add_message(remove_msg)
+                .add_message(remove_migrator_msg(&env, &core_addr)?)
                 .add_attribute("action", "migrate")
                 .add_attribute("status", "success"))
         }
+        MIGRATION_PLAN_REPLY_ID => {
+            let core_addr = CORE_ADDR.load(deps.storage)?;
+            let pending = PENDING_PLAN.load(deps.storage)?;
+            // This is called after all the migrations of the plan went
+            // through, if any assertion fails the whole plan is reverted.
+            test_plan_state(deps.as_ref(), &env, &pending)?;
+
+            PENDING_PLAN.remove(deps.storage);
+            MIGRATION_REPORT.save(
+                deps.storage,
+                &MigrationReport {
+                    dao: core_addr.clone(),
+                    height: env.block.height,
+                    time: env.block.time,
+                    migrations: pending.migrations,
+                    assertions_checked: pending.assertions.len() as u32,
+                },
+            )?;
+
+            Ok(Response::default()
+                .add_message(remove_migrator_msg(&env, &core_addr)?)
+                .add_attribute("action", "migrate_plan")
+                .add_attribute("status", "success"))
+        }
         _ => Err(ContractError::UnrecognisedReplyId),
     }
 }
 
+/// Disables the migrator as a proposal module of the DAO.
+fn remove_migrator_msg(env: &Env, core_addr: &Addr) -> StdResult<WasmMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: core_addr.to_string(),
+        msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
+            msgs: vec![WasmMsg::Execute {
+                contract_addr: core_addr.to_string(),
+                msg: to_json_binary(&dao_interface::msg::ExecuteMsg::UpdateProposalModules {
+                    to_add: vec![],
+                    to_disable: vec![env.contract.address.to_string()],
+                })?,
+                funds: vec![],
+            }
+            .into()],
+            proposal_id: None,
+        })?,
+        funds: vec![],
+    })
+}
+
 fn query_state_v1(deps: Deps, module_addrs: ModulesAddrs) -> Result<TestState, ContractError> {
     let proposal_counts = query_proposal_count_v1(deps, module_addrs.proposals.clone())?;
     let (proposals, sample_proposal_data) = query_proposal_v1(deps, module_addrs.proposals)?;
@@ -429,3 +588,96 @@ fn test_state(deps: Deps) -> Result<(), ContractError> {
         Err(ContractError::TestFailed)
     }
 }
+
+/// Checks the `Before` assertions of a plan, and returns the state
+/// checked by the assertions that compare before and after migration.
+fn query_plan_state(
+    deps: Deps,
+    env: &Env,
+    assertions: &[StateAssertion],
+) -> Result<Vec<Option<Binary>>, ContractError> {
+    assertions
+        .iter()
+        .enumerate()
+        .map(|(index, assertion)| match assertion {
+            StateAssertion::Before {
+                contract,
+                query,
+                expected,
+            } => {
+                if query_smart_raw(deps, contract.clone(), query.clone())? == *expected {
+                    Ok(None)
+                } else {
+                    Err(ContractError::AssertionFailed { index })
+                }
+            }
+            StateAssertion::After { .. } => Ok(None),
+            _ => query_unchanged_state(deps, env, assertion),
+        })
+        .collect()
+}
+
+/// Returns the state checked by an assertion that compares before and
+/// after migration.
+fn query_unchanged_state(
+    deps: Deps,
+    env: &Env,
+    assertion: &StateAssertion,
+) -> Result<Option<Binary>, ContractError> {
+    // Snapshotted voting power at the current height doesn't include
+    // the changes made during this block, such as by the migration.
+    let height = env.block.height + 1;
+
+    Ok(Some(match assertion {
+        StateAssertion::Unchanged { contract, query } => {
+            query_smart_raw(deps, contract.clone(), query.clone())?
+        }
+        StateAssertion::ProposalCountUnchanged { module } => {
+            let module = deps.api.addr_validate(module)?;
+            to_json_binary(&query_proposal_count_v2(deps, vec![module])?)?
+        }
+        StateAssertion::TotalPowerUnchanged { module } => {
+            let module = deps.api.addr_validate(module)?;
+            to_json_binary(&query_total_voting_power_v2(deps, module, height)?)?
+        }
+        StateAssertion::VotingPowerUnchanged { module, address } => {
+            let module = deps.api.addr_validate(module)?;
+            let address = deps.api.addr_validate(address)?;
+            to_json_binary(&query_single_voting_power_v2(
+                deps, module, address, height,
+            )?)?
+        }
+        StateAssertion::Before { .. } | StateAssertion::After { .. } => return Ok(None),
+    }))
+}
+
+fn test_plan_state(deps: Deps, env: &Env, pending: &PendingPlan) -> Result<(), ContractError> {
+    pending
+        .assertions
+        .iter()
+        .zip(pending.snapshots.iter())
+        .enumerate()
+        .try_for_each(|(index, (assertion, snapshot))| {
+            let (state, expected) = match (assertion, snapshot) {
+                (
+                    StateAssertion::After {
+                        contract,
+                        query,
+                        expected,
+                    },
+                    _,
+                ) => (
+                    Some(query_smart_raw(deps, contract.clone(), query.clone())?),
+                    expected,
+                ),
+                (_, Some(snapshot)) => (query_unchanged_state(deps, env, assertion)?, snapshot),
+                _ => return Ok(()),
+            };
+
+            if state.as_ref() == Some(expected) {
+                Ok(())
+            } else {
+                Err(ContractError::AssertionFailed { index })
+            }
+        })
+}
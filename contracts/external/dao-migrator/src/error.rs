@@ -45,4 +45,19 @@ pub enum ContractError {
 
     #[error("Proposal migration params length is not equal to proposal modules length")]
     MigrationParamsNotEqualProposalModulesLength,
+
+    #[error("Migration plan has no migrations")]
+    EmptyMigrationPlan,
+
+    #[error("Migration plan migrates module {address} more than once")]
+    DuplicateModuleMigration { address: String },
+
+    #[error("Migrator can't migrate itself")]
+    CantMigrateMigrator,
+
+    #[error("{address} is not a SubDAO of the DAO")]
+    SubDaoNotFound { address: String },
+
+    #[error("State assertion {index} failed")]
+    AssertionFailed { index: usize },
 }
@@ -1,7 +1,7 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use dao_interface::query::SubDao;
 
-use crate::types::{MigrationParams, V1CodeIds, V2CodeIds};
+use crate::types::{MigrationParams, MigrationPlan, MigrationReport, V1CodeIds, V2CodeIds};
 
 #[cw_serde]
 pub struct MigrateV1ToV2 {
@@ -11,10 +11,27 @@ pub struct MigrateV1ToV2 {
     pub v2_code_ids: V2CodeIds,
 }
 
-pub type InstantiateMsg = MigrateV1ToV2;
+/// The migration the migrator runs. The migrator is instantiated with
+/// it and executes it on itself through the DAO's instantiate callback.
+///
+/// This is untagged so `dao-dao-core` can keep instantiating the
+/// migrator with a plain `MigrateV1ToV2` when migrating from v1.
+#[cw_serde]
+#[serde(untagged)]
+pub enum InstantiateMsg {
+    /// Migrates a v1 DAO and its modules to v2.
+    MigrateV1ToV2(MigrateV1ToV2),
+    /// Runs a migration plan for the DAO and its SubDAOs.
+    MigrationPlan(MigrationPlan),
+}
 
-pub type ExecuteMsg = MigrateV1ToV2;
+pub type ExecuteMsg = InstantiateMsg;
 
 #[cw_serde]
 #[derive(QueryResponses)]
-pub enum QueryMsg {}
+pub enum QueryMsg {
+    /// Returns the report of the migration plan this migrator ran, if
+    /// it ran one.
+    #[returns(Option<MigrationReport>)]
+    Report {},
+}
@@ -1,7 +1,7 @@
 use cosmwasm_std::Addr;
 use cw_storage_plus::Item;
 
-use crate::types::{ModulesAddrs, TestState};
+use crate::types::{MigrationReport, ModulesAddrs, PendingPlan, TestState};
 
 /// Holds data about the DAO before migration (so we can test against it after migration)
 pub const TEST_STATE: Item<TestState> = Item::new("test_state");
@@ -9,3 +9,7 @@ pub const TEST_STATE: Item<TestState> = Item::new("test_state");
 pub const MODULES_ADDRS: Item<ModulesAddrs> = Item::new("module_addrs");
 /// Hold the core address to be used in reply
 pub const CORE_ADDR: Item<Addr> = Item::new("core_addr");
+/// Holds the assertions and snapshots of a running migration plan
+pub const PENDING_PLAN: Item<PendingPlan> = Item::new("pending_plan");
+/// The report of the migration plan once it ran successfully
+pub const MIGRATION_REPORT: Item<MigrationReport> = Item::new("migration_report");
@@ -2,3 +2,4 @@ pub mod helpers;
 pub mod setup;
 pub mod state_helpers;
 pub mod test_migration;
+pub mod test_migration_plan;
@@ -7,7 +7,7 @@ use dao_testing::contracts::stake_cw20_v03_contract;
 
 use crate::{
     testing::helpers::get_module_addrs,
-    types::{MigrationParams, ProposalParams, V1CodeIds},
+    types::{MigrationParams, MigrationPlan, ProposalParams, V1CodeIds},
 };
 
 use super::helpers::{
@@ -304,15 +304,17 @@ pub fn execute_migration(
                     msg: to_json_binary(&dao_interface::msg::ExecuteMsg::UpdateProposalModules {
                         to_add: vec![ModuleInstantiateInfo {
                             code_id: migrator_code_id,
-                            msg: to_json_binary(&crate::msg::InstantiateMsg {
-                                sub_daos: params.sub_daos.unwrap(),
-                                migration_params: MigrationParams {
-                                    migrate_stake_cw20_manager: params.migrate_cw20,
-                                    proposal_params,
+                            msg: to_json_binary(&crate::msg::InstantiateMsg::MigrateV1ToV2(
+                                crate::msg::MigrateV1ToV2 {
+                                    sub_daos: params.sub_daos.unwrap(),
+                                    migration_params: MigrationParams {
+                                        migrate_stake_cw20_manager: params.migrate_cw20,
+                                        proposal_params,
+                                    },
+                                    v1_code_ids,
+                                    v2_code_ids,
                                 },
-                                v1_code_ids,
-                                v2_code_ids,
-                            })
+                            ))
                             .unwrap(),
                             admin: Some(Admin::CoreModule {}),
                             funds: vec![],
@@ -452,3 +454,83 @@ pub fn execute_migration_from_core(
         &[],
     )
 }
+
+/// Instantiate a basic DAO with proposal and voting modules, and migrate
+/// it to V2.
+pub fn setup_dao_v2(voting_type: VotingType) -> (App, ModuleAddrs) {
+    let (mut app, module_addrs, v1_code_ids) = setup_dao_v1(voting_type);
+    execute_migration(app.borrow_mut(), &module_addrs, v1_code_ids, None, None).unwrap();
+
+    (app, module_addrs)
+}
+
+/// Pass and execute a proposal on a V2 DAO that adds a migrator module
+/// running the given migration plan.
+pub fn execute_migration_plan(
+    app: &mut App,
+    module_addrs: &ModuleAddrs,
+    plan: MigrationPlan,
+) -> Result<AppResponse, anyhow::Error> {
+    let sender = Addr::unchecked(SENDER_ADDR);
+    let migrator_code_id = app.store_code(migrator_contract());
+
+    app.execute_contract(
+        sender.clone(),
+        module_addrs.proposals[0].clone(),
+        &dao_proposal_single::msg::ExecuteMsg::Propose(
+            dao_voting::proposal::SingleChoiceProposeMsg {
+                title: "t3".to_string(),
+                description: "d3".to_string(),
+                msgs: vec![WasmMsg::Execute {
+                    contract_addr: module_addrs.core.to_string(),
+                    msg: to_json_binary(&dao_interface::msg::ExecuteMsg::UpdateProposalModules {
+                        to_add: vec![ModuleInstantiateInfo {
+                            code_id: migrator_code_id,
+                            msg: to_json_binary(&crate::msg::InstantiateMsg::MigrationPlan(plan))
+                                .unwrap(),
+                            admin: Some(Admin::CoreModule {}),
+                            funds: vec![],
+                            label: "migrator".to_string(),
+                        }],
+                        to_disable: vec![],
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into()],
+                proposer: None,
+                vote: None,
+                schedule: None,
+            },
+        ),
+        &[],
+    )
+    .unwrap();
+
+    let proposal_id: u64 = app
+        .wrap()
+        .query_wasm_smart(
+            module_addrs.proposals[0].clone(),
+            &dao_proposal_single::msg::QueryMsg::ProposalCount {},
+        )
+        .unwrap();
+
+    app.execute_contract(
+        sender.clone(),
+        module_addrs.proposals[0].clone(),
+        &dao_proposal_single::msg::ExecuteMsg::Vote {
+            proposal_id,
+            vote: dao_voting::voting::Vote::Yes,
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        sender,
+        module_addrs.proposals[0].clone(),
+        &dao_proposal_single::msg::ExecuteMsg::Execute { proposal_id },
+        &[],
+    )
+}
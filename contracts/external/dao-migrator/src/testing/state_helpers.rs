@@ -47,6 +47,8 @@ pub fn query_proposal_v1(
         proposer: proposal.proposer,
         start_height: proposal.start_height,
         min_voting_period: proposal.min_voting_period.map(v1_expiration_to_v2),
+        review_period: None,
+        schedule: None,
         expiration: v1_expiration_to_v2(proposal.expiration),
         threshold: v1_threshold_to_v2(proposal.threshold),
         total_power: proposal.total_power,
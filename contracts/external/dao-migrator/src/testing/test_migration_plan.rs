@@ -0,0 +1,274 @@
+use std::borrow::BorrowMut;
+
+use cosmwasm_std::{to_json_binary, Addr, Binary};
+use cw_multi_test::{App, Executor};
+use dao_interface::state::ProposalModuleStatus;
+use dao_voting::status::Status;
+
+use crate::{
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    testing::{
+        helpers::{
+            dao_voting_cw4_contract, migrator_contract, ModuleAddrs, VotingType, SENDER_ADDR,
+        },
+        setup::{execute_migration_plan, setup_dao_v2},
+    },
+    types::{
+        MigrationPlan, MigrationReport, ModuleMigration, ModuleMigrationReport, StateAssertion,
+    },
+    ContractError,
+};
+
+fn code_id(app: &App, addr: &Addr) -> u64 {
+    app.wrap().query_wasm_contract_info(addr).unwrap().code_id
+}
+
+/// A plan migrating the voting module to a freshly stored code ID.
+fn voting_plan(app: &mut App, module_addrs: &ModuleAddrs) -> MigrationPlan {
+    let new_code_id = app.store_code(dao_voting_cw4_contract());
+
+    MigrationPlan {
+        migrations: vec![ModuleMigration {
+            sub_dao: None,
+            address: module_addrs.voting.to_string(),
+            from_code_id: Some(code_id(app, &module_addrs.voting)),
+            new_code_id,
+            msg: to_json_binary(&dao_voting_cw4::msg::MigrateMsg {}).unwrap(),
+        }],
+        assertions: vec![],
+    }
+}
+
+/// Instantiate a migrator as if it was added by the DAO, without
+/// running its instantiate callback.
+fn instantiate_migrator(app: &mut App, module_addrs: &ModuleAddrs, plan: MigrationPlan) -> Addr {
+    let migrator_code_id = app.store_code(migrator_contract());
+    app.instantiate_contract(
+        migrator_code_id,
+        module_addrs.core.clone(),
+        &InstantiateMsg::MigrationPlan(plan),
+        &[],
+        "migrator",
+        None,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_migration_plan() {
+    let (mut app, module_addrs) = setup_dao_v2(VotingType::Cw4);
+    let old_code_id = code_id(&app, &module_addrs.voting);
+
+    let total_power_query =
+        to_json_binary(&dao_interface::voting::Query::TotalPowerAtHeight { height: None }).unwrap();
+    let info: dao_interface::voting::InfoResponse = app
+        .wrap()
+        .query_wasm_smart(&module_addrs.voting, &dao_interface::voting::Query::Info {})
+        .unwrap();
+
+    let mut plan = voting_plan(app.borrow_mut(), &module_addrs);
+    let new_code_id = plan.migrations[0].new_code_id;
+    plan.assertions = vec![
+        StateAssertion::Before {
+            contract: module_addrs.core.to_string(),
+            query: to_json_binary(&dao_interface::msg::QueryMsg::VotingModule {}).unwrap(),
+            expected: to_json_binary(&module_addrs.voting).unwrap(),
+        },
+        StateAssertion::Unchanged {
+            contract: module_addrs.voting.to_string(),
+            query: total_power_query,
+        },
+        StateAssertion::After {
+            contract: module_addrs.voting.to_string(),
+            query: to_json_binary(&dao_interface::voting::Query::Info {}).unwrap(),
+            expected: to_json_binary(&info).unwrap(),
+        },
+        StateAssertion::ProposalCountUnchanged {
+            module: module_addrs.proposals[0].to_string(),
+        },
+        StateAssertion::TotalPowerUnchanged {
+            module: module_addrs.voting.to_string(),
+        },
+        StateAssertion::VotingPowerUnchanged {
+            module: module_addrs.voting.to_string(),
+            address: SENDER_ADDR.to_string(),
+        },
+    ];
+
+    let res = execute_migration_plan(app.borrow_mut(), &module_addrs, plan).unwrap();
+    assert_eq!(code_id(&app, &module_addrs.voting), new_code_id);
+
+    let migrator = res
+        .events
+        .iter()
+        .find(|event| event.ty == "instantiate")
+        .and_then(|event| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == "_contract_address")
+        })
+        .map(|attr| Addr::unchecked(&attr.value))
+        .unwrap();
+
+    let report: Option<MigrationReport> = app
+        .wrap()
+        .query_wasm_smart(&migrator, &QueryMsg::Report {})
+        .unwrap();
+    let block = app.block_info();
+    assert_eq!(
+        report,
+        Some(MigrationReport {
+            dao: module_addrs.core.clone(),
+            height: block.height,
+            time: block.time,
+            migrations: vec![ModuleMigrationReport {
+                dao: module_addrs.core.clone(),
+                address: module_addrs.voting.clone(),
+                old_code_id,
+                new_code_id,
+            }],
+            assertions_checked: 6,
+        })
+    );
+
+    // The migrator removed itself from the DAO.
+    let modules: Vec<dao_interface::state::ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            &module_addrs.core,
+            &dao_interface::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let migrator_module = modules.iter().find(|m| m.address == migrator).unwrap();
+    assert_eq!(migrator_module.status, ProposalModuleStatus::Disabled);
+}
+
+#[test]
+fn test_migration_plan_failed_assertion_reverts() {
+    let (mut app, module_addrs) = setup_dao_v2(VotingType::Cw4);
+    let old_code_id = code_id(&app, &module_addrs.voting);
+
+    let mut plan = voting_plan(app.borrow_mut(), &module_addrs);
+    plan.assertions = vec![StateAssertion::After {
+        contract: module_addrs.voting.to_string(),
+        query: to_json_binary(&dao_interface::voting::Query::Info {}).unwrap(),
+        expected: to_json_binary("not the info").unwrap(),
+    }];
+
+    // The proposal closes on execution failure, so the migration
+    // failing doesn't fail the transaction.
+    execute_migration_plan(app.borrow_mut(), &module_addrs, plan).unwrap();
+
+    let proposal_id: u64 = app
+        .wrap()
+        .query_wasm_smart(
+            &module_addrs.proposals[0],
+            &dao_proposal_single::msg::QueryMsg::ProposalCount {},
+        )
+        .unwrap();
+    let proposal: dao_proposal_single::query::ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &module_addrs.proposals[0],
+            &dao_proposal_single::msg::QueryMsg::Proposal { proposal_id },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.status, Status::ExecutionFailed);
+
+    // Nothing was migrated.
+    assert_eq!(code_id(&app, &module_addrs.voting), old_code_id);
+}
+
+#[test]
+fn test_migration_plan_validation() {
+    let (mut app, module_addrs) = setup_dao_v2(VotingType::Cw4);
+    let plan = voting_plan(app.borrow_mut(), &module_addrs);
+    let migrator = instantiate_migrator(app.borrow_mut(), &module_addrs, plan.clone());
+
+    let execute = |app: &mut App, sender: &Addr, plan: MigrationPlan| {
+        app.execute_contract(
+            sender.clone(),
+            migrator.clone(),
+            &ExecuteMsg::MigrationPlan(plan),
+            &[],
+        )
+        .unwrap_err()
+        .downcast::<ContractError>()
+        .unwrap()
+    };
+
+    // Only the DAO can run the plan.
+    let err = execute(app.borrow_mut(), &Addr::unchecked("random"), plan.clone());
+    assert_eq!(err, ContractError::Unauthorized);
+
+    let err = execute(
+        app.borrow_mut(),
+        &module_addrs.core,
+        MigrationPlan {
+            migrations: vec![],
+            assertions: vec![],
+        },
+    );
+    assert_eq!(err, ContractError::EmptyMigrationPlan);
+
+    let mut duplicate = plan.clone();
+    duplicate.migrations.push(plan.migrations[0].clone());
+    let err = execute(app.borrow_mut(), &module_addrs.core, duplicate);
+    assert_eq!(
+        err,
+        ContractError::DuplicateModuleMigration {
+            address: module_addrs.voting.to_string()
+        }
+    );
+
+    let mut itself = plan.clone();
+    itself.migrations[0].address = migrator.to_string();
+    let err = execute(app.borrow_mut(), &module_addrs.core, itself);
+    assert_eq!(err, ContractError::CantMigrateMigrator);
+
+    let mut wrong_code_id = plan.clone();
+    wrong_code_id.migrations[0].from_code_id = Some(555);
+    let err = execute(app.borrow_mut(), &module_addrs.core, wrong_code_id);
+    assert_eq!(
+        err,
+        ContractError::CantMigrateModule {
+            code_id: code_id(&app, &module_addrs.voting)
+        }
+    );
+
+    let mut not_sub_dao = plan.clone();
+    not_sub_dao.migrations[0].sub_dao = Some("sub_dao_1".to_string());
+    let err = execute(app.borrow_mut(), &module_addrs.core, not_sub_dao);
+    assert_eq!(
+        err,
+        ContractError::SubDaoNotFound {
+            address: "sub_dao_1".to_string()
+        }
+    );
+
+    let mut failing_before = plan;
+    failing_before.assertions = vec![
+        StateAssertion::After {
+            contract: module_addrs.voting.to_string(),
+            query: to_json_binary(&dao_interface::voting::Query::Info {}).unwrap(),
+            expected: Binary::default(),
+        },
+        StateAssertion::Before {
+            contract: module_addrs.core.to_string(),
+            query: to_json_binary(&dao_interface::msg::QueryMsg::VotingModule {}).unwrap(),
+            expected: to_json_binary(&module_addrs.core).unwrap(),
+        },
+    ];
+    let err = execute(app.borrow_mut(), &module_addrs.core, failing_before);
+    assert_eq!(err, ContractError::AssertionFailed { index: 1 });
+
+    let report: Option<MigrationReport> = app
+        .wrap()
+        .query_wasm_smart(&migrator, &QueryMsg::Report {})
+        .unwrap();
+    assert_eq!(report, None);
+}
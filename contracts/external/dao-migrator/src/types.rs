@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Binary, Timestamp, Uint128};
 use dao_voting::veto::VetoConfig;
 
 use crate::ContractError;
@@ -115,6 +115,110 @@ impl ModulesAddrs {
     }
 }
 
+/// A declarative migration plan for a DAO and its SubDAOs. All
+/// migrations in the plan run in a single transaction, if any of them
+/// or any of the assertions fails, nothing is migrated.
+#[cw_serde]
+pub struct MigrationPlan {
+    /// The module migrations to run, in order.
+    pub migrations: Vec<ModuleMigration>,
+    /// Assertions on the state of the DAO's modules, checked before
+    /// and after the migrations run.
+    pub assertions: Vec<StateAssertion>,
+}
+
+/// A single module migration of a `MigrationPlan`.
+#[cw_serde]
+pub struct ModuleMigration {
+    /// The SubDAO the module belongs to, if it isn't a module of the
+    /// DAO itself. The migration is sent through the SubDAO's
+    /// `ExecuteAdminMsgs`, so the DAO must be the SubDAO's admin.
+    pub sub_dao: Option<String>,
+    /// The address of the module to migrate.
+    pub address: String,
+    /// The code ID the module is expected to have before the
+    /// migration. If set and the module has a different code ID the
+    /// migration is aborted.
+    pub from_code_id: Option<u64>,
+    /// The code ID to migrate the module to.
+    pub new_code_id: u64,
+    /// The message passed to the module's migrate entry point.
+    pub msg: Binary,
+}
+
+/// A check of the state of the DAO's modules around a migration.
+///
+/// The responses of `Before`, `After` and `Unchanged` smart queries
+/// are compared byte for byte with the JSON the queried contract
+/// returns, so a migration that adds a field to a response fails an
+/// `Unchanged` assertion on it. The proposal count and voting power
+/// assertions compare the values themselves.
+#[cw_serde]
+pub enum StateAssertion {
+    /// The query must return `expected` before the migration.
+    Before {
+        contract: String,
+        query: Binary,
+        expected: Binary,
+    },
+    /// The query must return `expected` after the migration.
+    After {
+        contract: String,
+        query: Binary,
+        expected: Binary,
+    },
+    /// The query must return the same response before and after the
+    /// migration.
+    Unchanged { contract: String, query: Binary },
+    /// The proposal count of a `dao-proposal-single` module must be
+    /// the same before and after the migration.
+    ProposalCountUnchanged { module: String },
+    /// The total power of a voting module must be the same before and
+    /// after the migration.
+    TotalPowerUnchanged { module: String },
+    /// The voting power of `address` in a voting module must be the
+    /// same before and after the migration.
+    VotingPowerUnchanged { module: String, address: String },
+}
+
+/// Data about a running migration plan that we need to test and
+/// report on it once the migrations went through.
+#[cw_serde]
+pub struct PendingPlan {
+    pub assertions: Vec<StateAssertion>,
+    /// The state checked by the assertions that compare before and
+    /// after the migration, as it was before, by assertion index.
+    pub snapshots: Vec<Option<Binary>>,
+    pub migrations: Vec<ModuleMigrationReport>,
+}
+
+/// A report of a migration plan that ran successfully.
+#[cw_serde]
+pub struct MigrationReport {
+    /// The DAO that ran the migration.
+    pub dao: Addr,
+    /// The block height the migration ran at.
+    pub height: u64,
+    /// The block time the migration ran at.
+    pub time: Timestamp,
+    /// The modules that were migrated, in order.
+    pub migrations: Vec<ModuleMigrationReport>,
+    /// The number of state assertions that were checked.
+    pub assertions_checked: u32,
+}
+
+#[cw_serde]
+pub struct ModuleMigrationReport {
+    /// The DAO or SubDAO the module belongs to.
+    pub dao: Addr,
+    /// The address of the module.
+    pub address: Addr,
+    /// The code ID of the module before the migration.
+    pub old_code_id: u64,
+    /// The code ID of the module after the migration.
+    pub new_code_id: u64,
+}
+
 // Test helper types
 
 pub struct SingleProposalData {
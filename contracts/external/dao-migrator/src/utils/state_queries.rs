@@ -1,4 +1,7 @@
-use cosmwasm_std::{Addr, Deps, StdResult, Uint128};
+use cosmwasm_std::{
+    to_json_vec, Addr, Binary, ContractResult, Deps, Empty, QueryRequest, StdError, StdResult,
+    SystemResult, Uint128, WasmQuery,
+};
 
 use crate::{types::SingleProposalData, ContractError};
 
@@ -76,6 +79,8 @@ pub fn query_proposal_v1(
                 proposer: proposal.proposer,
                 start_height: proposal.start_height,
                 min_voting_period: proposal.min_voting_period.map(v1_expiration_to_v2),
+                review_period: None,
+                schedule: None,
                 expiration: v1_expiration_to_v2(proposal.expiration),
                 threshold: v1_threshold_to_v2(proposal.threshold),
                 total_power: proposal.total_power,
@@ -200,3 +205,23 @@ pub fn query_single_voting_power_v2(
     )?;
     Ok(res.power)
 }
+
+/// Runs a smart query and returns the response as the queried contract
+/// serialized it, so it can be compared to an expected response.
+pub fn query_smart_raw(deps: Deps, contract: String, msg: Binary) -> StdResult<Binary> {
+    let request: QueryRequest<Empty> = WasmQuery::Smart {
+        contract_addr: contract,
+        msg,
+    }
+    .into();
+
+    match deps.querier.raw_query(&to_json_vec(&request)?) {
+        SystemResult::Err(system_err) => Err(StdError::generic_err(format!(
+            "Querier system error: {system_err}"
+        ))),
+        SystemResult::Ok(ContractResult::Err(contract_err)) => Err(StdError::generic_err(format!(
+            "Querier contract error: {contract_err}"
+        ))),
+        SystemResult::Ok(ContractResult::Ok(value)) => Ok(value),
+    }
+}
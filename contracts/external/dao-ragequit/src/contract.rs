@@ -0,0 +1,366 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdError, StdResult, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw_denom::{CheckedDenom, UncheckedDenom};
+use cw_storage_plus::Bound;
+use dao_interface::{
+    state::ProposalModuleStatus,
+    voting::{self, InfoResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse},
+};
+use dao_proposal_single::query::{ProposalResponse, VoteResponse};
+use dao_voting::{status::Status, voting::Vote};
+
+use crate::{
+    error::ContractError,
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg, QuoteResponse},
+    state::{Config, Payout, Ragequit, CONFIG, RAGEQUITS},
+};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-ragequit";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const DEFAULT_LIMIT: u32 = 30;
+
+/// Where a member's stake is held, and so forfeited from.
+enum Stake {
+    /// A `dao-voting-token-staked` voting module.
+    Native(Addr),
+    /// The `cw20-stake` contract of a `dao-voting-cw20-staked` voting
+    /// module.
+    Cw20(Addr),
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // This module is a proposal module of the DAO so that it may pay
+    // members from the treasury, and so is instantiated by the DAO.
+    let dao = info.sender;
+    let denoms = check_denoms(deps.as_ref(), msg.denoms)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            dao: dao.clone(),
+            denoms,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", dao))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Ragequit {
+            proposal_module,
+            proposal_id,
+        } => execute_ragequit(deps, env, info, proposal_module, proposal_id),
+        ExecuteMsg::UpdateDenoms { denoms } => execute_update_denoms(deps, info, denoms),
+    }
+}
+
+pub fn execute_ragequit(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_module: String,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let proposal_module = deps.api.addr_validate(&proposal_module)?;
+    if RAGEQUITS.has(deps.storage, (&proposal_module, proposal_id, &info.sender)) {
+        return Err(ContractError::AlreadyRagequit {});
+    }
+
+    let (quote, stake) = quote(
+        deps.as_ref(),
+        &env,
+        &config,
+        &proposal_module,
+        proposal_id,
+        &info.sender,
+    )?;
+
+    // The stake is forfeited to the DAO's treasury.
+    let forfeit = match stake {
+        Stake::Native(voting_module) => WasmMsg::Execute {
+            contract_addr: voting_module.into_string(),
+            msg: to_json_binary(&dao_voting_token_staked::msg::ExecuteMsg::Forfeit {
+                address: info.sender.to_string(),
+                amount: quote.forfeit,
+            })?,
+            funds: vec![],
+        },
+        Stake::Cw20(staking_contract) => WasmMsg::Execute {
+            contract_addr: staking_contract.into_string(),
+            msg: to_json_binary(&cw20_stake::msg::ExecuteMsg::Forfeit {
+                address: info.sender.to_string(),
+                amount: quote.forfeit,
+            })?,
+            funds: vec![],
+        },
+    };
+    let mut msgs: Vec<CosmosMsg> = vec![forfeit.into()];
+    for payout in &quote.payouts {
+        msgs.push(
+            payout
+                .denom
+                .get_transfer_to_message(&info.sender, payout.amount)?,
+        );
+    }
+
+    RAGEQUITS.save(
+        deps.storage,
+        (&proposal_module, proposal_id, &info.sender),
+        &Ragequit {
+            member: info.sender.clone(),
+            forfeited: quote.forfeit,
+            payouts: quote.payouts,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_message(WasmMsg::Execute {
+            contract_addr: config.dao.into_string(),
+            msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
+                msgs,
+                proposal_id: None,
+            })?,
+            funds: vec![],
+        })
+        .add_attribute("action", "ragequit")
+        .add_attribute("member", info.sender)
+        .add_attribute("proposal_module", proposal_module)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("forfeited", quote.forfeit))
+}
+
+pub fn execute_update_denoms(
+    deps: DepsMut,
+    info: MessageInfo,
+    denoms: Vec<UncheckedDenom>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    config.denoms = check_denoms(deps.as_ref(), denoms)?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::default().add_attribute("action", "update_denoms"))
+}
+
+fn check_denoms(
+    deps: Deps,
+    denoms: Vec<UncheckedDenom>,
+) -> Result<Vec<CheckedDenom>, ContractError> {
+    if denoms.is_empty() {
+        return Err(ContractError::NoDenoms {});
+    }
+    let mut checked: Vec<CheckedDenom> = Vec::with_capacity(denoms.len());
+    for denom in denoms {
+        let denom = denom.into_checked(deps)?;
+        if checked.contains(&denom) {
+            return Err(ContractError::DuplicateDenom {});
+        }
+        checked.push(denom);
+    }
+    Ok(checked)
+}
+
+/// Gets the DAO's voting module and where its members stake.
+fn stake(deps: Deps, dao: &Addr) -> Result<(Addr, Stake), ContractError> {
+    let voting_module: Addr = deps
+        .querier
+        .query_wasm_smart(dao, &dao_interface::msg::QueryMsg::VotingModule {})?;
+    let info: InfoResponse = deps
+        .querier
+        .query_wasm_smart(&voting_module, &voting::Query::Info {})?;
+    match info.info.contract.as_str() {
+        "crates.io:dao-voting-token-staked" => {
+            Ok((voting_module.clone(), Stake::Native(voting_module)))
+        }
+        "crates.io:dao-voting-cw20-staked" => {
+            let staking_contract: Addr = deps.querier.query_wasm_smart(
+                &voting_module,
+                &dao_voting_cw20_staked::msg::QueryMsg::StakingContract {},
+            )?;
+            Ok((voting_module, Stake::Cw20(staking_contract)))
+        }
+        _ => Err(ContractError::UnsupportedVotingModule {
+            contract: info.info.contract,
+        }),
+    }
+}
+
+/// Checks that `member` may ragequit from a proposal, and computes
+/// what they would forfeit and receive.
+fn quote(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    proposal_module: &Addr,
+    proposal_id: u64,
+    member: &Addr,
+) -> Result<(QuoteResponse, Stake), ContractError> {
+    let module = dao_dao_core::state::PROPOSAL_MODULES.query(
+        &deps.querier,
+        config.dao.clone(),
+        proposal_module.clone(),
+    )?;
+    match module {
+        Some(module) if module.status == ProposalModuleStatus::Enabled => (),
+        _ => {
+            return Err(ContractError::UnknownProposalModule {
+                module: proposal_module.clone(),
+            })
+        }
+    }
+    // Proposals and votes are queried in the shape of
+    // `dao-proposal-single`, which is the only proposal module with
+    // yes and no votes and a veto timelock.
+    let version = cw2::query_contract_info(&deps.querier, proposal_module)?;
+    if version.contract != "crates.io:dao-proposal-single" {
+        return Err(ContractError::UnsupportedProposalModule {
+            contract: version.contract,
+        });
+    }
+
+    let proposal: ProposalResponse = deps.querier.query_wasm_smart(
+        proposal_module,
+        &dao_proposal_single::msg::QueryMsg::Proposal { proposal_id },
+    )?;
+    if !matches!(proposal.proposal.status, Status::VetoTimelock { .. }) {
+        return Err(ContractError::NotTimelocked { proposal_id });
+    }
+
+    let vote: VoteResponse = deps.querier.query_wasm_smart(
+        proposal_module,
+        &dao_proposal_single::msg::QueryMsg::GetVote {
+            proposal_id,
+            voter: member.to_string(),
+        },
+    )?;
+    let vote = match vote.vote {
+        Some(vote) if vote.vote == Vote::No => vote,
+        _ => return Err(ContractError::NotNoVoter {}),
+    };
+
+    let (voting_module, stake) = stake(deps, &config.dao)?;
+    // Voting power at the next height includes changes made earlier
+    // in this block, so members can not be paid for stake they have
+    // just unstaked.
+    let height = Some(env.block.height + 1);
+    let power: VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
+        &voting_module,
+        &voting::Query::VotingPowerAtHeight {
+            address: member.to_string(),
+            height,
+        },
+    )?;
+    let total: TotalPowerAtHeightResponse = deps.querier.query_wasm_smart(
+        &voting_module,
+        &voting::Query::TotalPowerAtHeight { height },
+    )?;
+
+    // Members may only exit with the stake they voted with, so that
+    // stake added after the vote can not be exchanged for treasury.
+    let forfeit = power.power.min(vote.power);
+    if forfeit.is_zero() {
+        return Err(ContractError::NothingStaked {});
+    }
+
+    let mut payouts = vec![];
+    for denom in &config.denoms {
+        let balance = denom.query_balance(&deps.querier, &config.dao)?;
+        let amount = balance.multiply_ratio(forfeit, total.power);
+        if !amount.is_zero() {
+            payouts.push(Payout {
+                denom: denom.clone(),
+                amount,
+            });
+        }
+    }
+
+    Ok((QuoteResponse { forfeit, payouts }, stake))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Quote {
+            proposal_module,
+            proposal_id,
+            address,
+        } => query_quote(deps, env, proposal_module, proposal_id, address),
+        QueryMsg::Ragequits {
+            proposal_module,
+            proposal_id,
+            start_after,
+            limit,
+        } => query_ragequits(deps, proposal_module, proposal_id, start_after, limit),
+    }
+}
+
+pub fn query_quote(
+    deps: Deps,
+    env: Env,
+    proposal_module: String,
+    proposal_id: u64,
+    address: String,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let proposal_module = deps.api.addr_validate(&proposal_module)?;
+    let address = deps.api.addr_validate(&address)?;
+    if RAGEQUITS.has(deps.storage, (&proposal_module, proposal_id, &address)) {
+        return Err(StdError::generic_err(
+            ContractError::AlreadyRagequit {}.to_string(),
+        ));
+    }
+    let (quote, _) = quote(deps, &env, &config, &proposal_module, proposal_id, &address)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    to_json_binary(&quote)
+}
+
+pub fn query_ragequits(
+    deps: Deps,
+    proposal_module: String,
+    proposal_id: u64,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Binary> {
+    let proposal_module = deps.api.addr_validate(&proposal_module)?;
+    let start_after = start_after
+        .map(|s| deps.api.addr_validate(&s))
+        .transpose()?;
+    let ragequits = RAGEQUITS
+        .prefix((&proposal_module, proposal_id))
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit.unwrap_or(DEFAULT_LIMIT) as usize)
+        .map(|item| item.map(|(_, ragequit)| ragequit))
+        .collect::<StdResult<Vec<_>>>()?;
+    to_json_binary(&ragequits)
+}
@@ -0,0 +1,42 @@
+use cosmwasm_std::{Addr, StdError};
+use cw_denom::DenomError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Denom(#[from] DenomError),
+
+    #[error("unauthorized")]
+    Unauthorized {},
+
+    #[error("at least one treasury denom must be configured")]
+    NoDenoms {},
+
+    #[error("treasury denoms may not contain duplicates")]
+    DuplicateDenom {},
+
+    #[error("({module}) is not an enabled proposal module of this DAO")]
+    UnknownProposalModule { module: Addr },
+
+    #[error("proposal ({proposal_id}) is not in its veto timelock")]
+    NotTimelocked { proposal_id: u64 },
+
+    #[error("only members who voted no on the proposal may ragequit")]
+    NotNoVoter {},
+
+    #[error("already ragequit from this proposal")]
+    AlreadyRagequit {},
+
+    #[error("no stake to forfeit")]
+    NothingStaked {},
+
+    #[error("ragequit does not support proposal module contract ({contract})")]
+    UnsupportedProposalModule { contract: String },
+
+    #[error("ragequit does not support voting module contract ({contract})")]
+    UnsupportedVotingModule { contract: String },
+}
@@ -0,0 +1,61 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+use cw_denom::UncheckedDenom;
+
+use crate::state::Payout;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The treasury denoms members receive a share of when they
+    /// ragequit.
+    pub denoms: Vec<UncheckedDenom>,
+}
+
+#[cw_serde]
+#[derive(cw_orch::ExecuteFns)]
+pub enum ExecuteMsg {
+    /// Forfeits the sender's stake in exchange for a pro-rata share
+    /// of the DAO's treasury. The sender must have voted No on the
+    /// proposal, and the proposal must be in its veto timelock.
+    Ragequit {
+        proposal_module: String,
+        proposal_id: u64,
+    },
+    /// Callable by the DAO. Updates the treasury denoms members
+    /// receive a share of.
+    UpdateDenoms { denoms: Vec<UncheckedDenom> },
+}
+
+#[cw_serde]
+#[derive(QueryResponses, cw_orch::QueryFns)]
+pub enum QueryMsg {
+    /// Gets the module's config.
+    #[returns(crate::state::Config)]
+    Config {},
+    /// Gets what `address` would forfeit and receive by ragequitting
+    /// from the proposal now. Errors if `address` may not ragequit.
+    #[returns(QuoteResponse)]
+    Quote {
+        proposal_module: String,
+        proposal_id: u64,
+        address: String,
+    },
+    /// Lists the members who have ragequit from a proposal.
+    #[returns(Vec<crate::state::Ragequit>)]
+    Ragequits {
+        proposal_module: String,
+        proposal_id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct QuoteResponse {
+    /// The amount of stake that would be forfeited. This is the
+    /// lesser of the member's current stake and the voting power
+    /// they voted with.
+    pub forfeit: Uint128,
+    /// The treasury that would be received in exchange.
+    pub payouts: Vec<Payout>,
+}
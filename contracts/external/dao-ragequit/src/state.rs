@@ -0,0 +1,35 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_denom::CheckedDenom;
+use cw_storage_plus::{Item, Map};
+
+#[cw_serde]
+pub struct Config {
+    /// The DAO this module belongs to.
+    pub dao: Addr,
+    /// The treasury denoms members receive a share of when they
+    /// ragequit.
+    pub denoms: Vec<CheckedDenom>,
+}
+
+/// An amount of a treasury denom paid out to a member.
+#[cw_serde]
+pub struct Payout {
+    pub denom: CheckedDenom,
+    pub amount: Uint128,
+}
+
+/// A member's exit from the DAO.
+#[cw_serde]
+pub struct Ragequit {
+    pub member: Addr,
+    /// The amount of stake the member forfeited.
+    pub forfeited: Uint128,
+    /// The treasury the member received in exchange.
+    pub payouts: Vec<Payout>,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// Ragequits, keyed by (proposal module, proposal ID, member).
+pub const RAGEQUITS: Map<(&Addr, u64, &Addr), Ragequit> = Map::new("ragequits");
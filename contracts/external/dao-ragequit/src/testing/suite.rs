@@ -0,0 +1,420 @@
+use cosmwasm_std::{coins, to_json_binary, Addr, Empty, Uint128};
+use cw_denom::UncheckedDenom;
+use cw_multi_test::{
+    next_block, App, AppResponse, BankSudo, Contract, ContractWrapper, Executor, SudoMsg,
+};
+use cw_utils::Duration;
+use dao_interface::state::{Admin, ModuleInstantiateInfo, ProposalModule};
+use dao_testing::contracts::{
+    cw20_base_contract, cw20_stake_contract, cw20_staked_balances_voting_contract,
+    dao_dao_contract, native_staked_balances_voting_contract, proposal_single_contract,
+};
+use dao_voting::{
+    pre_propose::PreProposeInfo,
+    proposal::SingleChoiceProposeMsg,
+    threshold::{PercentageThreshold, Threshold},
+    veto::VetoConfig,
+    voting::Vote,
+};
+
+use crate::{
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg, QuoteResponse},
+    state::Ragequit,
+};
+
+pub(crate) const STAKE_DENOM: &str = "ujuno";
+pub(crate) const TREASURY_DENOM: &str = "uatom";
+
+fn ragequit_contract() -> Box<dyn Contract<Empty>> {
+    Box::new(ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    ))
+}
+
+pub(crate) struct Suite {
+    pub app: App,
+    pub core: Addr,
+    pub proposal_single: Addr,
+    pub ragequit: Addr,
+    /// The cw20 governance token, if the DAO stakes a cw20.
+    pub cw20: Option<Addr>,
+    /// The contract members stake with.
+    pub staking: Addr,
+}
+
+pub(crate) struct SuiteBuilder {
+    cw20: bool,
+    members: Vec<(&'static str, u128)>,
+    treasury: u128,
+}
+
+impl Default for SuiteBuilder {
+    fn default() -> Self {
+        Self {
+            cw20: false,
+            members: vec![("alice", 60), ("bob", 30), ("carol", 10)],
+            treasury: 1000,
+        }
+    }
+}
+
+impl SuiteBuilder {
+    pub fn with_cw20(mut self) -> Self {
+        self.cw20 = true;
+        self
+    }
+
+    pub fn build(self) -> Suite {
+        let mut app = App::default();
+
+        let core_id = app.store_code(dao_dao_contract());
+        let single_id = app.store_code(proposal_single_contract());
+        let ragequit_id = app.store_code(ragequit_contract());
+
+        let voting_module_instantiate_info = if self.cw20 {
+            ModuleInstantiateInfo {
+                code_id: app.store_code(cw20_staked_balances_voting_contract()),
+                msg: to_json_binary(&dao_voting_cw20_staked::msg::InstantiateMsg {
+                    active_threshold: None,
+                    token_info: dao_voting_cw20_staked::msg::TokenInfo::New {
+                        code_id: app.store_code(cw20_base_contract()),
+                        label: "governance token".to_string(),
+                        name: "DAO".to_string(),
+                        symbol: "DAO".to_string(),
+                        decimals: 6,
+                        initial_balances: self
+                            .members
+                            .iter()
+                            .map(|(addr, amount)| cw20::Cw20Coin {
+                                address: addr.to_string(),
+                                amount: Uint128::new(*amount),
+                            })
+                            .collect(),
+                        marketing: None,
+                        staking_code_id: app.store_code(cw20_stake_contract()),
+                        unstaking_duration: None,
+                        initial_dao_balance: Some(Uint128::new(self.treasury)),
+                    },
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "voting module".to_string(),
+            }
+        } else {
+            for (addr, amount) in &self.members {
+                app.sudo(SudoMsg::Bank(BankSudo::Mint {
+                    to_address: addr.to_string(),
+                    amount: coins(*amount, STAKE_DENOM),
+                }))
+                .unwrap();
+            }
+            ModuleInstantiateInfo {
+                code_id: app.store_code(native_staked_balances_voting_contract()),
+                msg: to_json_binary(&dao_voting_token_staked::msg::InstantiateMsg {
+                    token_info: dao_voting_token_staked::msg::TokenInfo::Existing {
+                        denom: STAKE_DENOM.to_string(),
+                    },
+                    unstaking_duration: None,
+                    active_threshold: None,
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "voting module".to_string(),
+            }
+        };
+
+        let core = app
+            .instantiate_contract(
+                core_id,
+                Addr::unchecked("creator"),
+                &dao_interface::msg::InstantiateMsg {
+                    dao_uri: None,
+                    admin: None,
+                    name: "DAO".to_string(),
+                    description: "A DAO with a ragequit module.".to_string(),
+                    image_url: None,
+                    automatically_add_cw20s: true,
+                    automatically_add_cw721s: true,
+                    voting_module_instantiate_info,
+                    proposal_modules_instantiate_info: vec![
+                        ModuleInstantiateInfo {
+                            code_id: single_id,
+                            msg: to_json_binary(&dao_proposal_single::msg::InstantiateMsg {
+                                threshold: Threshold::AbsolutePercentage {
+                                    percentage: PercentageThreshold::Majority {},
+                                },
+                                max_voting_period: Duration::Height(20),
+                                min_voting_period: None,
+                                only_members_execute: false,
+                                allow_revoting: false,
+                                pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+                                close_proposal_on_execution_failure: true,
+                                review_period: None,
+                                veto: Some(VetoConfig {
+                                    timelock_duration: Duration::Height(10),
+                                    vetoer: "vetoer".to_string(),
+                                    early_execute: false,
+                                    veto_before_passed: false,
+                                }),
+                            })
+                            .unwrap(),
+                            admin: Some(Admin::CoreModule {}),
+                            funds: vec![],
+                            label: "proposal module".to_string(),
+                        },
+                        ModuleInstantiateInfo {
+                            code_id: ragequit_id,
+                            msg: to_json_binary(&InstantiateMsg {
+                                denoms: vec![UncheckedDenom::Native(TREASURY_DENOM.to_string())],
+                            })
+                            .unwrap(),
+                            admin: Some(Admin::CoreModule {}),
+                            funds: vec![],
+                            label: "ragequit".to_string(),
+                        },
+                    ],
+                    initial_items: None,
+                },
+                &[],
+                "DAO",
+                None,
+            )
+            .unwrap();
+        app.sudo(SudoMsg::Bank(BankSudo::Mint {
+            to_address: core.to_string(),
+            amount: coins(self.treasury, TREASURY_DENOM),
+        }))
+        .unwrap();
+
+        let modules: Vec<ProposalModule> = app
+            .wrap()
+            .query_wasm_smart(
+                &core,
+                &dao_interface::msg::QueryMsg::ProposalModules {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        let module_with_code = |app: &App, code_id: u64| {
+            modules
+                .iter()
+                .find(|m| {
+                    app.wrap()
+                        .query_wasm_contract_info(&m.address)
+                        .unwrap()
+                        .code_id
+                        == code_id
+                })
+                .unwrap()
+                .address
+                .clone()
+        };
+        let proposal_single = module_with_code(&app, single_id);
+        let ragequit = module_with_code(&app, ragequit_id);
+
+        let voting_module: Addr = app
+            .wrap()
+            .query_wasm_smart(&core, &dao_interface::msg::QueryMsg::VotingModule {})
+            .unwrap();
+        let (cw20, staking) = if self.cw20 {
+            let cw20: Addr = app
+                .wrap()
+                .query_wasm_smart(
+                    &voting_module,
+                    &dao_interface::voting::Query::TokenContract {},
+                )
+                .unwrap();
+            let staking: Addr = app
+                .wrap()
+                .query_wasm_smart(
+                    &voting_module,
+                    &dao_voting_cw20_staked::msg::QueryMsg::StakingContract {},
+                )
+                .unwrap();
+            for (addr, amount) in &self.members {
+                app.execute_contract(
+                    Addr::unchecked(*addr),
+                    cw20.clone(),
+                    &cw20::Cw20ExecuteMsg::Send {
+                        contract: staking.to_string(),
+                        amount: Uint128::new(*amount),
+                        msg: to_json_binary(&cw20_stake::msg::ReceiveMsg::Stake {}).unwrap(),
+                    },
+                    &[],
+                )
+                .unwrap();
+            }
+            (Some(cw20), staking)
+        } else {
+            for (addr, amount) in &self.members {
+                app.execute_contract(
+                    Addr::unchecked(*addr),
+                    voting_module.clone(),
+                    &dao_voting_token_staked::msg::ExecuteMsg::Stake {},
+                    &coins(*amount, STAKE_DENOM),
+                )
+                .unwrap();
+            }
+            (None, voting_module)
+        };
+        app.update_block(next_block);
+
+        Suite {
+            app,
+            core,
+            proposal_single,
+            ragequit,
+            cw20,
+            staking,
+        }
+    }
+}
+
+impl Suite {
+    pub fn propose(&mut self, proposer: &str) -> u64 {
+        self.app
+            .execute_contract(
+                Addr::unchecked(proposer),
+                self.proposal_single.clone(),
+                &dao_proposal_single::msg::ExecuteMsg::Propose(SingleChoiceProposeMsg {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    proposer: None,
+                    vote: None,
+                    schedule: None,
+                }),
+                &[],
+            )
+            .unwrap();
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.proposal_single,
+                &dao_proposal_single::msg::QueryMsg::ProposalCount {},
+            )
+            .unwrap()
+    }
+
+    pub fn vote(&mut self, voter: &str, proposal_id: u64, vote: Vote) {
+        self.app
+            .execute_contract(
+                Addr::unchecked(voter),
+                self.proposal_single.clone(),
+                &dao_proposal_single::msg::ExecuteMsg::Vote {
+                    proposal_id,
+                    vote,
+                    rationale: None,
+                },
+                &[],
+            )
+            .unwrap();
+    }
+
+    pub fn ragequit(&mut self, member: &str, proposal_id: u64) -> anyhow::Result<AppResponse> {
+        let proposal_module = self.proposal_single.to_string();
+        self.ragequit_from(member, &proposal_module, proposal_id)
+    }
+
+    pub fn ragequit_from(
+        &mut self,
+        member: &str,
+        proposal_module: &str,
+        proposal_id: u64,
+    ) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(member),
+            self.ragequit.clone(),
+            &ExecuteMsg::Ragequit {
+                proposal_module: proposal_module.to_string(),
+                proposal_id,
+            },
+            &[],
+        )
+    }
+
+    pub fn update_denoms(
+        &mut self,
+        sender: &Addr,
+        denoms: Vec<UncheckedDenom>,
+    ) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.ragequit.clone(),
+            &ExecuteMsg::UpdateDenoms { denoms },
+            &[],
+        )
+    }
+
+    pub fn quote(&self, member: &str, proposal_id: u64) -> QuoteResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.ragequit,
+                &QueryMsg::Quote {
+                    proposal_module: self.proposal_single.to_string(),
+                    proposal_id,
+                    address: member.to_string(),
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn ragequits(&self, proposal_id: u64) -> Vec<Ragequit> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.ragequit,
+                &QueryMsg::Ragequits {
+                    proposal_module: self.proposal_single.to_string(),
+                    proposal_id,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap()
+    }
+
+    pub fn voting_power(&self, member: &str) -> Uint128 {
+        let voting_module: Addr = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.core, &dao_interface::msg::QueryMsg::VotingModule {})
+            .unwrap();
+        let resp: dao_interface::voting::VotingPowerAtHeightResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                voting_module,
+                &dao_interface::voting::Query::VotingPowerAtHeight {
+                    address: member.to_string(),
+                    height: None,
+                },
+            )
+            .unwrap();
+        resp.power
+    }
+
+    pub fn native_balance(&self, addr: impl Into<String>, denom: &str) -> Uint128 {
+        self.app.wrap().query_balance(addr, denom).unwrap().amount
+    }
+
+    pub fn cw20_balance(&self, addr: impl Into<String>) -> Uint128 {
+        let resp: cw20::BalanceResponse = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                self.cw20.as_ref().unwrap(),
+                &cw20::Cw20QueryMsg::Balance {
+                    address: addr.into(),
+                },
+            )
+            .unwrap();
+        resp.balance
+    }
+}
@@ -0,0 +1,151 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw_denom::{CheckedDenom, UncheckedDenom};
+use cw_multi_test::next_block;
+use dao_voting::voting::Vote;
+
+use crate::{state::Payout, ContractError};
+
+use super::suite::{SuiteBuilder, STAKE_DENOM, TREASURY_DENOM};
+
+#[test]
+fn test_ragequit() {
+    let mut suite = SuiteBuilder::default().build();
+    let proposal_id = suite.propose("alice");
+
+    suite.vote("bob", proposal_id, Vote::No);
+    suite.vote("carol", proposal_id, Vote::Abstain);
+    // The proposal is still open, so there is nothing to exit from
+    // yet.
+    let err: ContractError = suite
+        .ragequit("bob", proposal_id)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotTimelocked { proposal_id });
+
+    // Alice's vote passes the proposal and it enters its timelock.
+    suite.vote("alice", proposal_id, Vote::Yes);
+    suite.app.update_block(next_block);
+
+    let err: ContractError = suite
+        .ragequit("carol", proposal_id)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotNoVoter {});
+    let err: ContractError = suite
+        .ragequit("alice", proposal_id)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NotNoVoter {});
+    let err: ContractError = suite
+        .ragequit_from("bob", "notamodule", proposal_id)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::UnknownProposalModule {
+            module: Addr::unchecked("notamodule")
+        }
+    );
+    // Only `dao-proposal-single` proposals may be exited from.
+    let ragequit = suite.ragequit.to_string();
+    let err: ContractError = suite
+        .ragequit_from("bob", &ragequit, proposal_id)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::UnsupportedProposalModule {
+            contract: "crates.io:dao-ragequit".to_string()
+        }
+    );
+
+    let payouts = vec![Payout {
+        denom: CheckedDenom::Native(TREASURY_DENOM.to_string()),
+        amount: Uint128::new(300),
+    }];
+    let quote = suite.quote("bob", proposal_id);
+    assert_eq!(quote.forfeit, Uint128::new(30));
+    assert_eq!(quote.payouts, payouts);
+
+    suite.ragequit("bob", proposal_id).unwrap();
+    assert_eq!(
+        suite.native_balance("bob", TREASURY_DENOM),
+        Uint128::new(300)
+    );
+    assert_eq!(
+        suite.native_balance(&suite.core, TREASURY_DENOM),
+        Uint128::new(700)
+    );
+    assert_eq!(
+        suite.native_balance(&suite.core, STAKE_DENOM),
+        Uint128::new(30)
+    );
+    suite.app.update_block(next_block);
+    assert_eq!(suite.voting_power("bob"), Uint128::zero());
+
+    let ragequits = suite.ragequits(proposal_id);
+    assert_eq!(ragequits.len(), 1);
+    assert_eq!(ragequits[0].member, Addr::unchecked("bob"));
+    assert_eq!(ragequits[0].forfeited, Uint128::new(30));
+    assert_eq!(ragequits[0].payouts, payouts);
+
+    let err: ContractError = suite
+        .ragequit("bob", proposal_id)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AlreadyRagequit {});
+}
+
+#[test]
+fn test_ragequit_cw20() {
+    let mut suite = SuiteBuilder::default().with_cw20().build();
+    let cw20 = suite.cw20.clone().unwrap();
+    let core = suite.core.clone();
+
+    let err: ContractError = suite
+        .update_denoms(
+            &Addr::unchecked("bob"),
+            vec![UncheckedDenom::Cw20(cw20.to_string())],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+    let err: ContractError = suite
+        .update_denoms(&core, vec![])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoDenoms {});
+    suite
+        .update_denoms(
+            &core,
+            vec![
+                UncheckedDenom::Native(TREASURY_DENOM.to_string()),
+                UncheckedDenom::Cw20(cw20.to_string()),
+            ],
+        )
+        .unwrap();
+
+    let proposal_id = suite.propose("alice");
+    suite.vote("bob", proposal_id, Vote::No);
+    suite.vote("alice", proposal_id, Vote::Yes);
+    suite.app.update_block(next_block);
+
+    suite.ragequit("bob", proposal_id).unwrap();
+    assert_eq!(
+        suite.native_balance("bob", TREASURY_DENOM),
+        Uint128::new(300)
+    );
+    assert_eq!(suite.cw20_balance("bob"), Uint128::new(300));
+    // The DAO keeps its remaining treasury plus Bob's forfeited
+    // stake.
+    assert_eq!(suite.cw20_balance(&core), Uint128::new(730));
+    assert_eq!(suite.cw20_balance(&suite.staking), Uint128::new(70));
+}
@@ -95,12 +95,14 @@ pub fn execute_propose(
             description,
             msgs,
             vote,
+            schedule,
         } => ProposeMsg {
             title,
             description,
             msgs,
             proposer: Some(info.sender.to_string()),
             vote,
+            schedule,
         },
     };
 
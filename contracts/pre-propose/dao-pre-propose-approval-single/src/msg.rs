@@ -4,7 +4,10 @@ use dao_pre_propose_base::msg::{
     ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, MigrateMsg as MigrateBase,
     QueryMsg as QueryBase,
 };
-use dao_voting::{proposal::SingleChoiceProposeMsg as ProposeMsg, voting::SingleChoiceAutoVote};
+use dao_voting::{
+    proposal::SingleChoiceProposeMsg as ProposeMsg, schedule::ExecutionSchedule,
+    voting::SingleChoiceAutoVote,
+};
 
 #[cw_serde]
 pub enum ApproverProposeMessage {
@@ -22,6 +25,8 @@ pub enum ProposeMessage {
         description: String,
         msgs: Vec<CosmosMsg<Empty>>,
         vote: Option<SingleChoiceAutoVote>,
+        #[serde(default)]
+        schedule: Option<ExecutionSchedule>,
     },
 }
 
@@ -99,6 +99,7 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        review_period: None,
         veto: None,
     }
 }
@@ -217,6 +218,7 @@ fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[
                 description: "description".to_string(),
                 msgs: vec![],
                 vote: None,
+                schedule: None,
             },
         },
         funds,
@@ -1206,6 +1208,7 @@ fn test_permissions() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    schedule: None,
                 },
             },
             &[],
@@ -1358,6 +1361,7 @@ fn test_no_deposit_required_members_submission() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    schedule: None,
                 },
             },
             &[],
@@ -1424,6 +1428,7 @@ fn test_anyone_denylist() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    schedule: None,
                 },
             },
             &[],
@@ -1480,6 +1485,7 @@ fn test_specific_allowlist_denylist() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    schedule: None,
                 },
             },
             &[],
@@ -1532,6 +1538,7 @@ fn test_specific_allowlist_denylist() {
                     description: "I am a member!!!".to_string(),
                     msgs: vec![],
                     vote: None,
+                    schedule: None,
                 },
             },
             &[],
@@ -1568,6 +1575,7 @@ fn test_specific_allowlist_denylist() {
                     description: "I am a member!!!".to_string(),
                     msgs: vec![],
                     vote: None,
+                    schedule: None,
                 },
             },
             &[],
@@ -1630,6 +1638,7 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            review_period: None,
             veto: None,
         }
     };
@@ -1699,6 +1708,7 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            review_period: None,
             veto: None,
         }
     };
@@ -2813,6 +2823,7 @@ fn test_migrate_from_v241() {
                     vote: Vote::Yes,
                     rationale: None,
                 }),
+                schedule: None,
             },
         },
         &[],
@@ -3174,6 +3185,7 @@ fn test_migrate_from_v241_with_policy_update() {
                     description: "d".to_string(),
                     msgs: vec![],
                     vote: None,
+                    schedule: None,
                 },
             },
             &[],
@@ -3195,6 +3207,7 @@ fn test_migrate_from_v241_with_policy_update() {
                 description: "d".to_string(),
                 msgs: vec![],
                 vote: None,
+                schedule: None,
             },
         },
         &[],
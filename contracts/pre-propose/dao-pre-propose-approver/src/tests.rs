@@ -116,6 +116,7 @@ fn get_proposal_module_approval_single_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        review_period: None,
         veto: None,
     }
 }
@@ -149,6 +150,7 @@ fn get_proposal_module_approver_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        review_period: None,
         veto: None,
     }
 }
@@ -331,6 +333,7 @@ fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[
                 description: "description".to_string(),
                 msgs: vec![],
                 vote: None,
+                schedule: None,
             },
         },
         funds,
@@ -1191,6 +1194,7 @@ fn test_permissions() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    schedule: None,
                 },
             },
             &[],
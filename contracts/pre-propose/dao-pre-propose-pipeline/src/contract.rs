@@ -0,0 +1,382 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response, StdResult,
+    SubMsg, SubMsgResult, WasmMsg,
+};
+use cw2::set_contract_version;
+
+use dao_pre_propose_base::{
+    error::PreProposeError, msg::ExecuteMsg as ExecuteBase, state::PreProposeContract,
+};
+use dao_proposal_multiple::{proposal::VoteResult, query::ProposalResponse};
+use dao_voting::{
+    multiple_choice::{MultipleChoiceOption, MultipleChoiceOptionType, MultipleChoiceOptions},
+    pre_propose::ProposalCreationPolicy,
+    proposal::{MultipleChoiceProposeMsg, SingleChoiceProposeMsg},
+    status::Status,
+};
+
+use crate::msg::{
+    BindingProposeMessage, BindingQueryMessage, ExecuteExt, ExecuteMsg, ExecuteOrHookMsg,
+    InstantiateExt, InstantiateMsg, MigrateMsg, ProposalHookExecuteMsg, ProposalHookMsg,
+    ProposeMessage, ProposeMessageInternal, QueryExt, QueryMsg,
+};
+use crate::state::{
+    BINDING_ID_TO_CHECK_ID, BINDING_PROPOSAL_ERRORS, BINDING_PROPOSAL_MODULE,
+    CHECK_ID_TO_BINDING_ID, CHOICES,
+};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-pipeline";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+type PrePropose =
+    PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, Empty, ProposeMessageInternal>;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, PreProposeError> {
+    let binding_proposal_module = deps
+        .api
+        .addr_validate(&msg.extension.binding_proposal_module)?;
+    BINDING_PROPOSAL_MODULE.save(deps.storage, &binding_proposal_module)?;
+
+    let resp = PrePropose::default().instantiate(deps.branch(), env, info, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(resp.add_attribute("binding_proposal_module", binding_proposal_module))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteOrHookMsg,
+) -> Result<Response, PreProposeError> {
+    let msg = match msg {
+        ExecuteOrHookMsg::Execute(msg) => msg,
+        ExecuteOrHookMsg::Hook(ProposalHookExecuteMsg::ProposalHook(msg)) => {
+            return execute_proposal_hook(deps, info, msg)
+        }
+    };
+
+    // As with `dao-pre-propose-multiple`, the `proposer` field of the
+    // propose message is set by this module, so external messages are
+    // transformed into internal ones before being handled by the base
+    // contract.
+    type ExecuteInternal = ExecuteBase<ProposeMessageInternal, ExecuteExt>;
+    let internalized = match msg {
+        ExecuteMsg::Propose { msg } => return execute_propose(deps, env, info, msg),
+        ExecuteMsg::Extension { msg } => match msg {
+            ExecuteExt::UpdateBindingProposalModule { address } => {
+                return execute_update_binding_proposal_module(deps, info, address)
+            }
+            ExecuteExt::CreateBindingProposal { id } => {
+                return execute_create_binding_proposal(deps, id)
+            }
+        },
+        ExecuteMsg::Withdraw { denom } => ExecuteInternal::Withdraw { denom },
+        ExecuteMsg::UpdateConfig {
+            deposit_info,
+            submission_policy,
+        } => ExecuteInternal::UpdateConfig {
+            deposit_info,
+            submission_policy,
+        },
+        ExecuteMsg::UpdateSubmissionPolicy {
+            denylist_add,
+            denylist_remove,
+            set_dao_members,
+            allowlist_add,
+            allowlist_remove,
+        } => ExecuteInternal::UpdateSubmissionPolicy {
+            denylist_add,
+            denylist_remove,
+            set_dao_members,
+            allowlist_add,
+            allowlist_remove,
+        },
+        ExecuteMsg::AddProposalSubmittedHook { address } => {
+            ExecuteInternal::AddProposalSubmittedHook { address }
+        }
+        ExecuteMsg::RemoveProposalSubmittedHook { address } => {
+            ExecuteInternal::RemoveProposalSubmittedHook { address }
+        }
+        ExecuteMsg::ProposalCompletedHook {
+            proposal_id,
+            new_status,
+        } => ExecuteInternal::ProposalCompletedHook {
+            proposal_id,
+            new_status,
+        },
+    };
+
+    PrePropose::default().execute(deps, env, info, internalized)
+}
+
+pub fn execute_propose(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ProposeMessage,
+) -> Result<Response, PreProposeError> {
+    let ProposeMessage::Propose {
+        title,
+        description,
+        choices,
+        vote,
+    } = msg;
+
+    // Temperature checks are not binding, so the messages of each
+    // option are kept here until the binding proposal is created
+    // rather than being executed by the temperature check.
+    let proposal_module = PrePropose::default().proposal_module.load(deps.storage)?;
+    let check_id = deps.querier.query_wasm_smart(
+        &proposal_module,
+        &dao_interface::proposal::Query::NextProposalId {},
+    )?;
+    CHOICES.save(deps.storage, check_id, &choices.options)?;
+
+    let choices = MultipleChoiceOptions {
+        options: choices
+            .options
+            .into_iter()
+            .map(|option| MultipleChoiceOption {
+                msgs: vec![],
+                ..option
+            })
+            .collect(),
+    };
+
+    PrePropose::default().execute_propose(
+        deps,
+        env,
+        info.clone(),
+        ProposeMessageInternal::Propose(MultipleChoiceProposeMsg {
+            title,
+            description,
+            choices,
+            proposer: Some(info.sender.into_string()),
+            vote,
+        }),
+    )
+}
+
+/// Creates the binding proposal of a temperature check once it
+/// passes, or once it is executed if it passed when voting closed.
+pub fn execute_proposal_hook(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    msg: ProposalHookMsg,
+) -> Result<Response, PreProposeError> {
+    let proposal_module = PrePropose::default().proposal_module.load(deps.storage)?;
+    if info.sender != proposal_module {
+        return Err(PreProposeError::NotModule {});
+    }
+
+    let response = Response::default().add_attribute("method", "execute_proposal_hook");
+    let check_id = match msg {
+        ProposalHookMsg::ProposalStatusChanged { id, new_status, .. }
+            if new_status == Status::Passed.to_string()
+                || new_status == Status::Executed.to_string() =>
+        {
+            id
+        }
+        _ => return Ok(response),
+    };
+
+    // An error here would cause the proposal module to remove this
+    // module from its proposal hook receivers, so failing to create
+    // the binding proposal is recorded rather than returned. It may be
+    // created later with `CreateBindingProposal`.
+    match binding_proposal(deps.branch(), check_id) {
+        Ok(Some((binding_id, msg))) => Ok(response
+            .add_submessage(msg)
+            .add_attribute("binding_proposal_id", binding_id.to_string())),
+        Ok(None) => Ok(response),
+        Err(err) => {
+            BINDING_PROPOSAL_ERRORS.save(deps.storage, check_id, &err.to_string())?;
+            Ok(response.add_attribute("binding_proposal_error", err.to_string()))
+        }
+    }
+}
+
+pub fn execute_create_binding_proposal(
+    deps: DepsMut,
+    id: u64,
+) -> Result<Response, PreProposeError> {
+    let (binding_id, msg) = binding_proposal(deps, id)?.ok_or(PreProposeError::Unsupported {})?;
+    Ok(Response::default()
+        .add_submessage(msg)
+        .add_attribute("method", "execute_create_binding_proposal")
+        .add_attribute("proposal", id.to_string())
+        .add_attribute("binding_proposal_id", binding_id.to_string()))
+}
+
+/// Prepares the binding proposal for the winning option of a passed
+/// temperature check and records the cross-reference between the
+/// two. Returns `None` if the temperature check has no binding
+/// proposal to create.
+fn binding_proposal(
+    deps: DepsMut,
+    check_id: u64,
+) -> Result<Option<(u64, SubMsg)>, PreProposeError> {
+    if CHECK_ID_TO_BINDING_ID.has(deps.storage, check_id) {
+        return Ok(None);
+    }
+    // Temperature checks created before this module was attached to
+    // the proposal module have nothing to carry over.
+    let choices = match CHOICES.may_load(deps.storage, check_id)? {
+        Some(choices) => choices,
+        None => return Ok(None),
+    };
+
+    let proposal_module = PrePropose::default().proposal_module.load(deps.storage)?;
+    let ProposalResponse { proposal, .. } = deps.querier.query_wasm_smart(
+        &proposal_module,
+        &dao_proposal_multiple::msg::QueryMsg::Proposal {
+            proposal_id: check_id,
+        },
+    )?;
+    if !matches!(proposal.status, Status::Passed | Status::Executed) {
+        return Ok(None);
+    }
+    let winner = match proposal.calculate_vote_result()? {
+        VoteResult::SingleWinner(winner)
+            if winner.option_type == MultipleChoiceOptionType::Standard =>
+        {
+            winner
+        }
+        _ => return Ok(None),
+    };
+    let choice = match choices.into_iter().nth(winner.index as usize) {
+        Some(choice) => choice,
+        None => return Ok(None),
+    };
+
+    let title = proposal.title;
+    let description = format!(
+        "{}\n\n## {}\n\n{}",
+        proposal.description, choice.title, choice.description
+    );
+
+    let binding_module = BINDING_PROPOSAL_MODULE.load(deps.storage)?;
+    let binding_id: u64 = deps.querier.query_wasm_smart(
+        &binding_module,
+        &dao_interface::proposal::Query::NextProposalId {},
+    )?;
+    let policy: ProposalCreationPolicy = deps.querier.query_wasm_smart(
+        &binding_module,
+        &BindingQueryMessage::ProposalCreationPolicy {},
+    )?;
+    let msg = match policy {
+        ProposalCreationPolicy::Anyone {} => WasmMsg::Execute {
+            contract_addr: binding_module.into_string(),
+            msg: to_json_binary(&BindingProposeMessage::Propose(SingleChoiceProposeMsg {
+                title,
+                description,
+                msgs: choice.msgs,
+                proposer: None,
+                vote: None,
+                schedule: None,
+            }))?,
+            funds: vec![],
+        },
+        // Binding modules with a pre-propose module are proposed to
+        // through it, so this module must be permitted to submit
+        // proposals there.
+        ProposalCreationPolicy::Module { addr } => WasmMsg::Execute {
+            contract_addr: addr.into_string(),
+            msg: to_json_binary(&dao_pre_propose_single::ExecuteMsg::Propose {
+                msg: dao_pre_propose_single::ProposeMessage::Propose {
+                    title,
+                    description,
+                    msgs: choice.msgs,
+                    vote: None,
+                    schedule: None,
+                },
+            })?,
+            funds: vec![],
+        },
+    };
+
+    CHECK_ID_TO_BINDING_ID.save(deps.storage, check_id, &binding_id)?;
+    BINDING_ID_TO_CHECK_ID.save(deps.storage, binding_id, &check_id)?;
+    BINDING_PROPOSAL_ERRORS.remove(deps.storage, check_id);
+
+    // The temperature check's ID is used as the reply ID so the
+    // cross-reference can be removed if creating the proposal fails.
+    Ok(Some((binding_id, SubMsg::reply_on_error(msg, check_id))))
+}
+
+pub fn execute_update_binding_proposal_module(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, PreProposeError> {
+    let dao = PrePropose::default().dao.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(PreProposeError::NotDao {});
+    }
+
+    let binding_proposal_module = deps.api.addr_validate(&address)?;
+    BINDING_PROPOSAL_MODULE.save(deps.storage, &binding_proposal_module)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "update_binding_proposal_module")
+        .add_attribute("binding_proposal_module", binding_proposal_module))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::QueryExtension { msg } => match msg {
+            QueryExt::BindingProposalModule {} => {
+                to_json_binary(&BINDING_PROPOSAL_MODULE.load(deps.storage)?)
+            }
+            QueryExt::Choices { id } => to_json_binary(&CHOICES.may_load(deps.storage, id)?),
+            QueryExt::BindingProposalIdForCheckId { id } => {
+                to_json_binary(&CHECK_ID_TO_BINDING_ID.may_load(deps.storage, id)?)
+            }
+            QueryExt::CheckIdForBindingProposalId { id } => {
+                to_json_binary(&BINDING_ID_TO_CHECK_ID.may_load(deps.storage, id)?)
+            }
+            QueryExt::BindingProposalError { id } => {
+                to_json_binary(&BINDING_PROPOSAL_ERRORS.may_load(deps.storage, id)?)
+            }
+        },
+        _ => PrePropose::default().query(deps, env, msg),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, PreProposeError> {
+    let check_id = msg.id;
+    let error = match msg.result {
+        SubMsgResult::Err(error) => error,
+        SubMsgResult::Ok(_) => return Err(PreProposeError::UnknownReplyID {}),
+    };
+    let binding_id = CHECK_ID_TO_BINDING_ID
+        .may_load(deps.storage, check_id)?
+        .ok_or(PreProposeError::UnknownReplyID {})?;
+
+    CHECK_ID_TO_BINDING_ID.remove(deps.storage, check_id);
+    BINDING_ID_TO_CHECK_ID.remove(deps.storage, binding_id);
+    BINDING_PROPOSAL_ERRORS.save(deps.storage, check_id, &error)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "binding_proposal_failed")
+        .add_attribute("proposal", check_id.to_string())
+        .add_attribute("error", error))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(mut deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, PreProposeError> {
+    let res = PrePropose::default().migrate(deps.branch(), msg);
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    res
+}
@@ -0,0 +1,124 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Empty;
+use dao_pre_propose_base::msg::{
+    ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, MigrateMsg as MigrateBase,
+    QueryMsg as QueryBase,
+};
+use dao_voting::{
+    multiple_choice::{MultipleChoiceAutoVote, MultipleChoiceOptions},
+    proposal::{MultipleChoiceProposeMsg, SingleChoiceProposeMsg},
+};
+
+#[cw_serde]
+pub enum ProposeMessage {
+    Propose {
+        title: String,
+        description: String,
+        choices: MultipleChoiceOptions,
+        vote: Option<MultipleChoiceAutoVote>,
+    },
+}
+
+#[cw_serde]
+pub struct InstantiateExt {
+    /// The `dao-proposal-single` module that binding proposals are
+    /// created in once a temperature check passes.
+    pub binding_proposal_module: String,
+}
+
+#[cw_serde]
+pub enum ExecuteExt {
+    /// Updates the module binding proposals are created in. Only
+    /// callable by the DAO.
+    UpdateBindingProposalModule { address: String },
+    /// Creates the binding proposal for a passed temperature check if
+    /// it was not created when the temperature check passed. Callable
+    /// by anyone.
+    CreateBindingProposal { id: u64 },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryExt {
+    /// The module binding proposals are created in.
+    #[returns(cosmwasm_std::Addr)]
+    BindingProposalModule {},
+    /// The options of a temperature check as they were proposed,
+    /// including the messages that are moved into its binding
+    /// proposal.
+    #[returns(::std::option::Option<Vec<dao_voting::multiple_choice::MultipleChoiceOption>>)]
+    Choices { id: u64 },
+    #[returns(::std::option::Option<u64>)]
+    BindingProposalIdForCheckId { id: u64 },
+    #[returns(::std::option::Option<u64>)]
+    CheckIdForBindingProposalId { id: u64 },
+    /// Why creating the binding proposal of a temperature check last
+    /// failed, if it has not since been created.
+    #[returns(::std::option::Option<String>)]
+    BindingProposalError { id: u64 },
+}
+
+pub type InstantiateMsg = InstantiateBase<InstantiateExt>;
+pub type ExecuteMsg = ExecuteBase<ProposeMessage, ExecuteExt>;
+pub type QueryMsg = QueryBase<QueryExt>;
+pub type MigrateMsg = MigrateBase<Empty>;
+
+/// The proposal hooks of the temperature check module. This module
+/// must be added as a proposal hook receiver of the temperature check
+/// module so that binding proposals are created when temperature
+/// checks pass.
+#[cw_serde]
+pub enum ProposalHookMsg {
+    NewProposal {
+        id: u64,
+        proposer: String,
+    },
+    ProposalStatusChanged {
+        id: u64,
+        old_status: String,
+        new_status: String,
+    },
+    ProposalAmended {
+        id: u64,
+        revision: u64,
+    },
+}
+
+#[cw_serde]
+pub enum ProposalHookExecuteMsg {
+    ProposalHook(ProposalHookMsg),
+}
+
+/// The messages executed by this module: its `ExecuteMsg`, and the
+/// proposal hooks of the temperature check module.
+///
+/// This is untagged so that both may be sent to the module as they
+/// are.
+#[cw_serde]
+#[serde(untagged)]
+pub enum ExecuteOrHookMsg {
+    Execute(ExecuteMsg),
+    Hook(ProposalHookExecuteMsg),
+}
+
+/// Internal version of the propose message that includes the
+/// `proposer` field. The module will fill this in based on the sender
+/// of the external message.
+#[cw_serde]
+pub enum ProposeMessageInternal {
+    Propose(MultipleChoiceProposeMsg),
+}
+
+/// The propose message of a `dao-proposal-single` module that does
+/// not have a pre-propose module.
+#[cw_serde]
+pub enum BindingProposeMessage {
+    Propose(SingleChoiceProposeMsg),
+}
+
+/// The query of a `dao-proposal-single` module used to find how
+/// binding proposals may be created.
+#[cw_serde]
+pub enum BindingQueryMessage {
+    ProposalCreationPolicy {},
+}
@@ -0,0 +1,16 @@
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, Map};
+use dao_voting::multiple_choice::MultipleChoiceOption;
+
+// The dao-proposal-single module binding proposals are created in
+pub const BINDING_PROPOSAL_MODULE: Item<Addr> = Item::new("binding_proposal_module");
+// Maps temperature check ids to their options as they were proposed,
+// messages included
+pub const CHOICES: Map<u64, Vec<MultipleChoiceOption>> = Map::new("choices");
+// Maps temperature check ids to binding proposal ids
+pub const CHECK_ID_TO_BINDING_ID: Map<u64, u64> = Map::new("check_to_binding");
+// Maps binding proposal ids to temperature check ids
+pub const BINDING_ID_TO_CHECK_ID: Map<u64, u64> = Map::new("binding_to_check");
+// Maps temperature check ids to why creating their binding proposal
+// last failed, until it is created
+pub const BINDING_PROPOSAL_ERRORS: Map<u64, String> = Map::new("binding_proposal_errors");
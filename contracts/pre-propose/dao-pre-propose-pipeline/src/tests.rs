@@ -0,0 +1,616 @@
+use cosmwasm_std::{coins, to_json_binary, Addr, BankMsg, CosmosMsg, Empty, Uint128};
+use cw20::Cw20Coin;
+use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor, SudoMsg};
+use cw_utils::Duration;
+use dao_interface::state::{Admin, ModuleInstantiateInfo, ProposalModule};
+use dao_pre_propose_base::error::PreProposeError;
+use dao_testing::{
+    contracts::{pre_propose_single_contract, proposal_single_contract},
+    helpers::instantiate_with_cw4_groups_governance,
+};
+use dao_voting::{
+    multiple_choice::{
+        MultipleChoiceOption, MultipleChoiceOptions, MultipleChoiceVote, VotingStrategy,
+    },
+    pre_propose::{PreProposeInfo, PreProposeSubmissionPolicy, ProposalCreationPolicy},
+    status::Status,
+    threshold::{PercentageThreshold, Threshold},
+    voting::Vote,
+};
+
+use crate::{contract::*, msg::*};
+
+fn dao_proposal_multiple_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_proposal_multiple::contract::execute,
+        dao_proposal_multiple::contract::instantiate,
+        dao_proposal_multiple::contract::query,
+    )
+    .with_reply(dao_proposal_multiple::contract::reply);
+    Box::new(contract)
+}
+
+fn dao_pre_propose_pipeline_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(execute, instantiate, query)
+        .with_reply(reply)
+        .with_migrate(migrate);
+    Box::new(contract)
+}
+
+fn get_binding_module_instantiate(
+    pre_propose_info: PreProposeInfo,
+) -> dao_proposal_single::msg::InstantiateMsg {
+    dao_proposal_single::msg::InstantiateMsg {
+        threshold: Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        },
+        max_voting_period: Duration::Time(86400),
+        min_voting_period: None,
+        only_members_execute: false,
+        allow_revoting: false,
+        pre_propose_info,
+        close_proposal_on_execution_failure: false,
+        review_period: None,
+        veto: None,
+    }
+}
+
+struct DefaultTestSetup {
+    core_addr: Addr,
+    binding_module: Addr,
+    check_module: Addr,
+    pipeline: Addr,
+}
+
+/// Creates a DAO with a `dao-proposal-single` binding module, then
+/// adds a `dao-proposal-multiple` temperature check module with this
+/// module as its pre-propose module and proposal hook receiver.
+fn setup_default_test(app: &mut App, binding_pre_propose: bool) -> DefaultTestSetup {
+    let single_id = app.store_code(proposal_single_contract());
+    let multiple_id = app.store_code(dao_proposal_multiple_contract());
+    let pipeline_id = app.store_code(dao_pre_propose_pipeline_contract());
+
+    let binding_pre_propose_info = if binding_pre_propose {
+        let pre_propose_id = app.store_code(pre_propose_single_contract());
+        PreProposeInfo::ModuleMayPropose {
+            info: ModuleInstantiateInfo {
+                code_id: pre_propose_id,
+                msg: to_json_binary(&dao_pre_propose_single::InstantiateMsg {
+                    deposit_info: None,
+                    submission_policy: PreProposeSubmissionPolicy::Anyone { denylist: vec![] },
+                    extension: Empty::default(),
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "binding pre-propose module".to_string(),
+            },
+        }
+    } else {
+        PreProposeInfo::AnyoneMayPropose {}
+    };
+
+    let core_addr = instantiate_with_cw4_groups_governance(
+        app,
+        single_id,
+        to_json_binary(&get_binding_module_instantiate(binding_pre_propose_info)).unwrap(),
+        Some(vec![
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(9),
+            },
+            Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(8),
+            },
+        ]),
+    );
+    let binding_module = get_proposal_modules(app, &core_addr)
+        .into_iter()
+        .next()
+        .unwrap()
+        .address;
+
+    app.execute_contract(
+        core_addr.clone(),
+        core_addr.clone(),
+        &dao_interface::msg::ExecuteMsg::UpdateProposalModules {
+            to_add: vec![ModuleInstantiateInfo {
+                code_id: multiple_id,
+                msg: to_json_binary(&dao_proposal_multiple::msg::InstantiateMsg {
+                    voting_strategy: VotingStrategy::SingleChoice {
+                        quorum: PercentageThreshold::Majority {},
+                    },
+                    min_voting_period: None,
+                    max_voting_period: Duration::Time(86400),
+                    only_members_execute: false,
+                    allow_revoting: false,
+                    pre_propose_info: PreProposeInfo::ModuleMayPropose {
+                        info: ModuleInstantiateInfo {
+                            code_id: pipeline_id,
+                            msg: to_json_binary(&InstantiateMsg {
+                                deposit_info: None,
+                                submission_policy: PreProposeSubmissionPolicy::Specific {
+                                    dao_members: true,
+                                    allowlist: vec![],
+                                    denylist: vec![],
+                                },
+                                extension: InstantiateExt {
+                                    binding_proposal_module: binding_module.to_string(),
+                                },
+                            })
+                            .unwrap(),
+                            admin: Some(Admin::CoreModule {}),
+                            funds: vec![],
+                            label: "pipeline".to_string(),
+                        },
+                    },
+                    close_proposal_on_execution_failure: false,
+                    veto: None,
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "temperature checks".to_string(),
+            }],
+            to_disable: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let check_module = get_proposal_modules(app, &core_addr)
+        .into_iter()
+        .map(|module| module.address)
+        .find(|address| *address != binding_module)
+        .unwrap();
+    let pipeline = match get_creation_policy(app, &check_module) {
+        ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a module for the proposal creation policy"),
+    };
+    app.execute_contract(
+        core_addr.clone(),
+        check_module.clone(),
+        &dao_proposal_multiple::msg::ExecuteMsg::AddProposalHook {
+            address: pipeline.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The DAO pays out the messages of the winning options.
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: coins(100, "ujuno"),
+    }))
+    .unwrap();
+
+    DefaultTestSetup {
+        core_addr,
+        binding_module,
+        check_module,
+        pipeline,
+    }
+}
+
+fn get_proposal_modules(app: &App, core_addr: &Addr) -> Vec<ProposalModule> {
+    app.wrap()
+        .query_wasm_smart(
+            core_addr,
+            &dao_interface::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap()
+}
+
+fn get_creation_policy(app: &App, proposal_module: &Addr) -> ProposalCreationPolicy {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_module,
+            &dao_proposal_single::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap()
+}
+
+fn payout_message(amount: u128) -> CosmosMsg {
+    BankMsg::Send {
+        to_address: "payee".to_string(),
+        amount: coins(amount, "ujuno"),
+    }
+    .into()
+}
+
+fn make_temperature_check(
+    app: &mut App,
+    check_module: &Addr,
+    pipeline: &Addr,
+    proposer: &str,
+) -> u64 {
+    let id: u64 = app
+        .wrap()
+        .query_wasm_smart(
+            check_module,
+            &dao_proposal_multiple::msg::QueryMsg::NextProposalId {},
+        )
+        .unwrap();
+    app.execute_contract(
+        Addr::unchecked(proposer),
+        pipeline.clone(),
+        &ExecuteMsg::Propose {
+            msg: ProposeMessage::Propose {
+                title: "payout".to_string(),
+                description: "how much should we pay?".to_string(),
+                choices: MultipleChoiceOptions {
+                    options: vec![
+                        MultipleChoiceOption {
+                            title: "ten".to_string(),
+                            description: "pay ten".to_string(),
+                            msgs: vec![payout_message(10)],
+                        },
+                        MultipleChoiceOption {
+                            title: "twenty".to_string(),
+                            description: "pay twenty".to_string(),
+                            msgs: vec![payout_message(20)],
+                        },
+                    ],
+                },
+                vote: None,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    id
+}
+
+fn vote_on_check(app: &mut App, check_module: &Addr, sender: &str, id: u64, option_id: u32) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        check_module.clone(),
+        &dao_proposal_multiple::msg::ExecuteMsg::Vote {
+            proposal_id: id,
+            vote: MultipleChoiceVote { option_id },
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+fn execute_check(app: &mut App, check_module: &Addr, id: u64) {
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        check_module.clone(),
+        &dao_proposal_multiple::msg::ExecuteMsg::Execute { proposal_id: id },
+        &[],
+    )
+    .unwrap();
+}
+
+fn get_binding_id(app: &App, pipeline: &Addr, check_id: u64) -> Option<u64> {
+    app.wrap()
+        .query_wasm_smart(
+            pipeline,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::BindingProposalIdForCheckId { id: check_id },
+            },
+        )
+        .unwrap()
+}
+
+fn get_check_id(app: &App, pipeline: &Addr, binding_id: u64) -> Option<u64> {
+    app.wrap()
+        .query_wasm_smart(
+            pipeline,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::CheckIdForBindingProposalId { id: binding_id },
+            },
+        )
+        .unwrap()
+}
+
+fn get_binding_error(app: &App, pipeline: &Addr, check_id: u64) -> Option<String> {
+    app.wrap()
+        .query_wasm_smart(
+            pipeline,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::BindingProposalError { id: check_id },
+            },
+        )
+        .unwrap()
+}
+
+fn get_binding_proposal(
+    app: &App,
+    binding_module: &Addr,
+    id: u64,
+) -> dao_proposal_single::proposal::SingleChoiceProposal {
+    let res: dao_proposal_single::query::ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            binding_module,
+            &dao_proposal_single::msg::QueryMsg::Proposal { proposal_id: id },
+        )
+        .unwrap();
+    res.proposal
+}
+
+fn get_balance(app: &App, address: &str) -> Uint128 {
+    app.wrap().query_balance(address, "ujuno").unwrap().amount
+}
+
+#[test]
+fn test_passed_check_creates_binding_proposal() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        binding_module,
+        check_module,
+        pipeline,
+    } = setup_default_test(&mut app, false);
+
+    let check_id = make_temperature_check(&mut app, &check_module, &pipeline, "ekez");
+
+    // The messages of each option are kept by the pipeline rather than
+    // the temperature check.
+    let check: dao_proposal_multiple::query::ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &check_module,
+            &dao_proposal_multiple::msg::QueryMsg::Proposal {
+                proposal_id: check_id,
+            },
+        )
+        .unwrap();
+    assert!(check
+        .proposal
+        .choices
+        .iter()
+        .all(|choice| choice.msgs.is_empty()));
+    let choices: Option<Vec<MultipleChoiceOption>> = app
+        .wrap()
+        .query_wasm_smart(
+            &pipeline,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::Choices { id: check_id },
+            },
+        )
+        .unwrap();
+    assert_eq!(choices.unwrap()[1].msgs, vec![payout_message(20)]);
+
+    // Only the temperature check module may send proposal hooks.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pipeline.clone(),
+            &ProposalHookExecuteMsg::ProposalHook(ProposalHookMsg::ProposalStatusChanged {
+                id: check_id,
+                old_status: Status::Open.to_string(),
+                new_status: Status::Passed.to_string(),
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotModule {});
+
+    // The binding proposal is created as soon as the temperature
+    // check passes, without it being executed.
+    vote_on_check(&mut app, &check_module, "ekez", check_id, 1);
+    assert_eq!(get_balance(&app, "payee"), Uint128::zero());
+
+    let binding_id = get_binding_id(&app, &pipeline, check_id).unwrap();
+    assert_eq!(get_check_id(&app, &pipeline, binding_id), Some(check_id));
+    assert_eq!(get_binding_error(&app, &pipeline, check_id), None);
+
+    let binding = get_binding_proposal(&app, &binding_module, binding_id);
+    assert_eq!(binding.title, "payout");
+    assert_eq!(
+        binding.description,
+        "how much should we pay?\n\n## twenty\n\npay twenty"
+    );
+    assert_eq!(binding.msgs, vec![payout_message(20)]);
+    assert_eq!(binding.proposer, pipeline);
+    assert_eq!(binding.status, Status::Open);
+
+    // Executing the temperature check creates nothing more.
+    execute_check(&mut app, &check_module, check_id);
+    assert_eq!(get_binding_id(&app, &pipeline, check_id), Some(binding_id));
+    let next_id: u64 = app
+        .wrap()
+        .query_wasm_smart(
+            &binding_module,
+            &dao_proposal_single::msg::QueryMsg::NextProposalId {},
+        )
+        .unwrap();
+    assert_eq!(next_id, binding_id + 1);
+
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        binding_module.clone(),
+        &dao_proposal_single::msg::ExecuteMsg::Vote {
+            proposal_id: binding_id,
+            vote: Vote::Yes,
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        binding_module,
+        &dao_proposal_single::msg::ExecuteMsg::Execute {
+            proposal_id: binding_id,
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(get_balance(&app, "payee"), Uint128::new(20));
+}
+
+#[test]
+fn test_binding_proposal_through_pre_propose() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        binding_module,
+        check_module,
+        pipeline,
+    } = setup_default_test(&mut app, true);
+
+    let check_id = make_temperature_check(&mut app, &check_module, &pipeline, "keze");
+    vote_on_check(&mut app, &check_module, "ekez", check_id, 0);
+
+    let binding_id = get_binding_id(&app, &pipeline, check_id).unwrap();
+    let binding = get_binding_proposal(&app, &binding_module, binding_id);
+    assert_eq!(binding.msgs, vec![payout_message(10)]);
+    assert_eq!(binding.proposer, pipeline);
+}
+
+#[test]
+fn test_rejected_check_creates_nothing() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        binding_module,
+        check_module,
+        pipeline,
+    } = setup_default_test(&mut app, false);
+
+    let check_id = make_temperature_check(&mut app, &check_module, &pipeline, "ekez");
+    // "None of the above"
+    vote_on_check(&mut app, &check_module, "ekez", check_id, 2);
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        check_module,
+        &dao_proposal_multiple::msg::ExecuteMsg::Close {
+            proposal_id: check_id,
+        },
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(get_binding_id(&app, &pipeline, check_id), None);
+    let next_id: u64 = app
+        .wrap()
+        .query_wasm_smart(
+            &binding_module,
+            &dao_proposal_single::msg::QueryMsg::NextProposalId {},
+        )
+        .unwrap();
+    assert_eq!(next_id, 1);
+
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pipeline,
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::CreateBindingProposal { id: check_id },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::Unsupported {});
+}
+
+#[test]
+fn test_failed_binding_proposal_is_retried() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        binding_module,
+        check_module,
+        pipeline,
+    } = setup_default_test(&mut app, false);
+
+    // Only the DAO may update the binding module.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pipeline.clone(),
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::UpdateBindingProposalModule {
+                    address: "ekez".to_string(),
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotDao {});
+
+    // Point the pipeline at something that is not a proposal module.
+    app.execute_contract(
+        core_addr.clone(),
+        pipeline.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::UpdateBindingProposalModule {
+                address: "nothing".to_string(),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    let check_id = make_temperature_check(&mut app, &check_module, &pipeline, "ekez");
+    vote_on_check(&mut app, &check_module, "ekez", check_id, 0);
+
+    // The temperature check passes, the failure is recorded, and the
+    // pipeline remains its pre-propose module.
+    assert_eq!(get_binding_id(&app, &pipeline, check_id), None);
+    assert!(get_binding_error(&app, &pipeline, check_id).is_some());
+    execute_check(&mut app, &check_module, check_id);
+    assert_eq!(
+        get_creation_policy(&app, &check_module),
+        ProposalCreationPolicy::Module {
+            addr: pipeline.clone()
+        }
+    );
+
+    app.execute_contract(
+        core_addr,
+        pipeline.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::UpdateBindingProposalModule {
+                address: binding_module.to_string(),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked("keze"),
+        pipeline.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::CreateBindingProposal { id: check_id },
+        },
+        &[],
+    )
+    .unwrap();
+
+    let binding_id = get_binding_id(&app, &pipeline, check_id).unwrap();
+    let binding = get_binding_proposal(&app, &binding_module, binding_id);
+    assert_eq!(binding.msgs, vec![payout_message(10)]);
+    assert_eq!(get_binding_error(&app, &pipeline, check_id), None);
+
+    // Binding proposals are only created once.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("keze"),
+            pipeline,
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::CreateBindingProposal { id: check_id },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::Unsupported {});
+}
@@ -14,7 +14,10 @@ use dao_pre_propose_base::{
     },
     state::PreProposeContract,
 };
-use dao_voting::{proposal::SingleChoiceProposeMsg as ProposeMsg, voting::SingleChoiceAutoVote};
+use dao_voting::{
+    proposal::SingleChoiceProposeMsg as ProposeMsg, schedule::ExecutionSchedule,
+    voting::SingleChoiceAutoVote,
+};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -30,6 +33,8 @@ pub enum ProposeMessage {
         description: String,
         msgs: Vec<CosmosMsg<Empty>>,
         vote: Option<SingleChoiceAutoVote>,
+        #[serde(default)]
+        schedule: Option<ExecutionSchedule>,
     },
 }
 
@@ -80,6 +85,7 @@ pub fn execute(
                     description,
                     msgs,
                     vote,
+                    schedule,
                 },
         } => ExecuteInternal::Propose {
             msg: ProposeMessageInternal::Propose(ProposeMsg {
@@ -89,6 +95,7 @@ pub fn execute(
                 description,
                 msgs,
                 vote,
+                schedule,
             }),
         },
         ExecuteMsg::Extension { msg } => ExecuteInternal::Extension { msg },
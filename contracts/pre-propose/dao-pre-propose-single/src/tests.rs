@@ -97,6 +97,7 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        review_period: None,
         veto: None,
     }
 }
@@ -220,6 +221,7 @@ fn make_proposal(
                 description: "description".to_string(),
                 msgs: vec![],
                 vote: None,
+                schedule: None,
             },
         },
         funds,
@@ -879,6 +881,7 @@ fn test_permissions() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    schedule: None,
                 },
             },
             &[],
@@ -971,6 +974,7 @@ fn test_no_deposit_required_members_submission() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    schedule: None,
                 },
             },
             &[],
@@ -1039,6 +1043,7 @@ fn test_anyone_denylist() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    schedule: None,
                 },
             },
             &[],
@@ -1101,6 +1106,7 @@ fn test_specific_allowlist_denylist() {
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
                     vote: None,
+                    schedule: None,
                 },
             },
             &[],
@@ -1159,6 +1165,7 @@ fn test_specific_allowlist_denylist() {
                     description: "I am a member!!!".to_string(),
                     msgs: vec![],
                     vote: None,
+                    schedule: None,
                 },
             },
             &[],
@@ -1195,6 +1202,7 @@ fn test_specific_allowlist_denylist() {
                     description: "I am a member!!!".to_string(),
                     msgs: vec![],
                     vote: None,
+                    schedule: None,
                 },
             },
             &[],
@@ -1293,6 +1301,7 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            review_period: None,
             veto: None,
         }
     };
@@ -1360,6 +1369,7 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            review_period: None,
             veto: None,
         }
     };
@@ -2487,6 +2497,7 @@ fn test_migrate_from_v241() {
                     vote: Vote::Yes,
                     rationale: None,
                 }),
+                schedule: None,
             },
         },
         &[],
@@ -2819,6 +2830,7 @@ fn test_migrate_from_v241_with_policy_update() {
                     description: "d".to_string(),
                     msgs: vec![],
                     vote: None,
+                    schedule: None,
                 },
             },
             &[],
@@ -2840,6 +2852,7 @@ fn test_migrate_from_v241_with_policy_update() {
                 description: "d".to_string(),
                 msgs: vec![],
                 vote: None,
+                schedule: None,
             },
         },
         &[],
@@ -7,12 +7,33 @@ use dao_voting::{
 
 use crate::ContractError;
 
+/// How ranked ballots are tallied to find a winner.
+#[cw_serde]
+#[derive(Copy, Default)]
+pub enum TallyMethod {
+    /// The Condorcet winner wins. If there is no Condorcet winner the
+    /// proposal is rejected.
+    #[default]
+    Condorcet,
+    /// The Condorcet winner wins. If there is no Condorcet winner the
+    /// Schulze winner wins once voting closes, which breaks cycles by
+    /// comparing the strongest paths between candidates.
+    Schulze,
+    /// Instant-runoff voting. Once voting closes, ballots are counted
+    /// in rounds with `Count`. Each round the candidate with the
+    /// least first preferences is eliminated until one candidate has
+    /// a majority.
+    InstantRunoff,
+}
+
 #[cw_serde]
 pub struct UncheckedConfig {
     pub quorum: PercentageThreshold,
     pub voting_period: Duration,
     pub min_voting_period: Option<Duration>,
     pub close_proposals_on_execution_failure: bool,
+    #[serde(default)]
+    pub tally_method: TallyMethod,
 }
 
 #[cw_serde]
@@ -21,6 +42,8 @@ pub(crate) struct Config {
     pub voting_period: Duration,
     pub min_voting_period: Option<Duration>,
     pub close_proposals_on_execution_failure: bool,
+    #[serde(default)]
+    pub tally_method: TallyMethod,
 }
 
 impl UncheckedConfig {
@@ -33,6 +56,7 @@ impl UncheckedConfig {
             close_proposals_on_execution_failure: self.close_proposals_on_execution_failure,
             voting_period,
             min_voting_period,
+            tally_method: self.tally_method,
         })
     }
 }
@@ -1,24 +1,29 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
+    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response, StdResult,
 };
 
 use cw2::set_contract_version;
+use cw_storage_plus::Bound;
 use dao_voting::reply::TaggedReplyId;
 use dao_voting::voting::{get_total_power, get_voting_power};
 
-use crate::config::UncheckedConfig;
+use crate::config::{TallyMethod, UncheckedConfig};
 use crate::error::ContractError;
+use crate::irv::{ranking_key, Count, RoundOutcome, RoundsResponse};
 use crate::msg::{Choice, ExecuteMsg, InstantiateMsg, QueryMsg};
 use crate::proposal::{Proposal, ProposalResponse, Status};
-use crate::state::{next_proposal_id, CONFIG, DAO, PROPOSAL, TALLY, VOTE};
+use crate::state::{next_proposal_id, CONFIG, COUNT, DAO, PROPOSAL, RANKINGS, ROUNDS, TALLY, VOTE};
 use crate::tally::Tally;
 use crate::vote::Vote;
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-condorcet";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The default number of rankings counted by a call to `Count`.
+const DEFAULT_COUNT_LIMIT: u32 = 100;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -78,7 +83,14 @@ pub fn instantiate(
 //     trying to avoid here is a proposal that is created but can not
 //     be voted on or executed.
 // [2] Tally::new computes the winner over the new matrix so that this
-//     is the case.
+//     is the case. for Schulze tallies it also computes the Schulze
+//     winner, which is O(N^3) and so is not computed on vote, but
+//     once on execute or close when there is no Condorcet winner.
+//
+// instant-runoff proposals are the exception: votes additionally
+// update the ballot's ranking, and ballots are counted after voting
+// closes in pages with `Count`, so that no one transaction needs to
+// load every ballot.
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
@@ -92,6 +104,9 @@ pub fn execute(
         ExecuteMsg::Vote { proposal_id, vote } => execute_vote(deps, env, info, proposal_id, vote),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
+        ExecuteMsg::Count { proposal_id, limit } => {
+            execute_count(deps, env, info, proposal_id, limit)
+        }
 
         ExecuteMsg::SetConfig(config) => execute_set_config(deps, info, config),
     }
@@ -127,6 +142,7 @@ fn execute_propose(
         total_power,
         env.block.height,
         config.voting_period.after(&env.block),
+        config.tally_method,
     );
     TALLY.save(deps.storage, id, &tally)?;
 
@@ -163,6 +179,13 @@ fn execute_vote(
     } else {
         let vote = Vote::new(vote, tally.candidates())?;
         VOTE.save(deps.storage, (proposal_id, info.sender.clone()), &vote)?;
+        if tally.method == TallyMethod::InstantRunoff {
+            RANKINGS.update(
+                deps.storage,
+                (proposal_id, ranking_key(&vote)),
+                |power| -> StdResult<_> { Ok(power.unwrap_or_default() + sender_power) },
+            )?;
+        }
 
         let mut tally = tally;
         tally.add_vote(vote, sender_power);
@@ -230,6 +253,82 @@ fn execute_close(
     }
 }
 
+fn execute_count(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u32,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let mut tally = TALLY.load(deps.storage, proposal_id)?;
+    let mut proposal = PROPOSAL.load(deps.storage, proposal_id)?;
+    if !tally.awaiting_count()
+        || !tally.expired(&env.block)
+        || proposal.update_status(&env.block, &tally) != Status::Open
+    {
+        return Err(ContractError::Uncountable {});
+    }
+
+    let mut count = COUNT
+        .may_load(deps.storage, proposal_id)?
+        .unwrap_or_else(|| Count::new(tally.candidates()));
+    let mut budget = limit.unwrap_or(DEFAULT_COUNT_LIMIT) as usize;
+    let mut winner = None;
+
+    while winner.is_none() {
+        let rankings = RANKINGS
+            .prefix(proposal_id)
+            .range(
+                deps.storage,
+                count.last_ranking.clone().map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(budget)
+            .collect::<StdResult<Vec<_>>>()?;
+        budget -= rankings.len();
+        for (ranking, power) in &rankings {
+            count.add_ranking(ranking, *power)?;
+        }
+        if let Some((ranking, _)) = rankings.into_iter().last() {
+            count.last_ranking = Some(ranking);
+        }
+        if budget == 0 {
+            // there may be more rankings to count this round.
+            break;
+        }
+
+        let round = count.eliminated.len() as u32;
+        let (results, outcome) = count.finish_round(&tally)?;
+        ROUNDS.save(deps.storage, (proposal_id, round), &results)?;
+        if let RoundOutcome::Winner(candidate) = outcome {
+            winner = Some(candidate);
+        }
+    }
+
+    let response = Response::default()
+        .add_attribute("method", "count")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("counter", info.sender);
+
+    match winner {
+        Some(winner) => {
+            tally.set_counted(winner);
+            TALLY.save(deps.storage, proposal_id, &tally)?;
+            COUNT.remove(deps.storage, proposal_id);
+
+            proposal.update_status(&env.block, &tally);
+            PROPOSAL.save(deps.storage, proposal_id, &proposal)?;
+
+            Ok(response.add_attribute("winner", winner.to_string()))
+        }
+        None => {
+            COUNT.save(deps.storage, proposal_id, &count)?;
+            Ok(response.add_attribute("round", count.eliminated.len().to_string()))
+        }
+    }
+}
+
 fn execute_set_config(
     deps: DepsMut,
     info: MessageInfo,
@@ -255,6 +354,13 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             to_json_binary(&ProposalResponse { proposal, tally })
         }
         QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Rounds { proposal_id } => to_json_binary(&RoundsResponse {
+            rounds: ROUNDS
+                .prefix(proposal_id)
+                .range(deps.storage, None, None, Order::Ascending)
+                .map(|r| r.map(|(_, round)| round))
+                .collect::<StdResult<_>>()?,
+        }),
         QueryMsg::NextProposalId {} => to_json_binary(&next_proposal_id(deps.storage)?),
         QueryMsg::Dao {} => to_json_binary(&DAO.load(deps.storage)?),
         QueryMsg::Info {} => to_json_binary(&dao_interface::voting::InfoResponse {
@@ -37,4 +37,12 @@ pub enum ContractError {
 
     #[error("must specify at least one choice for proposal")]
     ZeroChoices {},
+
+    #[error(
+        "only instant-runoff proposals that have closed for voting and met quorum may be counted"
+    )]
+    Uncountable {},
+
+    #[error("no candidates remain in the instant-runoff count")]
+    NoRemainingCandidates {},
 }
@@ -0,0 +1,206 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+use crate::{tally::Tally, vote::Vote, ContractError};
+
+/// The state of an instant-runoff count that spans more than one
+/// transaction.
+///
+/// Ballots are stored aggregated by ranking, so each round of the
+/// count is a pass over every distinct ranking. As the number of
+/// distinct rankings grows with the number of voters, a round may not
+/// fit in a single transaction and is instead counted in pages.
+#[cw_serde]
+pub struct Count {
+    /// Candidates eliminated in previous rounds, in order of
+    /// elimination.
+    pub eliminated: Vec<u32>,
+    /// Voting power counted so far this round for each candidate.
+    pub totals: Vec<Uint128>,
+    /// The last ranking counted this round.
+    pub last_ranking: Option<Vec<u8>>,
+}
+
+/// A completed round of an instant-runoff count.
+#[cw_serde]
+pub struct Round {
+    /// The voting power of ballots whose highest ranked remaining
+    /// candidate is each candidate. Eliminated candidates have zero.
+    pub totals: Vec<Uint128>,
+    /// The candidate eliminated this round. `None` in the final
+    /// round, in which a candidate had a majority.
+    pub eliminated: Option<u32>,
+}
+
+#[cw_serde]
+pub struct RoundsResponse {
+    pub rounds: Vec<Round>,
+}
+
+pub(crate) enum RoundOutcome {
+    Eliminated(u32),
+    Winner(u32),
+}
+
+/// Encodes a ballot as a storage key so that identical ballots are
+/// counted together.
+pub(crate) fn ranking_key(vote: &Vote) -> Vec<u8> {
+    vote.iter().flat_map(|c| c.to_be_bytes()).collect()
+}
+
+impl Count {
+    pub fn new(candidates: u32) -> Self {
+        Self {
+            eliminated: vec![],
+            totals: vec![Uint128::zero(); candidates as usize],
+            last_ranking: None,
+        }
+    }
+
+    fn remaining(&self) -> Vec<u32> {
+        (0..self.totals.len() as u32)
+            .filter(|c| !self.eliminated.contains(c))
+            .collect()
+    }
+
+    /// Counts `power` towards the highest ranked candidate in
+    /// `ranking` that has not been eliminated. Ballots rank every
+    /// candidate, so there is always one.
+    pub(crate) fn add_ranking(
+        &mut self,
+        ranking: &[u8],
+        power: Uint128,
+    ) -> Result<(), ContractError> {
+        let top = ranking
+            .chunks_exact(4)
+            .map(|c| u32::from_be_bytes([c[0], c[1], c[2], c[3]]))
+            .find(|c| !self.eliminated.contains(c))
+            .ok_or(ContractError::NoRemainingCandidates {})?;
+        self.totals[top as usize] += power;
+        Ok(())
+    }
+
+    /// Finishes the current round once every ranking has been
+    /// counted. If a candidate has a majority of the counted voting
+    /// power, or is the only candidate remaining, they win. Otherwise
+    /// the candidate with the least voting power is eliminated and a
+    /// new round begins.
+    ///
+    /// Ties for the least voting power are broken by eliminating the
+    /// tied candidate with the most head to head defeats against the
+    /// others, and then the candidate with the highest index.
+    pub(crate) fn finish_round(
+        &mut self,
+        tally: &Tally,
+    ) -> Result<(Round, RoundOutcome), ContractError> {
+        let candidates = self.totals.len();
+        let remaining = self.remaining();
+        let counted: Uint128 = remaining.iter().map(|&c| self.totals[c as usize]).sum();
+
+        // first candidate with the most voting power.
+        let leader = remaining
+            .iter()
+            .copied()
+            .reduce(|a, b| {
+                if self.totals[b as usize] > self.totals[a as usize] {
+                    b
+                } else {
+                    a
+                }
+            })
+            .ok_or(ContractError::NoRemainingCandidates {})?;
+        let lead = self.totals[leader as usize];
+
+        let outcome = if remaining.len() == 1 || lead > counted - lead {
+            RoundOutcome::Winner(leader)
+        } else {
+            let least = remaining
+                .iter()
+                .map(|&c| self.totals[c as usize])
+                .min()
+                .ok_or(ContractError::NoRemainingCandidates {})?;
+            let tied: Vec<u32> = remaining
+                .iter()
+                .copied()
+                .filter(|&c| self.totals[c as usize] == least)
+                .collect();
+            let eliminated = tied
+                .iter()
+                .copied()
+                .max_by_key(|&c| (tally.pairwise_defeats(c, &tied), c))
+                .ok_or(ContractError::NoRemainingCandidates {})?;
+            RoundOutcome::Eliminated(eliminated)
+        };
+
+        let round = Round {
+            totals: std::mem::replace(&mut self.totals, vec![Uint128::zero(); candidates]),
+            eliminated: match outcome {
+                RoundOutcome::Eliminated(c) => Some(c),
+                RoundOutcome::Winner(_) => None,
+            },
+        };
+        if let RoundOutcome::Eliminated(c) = outcome {
+            self.eliminated.push(c);
+        }
+        self.last_ranking = None;
+
+        Ok((round, outcome))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cw_utils::Expiration;
+
+    use super::*;
+    use crate::config::TallyMethod;
+
+    #[test]
+    fn test_elimination_tie_break() {
+        let candidates = 3;
+        let mut tally = Tally::new(
+            candidates,
+            Uint128::new(4),
+            0,
+            Expiration::Never {},
+            TallyMethod::InstantRunoff,
+        );
+        let mut count = Count::new(candidates);
+        for vote in [vec![0, 1, 2], vec![0, 1, 2], vec![1, 2, 0], vec![2, 0, 1]] {
+            let vote = Vote::new(vote, candidates).unwrap();
+            count
+                .add_ranking(&ranking_key(&vote), Uint128::one())
+                .unwrap();
+            tally.add_vote(vote, Uint128::one());
+        }
+
+        // 1 and 2 tie for the least first preferences, and 1 beats 2
+        // head to head, so 2 is eliminated.
+        let (round, outcome) = count.finish_round(&tally).unwrap();
+        assert_eq!(
+            round,
+            Round {
+                totals: vec![Uint128::new(2), Uint128::one(), Uint128::one()],
+                eliminated: Some(2),
+            }
+        );
+        assert!(matches!(outcome, RoundOutcome::Eliminated(2)));
+
+        for (vote, power) in [(vec![0, 1, 2], 2), (vec![1, 2, 0], 1), (vec![2, 0, 1], 1)] {
+            let vote = Vote::new(vote, candidates).unwrap();
+            count
+                .add_ranking(&ranking_key(&vote), Uint128::new(power))
+                .unwrap();
+        }
+
+        let (round, outcome) = count.finish_round(&tally).unwrap();
+        assert_eq!(
+            round,
+            Round {
+                totals: vec![Uint128::new(3), Uint128::one(), Uint128::zero()],
+                eliminated: None,
+            }
+        );
+        assert!(matches!(outcome, RoundOutcome::Winner(0)));
+    }
+}
@@ -2,6 +2,7 @@ mod cell;
 pub mod config;
 pub mod contract;
 mod error;
+pub mod irv;
 mod m;
 pub mod msg;
 pub mod proposal;
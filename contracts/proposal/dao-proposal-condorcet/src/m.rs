@@ -172,6 +172,53 @@ impl M {
             no_winnable_columns,
         }
     }
+
+    /// Computes the Schulze winner of M, if there is exactly one.
+    ///
+    /// The strength of a path between two candidates is its weakest
+    /// margin of victory, and p[x][y] is the strength of the
+    /// strongest path from x to y. The Schulze winner is the
+    /// candidate for which p[x][y] >= p[y][x] for every other y.
+    ///
+    /// This is O(N^3), so it should only be called when there is no
+    /// positive column.
+    pub fn schulze_winner(&self) -> Option<u32> {
+        let n = self.n as usize;
+        let mut p = vec![Uint128::zero(); n * n];
+        for x in 0..n {
+            for y in 0..n {
+                if x != y {
+                    if let Cell::Positive(margin) = self.get((x as u32, y as u32)) {
+                        p[x * n + y] = margin;
+                    }
+                }
+            }
+        }
+
+        for i in 0..n {
+            for j in 0..n {
+                if i == j {
+                    continue;
+                }
+                for k in 0..n {
+                    if k == i || k == j {
+                        continue;
+                    }
+                    let through_i = p[j * n + i].min(p[i * n + k]);
+                    if through_i > p[j * n + k] {
+                        p[j * n + k] = through_i;
+                    }
+                }
+            }
+        }
+
+        let mut winners =
+            (0..n).filter(|&x| (0..n).all(|y| x == y || p[x * n + y] >= p[y * n + x]));
+        match (winners.next(), winners.next()) {
+            (Some(winner), None) => Some(winner as u32),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -29,6 +29,15 @@ pub enum ExecuteMsg {
     Close {
         proposal_id: u32,
     },
+    /// Counts the ballots of an instant-runoff proposal once voting
+    /// has closed, counting at most `limit` distinct rankings.
+    /// Instant-runoff proposals remain open until counted, so this
+    /// must be called until the count finds a winner before the
+    /// proposal may be executed.
+    Count {
+        proposal_id: u32,
+        limit: Option<u32>,
+    },
     SetConfig(UncheckedConfig),
 }
 
@@ -40,4 +49,6 @@ pub enum QueryMsg {
     Proposal { id: u32 },
     #[returns(crate::config::Config)]
     Config {},
+    #[returns(crate::irv::RoundsResponse)]
+    Rounds { proposal_id: u32 },
 }
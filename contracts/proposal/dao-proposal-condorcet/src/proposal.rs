@@ -7,7 +7,7 @@ use dao_voting::{
 };
 
 use crate::{
-    config::Config,
+    config::{Config, TallyMethod},
     msg::Choice,
     tally::{Tally, Winner},
 };
@@ -31,7 +31,9 @@ pub struct Proposal {
 #[cw_serde]
 #[derive(Copy)]
 pub enum Status {
-    /// The proposal is open for voting.
+    /// The proposal is open for voting. Instant-runoff proposals
+    /// remain open after voting closes until their ballots have been
+    /// counted with `Count`.
     Open,
     /// The proposal has been rejected.
     Rejected,
@@ -81,7 +83,17 @@ fn status(block: &BlockInfo, proposal: &Proposal, tally: &Tally) -> Status {
                 match winner {
                     Winner::Never => Status::Rejected,
                     Winner::None => {
-                        if expired {
+                        // Schulze proposals without a Condorcet
+                        // winner are decided once voting closes, and
+                        // instant-runoff proposals stay open after
+                        // voting closes until their ballots are
+                        // counted.
+                        if expired && tally.method == TallyMethod::Schulze {
+                            match tally.schulze_winner() {
+                                Some(winner) => Status::Passed { winner },
+                                None => Status::Rejected,
+                            }
+                        } else if expired && !tally.awaiting_count() {
                             Status::Rejected
                         } else {
                             Status::Open
@@ -161,7 +173,10 @@ impl Proposal {
         let msgs = self.choices[winner as usize].msgs.clone();
         let core_exec = WasmMsg::Execute {
             contract_addr: dao.into_string(),
-            msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook { msgs })?,
+            msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
+                msgs,
+                proposal_id: Some(self.id as u64),
+            })?,
             funds: vec![],
         };
         Ok(if self.close_on_execution_failure {
@@ -1,7 +1,13 @@
-use cosmwasm_std::{Addr, StdResult, Storage};
+use cosmwasm_std::{Addr, StdResult, Storage, Uint128};
 use cw_storage_plus::{Item, Map};
 
-use crate::{config::Config, proposal::Proposal, tally::Tally, vote::Vote};
+use crate::{
+    config::Config,
+    irv::{Count, Round},
+    proposal::Proposal,
+    tally::Tally,
+    vote::Vote,
+};
 
 pub(crate) const DAO: Item<Addr> = Item::new("dao");
 pub(crate) const CONFIG: Item<Config> = Item::new("config");
@@ -10,6 +16,12 @@ pub(crate) const TALLY: Map<u32, Tally> = Map::new("tallys");
 pub(crate) const PROPOSAL: Map<u32, Proposal> = Map::new("proposals");
 pub(crate) const VOTE: Map<(u32, Addr), Vote> = Map::new("votes");
 
+// instant-runoff ballots aggregated by ranking, the state of counts
+// in progress, and the rounds of completed counts.
+pub(crate) const RANKINGS: Map<(u32, Vec<u8>), Uint128> = Map::new("rankings");
+pub(crate) const COUNT: Map<u32, Count> = Map::new("counts");
+pub(crate) const ROUNDS: Map<(u32, u32), Round> = Map::new("rounds");
+
 pub(crate) fn next_proposal_id(storage: &dyn Storage) -> StdResult<u32> {
     PROPOSAL
         .keys(storage, None, None, cosmwasm_std::Order::Descending)
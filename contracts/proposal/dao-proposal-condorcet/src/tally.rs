@@ -3,6 +3,8 @@ use cosmwasm_std::{BlockInfo, Uint128};
 use cw_utils::Expiration;
 
 use crate::{
+    cell::Cell,
+    config::TallyMethod,
     m::{Stats, M},
     vote::Vote,
 };
@@ -29,7 +31,13 @@ pub struct Tally {
     /// Amount of voting power that has yet to vote in this tally.
     pub power_outstanding: Uint128,
     /// The current winner. Always up to date and updated on vote.
+    /// For instant-runoff tallies, set once ballots are counted. For
+    /// Schulze tallies without a Condorcet winner, the Schulze winner
+    /// is found with `schulze_winner` once voting closes.
     pub winner: Winner,
+    /// How ballots are tallied to find the winner.
+    #[serde(default)]
+    pub method: TallyMethod,
 }
 
 #[cw_serde]
@@ -47,6 +55,7 @@ impl Tally {
         total_power: Uint128,
         start_height: u64,
         expiration: Expiration,
+        method: TallyMethod,
     ) -> Self {
         let mut tally = Self {
             m: M::new(candidates),
@@ -54,12 +63,18 @@ impl Tally {
             winner: Winner::None,
             start_height,
             expiration,
+            method,
         };
         // compute even though this will always be Winner::None so
         // that creating a tally has the same compute cost of adding a
         // vote which is needed so that gas(proposal_creation) >=
         // gas(vote).
         tally.winner = tally.winner();
+        // likewise, compute the Schulze winner so that
+        // gas(proposal_creation) >= gas(execute) for Schulze tallies.
+        if method == TallyMethod::Schulze {
+            tally.schulze_winner();
+        }
         tally
     }
 
@@ -94,7 +109,46 @@ impl Tally {
         self.winner = self.winner();
     }
 
+    /// Returns true if this is an instant-runoff tally whose ballots
+    /// have not yet been counted.
+    pub fn awaiting_count(&self) -> bool {
+        self.method == TallyMethod::InstantRunoff && self.winner == Winner::None
+    }
+
+    /// Sets the winner of an instant-runoff tally once its ballots
+    /// have been counted.
+    pub(crate) fn set_counted(&mut self, winner: u32) {
+        debug_assert!(self.awaiting_count());
+
+        self.winner = Winner::Some(winner)
+    }
+
+    /// Returns the Schulze winner of the tally, if there is exactly
+    /// one. This is O(N^3) in the number of candidates, so it is only
+    /// computed once voting closes on Schulze tallies without a
+    /// Condorcet winner rather than on every vote.
+    pub fn schulze_winner(&self) -> Option<u32> {
+        self.m.schulze_winner()
+    }
+
+    /// Returns the number of candidates in `candidates` that beat
+    /// `candidate` head to head. Used to break ties when eliminating
+    /// candidates in an instant-runoff count.
+    pub(crate) fn pairwise_defeats(&self, candidate: u32, candidates: &[u32]) -> usize {
+        candidates
+            .iter()
+            .filter(|&&other| {
+                other != candidate && matches!(self.m.get((other, candidate)), Cell::Positive(_))
+            })
+            .count()
+    }
+
     fn winner(&self) -> Winner {
+        // instant-runoff tallies have no winner until their ballots
+        // are counted after voting closes.
+        if self.method == TallyMethod::InstantRunoff {
+            return Winner::None;
+        }
         match self.m.stats(self.power_outstanding) {
             Stats::PositiveColumn { col, min_margin } => {
                 if min_margin > self.power_outstanding {
@@ -103,6 +157,9 @@ impl Tally {
                     Winner::Some(col)
                 }
             }
+            // a Schulze tally without a Condorcet winner is decided
+            // by `schulze_winner` once voting closes.
+            Stats::NoPositiveColumn { .. } if self.method == TallyMethod::Schulze => Winner::None,
             Stats::NoPositiveColumn {
                 no_winnable_columns,
             } => {
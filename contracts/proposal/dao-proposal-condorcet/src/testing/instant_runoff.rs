@@ -0,0 +1,118 @@
+use cosmwasm_std::Uint128;
+
+use crate::{config::TallyMethod, irv::Round, proposal::Status, tally::Winner, ContractError};
+
+use super::{
+    is_error,
+    suite::{unimportant_message, Suite, SuiteBuilder},
+};
+
+fn instant_runoff_suite() -> Suite {
+    let mut suite = SuiteBuilder::default().with_voters(&[("a", 40), ("b", 35), ("c", 25)]);
+    suite.instantiate.tally_method = TallyMethod::InstantRunoff;
+    let mut suite = suite.build();
+
+    suite
+        .propose(suite.sender(), vec![vec![unimportant_message()]; 3])
+        .unwrap();
+    suite.vote("a", 1, vec![0, 1, 2, 3]).unwrap();
+    suite.vote("b", 1, vec![1, 0, 2, 3]).unwrap();
+    suite.vote("c", 1, vec![2, 1, 0, 3]).unwrap();
+    suite
+}
+
+fn expected_rounds() -> Vec<Round> {
+    let totals = |t: [u128; 4]| t.into_iter().map(Uint128::new).collect::<Vec<_>>();
+    vec![
+        Round {
+            totals: totals([40, 35, 25, 0]),
+            eliminated: Some(3),
+        },
+        Round {
+            totals: totals([40, 35, 25, 0]),
+            eliminated: Some(2),
+        },
+        Round {
+            totals: totals([40, 60, 0, 0]),
+            eliminated: None,
+        },
+    ]
+}
+
+#[test]
+fn test_instant_runoff() {
+    let mut suite = instant_runoff_suite();
+
+    // ballots may not be counted while voting is open.
+    let err = suite.count("a", 1, None);
+    is_error!(err, &ContractError::Uncountable {}.to_string());
+
+    // voting has closed, but the proposal stays open until counted.
+    suite.a_week_passes();
+    let (winner, status) = suite.query_winner_and_status(1);
+    assert_eq!(winner, Winner::None);
+    assert_eq!(status, Status::Open);
+
+    // anyone may count.
+    suite.count("someone", 1, None).unwrap();
+
+    let (winner, status) = suite.query_winner_and_status(1);
+    assert_eq!(winner, Winner::Some(1));
+    assert_eq!(status, Status::Passed { winner: 1 });
+    assert_eq!(suite.query_rounds(1).rounds, expected_rounds());
+
+    // ballots may only be counted once.
+    let err = suite.count("a", 1, None);
+    is_error!(err, &ContractError::Uncountable {}.to_string());
+}
+
+#[test]
+fn test_instant_runoff_paginated_count() {
+    let mut suite = instant_runoff_suite();
+    suite.a_week_passes();
+
+    let mut counts = 0;
+    while suite.query_winner_and_status(1).1 == Status::Open {
+        suite.count("a", 1, Some(2)).unwrap();
+        counts += 1;
+    }
+    assert_eq!(counts, 5);
+
+    assert_eq!(
+        suite.query_winner_and_status(1),
+        (Winner::Some(1), Status::Passed { winner: 1 })
+    );
+    assert_eq!(suite.query_rounds(1).rounds, expected_rounds());
+}
+
+#[test]
+fn test_instant_runoff_quorum() {
+    let mut suite = SuiteBuilder::default().with_voters(&[("a", 10), ("b", 90)]);
+    suite.instantiate.tally_method = TallyMethod::InstantRunoff;
+    let mut suite = suite.build();
+
+    suite
+        .propose(suite.sender(), vec![vec![unimportant_message()]])
+        .unwrap();
+    suite.vote("a", 1, vec![0, 1]).unwrap();
+    suite.a_week_passes();
+
+    // proposals that do not meet quorum are rejected without being
+    // counted.
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Rejected);
+    let err = suite.count("a", 1, None);
+    is_error!(err, &ContractError::Uncountable {}.to_string());
+    suite.close("a", 1).unwrap();
+}
+
+#[test]
+fn test_count_condorcet_proposal() {
+    let mut suite = SuiteBuilder::default().with_proposal(1).build();
+    suite.vote(suite.sender(), 1, vec![0, 1]).unwrap();
+    suite.a_week_passes();
+
+    let err = suite.count(suite.sender(), 1, None);
+    is_error!(err, &ContractError::Uncountable {}.to_string());
+    assert!(suite.query_rounds(1).rounds.is_empty());
+}
@@ -2,7 +2,7 @@ use cosmwasm_std::Decimal;
 use cw_utils::Duration;
 use dao_voting::threshold::PercentageThreshold;
 
-use crate::config::UncheckedConfig;
+use crate::config::{TallyMethod, UncheckedConfig};
 
 use super::suite::SuiteBuilder;
 
@@ -24,6 +24,7 @@ fn test_instantiate_conflicting_proposal_durations() {
         voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Height(11)),
         close_proposals_on_execution_failure: true,
+        tally_method: TallyMethod::Condorcet,
     })
     .build();
 }
@@ -38,6 +39,7 @@ fn test_instantiate_conflicting_duration_types() {
         voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Time(9)),
         close_proposals_on_execution_failure: true,
+        tally_method: TallyMethod::Condorcet,
     })
     .build();
 }
@@ -49,6 +51,7 @@ fn test_instantiate_open_til_expiry() {
         voting_period: Duration::Height(10),
         min_voting_period: Some(Duration::Height(10)),
         close_proposals_on_execution_failure: true,
+        tally_method: TallyMethod::Condorcet,
     })
     .build();
     SuiteBuilder::with_config(UncheckedConfig {
@@ -56,6 +59,7 @@ fn test_instantiate_open_til_expiry() {
         voting_period: Duration::Time(10),
         min_voting_period: Some(Duration::Time(10)),
         close_proposals_on_execution_failure: true,
+        tally_method: TallyMethod::Condorcet,
     })
     .build();
 }
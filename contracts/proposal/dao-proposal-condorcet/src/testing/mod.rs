@@ -1,3 +1,4 @@
+mod instant_runoff;
 mod instantiation;
 mod proposals;
 mod suite;
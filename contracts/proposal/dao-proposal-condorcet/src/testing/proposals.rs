@@ -2,7 +2,7 @@ use cosmwasm_std::{to_json_binary, WasmMsg};
 use cw_utils::Duration;
 
 use crate::{
-    config::UncheckedConfig,
+    config::{TallyMethod, UncheckedConfig},
     msg::ExecuteMsg,
     proposal::{ProposalResponse, Status},
     tally::Winner,
@@ -176,6 +176,7 @@ fn test_proposal_set_config() {
                     voting_period: config.voting_period,
                     min_voting_period: None,
                     close_proposals_on_execution_failure: false,
+                    tally_method: TallyMethod::Condorcet,
                 }))
                 .unwrap(),
                 funds: vec![],
@@ -199,6 +200,7 @@ fn test_proposal_set_config() {
                     voting_period: config.voting_period,
                     min_voting_period: Some(Duration::Height(10)),
                     close_proposals_on_execution_failure: false,
+                    tally_method: TallyMethod::Condorcet,
                 }))
                 .unwrap(),
                 funds: vec![],
@@ -236,3 +238,33 @@ fn test_execution_fail_handling() {
     // no state changes get committed.
     suite.execute(suite.sender(), 1).unwrap_err();
 }
+
+// a cycle leaves no condorcet winner, and the schulze winner passes
+// once voting closes.
+#[test]
+fn test_schulze_proposal() {
+    let mut suite = SuiteBuilder::default().with_voters(&[("a", 4), ("b", 3), ("c", 2)]);
+    suite.instantiate.tally_method = TallyMethod::Schulze;
+    let mut suite = suite.build();
+
+    suite.propose(suite.sender(), vec![vec![]; 3]).unwrap();
+    suite.vote("a", 1, vec![0, 1, 2, 3]).unwrap();
+    suite.vote("b", 1, vec![1, 2, 0, 3]).unwrap();
+    suite.vote("c", 1, vec![2, 0, 1, 3]).unwrap();
+
+    // the schulze winner is not found until voting closes.
+    suite.a_day_passes();
+    let (winner, status) = suite.query_winner_and_status(1);
+    assert_eq!(winner, Winner::None);
+    assert_eq!(status, Status::Open);
+    let err = suite.execute("a", 1);
+    is_error!(err, &ContractError::Unexecutable {}.to_string());
+
+    suite.a_week_passes();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Passed { winner: 0 });
+
+    suite.execute("a", 1).unwrap();
+    let (_, status) = suite.query_winner_and_status(1);
+    assert_eq!(status, Status::Executed);
+}
@@ -12,8 +12,9 @@ use dao_voting::threshold::PercentageThreshold;
 use dao_voting_cw4::msg::GroupContract;
 
 use crate::{
-    config::{Config, UncheckedConfig},
+    config::{Config, TallyMethod, UncheckedConfig},
     contract::{CONTRACT_NAME, CONTRACT_VERSION},
+    irv::RoundsResponse,
     msg::{Choice, ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::{ProposalResponse, Status},
     tally::Winner,
@@ -40,6 +41,7 @@ impl Default for SuiteBuilder {
                 voting_period: Duration::Time(60 * 60 * 24 * 7),
                 min_voting_period: Some(Duration::Time(60 * 60 * 24)),
                 close_proposals_on_execution_failure: true,
+                tally_method: TallyMethod::Condorcet,
             },
             with_proposal: None,
             with_voters: vec![("sender".to_string(), 10)],
@@ -212,6 +214,13 @@ impl Suite {
         (q.tally.winner, q.proposal.last_status())
     }
 
+    pub fn query_rounds(&self, proposal_id: u32) -> RoundsResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.condorcet, &QueryMsg::Rounds { proposal_id })
+            .unwrap()
+    }
+
     pub fn query_next_proposal_id(&self) -> u32 {
         self.app
             .wrap()
@@ -290,6 +299,22 @@ impl Suite {
             )
             .map(|_| ())
     }
+
+    pub fn count<S: Into<String>>(
+        &mut self,
+        sender: S,
+        proposal_id: u32,
+        limit: Option<u32>,
+    ) -> anyhow::Result<()> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.condorcet.clone(),
+                &ExecuteMsg::Count { proposal_id, limit },
+                &[],
+            )
+            .map(|_| ())
+    }
 }
 
 pub fn unimportant_message() -> CosmosMsg {
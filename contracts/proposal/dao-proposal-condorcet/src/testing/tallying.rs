@@ -2,6 +2,7 @@ use cosmwasm_std::Uint128;
 use cw_utils::Expiration;
 
 use crate::{
+    config::TallyMethod,
     tally::{Tally, Winner},
     vote::Vote,
 };
@@ -9,7 +10,13 @@ use crate::{
 #[test]
 fn test_pair_election() {
     let candidates = 2;
-    let mut tally = Tally::new(candidates, Uint128::new(3), 0, Expiration::Never {});
+    let mut tally = Tally::new(
+        candidates,
+        Uint128::new(3),
+        0,
+        Expiration::Never {},
+        TallyMethod::Condorcet,
+    );
 
     tally.add_vote(Vote::new(vec![0, 1], candidates).unwrap(), Uint128::one());
     tally.add_vote(Vote::new(vec![1, 0], candidates).unwrap(), Uint128::one());
@@ -21,7 +28,13 @@ fn test_pair_election() {
 #[test]
 fn test_triplet_election() {
     let candidates = 3;
-    let mut tally = Tally::new(candidates, Uint128::new(3), 0, Expiration::Never {});
+    let mut tally = Tally::new(
+        candidates,
+        Uint128::new(3),
+        0,
+        Expiration::Never {},
+        TallyMethod::Condorcet,
+    );
 
     tally.add_vote(
         Vote::new(vec![0, 1, 2], candidates).unwrap(),
@@ -45,7 +58,13 @@ fn test_triplet_election() {
 #[test]
 fn test_condorcet_paradox() {
     let candidates = 3;
-    let mut tally = Tally::new(candidates, Uint128::new(6), 0, Expiration::Never {});
+    let mut tally = Tally::new(
+        candidates,
+        Uint128::new(6),
+        0,
+        Expiration::Never {},
+        TallyMethod::Condorcet,
+    );
 
     tally.add_vote(
         Vote::new(vec![0, 2, 1], candidates).unwrap(),
@@ -96,7 +115,13 @@ fn test_condorcet_paradox() {
 #[test]
 fn test_tally_overflow() {
     let candidates = 6;
-    let mut tally = Tally::new(candidates, Uint128::MAX, 0, Expiration::Never {});
+    let mut tally = Tally::new(
+        candidates,
+        Uint128::MAX,
+        0,
+        Expiration::Never {},
+        TallyMethod::Condorcet,
+    );
 
     tally.add_vote(
         Vote::new(vec![1, 2, 3, 4, 5, 0], candidates).unwrap(),
@@ -117,7 +142,13 @@ fn test_tally_overflow() {
 #[test]
 fn test_winner_none() {
     let candidates = 6;
-    let mut tally = Tally::new(candidates, Uint128::new(9), 0, Expiration::Never {});
+    let mut tally = Tally::new(
+        candidates,
+        Uint128::new(9),
+        0,
+        Expiration::Never {},
+        TallyMethod::Condorcet,
+    );
 
     tally.add_vote(
         Vote::new(vec![1, 2, 3, 4, 5, 0], candidates).unwrap(),
@@ -151,3 +182,74 @@ fn test_winner_none() {
     // -2 -2  2  2  4  \
     assert_eq!(tally.winner, Winner::None)
 }
+
+#[test]
+fn test_schulze_breaks_cycle() {
+    let candidates = 3;
+    let new_tally =
+        |method| Tally::new(candidates, Uint128::new(9), 0, Expiration::Never {}, method);
+    let mut condorcet = new_tally(TallyMethod::Condorcet);
+    let mut schulze = new_tally(TallyMethod::Schulze);
+
+    for (vote, power) in [(vec![0, 1, 2], 4), (vec![1, 2, 0], 3), (vec![2, 0, 1], 2)] {
+        condorcet.add_vote(
+            Vote::new(vote.clone(), candidates).unwrap(),
+            Uint128::new(power),
+        );
+        schulze.add_vote(Vote::new(vote, candidates).unwrap(), Uint128::new(power));
+    }
+
+    // 0 beats 1 by 3, 1 beats 2 by 5, and 2 beats 0 by 1.
+    //
+    // ```
+    //   \ -3  1
+    //   3  \ -5
+    //  -1  5  \
+    // ```
+    //
+    // the weakest link in the cycle is 2 > 0, so the strongest path
+    // from 0 to 2 (0 > 1 > 2, strength 3) beats the strongest path
+    // from 2 to 0 (strength 1), and 0 is the Schulze winner.
+    assert_eq!(condorcet.winner, Winner::Never);
+    // the Schulze winner is found once voting closes rather than on
+    // every vote.
+    assert_eq!(schulze.winner, Winner::None);
+    assert_eq!(schulze.schulze_winner(), Some(0));
+}
+
+#[test]
+fn test_schulze_tie() {
+    let candidates = 3;
+    let mut tally = Tally::new(
+        candidates,
+        Uint128::new(3),
+        0,
+        Expiration::Never {},
+        TallyMethod::Schulze,
+    );
+
+    // a perfectly balanced cycle has no Schulze winner.
+    for vote in [vec![0, 1, 2], vec![1, 2, 0], vec![2, 0, 1]] {
+        tally.add_vote(Vote::new(vote, candidates).unwrap(), Uint128::one());
+    }
+    assert_eq!(tally.winner, Winner::None);
+    assert_eq!(tally.schulze_winner(), None);
+}
+
+#[test]
+fn test_instant_runoff_tally_has_no_winner() {
+    let candidates = 2;
+    let mut tally = Tally::new(
+        candidates,
+        Uint128::new(3),
+        0,
+        Expiration::Never {},
+        TallyMethod::InstantRunoff,
+    );
+
+    tally.add_vote(Vote::new(vec![0, 1], candidates).unwrap(), Uint128::new(3));
+
+    // instant-runoff tallies are only decided once counted.
+    assert_eq!(tally.winner, Winner::None);
+    assert!(tally.awaiting_count());
+}
@@ -15,6 +15,7 @@ use dao_hooks::proposal::{
 use dao_hooks::vote::new_vote_hooks;
 use dao_interface::voting::IsActiveResponse;
 use dao_voting::{
+    delegation::{get_delegated_voting_power, get_delegations_at_height},
     multiple_choice::{MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy},
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     proposal::{MultipleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE},
@@ -26,7 +27,10 @@ use dao_voting::{
     voting::{get_total_power, get_voting_power, validate_voting_period},
 };
 
-use crate::{msg::MigrateMsg, state::CREATION_POLICY};
+use crate::{
+    msg::MigrateMsg,
+    state::{CREATION_POLICY, DELEGATION_MODULE, DELEGATION_OVERRIDES},
+};
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::{MultipleChoiceProposal, VoteResult},
@@ -129,6 +133,9 @@ pub fn execute(
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
         }
+        ExecuteMsg::UpdateDelegationModule { module } => {
+            execute_update_delegation_module(deps, info, module)
+        }
         ExecuteMsg::AddProposalHook { address } => {
             execute_add_proposal_hook(deps, env, info, address)
         }
@@ -364,7 +371,7 @@ pub fn execute_veto(
 }
 
 pub fn execute_vote(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     sender: Addr,
     proposal_id: u64,
@@ -392,16 +399,36 @@ pub fn execute_vote(
         return Err(ContractError::Expired { id: proposal_id });
     }
 
-    let vote_power = get_voting_power(
+    let mut vote_power = get_voting_power(
         deps.as_ref(),
         sender.clone(),
         &config.dao,
         Some(prop.start_height),
     )?;
+
+    // Delegates also vote with the voting power delegated to them,
+    // less what delegators have already claimed back by voting
+    // directly on this proposal.
+    let delegation_module = DELEGATION_MODULE.may_load(deps.storage)?;
+    if let Some(delegation_module) = &delegation_module {
+        let delegated = get_delegated_voting_power(
+            deps.as_ref(),
+            delegation_module,
+            &sender,
+            prop.start_height,
+        )?;
+        let overridden = DELEGATION_OVERRIDES
+            .may_load(deps.storage, (proposal_id, &sender))?
+            .unwrap_or_default();
+        vote_power += delegated.saturating_sub(overridden);
+    }
+
     if vote_power.is_zero() {
         return Err(ContractError::NotRegistered {});
     }
 
+    let is_first_vote = !BALLOTS.has(deps.storage, (proposal_id, &sender));
+
     BALLOTS.update(deps.storage, (proposal_id, &sender), |bal| match bal {
         Some(current_ballot) => {
             if prop.allow_revoting {
@@ -431,6 +458,20 @@ pub fn execute_vote(
         }),
     })?;
 
+    // A delegator voting directly takes back the voting power they
+    // delegated, removing it from any delegate who already voted.
+    if is_first_vote {
+        if let Some(delegation_module) = &delegation_module {
+            apply_delegator_override(
+                deps.branch(),
+                delegation_module,
+                &mut prop,
+                proposal_id,
+                &sender,
+            )?;
+        }
+    }
+
     let old_status = prop.status;
 
     prop.votes.add_vote(vote, vote_power)?;
@@ -542,6 +583,7 @@ pub fn execute_execute(
                     contract_addr: config.dao.to_string(),
                     msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
                         msgs: winning_choice.msgs,
+                        proposal_id: Some(proposal_id),
                     })?,
                     funds: vec![],
                 };
@@ -694,6 +736,41 @@ pub fn execute_update_proposal_creation_policy(
         .add_attribute("new_policy", format!("{initial_policy:?}")))
 }
 
+/// Removes the voting power `delegator` delegated from the ballots of
+/// delegates who already voted on the proposal and records it so that
+/// delegates voting later do not count it.
+fn apply_delegator_override(
+    deps: DepsMut,
+    delegation_module: &Addr,
+    prop: &mut MultipleChoiceProposal,
+    proposal_id: u64,
+    delegator: &Addr,
+) -> Result<(), ContractError> {
+    let delegations =
+        get_delegations_at_height(deps.as_ref(), delegation_module, delegator, prop.start_height)?;
+
+    for delegation in delegations {
+        DELEGATION_OVERRIDES.update(
+            deps.storage,
+            (proposal_id, &delegation.delegate),
+            |overridden| -> StdResult<_> {
+                Ok(overridden.unwrap_or_default() + delegation.power)
+            },
+        )?;
+
+        if let Some(mut ballot) =
+            BALLOTS.may_load(deps.storage, (proposal_id, &delegation.delegate))?
+        {
+            let removed = delegation.power.min(ballot.power);
+            prop.votes.remove_vote(ballot.vote, removed)?;
+            ballot.power -= removed;
+            BALLOTS.save(deps.storage, (proposal_id, &delegation.delegate), &ballot)?;
+        }
+    }
+
+    Ok(())
+}
+
 pub fn execute_update_rationale(
     deps: DepsMut,
     info: MessageInfo,
@@ -808,6 +885,34 @@ pub fn execute_remove_vote_hook(
         .add_attribute("address", address))
 }
 
+pub fn execute_update_delegation_module(
+    deps: DepsMut,
+    info: MessageInfo,
+    module: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let module = match module {
+        Some(module) => {
+            let module = deps.api.addr_validate(&module)?;
+            DELEGATION_MODULE.save(deps.storage, &module)?;
+            module.into_string()
+        }
+        None => {
+            DELEGATION_MODULE.remove(deps.storage);
+            "_none".to_string()
+        }
+    };
+
+    Ok(Response::default()
+        .add_attribute("action", "update_delegation_module")
+        .add_attribute("sender", info.sender)
+        .add_attribute("delegation_module", module))
+}
+
 pub fn add_hook(
     hooks: Hooks,
     storage: &mut dyn Storage,
@@ -864,6 +969,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
         QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::DelegationModule {} => {
+            to_json_binary(&DELEGATION_MODULE.may_load(deps.storage)?)
+        }
         QueryMsg::Dao {} => query_dao(deps),
     }
 }
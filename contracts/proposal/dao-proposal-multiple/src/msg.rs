@@ -133,6 +133,12 @@ pub enum ExecuteMsg {
     UpdatePreProposeInfo {
         info: PreProposeInfo,
     },
+    /// Sets the vote delegation module used to apply delegated voting
+    /// power, or removes it if `None`. Only the DAO may call this
+    /// method.
+    UpdateDelegationModule {
+        module: Option<String>,
+    },
     AddProposalHook {
         address: String,
     },
@@ -192,6 +198,10 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Gets the vote delegation module used to apply delegated voting
+    /// power, if any.
+    #[returns(::std::option::Option<::cosmwasm_std::Addr>)]
+    DelegationModule {},
 }
 
 #[cw_serde]
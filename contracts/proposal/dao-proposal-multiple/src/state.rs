@@ -72,3 +72,9 @@ pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+/// The vote delegation module used to apply delegated voting power
+/// (if any).
+pub const DELEGATION_MODULE: Item<Addr> = Item::new("delegation_module");
+/// Voting power withdrawn from each delegate by delegators who voted
+/// directly on a proposal, keyed by (proposal ID, delegate).
+pub const DELEGATION_OVERRIDES: Map<(u64, &Addr), Uint128> = Map::new("delegation_overrides");
@@ -0,0 +1,1105 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Addr, Attribute, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order,
+    Reply, Response, StdError, StdResult, Storage, SubMsg, Timestamp, WasmMsg,
+};
+use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use cw_hooks::Hooks;
+use cw_storage_plus::Bound;
+use cw_utils::{parse_reply_instantiate_data, Duration};
+use dao_hooks::proposal::{
+    new_proposal_hooks, proposal_completed_hooks, proposal_status_changed_hooks,
+};
+use dao_hooks::vote::new_vote_hooks;
+use dao_interface::voting::IsActiveResponse;
+use dao_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
+use dao_voting::proposal::{
+    SingleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE,
+};
+use dao_voting::reply::{
+    failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
+};
+use dao_voting::schedule::{ExecutionSchedule, MAX_EXECUTIONS_PER_CALL};
+use dao_voting::status::Status;
+use dao_voting::threshold::{validate_percentage, PercentageThreshold};
+use dao_voting::veto::{VetoConfig, VetoError};
+use dao_voting::voting::{get_total_power, get_voting_power, Vote, Votes};
+
+use crate::msg::MigrateMsg;
+use crate::proposal::{advance_proposal_id, next_proposal_id, OptimisticProposal};
+use crate::{
+    error::ContractError,
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    query::{
+        ProposalListResponse, ProposalResponse, UpcomingExecution, UpcomingExecutionsResponse,
+        VoteInfo, VoteListResponse, VoteResponse,
+    },
+    state::{
+        Ballot, Config, BALLOTS, CONFIG, CREATION_POLICY, EXECUTION_CURSORS, PROPOSALS,
+        PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS,
+    },
+};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-optimistic";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    validate_percentage(&msg.objection_threshold)?;
+
+    let dao = info.sender;
+
+    let (initial_policy, pre_propose_messages) = msg
+        .pre_propose_info
+        .into_initial_policy_and_messages(dao.clone())?;
+
+    // if veto is configured, validate its fields
+    if let Some(veto_config) = &msg.veto {
+        veto_config.validate(&deps.as_ref(), &msg.max_voting_period)?;
+    };
+
+    let config = Config {
+        objection_threshold: msg.objection_threshold,
+        max_voting_period: msg.max_voting_period,
+        only_members_execute: msg.only_members_execute,
+        allow_revoting: msg.allow_revoting,
+        dao: dao.clone(),
+        close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
+        veto: msg.veto,
+    };
+
+    // Initialize proposal count to zero so that queries return zero
+    // instead of None.
+    PROPOSAL_COUNT.save(deps.storage, &0)?;
+    CONFIG.save(deps.storage, &config)?;
+    CREATION_POLICY.save(deps.storage, &initial_policy)?;
+
+    Ok(Response::default()
+        .add_submessages(pre_propose_messages)
+        .add_attribute("action", "instantiate")
+        .add_attribute("dao", dao))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Propose(propose_msg) => execute_propose(deps, env, info.sender, propose_msg),
+        ExecuteMsg::Vote {
+            proposal_id,
+            vote,
+            rationale,
+        } => execute_vote(deps, env, info.sender, proposal_id, vote, rationale),
+        ExecuteMsg::UpdateRationale {
+            proposal_id,
+            rationale,
+        } => execute_update_rationale(deps, info, proposal_id, rationale),
+        ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
+        ExecuteMsg::ExecuteScheduled { proposal_id, limit } => {
+            execute_execute_scheduled(deps, env, info, proposal_id, limit)
+        }
+        ExecuteMsg::Veto { proposal_id } => execute_veto(deps, env, info, proposal_id),
+        ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
+        ExecuteMsg::UpdateConfig {
+            objection_threshold,
+            max_voting_period,
+            only_members_execute,
+            allow_revoting,
+            dao,
+            close_proposal_on_execution_failure,
+            veto,
+        } => execute_update_config(
+            deps,
+            info,
+            objection_threshold,
+            max_voting_period,
+            only_members_execute,
+            allow_revoting,
+            dao,
+            close_proposal_on_execution_failure,
+            veto,
+        ),
+        ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
+            execute_update_proposal_creation_policy(deps, info, new_info)
+        }
+        ExecuteMsg::AddProposalHook { address } => {
+            execute_add_hook(deps, info, PROPOSAL_HOOKS, "add_proposal_hook", address)
+        }
+        ExecuteMsg::RemoveProposalHook { address } => {
+            execute_remove_hook(deps, info, PROPOSAL_HOOKS, "remove_proposal_hook", address)
+        }
+        ExecuteMsg::AddVoteHook { address } => {
+            execute_add_hook(deps, info, VOTE_HOOKS, "add_vote_hook", address)
+        }
+        ExecuteMsg::RemoveVoteHook { address } => {
+            execute_remove_hook(deps, info, VOTE_HOOKS, "remove_vote_hook", address)
+        }
+    }
+}
+
+pub fn execute_propose(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    ProposeMsg {
+        title,
+        description,
+        msgs,
+        proposer,
+        vote,
+        schedule,
+    }: ProposeMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+
+    // Check that the sender is permitted to create proposals.
+    if !proposal_creation_policy.is_permitted(&sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Determine the appropriate proposer. If this is coming from our
+    // pre-propose module, it must be specified. Otherwise, the
+    // proposer should not be specified.
+    let proposer = match (proposer, &proposal_creation_policy) {
+        (None, ProposalCreationPolicy::Anyone {}) => sender.clone(),
+        // `is_permitted` above checks that an allowed module is
+        // actually sending the propose message.
+        (Some(proposer), ProposalCreationPolicy::Module { .. }) => {
+            deps.api.addr_validate(&proposer)?
+        }
+        _ => return Err(ContractError::InvalidProposer {}),
+    };
+
+    if let Some(schedule) = &schedule {
+        schedule.validate()?;
+    }
+
+    let voting_module: Addr = deps.querier.query_wasm_smart(
+        config.dao.clone(),
+        &dao_interface::msg::QueryMsg::VotingModule {},
+    )?;
+
+    // Voting modules are not required to implement this
+    // query. Lacking an implementation they are active by default.
+    let active_resp: IsActiveResponse = deps
+        .querier
+        .query_wasm_smart(voting_module, &dao_interface::voting::Query::IsActive {})
+        .unwrap_or(IsActiveResponse { active: true });
+
+    if !active_resp.active {
+        return Err(ContractError::InactiveDao {});
+    }
+
+    let total_power = get_total_power(deps.as_ref(), &config.dao, Some(env.block.height))?;
+
+    let proposal = {
+        // Limit mutability to this block.
+        let mut proposal = OptimisticProposal {
+            title,
+            description,
+            proposer: proposer.clone(),
+            start_height: env.block.height,
+            schedule,
+            expiration: config.max_voting_period.after(&env.block),
+            objection_threshold: config.objection_threshold,
+            total_power,
+            msgs,
+            status: Status::Open,
+            votes: Votes::zero(),
+            allow_revoting: config.allow_revoting,
+            veto: config.veto,
+        };
+        // Update the proposal's status. Addresses case where proposal
+        // expires on the same block as it is created.
+        proposal.update_status(&env.block)?;
+        proposal
+    };
+    let id = advance_proposal_id(deps.storage)?;
+
+    // Limit the size of proposals so that they may always be queried.
+    let proposal_size = cosmwasm_std::to_json_vec(&proposal)?.len() as u64;
+    if proposal_size > MAX_PROPOSAL_SIZE {
+        return Err(ContractError::ProposalTooLarge {
+            size: proposal_size,
+            max: MAX_PROPOSAL_SIZE,
+        });
+    }
+
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
+
+    // Auto cast vote if given.
+    let (vote_hooks, vote_attributes) = if let Some(vote) = vote {
+        let response = execute_vote(deps, env, proposer, id, vote.vote, vote.rationale.clone())?;
+        (
+            response.messages,
+            vec![
+                Attribute {
+                    key: "position".to_string(),
+                    value: vote.vote.to_string(),
+                },
+                Attribute {
+                    key: "rationale".to_string(),
+                    value: vote.rationale.unwrap_or_else(|| "_none".to_string()),
+                },
+            ],
+        )
+    } else {
+        (vec![], vec![])
+    };
+
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_submessages(vote_hooks)
+        .add_attribute("action", "propose")
+        .add_attribute("sender", sender)
+        .add_attribute("proposal_id", id.to_string())
+        .add_attributes(vote_attributes)
+        .add_attribute("status", proposal.status.to_string()))
+}
+
+pub fn execute_veto(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    // ensure status is up to date
+    prop.update_status(&env.block)?;
+    let old_status = prop.status;
+
+    let veto_config = prop
+        .veto
+        .as_ref()
+        .ok_or(VetoError::NoVetoConfiguration {})?;
+
+    // Check sender is vetoer
+    veto_config.check_is_vetoer(&info)?;
+
+    match prop.status {
+        Status::Open => {
+            // can only veto an open proposal if veto_before_passed is enabled.
+            veto_config.check_veto_before_passed_enabled()?;
+        }
+        Status::Passed => {
+            // if this proposal has veto configured but is in the passed state,
+            // the timelock already expired, so provide a more specific error.
+            return Err(ContractError::VetoError(VetoError::TimelockExpired {}));
+        }
+        Status::VetoTimelock { expiration } => {
+            // vetoer can veto the proposal iff the timelock is active/not
+            // expired.
+            if expiration.is_expired(&env.block) {
+                return Err(ContractError::VetoError(VetoError::TimelockExpired {}));
+            }
+        }
+        // generic status error if the proposal has any other status.
+        _ => {
+            return Err(ContractError::VetoError(VetoError::InvalidProposalStatus {
+                status: prop.status.to_string(),
+            }));
+        }
+    }
+
+    prop.status = Status::Vetoed;
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let proposal_status_changed_hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        prop.status.to_string(),
+    )?;
+
+    // Add prepropose / deposit module hook which will handle deposit refunds.
+    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+    let proposal_completed_hooks =
+        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "veto")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_submessages(proposal_status_changed_hooks)
+        .add_submessages(proposal_completed_hooks))
+}
+
+pub fn execute_execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    let config = CONFIG.load(deps.storage)?;
+
+    // determine if this sender can execute
+    let mut sender_can_execute = true;
+    if config.only_members_execute {
+        let power = get_voting_power(
+            deps.as_ref(),
+            info.sender.clone(),
+            &config.dao,
+            Some(prop.start_height),
+        )?;
+
+        sender_can_execute = !power.is_zero();
+    }
+
+    // Check here that the proposal is passed or timelocked. Allow it
+    // to be executed in timelock state if early_execute is enabled
+    // and the sender is the vetoer.
+    prop.update_status(&env.block)?;
+    let old_status = prop.status;
+    match &prop.status {
+        Status::Passed => {
+            if !sender_can_execute {
+                return Err(ContractError::Unauthorized {});
+            }
+        }
+        Status::VetoTimelock { .. } => {
+            // should never error if in veto timelock state
+            let veto_config = prop
+                .veto
+                .as_ref()
+                .ok_or(VetoError::NoVetoConfiguration {})?;
+
+            // check that the sender is the vetoer
+            if veto_config.vetoer != info.sender {
+                // if the sender can normally execute, but is not the vetoer,
+                // return timelocked error. otherwise return unauthorized.
+                if sender_can_execute {
+                    return Err(ContractError::VetoError(VetoError::Timelocked {}));
+                } else {
+                    return Err(ContractError::Unauthorized {});
+                }
+            }
+
+            // if veto timelocked, only allow execution if early_execute enabled
+            veto_config.check_early_execute_enabled()?;
+        }
+        _ => {
+            return Err(ContractError::NotPassed {});
+        }
+    }
+
+    prop.status = Status::Executed;
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let response = match prop.schedule {
+        // Scheduled proposals are executed on their schedule from
+        // here on, starting with any executions that are already due.
+        Some(ref schedule) => {
+            let (messages, executions) = execute_due(
+                deps.storage,
+                &config,
+                proposal_id,
+                &prop,
+                schedule,
+                0,
+                &env.block.time,
+                MAX_EXECUTIONS_PER_CALL,
+            )?;
+            Response::default()
+                .add_submessages(messages)
+                .add_attribute("executions", executions.to_string())
+        }
+        None => {
+            if !prop.msgs.is_empty() {
+                Response::default().add_submessage(proposal_execution_message(
+                    &config,
+                    proposal_id,
+                    prop.msgs,
+                )?)
+            } else {
+                Response::default()
+            }
+        }
+    };
+
+    let proposal_status_changed_hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        prop.status.to_string(),
+    )?;
+
+    // Add prepropose / deposit module hook which will handle deposit refunds.
+    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+    let proposal_completed_hooks =
+        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+
+    Ok(response
+        .add_submessages(proposal_status_changed_hooks)
+        .add_submessages(proposal_completed_hooks)
+        .add_attribute("action", "execute")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("dao", config.dao))
+}
+
+/// Executes the executions of a scheduled proposal that have come
+/// due since it was last cranked, up to `limit` of them.
+pub fn execute_execute_scheduled(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let executed = EXECUTION_CURSORS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NotScheduled { id: proposal_id })?;
+    let prop = PROPOSALS.load(deps.storage, proposal_id)?;
+    let schedule = prop
+        .schedule
+        .clone()
+        .ok_or(ContractError::NotScheduled { id: proposal_id })?;
+
+    if schedule.due_count(&env.block.time) <= executed {
+        return Err(ContractError::NothingDue {
+            id: proposal_id,
+            due_at: schedule.due_at(executed).unwrap_or_default(),
+        });
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let (messages, executions) = execute_due(
+        deps.storage,
+        &config,
+        proposal_id,
+        &prop,
+        &schedule,
+        executed,
+        &env.block.time,
+        limit.map_or(MAX_EXECUTIONS_PER_CALL, |limit| {
+            limit.min(MAX_EXECUTIONS_PER_CALL)
+        }),
+    )?;
+
+    Ok(Response::default()
+        .add_submessages(messages)
+        .add_attribute("action", "execute_scheduled")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("executions", executions.to_string())
+        .add_attribute(
+            "remaining",
+            (schedule.times() - executed - executions).to_string(),
+        ))
+}
+
+/// Creates a message executing `msgs` on behalf of the DAO. If the
+/// module is configured to close proposals whose execution fails,
+/// failures are caught and handled in `reply`.
+fn proposal_execution_message(
+    config: &Config,
+    proposal_id: u64,
+    msgs: Vec<CosmosMsg>,
+) -> StdResult<SubMsg> {
+    let execute_message = WasmMsg::Execute {
+        contract_addr: config.dao.to_string(),
+        msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
+            msgs,
+            proposal_id: Some(proposal_id),
+        })?,
+        funds: vec![],
+    };
+    Ok(match config.close_proposal_on_execution_failure {
+        true => {
+            let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
+            SubMsg::reply_on_error(execute_message, masked_proposal_id)
+        }
+        false => SubMsg::new(execute_message),
+    })
+}
+
+/// Creates a message for each execution of a scheduled proposal that
+/// has come due after the first `executed` executions, up to `limit`
+/// of them, and advances the proposal's execution cursor past them.
+/// Returns the messages and the number of executions made.
+#[allow(clippy::too_many_arguments)]
+fn execute_due(
+    storage: &mut dyn Storage,
+    config: &Config,
+    proposal_id: u64,
+    prop: &OptimisticProposal,
+    schedule: &ExecutionSchedule,
+    executed: u32,
+    now: &Timestamp,
+    limit: u32,
+) -> Result<(Vec<SubMsg>, u32), ContractError> {
+    let due = schedule
+        .due_count(now)
+        .max(executed)
+        .min(executed.saturating_add(limit));
+    let messages = if prop.msgs.is_empty() {
+        vec![]
+    } else {
+        (executed..due)
+            .map(|_| proposal_execution_message(config, proposal_id, prop.msgs.clone()))
+            .collect::<StdResult<Vec<_>>>()?
+    };
+
+    if due == schedule.times() {
+        EXECUTION_CURSORS.remove(storage, proposal_id);
+    } else {
+        EXECUTION_CURSORS.save(storage, proposal_id, &due)?;
+    }
+
+    Ok((messages, due - executed))
+}
+
+pub fn execute_vote(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    proposal_id: u64,
+    vote: Vote,
+    rationale: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    // Allow voting on proposals until they expire, even if they have
+    // already been rejected, so that the full extent of objections
+    // is recorded.
+    if prop.expiration.is_expired(&env.block) {
+        return Err(ContractError::Expired { id: proposal_id });
+    }
+
+    let vote_power = get_voting_power(
+        deps.as_ref(),
+        sender.clone(),
+        &config.dao,
+        Some(prop.start_height),
+    )?;
+    if vote_power.is_zero() {
+        return Err(ContractError::NotRegistered {});
+    }
+
+    BALLOTS.update(deps.storage, (proposal_id, &sender), |bal| match bal {
+        Some(current_ballot) => {
+            if prop.allow_revoting {
+                if current_ballot.vote == vote {
+                    // Don't allow casting the same vote more than
+                    // once. This seems liable to be confusing
+                    // behavior.
+                    Err(ContractError::AlreadyCast {})
+                } else {
+                    // Remove the old vote if this is a re-vote.
+                    prop.votes
+                        .remove_vote(current_ballot.vote, current_ballot.power);
+                    Ok(Ballot {
+                        power: vote_power,
+                        vote,
+                        rationale: rationale.clone(),
+                    })
+                }
+            } else {
+                Err(ContractError::AlreadyVoted {})
+            }
+        }
+        None => Ok(Ballot {
+            power: vote_power,
+            vote,
+            rationale: rationale.clone(),
+        }),
+    })?;
+
+    let old_status = prop.status;
+
+    prop.votes.add_vote(vote, vote_power);
+    prop.update_status(&env.block)?;
+
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let new_status = prop.status;
+    let change_hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        new_status.to_string(),
+    )?;
+
+    let vote_hooks = new_vote_hooks(
+        VOTE_HOOKS,
+        deps.storage,
+        proposal_id,
+        sender.to_string(),
+        vote.to_string(),
+    )?;
+
+    Ok(Response::default()
+        .add_submessages(change_hooks)
+        .add_submessages(vote_hooks)
+        .add_attribute("action", "vote")
+        .add_attribute("sender", sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("position", vote.to_string())
+        .add_attribute(
+            "rationale",
+            rationale.unwrap_or_else(|| "_none".to_string()),
+        )
+        .add_attribute("status", prop.status.to_string()))
+}
+
+pub fn execute_update_rationale(
+    deps: DepsMut,
+    info: MessageInfo,
+    proposal_id: u64,
+    rationale: Option<String>,
+) -> Result<Response, ContractError> {
+    BALLOTS.update(
+        deps.storage,
+        // info.sender can't be forged so we implicitly access control
+        // with the key.
+        (proposal_id, &info.sender),
+        |ballot| match ballot {
+            Some(ballot) => Ok(Ballot {
+                rationale: rationale.clone(),
+                ..ballot
+            }),
+            None => Err(ContractError::NoSuchVote {
+                id: proposal_id,
+                voter: info.sender.to_string(),
+            }),
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "update_rationale")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("rationale", rationale.as_deref().unwrap_or("_none")))
+}
+
+pub fn execute_close(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    prop.update_status(&env.block)?;
+    if prop.status != Status::Rejected {
+        return Err(ContractError::WrongCloseStatus {});
+    }
+
+    let old_status = prop.status;
+
+    prop.status = Status::Closed;
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let proposal_status_changed_hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        prop.status.to_string(),
+    )?;
+
+    // Add prepropose / deposit module hook which will handle deposit refunds.
+    let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
+    let proposal_completed_hooks =
+        proposal_completed_hooks(proposal_creation_policy, proposal_id, prop.status)?;
+
+    Ok(Response::default()
+        .add_submessages(proposal_status_changed_hooks)
+        .add_submessages(proposal_completed_hooks)
+        .add_attribute("action", "close")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    objection_threshold: PercentageThreshold,
+    max_voting_period: Duration,
+    only_members_execute: bool,
+    allow_revoting: bool,
+    dao: String,
+    close_proposal_on_execution_failure: bool,
+    veto: Option<VetoConfig>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Only the DAO may call this method.
+    if info.sender != config.dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    validate_percentage(&objection_threshold)?;
+    let dao = deps.api.addr_validate(&dao)?;
+
+    // if veto is configured, validate its fields
+    if let Some(veto_config) = &veto {
+        veto_config.validate(&deps.as_ref(), &max_voting_period)?;
+    };
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            objection_threshold,
+            max_voting_period,
+            only_members_execute,
+            allow_revoting,
+            dao,
+            close_proposal_on_execution_failure,
+            veto,
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "update_config")
+        .add_attribute("sender", info.sender))
+}
+
+pub fn execute_update_proposal_creation_policy(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_info: PreProposeInfo,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let (initial_policy, messages) = new_info.into_initial_policy_and_messages(config.dao)?;
+    CREATION_POLICY.save(deps.storage, &initial_policy)?;
+
+    Ok(Response::default()
+        .add_submessages(messages)
+        .add_attribute("action", "update_proposal_creation_policy")
+        .add_attribute("sender", info.sender)
+        .add_attribute("new_policy", format!("{initial_policy:?}")))
+}
+
+pub fn execute_add_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    hooks: Hooks,
+    action: &str,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        // Only DAO can add hooks
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let validated_address = deps.api.addr_validate(&address)?;
+    add_hook(hooks, deps.storage, validated_address)?;
+
+    Ok(Response::default()
+        .add_attribute("action", action)
+        .add_attribute("address", address))
+}
+
+pub fn execute_remove_hook(
+    deps: DepsMut,
+    info: MessageInfo,
+    hooks: Hooks,
+    action: &str,
+    address: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        // Only DAO can remove hooks
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let validated_address = deps.api.addr_validate(&address)?;
+    remove_hook(hooks, deps.storage, validated_address)?;
+
+    Ok(Response::default()
+        .add_attribute("action", action)
+        .add_attribute("address", address))
+}
+
+pub fn add_hook(
+    hooks: Hooks,
+    storage: &mut dyn Storage,
+    validated_address: Addr,
+) -> Result<(), ContractError> {
+    hooks
+        .add_hook(storage, validated_address)
+        .map_err(ContractError::HookError)?;
+    Ok(())
+}
+
+pub fn remove_hook(
+    hooks: Hooks,
+    storage: &mut dyn Storage,
+    validate_address: Addr,
+) -> Result<(), ContractError> {
+    hooks
+        .remove_hook(storage, validate_address)
+        .map_err(ContractError::HookError)?;
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Dao {} => to_json_binary(&CONFIG.load(deps.storage)?.dao),
+        QueryMsg::Info {} => query_info(deps),
+        QueryMsg::Proposal { proposal_id } => query_proposal(deps, env, proposal_id),
+        QueryMsg::ListProposals { start_after, limit } => {
+            query_list_proposals(deps, env, start_after, limit)
+        }
+        QueryMsg::ReverseProposals {
+            start_before,
+            limit,
+        } => query_reverse_proposals(deps, env, start_before, limit),
+        QueryMsg::GetVote { proposal_id, voter } => query_vote(deps, proposal_id, voter),
+        QueryMsg::ListVotes {
+            proposal_id,
+            start_after,
+            limit,
+        } => query_list_votes(deps, proposal_id, start_after, limit),
+        QueryMsg::UpcomingExecutions { start_after, limit } => {
+            query_upcoming_executions(deps, start_after, limit)
+        }
+        QueryMsg::ProposalCount {} => to_json_binary(&PROPOSAL_COUNT.load(deps.storage)?),
+        QueryMsg::NextProposalId {} => to_json_binary(&next_proposal_id(deps.storage)?),
+        QueryMsg::ProposalCreationPolicy {} => to_json_binary(&CREATION_POLICY.load(deps.storage)?),
+        QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
+        QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
+    }
+}
+
+pub fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    to_json_binary(&proposal.into_response(&env.block, id)?)
+}
+
+pub fn query_list_proposals(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let min = start_after.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let proposals = PROPOSALS
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            let (id, proposal) = item?;
+            proposal.into_response(&env.block, id)
+        })
+        .collect::<StdResult<Vec<ProposalResponse>>>()?;
+
+    to_json_binary(&ProposalListResponse { proposals })
+}
+
+pub fn query_reverse_proposals(
+    deps: Deps,
+    env: Env,
+    start_before: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let max = start_before.map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let proposals = PROPOSALS
+        .range(deps.storage, None, max, Order::Descending)
+        .take(limit as usize)
+        .map(|item| {
+            let (id, proposal) = item?;
+            proposal.into_response(&env.block, id)
+        })
+        .collect::<StdResult<Vec<ProposalResponse>>>()?;
+
+    to_json_binary(&ProposalListResponse { proposals })
+}
+
+pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Binary> {
+    let voter = deps.api.addr_validate(&voter)?;
+    let ballot = BALLOTS.may_load(deps.storage, (proposal_id, &voter))?;
+    let vote = ballot.map(|ballot| VoteInfo {
+        voter,
+        vote: ballot.vote,
+        power: ballot.power,
+        rationale: ballot.rationale,
+    });
+    to_json_binary(&VoteResponse { vote })
+}
+
+pub fn query_list_votes(
+    deps: Deps,
+    proposal_id: u64,
+    start_after: Option<String>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let start_after = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let min = start_after.as_ref().map(Bound::<&Addr>::exclusive);
+
+    let votes = BALLOTS
+        .prefix(proposal_id)
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            let (voter, ballot) = item?;
+            Ok(VoteInfo {
+                voter,
+                vote: ballot.vote,
+                power: ballot.power,
+                rationale: ballot.rationale,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&VoteListResponse { votes })
+}
+
+pub fn query_upcoming_executions(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let min = start_after.map(Bound::exclusive);
+
+    let executions = EXECUTION_CURSORS
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            let (proposal_id, executed) = item?;
+            let schedule = PROPOSALS
+                .load(deps.storage, proposal_id)?
+                .schedule
+                .ok_or_else(|| StdError::not_found("dao_voting::schedule::ExecutionSchedule"))?;
+            Ok(UpcomingExecution {
+                proposal_id,
+                due_at: schedule
+                    .due_at(executed)
+                    .ok_or_else(|| StdError::not_found("execution"))?,
+                remaining: schedule.times() - executed,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&UpcomingExecutionsResponse { executions })
+}
+
+pub fn query_info(deps: Deps) -> StdResult<Binary> {
+    let info = cw2::get_contract_version(deps.storage)?;
+    to_json_binary(&dao_interface::voting::InfoResponse { info })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let storage_version: ContractVersion = get_contract_version(deps.storage)?;
+
+    // Only migrate if newer
+    if storage_version.version.as_str() < CONTRACT_VERSION {
+        // Set contract to version to latest
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    }
+
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let repl = TaggedReplyId::new(msg.id)?;
+    match repl {
+        TaggedReplyId::FailedProposalExecution(proposal_id) => {
+            // A failed execution ends any remaining scheduled
+            // executions of the proposal.
+            EXECUTION_CURSORS.remove(deps.storage, proposal_id);
+            PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
+                Some(mut prop) => {
+                    prop.status = Status::ExecutionFailed;
+                    Ok(prop)
+                }
+                None => Err(ContractError::NoSuchProposal { id: proposal_id }),
+            })?;
+
+            Ok(Response::new()
+                .add_attribute("proposal_execution_failed", proposal_id.to_string())
+                .add_attribute(
+                    "error",
+                    msg.result.into_result().err().unwrap_or("None".to_string()),
+                ))
+        }
+        TaggedReplyId::FailedProposalHook(idx) => {
+            let addr = PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
+            Ok(Response::new().add_attribute("removed_proposal_hook", format!("{addr}:{idx}")))
+        }
+        TaggedReplyId::FailedVoteHook(idx) => {
+            let addr = VOTE_HOOKS.remove_hook_by_index(deps.storage, idx)?;
+            Ok(Response::new().add_attribute("removed_vote_hook", format!("{addr}:{idx}")))
+        }
+        TaggedReplyId::PreProposeModuleInstantiation => {
+            let res = parse_reply_instantiate_data(msg)?;
+
+            let module = deps.api.addr_validate(&res.contract_address)?;
+            CREATION_POLICY.save(
+                deps.storage,
+                &ProposalCreationPolicy::Module { addr: module },
+            )?;
+
+            match res.data {
+                Some(data) => Ok(Response::new()
+                    .add_attribute("update_pre_propose_module", res.contract_address)
+                    .set_data(data)),
+                None => Ok(Response::new()
+                    .add_attribute("update_pre_propose_module", res.contract_address)),
+            }
+        }
+        TaggedReplyId::FailedPreProposeModuleHook => {
+            let addr = match CREATION_POLICY.load(deps.storage)? {
+                ProposalCreationPolicy::Anyone {} => {
+                    // Something is off if we're getting this reply
+                    // and we don't have a pre-propose module
+                    // installed. This should be unreachable.
+                    return Err(ContractError::InvalidReplyID {
+                        id: failed_pre_propose_module_hook_id(),
+                    });
+                }
+                ProposalCreationPolicy::Module { addr } => {
+                    // Our pre-propose module has errored while
+                    // receiving a proposal hook, so remove it.
+                    CREATION_POLICY.save(deps.storage, &ProposalCreationPolicy::Anyone {})?;
+                    addr
+                }
+            };
+            Ok(Response::new().add_attribute("failed_prepropose_hook", format!("{addr}")))
+        }
+    }
+}
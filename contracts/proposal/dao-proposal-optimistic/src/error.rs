@@ -0,0 +1,79 @@
+use cosmwasm_std::{StdError, Timestamp};
+use cw_hooks::HookError;
+use cw_utils::ParseReplyError;
+use dao_voting::{reply::error::TagError, veto::VetoError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    ParseReplyError(#[from] ParseReplyError),
+
+    #[error(transparent)]
+    HookError(#[from] HookError),
+
+    #[error(transparent)]
+    VetoError(#[from] VetoError),
+
+    #[error(transparent)]
+    ThresholdError(#[from] dao_voting::threshold::ThresholdError),
+
+    #[error(transparent)]
+    ScheduleError(#[from] dao_voting::schedule::ScheduleError),
+
+    #[error(transparent)]
+    VotingError(#[from] dao_voting::error::VotingError),
+
+    #[error(transparent)]
+    Tag(#[from] TagError),
+
+    #[error("unauthorized")]
+    Unauthorized {},
+
+    #[error("no such proposal ({id})")]
+    NoSuchProposal { id: u64 },
+
+    #[error("no vote exists for proposal ({id}) and voter ({voter})")]
+    NoSuchVote { id: u64, voter: String },
+
+    #[error("proposal is ({size}) bytes, must be <= ({max}) bytes")]
+    ProposalTooLarge { size: u64, max: u64 },
+
+    #[error("Proposal ({id}) is expired")]
+    Expired { id: u64 },
+
+    #[error("not registered to vote (no voting power) at time of proposal creation")]
+    NotRegistered {},
+
+    #[error("already voted. this proposal does not support revoting")]
+    AlreadyVoted {},
+
+    #[error("already cast a vote with that option. change your vote to revote")]
+    AlreadyCast {},
+
+    #[error("proposal is not in 'passed' state")]
+    NotPassed {},
+
+    #[error("only rejected proposals may be closed")]
+    WrongCloseStatus {},
+
+    #[error("the DAO is currently inactive, you cannot create proposals")]
+    InactiveDao {},
+
+    #[error("proposal ({id}) has no outstanding scheduled executions")]
+    NotScheduled { id: u64 },
+
+    #[error("no execution of proposal ({id}) is due until ({due_at})")]
+    NothingDue { id: u64, due_at: Timestamp },
+
+    #[error(
+        "pre-propose modules must specify a proposer. lacking one, no proposer should be specified"
+    )]
+    InvalidProposer {},
+
+    #[error("received a reply failure with an invalid ID: ({id})")]
+    InvalidReplyID { id: u64 },
+}
@@ -0,0 +1,241 @@
+use std::ops::Add;
+
+use crate::query::ProposalResponse;
+use crate::state::PROPOSAL_COUNT;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, CosmosMsg, Empty, StdResult, Storage, Uint128};
+use cw_utils::Expiration;
+use dao_voting::schedule::ExecutionSchedule;
+use dao_voting::status::Status;
+use dao_voting::threshold::PercentageThreshold;
+use dao_voting::veto::VetoConfig;
+use dao_voting::voting::{does_vote_count_pass, Votes};
+
+#[cw_serde]
+pub struct OptimisticProposal {
+    /// The title of the proposal
+    pub title: String,
+    /// The main body of the proposal text
+    pub description: String,
+    /// The address that created this proposal.
+    pub proposer: Addr,
+    /// The block height at which this proposal was created. Voting
+    /// power queries should query for voting power at this block
+    /// height.
+    pub start_height: u64,
+    /// The schedule on which this proposal's messages are executed
+    /// once it is executed. None if the messages are executed
+    /// immediately.
+    #[serde(default)]
+    pub schedule: Option<ExecutionSchedule>,
+    /// The the time at which this proposal will expire and close for
+    /// additional votes. If it has not been objected to by then, it
+    /// passes.
+    pub expiration: Expiration,
+    /// The percentage of `total_power` that must vote `No` for this
+    /// proposal to be rejected.
+    pub objection_threshold: PercentageThreshold,
+    /// The total amount of voting power at the time of this
+    /// proposal's creation.
+    pub total_power: Uint128,
+    /// The messages that will be executed should this proposal pass.
+    pub msgs: Vec<CosmosMsg<Empty>>,
+    /// The proposal status
+    pub status: Status,
+    /// Votes on a particular proposal
+    pub votes: Votes,
+    /// Whether or not revoting is enabled. If revoting is enabled, a
+    /// proposal cannot be rejected until the voting period has
+    /// elapsed.
+    pub allow_revoting: bool,
+    /// Optional veto configuration. If set to `None`, veto option
+    /// is disabled. Otherwise contains the configuration for veto flow.
+    pub veto: Option<VetoConfig>,
+}
+
+pub fn next_proposal_id(store: &dyn Storage) -> StdResult<u64> {
+    Ok(PROPOSAL_COUNT.may_load(store)?.unwrap_or_default() + 1)
+}
+
+pub fn advance_proposal_id(store: &mut dyn Storage) -> StdResult<u64> {
+    let id: u64 = next_proposal_id(store)?;
+    PROPOSAL_COUNT.save(store, &id)?;
+    Ok(id)
+}
+
+impl OptimisticProposal {
+    /// Consumes the proposal and returns a version which may be used
+    /// in a query response. Statuses are only updated on vote,
+    /// execute, and close events, so the stored status may be out of
+    /// date if the proposal has expired since. This method recomputes
+    /// the status so that queries get accurate information.
+    pub fn into_response(mut self, block: &BlockInfo, id: u64) -> StdResult<ProposalResponse> {
+        self.update_status(block)?;
+        Ok(ProposalResponse { id, proposal: self })
+    }
+
+    /// Gets the current status of the proposal.
+    pub fn current_status(&self, block: &BlockInfo) -> StdResult<Status> {
+        match self.status {
+            Status::Open if self.is_rejected(block) => Ok(Status::Rejected),
+            Status::Open if self.is_passed(block) => match &self.veto {
+                // if prop is passed and veto is configured, calculate timelock
+                // expiration. if it's expired, this proposal has passed.
+                // otherwise, set status to `VetoTimelock`.
+                Some(veto_config) => {
+                    let expiration = self.expiration.add(veto_config.timelock_duration)?;
+
+                    if expiration.is_expired(block) {
+                        Ok(Status::Passed)
+                    } else {
+                        Ok(Status::VetoTimelock { expiration })
+                    }
+                }
+                // Otherwise the proposal is simply passed
+                None => Ok(Status::Passed),
+            },
+            Status::VetoTimelock { expiration } => {
+                // if prop timelock expired, proposal is now passed.
+                if expiration.is_expired(block) {
+                    Ok(Status::Passed)
+                } else {
+                    Ok(self.status)
+                }
+            }
+            _ => Ok(self.status),
+        }
+    }
+
+    /// Sets a proposals status to its current status.
+    pub fn update_status(&mut self, block: &BlockInfo) -> StdResult<()> {
+        let new_status = self.current_status(block)?;
+        self.status = new_status;
+        Ok(())
+    }
+
+    /// Returns true if enough voting power has voted `No` to reach
+    /// the objection threshold.
+    pub fn is_objected(&self) -> bool {
+        does_vote_count_pass(self.votes.no, self.total_power, self.objection_threshold)
+    }
+
+    /// Returns true iff this proposal has passed. Optimistic
+    /// proposals pass once their voting period has ended without
+    /// reaching the objection threshold.
+    pub fn is_passed(&self, block: &BlockInfo) -> bool {
+        self.expiration.is_expired(block) && !self.is_objected()
+    }
+
+    /// Returns true iff this proposal has been rejected. Without
+    /// revoting, objections can not be withdrawn, so a proposal is
+    /// rejected as soon as the objection threshold is reached.
+    pub fn is_rejected(&self, block: &BlockInfo) -> bool {
+        // If re-voting is allowed and the proposal is not expired no
+        // information is known.
+        if self.allow_revoting && !self.expiration.is_expired(block) {
+            return false;
+        }
+        self.is_objected()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cosmwasm_std::{testing::mock_env, Decimal};
+    use cw_utils::Duration;
+
+    fn setup_prop(
+        objection_threshold: PercentageThreshold,
+        no: u128,
+        is_expired: bool,
+        allow_revoting: bool,
+    ) -> (OptimisticProposal, BlockInfo) {
+        let block = mock_env().block;
+        let expiration = match is_expired {
+            true => Expiration::AtHeight(block.height - 5),
+            false => Expiration::AtHeight(block.height + 100),
+        };
+
+        let prop = OptimisticProposal {
+            title: "Demo".to_string(),
+            description: "Info".to_string(),
+            proposer: Addr::unchecked("test"),
+            start_height: 100,
+            schedule: None,
+            expiration,
+            objection_threshold,
+            total_power: Uint128::new(100),
+            msgs: vec![],
+            status: Status::Open,
+            votes: Votes {
+                yes: Uint128::zero(),
+                no: Uint128::new(no),
+                abstain: Uint128::zero(),
+            },
+            allow_revoting,
+            veto: None,
+        };
+        (prop, block)
+    }
+
+    fn status(
+        objection_threshold: PercentageThreshold,
+        no: u128,
+        is_expired: bool,
+        allow_revoting: bool,
+    ) -> Status {
+        let (prop, block) = setup_prop(objection_threshold, no, is_expired, allow_revoting);
+        prop.current_status(&block).unwrap()
+    }
+
+    #[test]
+    fn test_passes_without_objection() {
+        let threshold = PercentageThreshold::Percent(Decimal::percent(10));
+        assert_eq!(status(threshold, 0, false, false), Status::Open);
+        assert_eq!(status(threshold, 9, false, false), Status::Open);
+        assert_eq!(status(threshold, 0, true, false), Status::Passed);
+        assert_eq!(status(threshold, 9, true, false), Status::Passed);
+    }
+
+    #[test]
+    fn test_rejected_by_objection() {
+        let threshold = PercentageThreshold::Percent(Decimal::percent(10));
+        assert_eq!(status(threshold, 10, false, false), Status::Rejected);
+        assert_eq!(status(threshold, 10, true, false), Status::Rejected);
+
+        let majority = PercentageThreshold::Majority {};
+        assert_eq!(status(majority, 50, true, false), Status::Passed);
+        assert_eq!(status(majority, 51, false, false), Status::Rejected);
+    }
+
+    #[test]
+    fn test_revoting_delays_rejection() {
+        let threshold = PercentageThreshold::Percent(Decimal::percent(10));
+        assert_eq!(status(threshold, 10, false, true), Status::Open);
+        assert_eq!(status(threshold, 10, true, true), Status::Rejected);
+        assert_eq!(status(threshold, 0, true, true), Status::Passed);
+    }
+
+    #[test]
+    fn test_veto_timelock_after_expiration() {
+        let threshold = PercentageThreshold::Percent(Decimal::percent(10));
+        let (mut prop, mut block) = setup_prop(threshold, 0, true, false);
+        prop.veto = Some(VetoConfig {
+            timelock_duration: Duration::Height(10),
+            vetoer: "vetoer".to_string(),
+            early_execute: false,
+            veto_before_passed: false,
+        });
+
+        assert_eq!(
+            prop.current_status(&block).unwrap(),
+            Status::VetoTimelock {
+                expiration: Expiration::AtHeight(block.height + 5)
+            }
+        );
+
+        block.height += 5;
+        assert_eq!(prop.current_status(&block).unwrap(), Status::Passed);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::proposal::OptimisticProposal;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+use dao_voting::voting::Vote;
+
+/// Information about a proposal returned by proposal queries.
+#[cw_serde]
+pub struct ProposalResponse {
+    /// The ID of the proposal being returned.
+    pub id: u64,
+    pub proposal: OptimisticProposal,
+}
+
+/// Information about a vote that was cast.
+#[cw_serde]
+pub struct VoteInfo {
+    /// The address that voted.
+    pub voter: Addr,
+    /// Position on the vote.
+    pub vote: Vote,
+    /// The voting power behind the vote.
+    pub power: Uint128,
+    /// Address-specified rationale for the vote.
+    pub rationale: Option<String>,
+}
+
+/// Information about a vote.
+#[cw_serde]
+pub struct VoteResponse {
+    /// None if no such vote, Some otherwise.
+    pub vote: Option<VoteInfo>,
+}
+
+/// Information about the votes for a proposal.
+#[cw_serde]
+pub struct VoteListResponse {
+    pub votes: Vec<VoteInfo>,
+}
+
+/// A list of proposals returned by `ListProposals` and
+/// `ReverseProposals`.
+#[cw_serde]
+pub struct ProposalListResponse {
+    pub proposals: Vec<ProposalResponse>,
+}
+
+/// The next execution of a scheduled proposal.
+#[cw_serde]
+pub struct UpcomingExecution {
+    /// The ID of the scheduled proposal.
+    pub proposal_id: u64,
+    /// The time at which the next execution is due. If this is in
+    /// the past, the execution may be triggered with
+    /// `ExecuteScheduled`.
+    pub due_at: Timestamp,
+    /// The number of executions remaining, including the next one.
+    pub remaining: u32,
+}
+
+/// The upcoming executions returned by `UpcomingExecutions`.
+#[cw_serde]
+pub struct UpcomingExecutionsResponse {
+    pub executions: Vec<UpcomingExecution>,
+}
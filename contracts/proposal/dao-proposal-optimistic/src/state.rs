@@ -0,0 +1,69 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_hooks::Hooks;
+use cw_storage_plus::{Item, Map};
+use cw_utils::Duration;
+use dao_voting::{
+    pre_propose::ProposalCreationPolicy, threshold::PercentageThreshold, veto::VetoConfig,
+    voting::Vote,
+};
+
+use crate::proposal::OptimisticProposal;
+
+/// A vote cast for a proposal.
+#[cw_serde]
+pub struct Ballot {
+    /// The amount of voting power behind the vote.
+    pub power: Uint128,
+    /// The position.
+    pub vote: Vote,
+    /// An optional rationale for why this vote was cast.
+    pub rationale: Option<String>,
+}
+
+/// The governance module's configuration.
+#[cw_serde]
+pub struct Config {
+    /// The percentage of the total voting power at proposal creation
+    /// that must vote `No` for a proposal to be rejected.
+    pub objection_threshold: PercentageThreshold,
+    /// The amount of time a proposal may be objected to before it
+    /// passes.
+    pub max_voting_period: Duration,
+    /// If set to true only members may execute passed
+    /// proposals. Otherwise, any address may execute a passed
+    /// proposal.
+    pub only_members_execute: bool,
+    /// Allows changing votes before the proposal expires. If this is
+    /// enabled proposals will not be rejected early as final vote
+    /// information is not known until the time of proposal
+    /// expiration.
+    pub allow_revoting: bool,
+    /// The address of the DAO that this governance module is
+    /// associated with.
+    pub dao: Addr,
+    /// If set to true proposals will be closed if their execution
+    /// fails. Otherwise, proposals will remain open after execution
+    /// failure.
+    pub close_proposal_on_execution_failure: bool,
+    /// Optional veto configuration. If set to `None`, veto option
+    /// is disabled. Otherwise contains the configuration for veto flow.
+    pub veto: Option<VetoConfig>,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+/// The number of proposals that have been created.
+pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+pub const PROPOSALS: Map<u64, OptimisticProposal> = Map::new("proposals");
+pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+/// The number of executions made by executed proposals with a
+/// schedule, keyed by proposal ID. Removed once every execution in
+/// the schedule has been made.
+pub const EXECUTION_CURSORS: Map<u64, u32> = Map::new("execution_cursors");
+/// Consumers of proposal state change hooks.
+pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
+/// Consumers of vote hooks.
+pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
+/// The address of the pre-propose module associated with this
+/// proposal module (if any).
+pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
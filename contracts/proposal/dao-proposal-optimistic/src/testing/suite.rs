@@ -0,0 +1,405 @@
+use cosmwasm_std::{coins, to_json_binary, Addr, BankMsg, CosmosMsg, Decimal, Empty, Uint128};
+use cw_multi_test::{next_block, App, BankSudo, Contract, ContractWrapper, Executor, SudoMsg};
+use cw_utils::Duration;
+use dao_interface::state::{Admin, ModuleInstantiateInfo};
+use dao_testing::contracts::{
+    cw4_group_contract, dao_dao_contract, dao_voting_cw4_contract, pre_propose_single_contract,
+    proposal_optimistic_contract,
+};
+use dao_voting::{
+    pre_propose::{PreProposeInfo, PreProposeSubmissionPolicy, ProposalCreationPolicy},
+    proposal::SingleChoiceProposeMsg,
+    schedule::ExecutionSchedule,
+    threshold::PercentageThreshold,
+    voting::Vote,
+};
+use dao_voting_cw4::msg::GroupContract;
+
+use crate::{
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    proposal::OptimisticProposal,
+    query::{ProposalResponse, UpcomingExecution, UpcomingExecutionsResponse},
+};
+
+pub(crate) const DENOM: &str = "ujuno";
+
+fn hook_counter_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_proposal_hook_counter::contract::execute,
+        dao_proposal_hook_counter::contract::instantiate,
+        dao_proposal_hook_counter::contract::query,
+    );
+    Box::new(contract)
+}
+
+pub(crate) struct Suite {
+    pub app: App,
+    pub optimistic: Addr,
+    pub core: Addr,
+}
+
+pub(crate) struct SuiteBuilder {
+    pub instantiate: InstantiateMsg,
+    with_pre_propose: bool,
+    with_voters: Vec<(String, u64)>,
+}
+
+impl Default for SuiteBuilder {
+    fn default() -> Self {
+        Self {
+            instantiate: InstantiateMsg {
+                objection_threshold: PercentageThreshold::Percent(Decimal::percent(10)),
+                max_voting_period: Duration::Time(60 * 60 * 24 * 7),
+                only_members_execute: true,
+                allow_revoting: false,
+                pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+                close_proposal_on_execution_failure: true,
+                veto: None,
+            },
+            with_pre_propose: false,
+            with_voters: vec![
+                ("blue".to_string(), 10),
+                ("violet".to_string(), 10),
+                ("magenta".to_string(), 10),
+                ("gold".to_string(), 70),
+            ],
+        }
+    }
+}
+
+impl SuiteBuilder {
+    pub fn with_pre_propose(mut self) -> Self {
+        self.with_pre_propose = true;
+        self
+    }
+
+    pub fn build(mut self) -> Suite {
+        let initial_members: Vec<_> = self
+            .with_voters
+            .into_iter()
+            .map(|(addr, weight)| cw4::Member { addr, weight })
+            .collect();
+        let sender = Addr::unchecked(&initial_members[0].addr);
+
+        let mut app = App::default();
+        let optimistic_id = app.store_code(proposal_optimistic_contract());
+        let core_id = app.store_code(dao_dao_contract());
+        let cw4_id = app.store_code(cw4_group_contract());
+        let cw4_voting_id = app.store_code(dao_voting_cw4_contract());
+
+        if self.with_pre_propose {
+            let pre_propose_id = app.store_code(pre_propose_single_contract());
+            self.instantiate.pre_propose_info = PreProposeInfo::ModuleMayPropose {
+                info: ModuleInstantiateInfo {
+                    code_id: pre_propose_id,
+                    msg: to_json_binary(&dao_pre_propose_single::InstantiateMsg {
+                        deposit_info: None,
+                        submission_policy: PreProposeSubmissionPolicy::Specific {
+                            dao_members: true,
+                            allowlist: vec![],
+                            denylist: vec![],
+                        },
+                        extension: Empty::default(),
+                    })
+                    .unwrap(),
+                    admin: Some(Admin::CoreModule {}),
+                    funds: vec![],
+                    label: "pre-propose module".to_string(),
+                },
+            };
+        }
+
+        let core_instantiate = dao_interface::msg::InstantiateMsg {
+            admin: None,
+            name: "core module".to_string(),
+            description: "core module".to_string(),
+            image_url: None,
+            automatically_add_cw20s: false,
+            automatically_add_cw721s: false,
+            voting_module_instantiate_info: ModuleInstantiateInfo {
+                code_id: cw4_voting_id,
+                msg: to_json_binary(&dao_voting_cw4::msg::InstantiateMsg {
+                    group_contract: GroupContract::New {
+                        cw4_group_code_id: cw4_id,
+                        initial_members,
+                    },
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "voting module".to_string(),
+            },
+            proposal_modules_instantiate_info: vec![ModuleInstantiateInfo {
+                code_id: optimistic_id,
+                msg: to_json_binary(&self.instantiate).unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                funds: vec![],
+                label: "optimistic module".to_string(),
+            }],
+            initial_items: None,
+            dao_uri: None,
+        };
+        let core = app
+            .instantiate_contract(
+                core_id,
+                sender,
+                &core_instantiate,
+                &[],
+                "core module".to_string(),
+                None,
+            )
+            .unwrap();
+        let modules: Vec<dao_interface::state::ProposalModule> = app
+            .wrap()
+            .query_wasm_smart(
+                &core,
+                &dao_interface::msg::QueryMsg::ProposalModules {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        let optimistic = modules.into_iter().next().unwrap().address;
+
+        app.sudo(SudoMsg::Bank(BankSudo::Mint {
+            to_address: core.to_string(),
+            amount: coins(100, DENOM),
+        }))
+        .unwrap();
+        app.update_block(next_block);
+
+        Suite {
+            app,
+            optimistic,
+            core,
+        }
+    }
+}
+
+impl Suite {
+    pub fn a_day_passes(&mut self) {
+        self.app
+            .update_block(|b| b.time = b.time.plus_seconds(60 * 60 * 24))
+    }
+
+    pub fn a_week_passes(&mut self) {
+        self.app
+            .update_block(|b| b.time = b.time.plus_seconds(60 * 60 * 24 * 7))
+    }
+
+    pub fn balance(&self, addr: &str) -> Uint128 {
+        self.app.wrap().query_balance(addr, DENOM).unwrap().amount
+    }
+
+    /// Instantiates a hook counter and registers it as a consumer of
+    /// this module's proposal and vote hooks.
+    pub fn add_hook_counter(&mut self) -> Addr {
+        let id = self.app.store_code(hook_counter_contract());
+        let counter = self
+            .app
+            .instantiate_contract(
+                id,
+                self.core.clone(),
+                &dao_proposal_hook_counter::msg::InstantiateMsg {
+                    should_error: false,
+                },
+                &[],
+                "hook counter",
+                None,
+            )
+            .unwrap();
+        for msg in [
+            ExecuteMsg::AddProposalHook {
+                address: counter.to_string(),
+            },
+            ExecuteMsg::AddVoteHook {
+                address: counter.to_string(),
+            },
+        ] {
+            self.app
+                .execute_contract(self.core.clone(), self.optimistic.clone(), &msg, &[])
+                .unwrap();
+        }
+        counter
+    }
+
+    pub fn query_hook_count(
+        &self,
+        counter: &Addr,
+        msg: &dao_proposal_hook_counter::msg::QueryMsg,
+    ) -> u64 {
+        let count: dao_proposal_hook_counter::msg::CountResponse =
+            self.app.wrap().query_wasm_smart(counter, msg).unwrap();
+        count.count
+    }
+}
+
+// query
+impl Suite {
+    pub fn query_proposal(&self, proposal_id: u64) -> OptimisticProposal {
+        let ProposalResponse { proposal, .. } = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.optimistic, &QueryMsg::Proposal { proposal_id })
+            .unwrap();
+        proposal
+    }
+
+    pub fn query_upcoming_executions(&self) -> Vec<UpcomingExecution> {
+        let UpcomingExecutionsResponse { executions } = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.optimistic,
+                &QueryMsg::UpcomingExecutions {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        executions
+    }
+
+    pub fn query_next_proposal_id(&self) -> u64 {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.optimistic, &QueryMsg::NextProposalId {})
+            .unwrap()
+    }
+
+    pub fn query_pre_propose_module(&self) -> Addr {
+        let policy: ProposalCreationPolicy = self
+            .app
+            .wrap()
+            .query_wasm_smart(&self.optimistic, &QueryMsg::ProposalCreationPolicy {})
+            .unwrap();
+        match policy {
+            ProposalCreationPolicy::Module { addr } => addr,
+            ProposalCreationPolicy::Anyone {} => panic!("no pre-propose module"),
+        }
+    }
+}
+
+// execute
+impl Suite {
+    pub fn propose(&mut self, sender: &str, msgs: Vec<CosmosMsg>) -> anyhow::Result<u64> {
+        self.propose_scheduled(sender, msgs, None)
+    }
+
+    pub fn propose_scheduled(
+        &mut self,
+        sender: &str,
+        msgs: Vec<CosmosMsg>,
+        schedule: Option<ExecutionSchedule>,
+    ) -> anyhow::Result<u64> {
+        let id = self.query_next_proposal_id();
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            self.optimistic.clone(),
+            &ExecuteMsg::Propose(SingleChoiceProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs,
+                proposer: None,
+                vote: None,
+                schedule,
+            }),
+            &[],
+        )?;
+        Ok(id)
+    }
+
+    pub fn propose_through_pre_propose(
+        &mut self,
+        sender: &str,
+        msgs: Vec<CosmosMsg>,
+    ) -> anyhow::Result<u64> {
+        let id = self.query_next_proposal_id();
+        let pre_propose = self.query_pre_propose_module();
+        self.app.execute_contract(
+            Addr::unchecked(sender),
+            pre_propose,
+            &dao_pre_propose_single::ExecuteMsg::Propose {
+                msg: dao_pre_propose_single::ProposeMessage::Propose {
+                    title: "title".to_string(),
+                    description: "description".to_string(),
+                    msgs,
+                    vote: None,
+                    schedule: None,
+                },
+            },
+            &[],
+        )?;
+        Ok(id)
+    }
+
+    pub fn vote(&mut self, sender: &str, proposal_id: u64, vote: Vote) -> anyhow::Result<()> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.optimistic.clone(),
+                &ExecuteMsg::Vote {
+                    proposal_id,
+                    vote,
+                    rationale: None,
+                },
+                &[],
+            )
+            .map(|_| ())
+    }
+
+    pub fn execute(&mut self, sender: &str, proposal_id: u64) -> anyhow::Result<()> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.optimistic.clone(),
+                &ExecuteMsg::Execute { proposal_id },
+                &[],
+            )
+            .map(|_| ())
+    }
+
+    pub fn execute_scheduled(&mut self, sender: &str, proposal_id: u64) -> anyhow::Result<()> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.optimistic.clone(),
+                &ExecuteMsg::ExecuteScheduled {
+                    proposal_id,
+                    limit: None,
+                },
+                &[],
+            )
+            .map(|_| ())
+    }
+
+    pub fn veto(&mut self, sender: &str, proposal_id: u64) -> anyhow::Result<()> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.optimistic.clone(),
+                &ExecuteMsg::Veto { proposal_id },
+                &[],
+            )
+            .map(|_| ())
+    }
+
+    pub fn close(&mut self, sender: &str, proposal_id: u64) -> anyhow::Result<()> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender),
+                self.optimistic.clone(),
+                &ExecuteMsg::Close { proposal_id },
+                &[],
+            )
+            .map(|_| ())
+    }
+}
+
+pub fn payout_message() -> CosmosMsg {
+    BankMsg::Send {
+        to_address: "payee".to_string(),
+        amount: coins(10, DENOM),
+    }
+    .into()
+}
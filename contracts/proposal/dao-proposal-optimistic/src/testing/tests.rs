@@ -0,0 +1,314 @@
+use cosmwasm_std::{Addr, Decimal, Uint128};
+use cw_utils::Duration;
+use dao_proposal_hook_counter::msg::QueryMsg as CounterQueryMsg;
+use dao_voting::{
+    schedule::ExecutionSchedule, status::Status, threshold::PercentageThreshold, veto::VetoConfig,
+    voting::Vote,
+};
+
+use crate::query::UpcomingExecution;
+
+use super::{
+    is_error,
+    suite::{payout_message, SuiteBuilder},
+};
+
+#[test]
+fn test_passes_without_objection() {
+    let mut suite = SuiteBuilder::default().build();
+    let id = suite.propose("blue", vec![payout_message()]).unwrap();
+
+    // yes votes are recorded but a proposal may not pass before its
+    // voting period ends.
+    suite.vote("gold", id, Vote::Yes).unwrap();
+    assert_eq!(suite.query_proposal(id).status, Status::Open);
+    is_error!(
+        suite.execute("gold", id),
+        "proposal is not in 'passed' state"
+    );
+
+    // objections below the threshold do not reject the proposal.
+    suite.vote("magenta", id, Vote::Abstain).unwrap();
+    suite.a_week_passes();
+    assert_eq!(suite.query_proposal(id).status, Status::Passed);
+
+    suite.execute("blue", id).unwrap();
+    assert_eq!(suite.query_proposal(id).status, Status::Executed);
+    assert_eq!(suite.balance("payee"), Uint128::new(10));
+}
+
+#[test]
+fn test_objection_rejects() {
+    let mut suite = SuiteBuilder::default().build();
+    let id = suite.propose("gold", vec![payout_message()]).unwrap();
+
+    suite.vote("blue", id, Vote::No).unwrap();
+    assert_eq!(suite.query_proposal(id).status, Status::Rejected);
+
+    // voting remains open so that further objections are recorded.
+    suite.vote("violet", id, Vote::No).unwrap();
+    assert_eq!(suite.query_proposal(id).votes.no, Uint128::new(20));
+
+    suite.a_week_passes();
+    is_error!(
+        suite.execute("gold", id),
+        "proposal is not in 'passed' state"
+    );
+    is_error!(suite.vote("magenta", id, Vote::No), "expired");
+
+    suite.close("magenta", id).unwrap();
+    assert_eq!(suite.query_proposal(id).status, Status::Closed);
+    assert_eq!(suite.balance("payee"), Uint128::zero());
+}
+
+#[test]
+fn test_only_rejected_proposals_close() {
+    let mut suite = SuiteBuilder::default().build();
+    let id = suite.propose("gold", vec![payout_message()]).unwrap();
+
+    is_error!(
+        suite.close("gold", id),
+        "only rejected proposals may be closed"
+    );
+    suite.a_week_passes();
+    is_error!(
+        suite.close("gold", id),
+        "only rejected proposals may be closed"
+    );
+}
+
+#[test]
+fn test_revoting_defers_rejection() {
+    let mut builder = SuiteBuilder::default();
+    builder.instantiate.allow_revoting = true;
+    let mut suite = builder.build();
+    let id = suite.propose("gold", vec![payout_message()]).unwrap();
+
+    suite.vote("blue", id, Vote::No).unwrap();
+    assert_eq!(suite.query_proposal(id).status, Status::Open);
+
+    suite.vote("blue", id, Vote::Yes).unwrap();
+    suite.a_week_passes();
+    assert_eq!(suite.query_proposal(id).status, Status::Passed);
+}
+
+#[test]
+fn test_majority_objection_threshold() {
+    let mut builder = SuiteBuilder::default();
+    builder.instantiate.objection_threshold = PercentageThreshold::Majority {};
+    let mut suite = builder.build();
+    let id = suite.propose("gold", vec![payout_message()]).unwrap();
+
+    suite.vote("blue", id, Vote::No).unwrap();
+    suite.vote("violet", id, Vote::No).unwrap();
+    suite.vote("magenta", id, Vote::No).unwrap();
+    suite.a_week_passes();
+    assert_eq!(suite.query_proposal(id).status, Status::Passed);
+
+    let id = suite.propose("blue", vec![payout_message()]).unwrap();
+    suite.vote("gold", id, Vote::No).unwrap();
+    assert_eq!(suite.query_proposal(id).status, Status::Rejected);
+}
+
+#[test]
+fn test_veto_timelock() {
+    let mut builder = SuiteBuilder::default();
+    builder.instantiate.veto = Some(VetoConfig {
+        timelock_duration: Duration::Time(60 * 60 * 24),
+        vetoer: "oversight".to_string(),
+        early_execute: false,
+        veto_before_passed: false,
+    });
+    let mut suite = builder.build();
+
+    let id = suite.propose("gold", vec![payout_message()]).unwrap();
+    is_error!(
+        suite.veto("oversight", id),
+        "Vetoing before a proposal passes is not enabled"
+    );
+
+    suite.a_week_passes();
+    assert!(matches!(
+        suite.query_proposal(id).status,
+        Status::VetoTimelock { .. }
+    ));
+    is_error!(suite.execute("gold", id), "The proposal is timelocked");
+    is_error!(suite.veto("gold", id), "Only vetoer can veto a proposal");
+
+    suite.veto("oversight", id).unwrap();
+    assert_eq!(suite.query_proposal(id).status, Status::Vetoed);
+
+    // once the timelock expires the proposal may be executed.
+    let id = suite.propose("gold", vec![payout_message()]).unwrap();
+    suite.a_week_passes();
+    suite.a_day_passes();
+    assert_eq!(suite.query_proposal(id).status, Status::Passed);
+    is_error!(suite.veto("oversight", id), "timelock duration has expired");
+    suite.execute("gold", id).unwrap();
+    assert_eq!(suite.balance("payee"), Uint128::new(10));
+}
+
+#[test]
+fn test_execution_failure_closes_proposal() {
+    let mut suite = SuiteBuilder::default().build();
+    let id = suite
+        .propose(
+            "gold",
+            vec![payout_message(); 11], // the DAO only has 100 tokens.
+        )
+        .unwrap();
+    suite.a_week_passes();
+
+    suite.execute("gold", id).unwrap();
+    assert_eq!(suite.query_proposal(id).status, Status::ExecutionFailed);
+    assert_eq!(suite.balance("payee"), Uint128::zero());
+}
+
+#[test]
+fn test_scheduled_execution() {
+    let mut suite = SuiteBuilder::default().build();
+    let day = 60 * 60 * 24;
+    let start = suite.app.block_info().time.plus_seconds(8 * day);
+    let id = suite
+        .propose_scheduled(
+            "gold",
+            vec![payout_message()],
+            Some(ExecutionSchedule::Recurring {
+                start,
+                interval: day,
+                times: 3,
+            }),
+        )
+        .unwrap();
+    suite.a_week_passes();
+
+    // nothing is scheduled until the proposal is executed.
+    is_error!(
+        suite.execute_scheduled("blue", id),
+        "no outstanding scheduled executions"
+    );
+    suite.execute("gold", id).unwrap();
+    assert_eq!(suite.query_proposal(id).status, Status::Executed);
+    assert_eq!(suite.balance("payee"), Uint128::zero());
+    assert_eq!(
+        suite.query_upcoming_executions(),
+        vec![UpcomingExecution {
+            proposal_id: id,
+            due_at: start,
+            remaining: 3,
+        }]
+    );
+    is_error!(suite.execute_scheduled("blue", id), "is due until");
+
+    // executions that were missed are made when next cranked.
+    suite.app.update_block(|b| b.time = start.plus_seconds(day));
+    suite.execute_scheduled("blue", id).unwrap();
+    assert_eq!(suite.balance("payee"), Uint128::new(20));
+    assert_eq!(
+        suite.query_upcoming_executions(),
+        vec![UpcomingExecution {
+            proposal_id: id,
+            due_at: start.plus_seconds(2 * day),
+            remaining: 1,
+        }]
+    );
+
+    suite.a_week_passes();
+    suite.execute_scheduled("blue", id).unwrap();
+    assert_eq!(suite.balance("payee"), Uint128::new(30));
+    assert_eq!(suite.query_upcoming_executions(), vec![]);
+    is_error!(
+        suite.execute_scheduled("blue", id),
+        "no outstanding scheduled executions"
+    );
+}
+
+#[test]
+fn test_invalid_schedule() {
+    let mut suite = SuiteBuilder::default().build();
+    let start = suite.app.block_info().time;
+    is_error!(
+        suite.propose_scheduled(
+            "gold",
+            vec![payout_message()],
+            Some(ExecutionSchedule::Recurring {
+                start,
+                interval: 0,
+                times: 2,
+            }),
+        ),
+        "interval of a recurring execution schedule must be non-zero"
+    );
+}
+
+#[test]
+fn test_only_members_execute() {
+    let mut suite = SuiteBuilder::default().build();
+    let id = suite.propose("gold", vec![payout_message()]).unwrap();
+    suite.a_week_passes();
+
+    is_error!(suite.execute("stranger", id), "unauthorized");
+    suite.execute("violet", id).unwrap();
+}
+
+#[test]
+fn test_hooks() {
+    let mut suite = SuiteBuilder::default().build();
+    let counter = suite.add_hook_counter();
+
+    let id = suite.propose("gold", vec![payout_message()]).unwrap();
+    assert_eq!(
+        suite.query_hook_count(&counter, &CounterQueryMsg::ProposalCounter {}),
+        1
+    );
+
+    suite.vote("blue", id, Vote::No).unwrap();
+    assert_eq!(
+        suite.query_hook_count(&counter, &CounterQueryMsg::VoteCounter {}),
+        1
+    );
+    // open -> rejected
+    assert_eq!(
+        suite.query_hook_count(&counter, &CounterQueryMsg::StatusChangedCounter {}),
+        1
+    );
+
+    suite.close("blue", id).unwrap();
+    assert_eq!(
+        suite.query_hook_count(&counter, &CounterQueryMsg::StatusChangedCounter {}),
+        2
+    );
+}
+
+#[test]
+fn test_pre_propose_module() {
+    let mut suite = SuiteBuilder::default().with_pre_propose().build();
+
+    is_error!(
+        suite.propose("gold", vec![payout_message()]),
+        "unauthorized"
+    );
+    is_error!(
+        suite.propose_through_pre_propose("stranger", vec![payout_message()]),
+        "Unauthorized"
+    );
+
+    let id = suite
+        .propose_through_pre_propose("gold", vec![payout_message()])
+        .unwrap();
+    let proposal = suite.query_proposal(id);
+    assert_eq!(proposal.proposer, Addr::unchecked("gold"));
+    assert_eq!(proposal.total_power, Uint128::new(100));
+
+    suite.a_week_passes();
+    suite.execute("gold", id).unwrap();
+    assert_eq!(suite.balance("payee"), Uint128::new(10));
+}
+
+#[test]
+#[should_panic(expected = "Required threshold cannot be zero")]
+fn test_zero_objection_threshold() {
+    let mut builder = SuiteBuilder::default();
+    builder.instantiate.objection_threshold = PercentageThreshold::Percent(Decimal::zero());
+    builder.build();
+}
@@ -1,18 +1,22 @@
+use std::ops::Add;
+
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Attribute, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply,
-    Response, StdResult, Storage, SubMsg, WasmMsg,
+    to_json_binary, Addr, Attribute, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo,
+    Order, Reply, Response, StdError, StdResult, Storage, SubMsg, Timestamp, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw_hooks::Hooks;
 use cw_storage_plus::Bound;
 use cw_utils::{parse_reply_instantiate_data, Duration};
 use dao_hooks::proposal::{
-    new_proposal_hooks, proposal_completed_hooks, proposal_status_changed_hooks,
+    new_proposal_hooks, proposal_amended_hooks, proposal_completed_hooks,
+    proposal_status_changed_hooks,
 };
 use dao_hooks::vote::new_vote_hooks;
 use dao_interface::voting::IsActiveResponse;
+use dao_voting::delegation::{get_delegated_voting_power, get_delegations_at_height};
 use dao_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
 use dao_voting::proposal::{
     SingleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE,
@@ -20,6 +24,7 @@ use dao_voting::proposal::{
 use dao_voting::reply::{
     failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
 };
+use dao_voting::schedule::{ExecutionSchedule, MAX_EXECUTIONS_PER_CALL};
 use dao_voting::status::Status;
 use dao_voting::threshold::Threshold;
 use dao_voting::veto::{VetoConfig, VetoError};
@@ -27,7 +32,10 @@ use dao_voting::voting::{get_total_power, get_voting_power, validate_voting_peri
 
 use crate::msg::MigrateMsg;
 use crate::proposal::{next_proposal_id, SingleChoiceProposal};
-use crate::state::{Config, CREATION_POLICY};
+use crate::state::{
+    Config, Revision, CREATION_POLICY, DELEGATION_MODULE, DELEGATION_OVERRIDES, EXECUTION_CURSORS,
+    PRE_PROPOSED, REVISIONS,
+};
 use crate::v1_state::{
     v1_duration_to_v2, v1_expiration_to_v2, v1_status_to_v2, v1_threshold_to_v2, v1_votes_to_v2,
 };
@@ -36,13 +44,21 @@ use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::advance_proposal_id,
     query::ProposalListResponse,
-    query::{ProposalResponse, VoteInfo, VoteListResponse, VoteResponse},
+    query::{
+        ProposalResponse, RevisionListResponse, RevisionResponse, UpcomingExecution,
+        UpcomingExecutionsResponse, VoteInfo, VoteListResponse, VoteResponse,
+    },
     state::{Ballot, BALLOTS, CONFIG, PROPOSALS, PROPOSAL_COUNT, PROPOSAL_HOOKS, VOTE_HOOKS},
 };
 use cw_proposal_single_v1 as v1;
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Pre-propose modules whose approval of a proposal does not depend
+/// on its contents. Proposals they create may be amended by their
+/// proposer during review.
+const AMENDABLE_PRE_PROPOSE_MODULES: &[&str] = &["crates.io:dao-pre-propose-single"];
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -63,6 +79,8 @@ pub fn instantiate(
         .pre_propose_info
         .into_initial_policy_and_messages(dao.clone())?;
 
+    let review_period = validate_review_period(msg.review_period, &max_voting_period)?;
+
     // if veto is configured, validate its fields
     if let Some(veto_config) = &msg.veto {
         veto_config.validate(&deps.as_ref(), &max_voting_period)?;
@@ -76,6 +94,7 @@ pub fn instantiate(
         dao: dao.clone(),
         allow_revoting: msg.allow_revoting,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
+        review_period,
         veto: msg.veto,
     };
 
@@ -109,7 +128,16 @@ pub fn execute(
             proposal_id,
             rationale,
         } => execute_update_rationale(deps, info, proposal_id, rationale),
+        ExecuteMsg::Amend {
+            proposal_id,
+            title,
+            description,
+            msgs,
+        } => execute_amend(deps, env, info, proposal_id, title, description, msgs),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
+        ExecuteMsg::ExecuteScheduled { proposal_id, limit } => {
+            execute_execute_scheduled(deps, env, info, proposal_id, limit)
+        }
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
         ExecuteMsg::UpdateConfig {
             threshold,
@@ -119,6 +147,7 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            review_period,
             veto,
         } => execute_update_config(
             deps,
@@ -130,11 +159,15 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            review_period,
             veto,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
         }
+        ExecuteMsg::UpdateDelegationModule { module } => {
+            execute_update_delegation_module(deps, info, module)
+        }
         ExecuteMsg::AddProposalHook { address } => {
             execute_add_proposal_hook(deps, env, info, address)
         }
@@ -159,6 +192,7 @@ pub fn execute_propose(
         msgs,
         proposer,
         vote,
+        schedule,
     }: ProposeMsg,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
@@ -182,6 +216,10 @@ pub fn execute_propose(
         _ => return Err(ContractError::InvalidProposer {}),
     };
 
+    if let Some(schedule) = &schedule {
+        schedule.validate()?;
+    }
+
     let voting_module: Addr = deps.querier.query_wasm_smart(
         config.dao.clone(),
         &dao_interface::msg::QueryMsg::VotingModule {},
@@ -198,7 +236,24 @@ pub fn execute_propose(
         return Err(ContractError::InactiveDao {});
     }
 
-    let expiration = config.max_voting_period.after(&env.block);
+    // If a review period is configured, voting opens once it ends
+    // and the voting period begins from then.
+    let review_period = config
+        .review_period
+        .map(|review_period| review_period.after(&env.block));
+    let (expiration, min_voting_period) = match review_period {
+        Some(voting_start) => (
+            voting_start.add(config.max_voting_period)?,
+            config
+                .min_voting_period
+                .map(|min| voting_start.add(min))
+                .transpose()?,
+        ),
+        None => (
+            config.max_voting_period.after(&env.block),
+            config.min_voting_period.map(|min| min.after(&env.block)),
+        ),
+    };
 
     let total_power = get_total_power(deps.as_ref(), &config.dao, Some(env.block.height))?;
 
@@ -209,7 +264,9 @@ pub fn execute_propose(
             description,
             proposer: proposer.clone(),
             start_height: env.block.height,
-            min_voting_period: config.min_voting_period.map(|min| min.after(&env.block)),
+            min_voting_period,
+            review_period,
+            schedule,
             expiration,
             threshold: config.threshold,
             total_power,
@@ -250,6 +307,29 @@ pub fn execute_propose(
 
     PROPOSALS.save(deps.storage, id, &proposal)?;
 
+    // Proposals that may be amended keep every revision, starting
+    // with the proposal as it was created.
+    if proposal.review_period.is_some() {
+        REVISIONS.save(
+            deps.storage,
+            (id, 1),
+            &Revision {
+                title: proposal.title.clone(),
+                description: proposal.description.clone(),
+                msgs: proposal.msgs.clone(),
+                height: env.block.height,
+            },
+        )?;
+
+        // Proposals created by a pre-propose module may not be
+        // amended, unless the module does not approve their contents.
+        if let ProposalCreationPolicy::Module { addr } = &proposal_creation_policy {
+            if !is_amendable_pre_propose_module(deps.as_ref(), addr) {
+                PRE_PROPOSED.save(deps.storage, id, addr)?;
+            }
+        }
+    }
+
     let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
 
     // Auto cast vote if given.
@@ -282,6 +362,193 @@ pub fn execute_propose(
         .add_attribute("status", proposal.status.to_string()))
 }
 
+/// Executes the executions of a scheduled proposal that have come
+/// due since it was last cranked, up to `limit` of them.
+pub fn execute_execute_scheduled(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let executed = EXECUTION_CURSORS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NotScheduled { id: proposal_id })?;
+    let prop = PROPOSALS.load(deps.storage, proposal_id)?;
+    let schedule = prop
+        .schedule
+        .clone()
+        .ok_or(ContractError::NotScheduled { id: proposal_id })?;
+
+    if schedule.due_count(&env.block.time) <= executed {
+        return Err(ContractError::NothingDue {
+            id: proposal_id,
+            due_at: schedule.due_at(executed).unwrap_or_default(),
+        });
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let (messages, executions) = execute_due(
+        deps.storage,
+        &config,
+        proposal_id,
+        &prop,
+        &schedule,
+        executed,
+        &env.block.time,
+        limit.map_or(MAX_EXECUTIONS_PER_CALL, |limit| {
+            limit.min(MAX_EXECUTIONS_PER_CALL)
+        }),
+    )?;
+
+    Ok(Response::default()
+        .add_submessages(messages)
+        .add_attribute("action", "execute_scheduled")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("executions", executions.to_string())
+        .add_attribute(
+            "remaining",
+            (schedule.times() - executed - executions).to_string(),
+        ))
+}
+
+/// Creates a message executing `msgs` on behalf of the DAO. If the
+/// module is configured to close proposals whose execution fails,
+/// failures are caught and handled in `reply`.
+fn proposal_execution_message(
+    config: &Config,
+    proposal_id: u64,
+    msgs: Vec<CosmosMsg>,
+) -> StdResult<SubMsg> {
+    let execute_message = WasmMsg::Execute {
+        contract_addr: config.dao.to_string(),
+        msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
+            msgs,
+            proposal_id: Some(proposal_id),
+        })?,
+        funds: vec![],
+    };
+    Ok(match config.close_proposal_on_execution_failure {
+        true => {
+            let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
+            SubMsg::reply_on_error(execute_message, masked_proposal_id)
+        }
+        false => SubMsg::new(execute_message),
+    })
+}
+
+/// Creates a message for each execution of a scheduled proposal that
+/// has come due after the first `executed` executions, up to `limit`
+/// of them, and advances the proposal's execution cursor past them.
+/// Returns the messages and the number of executions made.
+#[allow(clippy::too_many_arguments)]
+fn execute_due(
+    storage: &mut dyn Storage,
+    config: &Config,
+    proposal_id: u64,
+    prop: &SingleChoiceProposal,
+    schedule: &ExecutionSchedule,
+    executed: u32,
+    now: &Timestamp,
+    limit: u32,
+) -> Result<(Vec<SubMsg>, u32), ContractError> {
+    let due = schedule
+        .due_count(now)
+        .max(executed)
+        .min(executed.saturating_add(limit));
+    let messages = if prop.msgs.is_empty() {
+        vec![]
+    } else {
+        (executed..due)
+            .map(|_| proposal_execution_message(config, proposal_id, prop.msgs.clone()))
+            .collect::<StdResult<Vec<_>>>()?
+    };
+
+    if due == schedule.times() {
+        EXECUTION_CURSORS.remove(storage, proposal_id);
+    } else {
+        EXECUTION_CURSORS.save(storage, proposal_id, &due)?;
+    }
+
+    Ok((messages, due - executed))
+}
+
+pub fn execute_amend(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    title: String,
+    description: String,
+    msgs: Vec<CosmosMsg<Empty>>,
+) -> Result<Response, ContractError> {
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    if prop.proposer != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Proposals may only be amended until their review period ends,
+    // and not at all once they have been vetoed.
+    let in_review = prop
+        .review_period
+        .map(|review_period| !review_period.is_expired(&env.block))
+        .unwrap_or(false);
+    if !in_review || prop.status != Status::Open {
+        return Err(ContractError::NotInReview { id: proposal_id });
+    }
+
+    // Amendments would bypass any approval or deposit checks made
+    // by the pre-propose module that created the proposal.
+    if PRE_PROPOSED.has(deps.storage, proposal_id) {
+        return Err(ContractError::PreProposedAmendment { id: proposal_id });
+    }
+
+    prop.title = title;
+    prop.description = description;
+    prop.msgs = msgs;
+
+    let proposal_size = cosmwasm_std::to_json_vec(&prop)?.len() as u64;
+    if proposal_size > MAX_PROPOSAL_SIZE {
+        return Err(ContractError::ProposalTooLarge {
+            size: proposal_size,
+            max: MAX_PROPOSAL_SIZE,
+        });
+    }
+
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let revision = REVISIONS
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .unwrap_or_default()
+        + 1;
+    REVISIONS.save(
+        deps.storage,
+        (proposal_id, revision),
+        &Revision {
+            title: prop.title,
+            description: prop.description,
+            msgs: prop.msgs,
+            height: env.block.height,
+        },
+    )?;
+
+    let hooks = proposal_amended_hooks(PROPOSAL_HOOKS, deps.storage, proposal_id, revision)?;
+
+    Ok(Response::default()
+        .add_submessages(hooks)
+        .add_attribute("action", "amend")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("revision", revision.to_string()))
+}
+
 pub fn execute_veto(
     deps: DepsMut,
     env: Env,
@@ -424,25 +691,34 @@ pub fn execute_execute(
 
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
 
-    let response = {
-        if !prop.msgs.is_empty() {
-            let execute_message = WasmMsg::Execute {
-                contract_addr: config.dao.to_string(),
-                msg: to_json_binary(&dao_interface::msg::ExecuteMsg::ExecuteProposalHook {
-                    msgs: prop.msgs,
-                })?,
-                funds: vec![],
-            };
-            match config.close_proposal_on_execution_failure {
-                true => {
-                    let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
-                    Response::default()
-                        .add_submessage(SubMsg::reply_on_error(execute_message, masked_proposal_id))
-                }
-                false => Response::default().add_message(execute_message),
-            }
-        } else {
+    let response = match prop.schedule {
+        // Scheduled proposals are executed on their schedule from
+        // here on, starting with any executions that are already due.
+        Some(ref schedule) => {
+            let (messages, executions) = execute_due(
+                deps.storage,
+                &config,
+                proposal_id,
+                &prop,
+                schedule,
+                0,
+                &env.block.time,
+                MAX_EXECUTIONS_PER_CALL,
+            )?;
             Response::default()
+                .add_submessages(messages)
+                .add_attribute("executions", executions.to_string())
+        }
+        None => {
+            if !prop.msgs.is_empty() {
+                Response::default().add_submessage(proposal_execution_message(
+                    &config,
+                    proposal_id,
+                    prop.msgs,
+                )?)
+            } else {
+                Response::default()
+            }
         }
     };
 
@@ -470,7 +746,7 @@ pub fn execute_execute(
 }
 
 pub fn execute_vote(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     sender: Addr,
     proposal_id: u64,
@@ -493,16 +769,43 @@ pub fn execute_vote(
         return Err(ContractError::Expired { id: proposal_id });
     }
 
-    let vote_power = get_voting_power(
+    // Voting opens once the review period ends.
+    if let Some(review_period) = prop.review_period {
+        if !review_period.is_expired(&env.block) {
+            return Err(ContractError::InReview { id: proposal_id });
+        }
+    }
+
+    let mut vote_power = get_voting_power(
         deps.as_ref(),
         sender.clone(),
         &config.dao,
         Some(prop.start_height),
     )?;
+
+    // Delegates also vote with the voting power delegated to them,
+    // less what delegators have already claimed back by voting
+    // directly on this proposal.
+    let delegation_module = DELEGATION_MODULE.may_load(deps.storage)?;
+    if let Some(delegation_module) = &delegation_module {
+        let delegated = get_delegated_voting_power(
+            deps.as_ref(),
+            delegation_module,
+            &sender,
+            prop.start_height,
+        )?;
+        let overridden = DELEGATION_OVERRIDES
+            .may_load(deps.storage, (proposal_id, &sender))?
+            .unwrap_or_default();
+        vote_power += delegated.saturating_sub(overridden);
+    }
+
     if vote_power.is_zero() {
         return Err(ContractError::NotRegistered {});
     }
 
+    let is_first_vote = !BALLOTS.has(deps.storage, (proposal_id, &sender));
+
     BALLOTS.update(deps.storage, (proposal_id, &sender), |bal| match bal {
         Some(current_ballot) => {
             if prop.allow_revoting {
@@ -535,6 +838,20 @@ pub fn execute_vote(
         }),
     })?;
 
+    // A delegator voting directly takes back the voting power they
+    // delegated, removing it from any delegate who already voted.
+    if is_first_vote {
+        if let Some(delegation_module) = &delegation_module {
+            apply_delegator_override(
+                deps.branch(),
+                delegation_module,
+                &mut prop,
+                proposal_id,
+                &sender,
+            )?;
+        }
+    }
+
     let old_status = prop.status;
 
     prop.votes.add_vote(vote, vote_power);
@@ -573,6 +890,43 @@ pub fn execute_vote(
         .add_attribute("status", prop.status.to_string()))
 }
 
+/// Removes the voting power `delegator` delegated from the ballots of
+/// delegates who already voted on the proposal and records it so that
+/// delegates voting later do not count it.
+fn apply_delegator_override(
+    deps: DepsMut,
+    delegation_module: &Addr,
+    prop: &mut SingleChoiceProposal,
+    proposal_id: u64,
+    delegator: &Addr,
+) -> Result<(), ContractError> {
+    let delegations = get_delegations_at_height(
+        deps.as_ref(),
+        delegation_module,
+        delegator,
+        prop.start_height,
+    )?;
+
+    for delegation in delegations {
+        DELEGATION_OVERRIDES.update(
+            deps.storage,
+            (proposal_id, &delegation.delegate),
+            |overridden| -> StdResult<_> { Ok(overridden.unwrap_or_default() + delegation.power) },
+        )?;
+
+        if let Some(mut ballot) =
+            BALLOTS.may_load(deps.storage, (proposal_id, &delegation.delegate))?
+        {
+            let removed = delegation.power.min(ballot.power);
+            prop.votes.remove_vote(ballot.vote, removed);
+            ballot.power -= removed;
+            BALLOTS.save(deps.storage, (proposal_id, &delegation.delegate), &ballot)?;
+        }
+    }
+
+    Ok(())
+}
+
 pub fn execute_update_rationale(
     deps: DepsMut,
     info: MessageInfo,
@@ -656,6 +1010,7 @@ pub fn execute_update_config(
     allow_revoting: bool,
     dao: String,
     close_proposal_on_execution_failure: bool,
+    review_period: Option<Duration>,
     veto: Option<VetoConfig>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
@@ -669,6 +1024,7 @@ pub fn execute_update_config(
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(min_voting_period, max_voting_period)?;
+    let review_period = validate_review_period(review_period, &max_voting_period)?;
 
     // if veto is configured, validate its fields
     if let Some(veto_config) = &veto {
@@ -685,6 +1041,7 @@ pub fn execute_update_config(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            review_period,
             veto,
         },
     )?;
@@ -714,6 +1071,57 @@ pub fn execute_update_proposal_creation_policy(
         .add_attribute("new_policy", format!("{initial_policy:?}")))
 }
 
+pub fn execute_update_delegation_module(
+    deps: DepsMut,
+    info: MessageInfo,
+    module: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.dao != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let module = match module {
+        Some(module) => {
+            let module = deps.api.addr_validate(&module)?;
+            DELEGATION_MODULE.save(deps.storage, &module)?;
+            module.into_string()
+        }
+        None => {
+            DELEGATION_MODULE.remove(deps.storage);
+            "_none".to_string()
+        }
+    };
+
+    Ok(Response::default()
+        .add_attribute("action", "update_delegation_module")
+        .add_attribute("sender", info.sender)
+        .add_attribute("delegation_module", module))
+}
+
+/// Checks that the review period, if any, has the same units as the
+/// voting period so that the two may be added together.
+/// Whether proposals created by the pre-propose module at `addr` may
+/// be amended. Modules without a cw2 contract version are assumed to
+/// approve the contents of their proposals.
+fn is_amendable_pre_propose_module(deps: Deps, addr: &Addr) -> bool {
+    cw2::query_contract_info(&deps.querier, addr)
+        .map(|info| AMENDABLE_PRE_PROPOSE_MODULES.contains(&info.contract.as_str()))
+        .unwrap_or(false)
+}
+
+fn validate_review_period(
+    review_period: Option<Duration>,
+    max_voting_period: &Duration,
+) -> Result<Option<Duration>, ContractError> {
+    match (review_period, max_voting_period) {
+        (None, _)
+        | (Some(Duration::Height(_)), Duration::Height(_))
+        | (Some(Duration::Time(_)), Duration::Time(_)) => Ok(review_period),
+        _ => Err(ContractError::ReviewPeriodUnitsConflict {}),
+    }
+}
+
 pub fn add_hook(
     hooks: Hooks,
     storage: &mut dyn Storage,
@@ -842,9 +1250,18 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             start_before,
             limit,
         } => query_reverse_proposals(deps, env, start_before, limit),
+        QueryMsg::ListRevisions {
+            proposal_id,
+            start_after,
+            limit,
+        } => query_list_revisions(deps, proposal_id, start_after, limit),
+        QueryMsg::UpcomingExecutions { start_after, limit } => {
+            query_upcoming_executions(deps, start_after, limit)
+        }
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
         QueryMsg::ProposalHooks {} => to_json_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_json_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::DelegationModule {} => to_json_binary(&DELEGATION_MODULE.may_load(deps.storage)?),
     }
 }
 
@@ -957,6 +1374,55 @@ pub fn query_list_votes(
     to_json_binary(&VoteListResponse { votes })
 }
 
+pub fn query_list_revisions(
+    deps: Deps,
+    proposal_id: u64,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let min = start_after.map(Bound::exclusive);
+
+    let revisions = REVISIONS
+        .prefix(proposal_id)
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| item.map(|(id, revision)| RevisionResponse { id, revision }))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&RevisionListResponse { revisions })
+}
+
+pub fn query_upcoming_executions(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT);
+    let min = start_after.map(Bound::exclusive);
+
+    let executions = EXECUTION_CURSORS
+        .range(deps.storage, min, None, Order::Ascending)
+        .take(limit as usize)
+        .map(|item| {
+            let (proposal_id, executed) = item?;
+            let schedule = PROPOSALS
+                .load(deps.storage, proposal_id)?
+                .schedule
+                .ok_or_else(|| StdError::not_found("dao_voting::schedule::ExecutionSchedule"))?;
+            Ok(UpcomingExecution {
+                proposal_id,
+                due_at: schedule
+                    .due_at(executed)
+                    .ok_or_else(|| StdError::not_found("execution"))?,
+                remaining: schedule.times() - executed,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    to_json_binary(&UpcomingExecutionsResponse { executions })
+}
+
 pub fn query_info(deps: Deps) -> StdResult<Binary> {
     let info = cw2::get_contract_version(deps.storage)?;
     to_json_binary(&dao_interface::voting::InfoResponse { info })
@@ -1001,6 +1467,7 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                     allow_revoting: current_config.allow_revoting,
                     dao: current_config.dao.clone(),
                     close_proposal_on_execution_failure,
+                    review_period: None,
                     veto,
                 },
             )?;
@@ -1039,6 +1506,8 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                         proposer: prop.proposer,
                         start_height: prop.start_height,
                         min_voting_period: prop.min_voting_period.map(v1_expiration_to_v2),
+                        review_period: None,
+                        schedule: None,
                         expiration: v1_expiration_to_v2(prop.expiration),
                         threshold: v1_threshold_to_v2(prop.threshold),
                         total_power: prop.total_power,
@@ -1070,6 +1539,9 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
     let repl = TaggedReplyId::new(msg.id)?;
     match repl {
         TaggedReplyId::FailedProposalExecution(proposal_id) => {
+            // A failed execution ends any remaining scheduled
+            // executions of the proposal.
+            EXECUTION_CURSORS.remove(deps.storage, proposal_id);
             PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
                 Some(mut prop) => {
                     prop.status = Status::ExecutionFailed;
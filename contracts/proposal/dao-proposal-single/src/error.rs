@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Timestamp};
 use cw_hooks::HookError;
 use cw_utils::ParseReplyError;
 use dao_voting::{reply::error::TagError, veto::VetoError};
@@ -24,6 +24,9 @@ pub enum ContractError {
     #[error(transparent)]
     ThresholdError(#[from] dao_voting::threshold::ThresholdError),
 
+    #[error(transparent)]
+    ScheduleError(#[from] dao_voting::schedule::ScheduleError),
+
     #[error(transparent)]
     VotingError(#[from] dao_voting::error::VotingError),
 
@@ -69,6 +72,24 @@ pub enum ContractError {
     #[error("min voting period must be less than or equal to max voting period")]
     InvalidMinVotingPeriod {},
 
+    #[error("review_period and max_voting_period must have the same units (height or time)")]
+    ReviewPeriodUnitsConflict {},
+
+    #[error("proposal ({id}) is under review and not yet open for voting")]
+    InReview { id: u64 },
+
+    #[error("proposal ({id}) is not under review and may not be amended")]
+    NotInReview { id: u64 },
+
+    #[error("proposal ({id}) was created by a pre-propose module and may not be amended")]
+    PreProposedAmendment { id: u64 },
+
+    #[error("proposal ({id}) has no outstanding scheduled executions")]
+    NotScheduled { id: u64 },
+
+    #[error("no execution of proposal ({id}) is due until ({due_at})")]
+    NothingDue { id: u64, due_at: Timestamp },
+
     #[error(
         "pre-propose modules must specify a proposer. lacking one, no proposer should be specified"
     )]
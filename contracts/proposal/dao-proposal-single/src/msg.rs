@@ -1,4 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{CosmosMsg, Empty};
 use cw_utils::Duration;
 use dao_dao_macros::proposal_module_query;
 use dao_voting::{
@@ -38,6 +39,11 @@ pub struct InstantiateMsg {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// An optional period after a proposal is created during which
+    /// its proposer may amend it. Voting opens once this period
+    /// ends. Must have the same units as `max_voting_period`. If
+    /// `None`, voting opens as soon as a proposal is created.
+    pub review_period: Option<Duration>,
     /// Optional veto configuration for proposal execution.
     /// If set, proposals can only be executed after the timelock
     /// delay expiration.
@@ -49,7 +55,9 @@ pub struct InstantiateMsg {
 #[cw_serde]
 #[derive(cw_orch::ExecuteFns)]
 pub enum ExecuteMsg {
-    /// Creates a proposal in the module.
+    /// Creates a proposal in the module. If a review period is
+    /// configured, voting is not yet open on the proposal, so
+    /// proposing with a `vote` fails.
     Propose(SingleChoiceProposeMsg),
     /// Votes on a proposal. Voting power is determined by the DAO's
     /// voting power module.
@@ -76,6 +84,30 @@ pub enum ExecuteMsg {
         /// The ID of the proposal to execute.
         proposal_id: u64,
     },
+    /// Executes the executions of a scheduled proposal that have
+    /// come due but not yet been executed. Callable by anyone once
+    /// the proposal has been executed with `Execute`.
+    ExecuteScheduled {
+        /// The ID of the scheduled proposal.
+        proposal_id: u64,
+        /// The maximum number of due executions to make. Defaults to,
+        /// and may be at most, ten.
+        limit: Option<u32>,
+    },
+    /// Amends a proposal during its review period. Only the proposal's
+    /// proposer may amend it. The previous revision is kept and may
+    /// be queried with `ListRevisions`.
+    Amend {
+        /// The ID of the proposal to amend.
+        proposal_id: u64,
+        /// The new title of the proposal.
+        title: String,
+        /// The new description of the proposal.
+        description: String,
+        /// The new messages that will be executed should the proposal
+        /// pass.
+        msgs: Vec<CosmosMsg<Empty>>,
+    },
     /// Callable only if veto is configured
     Veto {
         /// The ID of the proposal to veto.
@@ -123,6 +155,11 @@ pub enum ExecuteMsg {
         /// remain open until the DAO's treasury was large enough for it to be
         /// executed.
         close_proposal_on_execution_failure: bool,
+        /// An optional period after a proposal is created during
+        /// which its proposer may amend it. Voting opens once this
+        /// period ends. This will only apply to proposals created
+        /// after the config update.
+        review_period: Option<Duration>,
         /// Optional time delay on proposal execution, during which the
         /// proposal may be vetoed.
         veto: Option<VetoConfig>,
@@ -130,6 +167,10 @@ pub enum ExecuteMsg {
     /// Update's the proposal creation policy used for this
     /// module. Only the DAO may call this method.
     UpdatePreProposeInfo { info: PreProposeInfo },
+    /// Sets the vote delegation module used to apply delegated voting
+    /// power, or removes it if `None`. Only the DAO may call this
+    /// method.
+    UpdateDelegationModule { module: Option<String> },
     /// Adds an address as a consumer of proposal hooks. Consumers of
     /// proposal hooks have hook messages executed on them whenever
     /// the status of a proposal changes or a proposal is created. If
@@ -199,6 +240,33 @@ pub enum QueryMsg {
         /// query. If no limit is specified a max of 30 are returned.
         limit: Option<u64>,
     },
+    /// Lists the revisions of a proposal made during its review
+    /// period, in the order they were made. The first revision is
+    /// the proposal as it was created. Proposals created without a
+    /// review period have no revisions.
+    #[returns(crate::query::RevisionListResponse)]
+    ListRevisions {
+        /// The proposal to list the revisions of.
+        proposal_id: u64,
+        /// The revision to start listing revisions after.
+        start_after: Option<u64>,
+        /// The maximum number of revisions to return in response to
+        /// this query. If no limit is specified a max of 30 are
+        /// returned.
+        limit: Option<u64>,
+    },
+    /// Lists executed proposals with a schedule that has remaining
+    /// executions, in ascending order of proposal ID, along with when
+    /// their next execution is due.
+    #[returns(crate::query::UpcomingExecutionsResponse)]
+    UpcomingExecutions {
+        /// The proposal ID to start listing after.
+        start_after: Option<u64>,
+        /// The maximum number of proposals to return in response to
+        /// this query. If no limit is specified a max of 30 are
+        /// returned.
+        limit: Option<u64>,
+    },
     /// Returns the number of proposals that have been created in this module.
     #[returns(::std::primitive::u64)]
     ProposalCount {},
@@ -211,6 +279,10 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Gets the vote delegation module used to apply delegated voting
+    /// power, if any.
+    #[returns(::std::option::Option<::cosmwasm_std::Addr>)]
+    DelegationModule {},
 }
 
 #[cw_serde]
@@ -5,6 +5,7 @@ use crate::state::PROPOSAL_COUNT;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, BlockInfo, CosmosMsg, Decimal, Empty, StdResult, Storage, Uint128};
 use cw_utils::Expiration;
+use dao_voting::schedule::ExecutionSchedule;
 use dao_voting::status::Status;
 use dao_voting::threshold::{PercentageThreshold, Threshold};
 use dao_voting::veto::VetoConfig;
@@ -26,6 +27,17 @@ pub struct SingleChoiceProposal {
     /// voting. The proposal may not pass unless this is expired or
     /// None.
     pub min_voting_period: Option<Expiration>,
+    /// The time at which this proposal's review period ends and
+    /// voting opens. Until then, the proposer may amend the
+    /// proposal. None if the proposal was created without a review
+    /// period.
+    #[serde(default)]
+    pub review_period: Option<Expiration>,
+    /// The schedule on which this proposal's messages are executed
+    /// once it is executed. None if the messages are executed
+    /// immediately.
+    #[serde(default)]
+    pub schedule: Option<ExecutionSchedule>,
     /// The the time at which this proposal will expire and close for
     /// additional votes.
     pub expiration: Expiration,
@@ -305,6 +317,8 @@ mod test {
             start_height: 100,
             expiration,
             min_voting_period: Some(min_voting_period),
+            review_period: None,
+            schedule: None,
             allow_revoting,
             msgs: vec![],
             status: Status::Open,
@@ -1,6 +1,7 @@
 use crate::proposal::SingleChoiceProposal;
+use crate::state::Revision;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Timestamp, Uint128};
 use dao_voting::voting::Vote;
 
 /// Information about a proposal returned by proposal queries.
@@ -43,3 +44,36 @@ pub struct VoteListResponse {
 pub struct ProposalListResponse {
     pub proposals: Vec<ProposalResponse>,
 }
+
+/// A revision of a proposal returned by `ListRevisions`.
+#[cw_serde]
+pub struct RevisionResponse {
+    /// The number of the revision. The first revision is one.
+    pub id: u64,
+    pub revision: Revision,
+}
+
+/// The revisions of a proposal returned by `ListRevisions`.
+#[cw_serde]
+pub struct RevisionListResponse {
+    pub revisions: Vec<RevisionResponse>,
+}
+
+/// The next execution of a scheduled proposal.
+#[cw_serde]
+pub struct UpcomingExecution {
+    /// The ID of the scheduled proposal.
+    pub proposal_id: u64,
+    /// The time at which the next execution is due. If this is in
+    /// the past, the execution may be triggered with
+    /// `ExecuteScheduled`.
+    pub due_at: Timestamp,
+    /// The number of executions remaining, including the next one.
+    pub remaining: u32,
+}
+
+/// The upcoming executions returned by `UpcomingExecutions`.
+#[cw_serde]
+pub struct UpcomingExecutionsResponse {
+    pub executions: Vec<UpcomingExecution>,
+}
@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, CosmosMsg, Empty, Uint128};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
 use cw_utils::Duration;
@@ -24,6 +24,19 @@ pub struct Ballot {
     pub rationale: Option<String>,
 }
 
+/// A revision of a proposal's contents.
+#[cw_serde]
+pub struct Revision {
+    /// The title of the proposal.
+    pub title: String,
+    /// The description of the proposal.
+    pub description: String,
+    /// The messages that will be executed should the proposal pass.
+    pub msgs: Vec<CosmosMsg<Empty>>,
+    /// The block height at which this revision was made.
+    pub height: u64,
+}
+
 /// The governance module's configuration.
 #[cw_serde]
 pub struct Config {
@@ -58,6 +71,11 @@ pub struct Config {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// An optional period after a proposal is created during which
+    /// its proposer may amend it. Voting opens once this period
+    /// ends.
+    #[serde(default)]
+    pub review_period: Option<Duration>,
     /// Optional veto configuration. If set to `None`, veto option
     /// is disabled. Otherwise contains the configuration for veto flow.
     pub veto: Option<VetoConfig>,
@@ -70,6 +88,17 @@ pub const CONFIG: Item<Config> = Item::new("config_v2");
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
 pub const PROPOSALS: Map<u64, SingleChoiceProposal> = Map::new("proposals_v2");
 pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+/// Revisions of proposals created with a review period, keyed by
+/// proposal ID and revision number. Revision numbers start at one.
+pub const REVISIONS: Map<(u64, u64), Revision> = Map::new("revisions");
+/// The pre-propose module that created each proposal with a review
+/// period, keyed by proposal ID, where that module approves the
+/// contents of its proposals. These proposals may not be amended.
+pub const PRE_PROPOSED: Map<u64, Addr> = Map::new("pre_proposed");
+/// The number of executions made by executed proposals with a
+/// schedule, keyed by proposal ID. Removed once every execution in
+/// the schedule has been made.
+pub const EXECUTION_CURSORS: Map<u64, u32> = Map::new("execution_cursors");
 /// Consumers of proposal state change hooks.
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
@@ -77,3 +106,9 @@ pub const VOTE_HOOKS: Hooks = Hooks::new("vote_hooks");
 /// The address of the pre-propose module associated with this
 /// proposal module (if any).
 pub const CREATION_POLICY: Item<ProposalCreationPolicy> = Item::new("creation_policy");
+/// The vote delegation module used to apply delegated voting power
+/// (if any).
+pub const DELEGATION_MODULE: Item<Addr> = Item::new("delegation_module");
+/// Voting power withdrawn from each delegate by delegators who voted
+/// directly on a proposal, keyed by (proposal ID, delegate).
+pub const DELEGATION_OVERRIDES: Map<(u64, &Addr), Uint128> = Map::new("delegation_overrides");
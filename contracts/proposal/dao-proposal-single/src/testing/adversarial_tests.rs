@@ -180,6 +180,7 @@ pub fn test_executed_prop_state_remains_after_vote_swing() {
             false,
         ),
         close_proposal_on_execution_failure: true,
+        review_period: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -279,6 +280,7 @@ pub fn test_passed_prop_state_remains_after_vote_swing() {
             false,
         ),
         close_proposal_on_execution_failure: true,
+        review_period: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -1,6 +1,10 @@
-use cosmwasm_std::Empty;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+    WasmMsg,
+};
 
 use cw_multi_test::{Contract, ContractWrapper};
+use cw_storage_plus::Item;
 use dao_pre_propose_single as cppbps;
 
 pub(crate) fn cw20_base_contract() -> Box<dyn Contract<Empty>> {
@@ -59,6 +63,39 @@ pub(crate) fn pre_propose_single_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+const APPROVAL_PROPOSAL_MODULE: Item<Addr> = Item::new("proposal_module");
+
+/// A pre-propose module that reports itself as
+/// `dao-pre-propose-approval-single` and forwards every message it
+/// receives to the proposal module that instantiated it.
+pub(crate) fn approval_pre_propose_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        |deps: DepsMut,
+         _env: Env,
+         _info: MessageInfo,
+         msg: crate::msg::ExecuteMsg|
+         -> StdResult<Response> {
+            let proposal_module = APPROVAL_PROPOSAL_MODULE.load(deps.storage)?;
+            Ok(Response::default().add_message(WasmMsg::Execute {
+                contract_addr: proposal_module.into_string(),
+                msg: to_json_binary(&msg)?,
+                funds: vec![],
+            }))
+        },
+        |deps: DepsMut, _env: Env, info: MessageInfo, _msg: Empty| -> StdResult<Response> {
+            cw2::set_contract_version(
+                deps.storage,
+                "crates.io:dao-pre-propose-approval-single",
+                env!("CARGO_PKG_VERSION"),
+            )?;
+            APPROVAL_PROPOSAL_MODULE.save(deps.storage, &info.sender)?;
+            Ok(Response::default())
+        },
+        |_deps: Deps, _env: Env, _msg: Empty| -> StdResult<Binary> { to_json_binary(&Empty {}) },
+    );
+    Box::new(contract)
+}
+
 pub(crate) fn cw20_staked_balances_voting_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         dao_voting_cw20_staked::contract::execute,
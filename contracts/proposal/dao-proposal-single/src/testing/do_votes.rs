@@ -141,6 +141,7 @@ where
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        review_period: None,
         pre_propose_info,
     };
 
@@ -208,6 +209,7 @@ where
                 description: "This is a simple text proposal".to_string(),
                 msgs: vec![],
                 vote: None,
+                schedule: None,
             },
         },
         &funds,
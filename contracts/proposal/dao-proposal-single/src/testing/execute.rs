@@ -82,6 +82,7 @@ pub(crate) fn make_proposal(
                     msgs: msgs.clone(),
                     proposer: None,
                     vote,
+                    schedule: None,
                 }),
                 &[],
             )
@@ -96,6 +97,7 @@ pub(crate) fn make_proposal(
                         description: "description".to_string(),
                         msgs: msgs.clone(),
                         vote,
+                        schedule: None,
                     },
                 },
                 &funds,
@@ -178,6 +180,82 @@ pub(crate) fn execute_proposal_should_fail(
     .unwrap()
 }
 
+pub(crate) fn amend_proposal(
+    app: &mut App,
+    proposal_single: &Addr,
+    sender: &str,
+    proposal_id: u64,
+    title: &str,
+    msgs: Vec<CosmosMsg>,
+) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_single.clone(),
+        &ExecuteMsg::Amend {
+            proposal_id,
+            title: title.to_string(),
+            description: "description".to_string(),
+            msgs,
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+pub(crate) fn amend_proposal_should_fail(
+    app: &mut App,
+    proposal_single: &Addr,
+    sender: &str,
+    proposal_id: u64,
+) -> ContractError {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        proposal_single.clone(),
+        &ExecuteMsg::Amend {
+            proposal_id,
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs: vec![],
+        },
+        &[],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap()
+}
+
+pub(crate) fn execute_scheduled(app: &mut App, proposal_single: &Addr, proposal_id: u64) {
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        proposal_single.clone(),
+        &ExecuteMsg::ExecuteScheduled {
+            proposal_id,
+            limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+pub(crate) fn execute_scheduled_should_fail(
+    app: &mut App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+) -> ContractError {
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        proposal_single.clone(),
+        &ExecuteMsg::ExecuteScheduled {
+            proposal_id,
+            limit: None,
+        },
+        &[],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap()
+}
+
 pub(crate) fn vote_on_proposal_with_rationale(
     app: &mut App,
     proposal_single: &Addr,
@@ -8,7 +8,10 @@ use dao_voting::pre_propose::ProposalCreationPolicy;
 
 use crate::{
     msg::QueryMsg,
-    query::{ProposalListResponse, ProposalResponse, VoteListResponse, VoteResponse},
+    query::{
+        ProposalListResponse, ProposalResponse, RevisionListResponse, UpcomingExecutionsResponse,
+        VoteListResponse, VoteResponse,
+    },
     state::Config,
 };
 
@@ -72,6 +75,39 @@ pub(crate) fn query_list_votes(
         .unwrap()
 }
 
+pub(crate) fn query_list_revisions(
+    app: &App,
+    proposal_single: &Addr,
+    proposal_id: u64,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> RevisionListResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::ListRevisions {
+                proposal_id,
+                start_after,
+                limit,
+            },
+        )
+        .unwrap()
+}
+
+pub(crate) fn query_upcoming_executions(
+    app: &App,
+    proposal_single: &Addr,
+    start_after: Option<u64>,
+    limit: Option<u64>,
+) -> UpcomingExecutionsResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &QueryMsg::UpcomingExecutions { start_after, limit },
+        )
+        .unwrap()
+}
+
 pub(crate) fn query_vote(
     app: &App,
     proposal_module: &Addr,
@@ -11,7 +11,7 @@ use cw20::Cw20Coin;
 use cw_denom::CheckedDenom;
 use cw_hooks::{HookError, HooksResponse};
 use cw_multi_test::{next_block, App, Executor};
-use cw_utils::Duration;
+use cw_utils::{Duration, Expiration};
 use dao_interface::{
     state::{Admin, ModuleInstantiateInfo},
     voting::InfoResponse,
@@ -25,6 +25,7 @@ use dao_voting::{
         failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id,
         mask_proposal_hook_index, mask_vote_hook_index,
     },
+    schedule::{ExecutionSchedule, ScheduleError},
     status::Status,
     threshold::{ActiveThreshold, PercentageThreshold, Threshold},
     veto::{VetoConfig, VetoError},
@@ -35,14 +36,17 @@ use crate::{
     contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
     proposal::SingleChoiceProposal,
-    query::{ProposalResponse, VoteInfo},
+    query::{ProposalResponse, UpcomingExecution, VoteInfo},
     state::Config,
     testing::{
-        contracts::{pre_propose_single_contract, proposal_single_contract},
+        contracts::{
+            approval_pre_propose_contract, pre_propose_single_contract, proposal_single_contract,
+        },
         execute::{
             add_proposal_hook, add_proposal_hook_should_fail, add_vote_hook,
-            add_vote_hook_should_fail, close_proposal, close_proposal_should_fail,
-            execute_proposal, execute_proposal_should_fail, instantiate_cw20_base_default,
+            add_vote_hook_should_fail, amend_proposal, amend_proposal_should_fail, close_proposal,
+            close_proposal_should_fail, execute_proposal, execute_proposal_should_fail,
+            execute_scheduled, execute_scheduled_should_fail, instantiate_cw20_base_default,
             make_proposal, mint_cw20s, mint_natives, remove_proposal_hook,
             remove_proposal_hook_should_fail, remove_vote_hook, remove_vote_hook_should_fail,
             update_rationale, vote_on_proposal, vote_on_proposal_should_fail,
@@ -68,7 +72,9 @@ use crate::{
 use super::{
     do_votes::do_votes_staked_balances,
     execute::vote_on_proposal_with_rationale,
-    queries::{query_next_proposal_id, query_vote},
+    queries::{
+        query_list_revisions, query_next_proposal_id, query_upcoming_executions, query_vote,
+    },
     CREATOR_ADDR,
 };
 
@@ -121,6 +127,8 @@ fn test_simple_propose_staked_balances() {
         start_height: current_block.height,
         expiration: Duration::Time(604800).after(&current_block),
         min_voting_period: None,
+        review_period: None,
+        schedule: None,
         threshold: Threshold::ThresholdQuorum {
             quorum: PercentageThreshold::Percent(Decimal::percent(15)),
             threshold: PercentageThreshold::Majority {},
@@ -171,6 +179,8 @@ fn test_simple_proposal_cw4_voting() {
         start_height: current_block.height,
         expiration: Duration::Time(604800).after(&current_block),
         min_voting_period: None,
+        review_period: None,
+        schedule: None,
         threshold: Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Percent(Decimal::percent(15)),
             quorum: PercentageThreshold::Majority {},
@@ -223,6 +233,8 @@ fn test_simple_proposal_auto_vote_yes() {
         start_height: current_block.height,
         expiration: Duration::Time(604800).after(&current_block),
         min_voting_period: None,
+        review_period: None,
+        schedule: None,
         threshold: Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Percent(Decimal::percent(15)),
             quorum: PercentageThreshold::Majority {},
@@ -272,6 +284,8 @@ fn test_simple_proposal_auto_vote_no() {
         start_height: current_block.height,
         expiration: Duration::Time(604800).after(&current_block),
         min_voting_period: None,
+        review_period: None,
+        schedule: None,
         threshold: Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Percent(Decimal::percent(15)),
             quorum: PercentageThreshold::Majority {},
@@ -374,6 +388,8 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
         start_height: current_block.height,
         expiration: Duration::Time(604800).after(&current_block),
         min_voting_period: None,
+        review_period: None,
+        schedule: None,
         threshold: Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Percent(Decimal::percent(15)),
             quorum: PercentageThreshold::Majority {},
@@ -2073,6 +2089,7 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                review_period: None,
             })
             .unwrap(),
             funds: vec![],
@@ -2108,6 +2125,7 @@ fn test_update_config() {
             allow_revoting: false,
             dao: core_addr.clone(),
             close_proposal_on_execution_failure: false,
+            review_period: None,
         }
     );
 
@@ -2127,6 +2145,7 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                review_period: None,
             },
             &[],
         )
@@ -2156,6 +2175,7 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                review_period: None,
             },
             &[],
         )
@@ -2230,6 +2250,8 @@ fn test_anyone_may_propose_and_proposal_listing() {
                 proposer: Addr::unchecked("pppppp"),
                 start_height: current_block.height,
                 min_voting_period: None,
+                review_period: None,
+                schedule: None,
                 expiration: Duration::Time(604800).after(&current_block),
                 threshold: Threshold::ThresholdQuorum {
                     quorum: PercentageThreshold::Percent(Decimal::percent(15)),
@@ -2428,6 +2450,7 @@ fn test_active_threshold_absolute() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                schedule: None,
             }),
             &[],
         )
@@ -2467,6 +2490,7 @@ fn test_active_threshold_absolute() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                schedule: None,
             }),
             &[],
         )
@@ -2511,6 +2535,7 @@ fn test_active_threshold_percent() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                schedule: None,
             }),
             &[],
         )
@@ -2551,6 +2576,7 @@ fn test_active_threshold_percent() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                schedule: None,
             }),
             &[],
         )
@@ -2749,6 +2775,7 @@ fn test_allow_revoting_config_changes() {
             allow_revoting: false,
             dao: core_addr.to_string(),
             close_proposal_on_execution_failure: false,
+            review_period: None,
         },
         &[],
     )
@@ -3053,6 +3080,7 @@ fn test_proposal_count_initialized_to_zero() {
             allow_revoting: false,
             pre_propose_info,
             close_proposal_on_execution_failure: true,
+            review_period: None,
         },
         Some(vec![
             Cw20Coin {
@@ -3462,6 +3490,7 @@ fn test_execution_failed() {
             dao: config.dao.into_string(),
             // Disable.
             close_proposal_on_execution_failure: false,
+            review_period: None,
         },
         &[],
     )
@@ -3518,6 +3547,8 @@ fn test_reply_proposal_mock() {
                 start_height: env.block.height,
                 expiration: cw_utils::Duration::Height(6).after(&env.block),
                 min_voting_period: None,
+                review_period: None,
+                schedule: None,
                 threshold: Threshold::AbsolutePercentage {
                     percentage: PercentageThreshold::Majority {},
                 },
@@ -3567,6 +3598,7 @@ fn test_proposal_too_large() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                schedule: None,
             }),
             &[],
         )
@@ -3619,6 +3651,7 @@ fn test_proposal_creation_permissions() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                schedule: None,
             }),
             &[],
         )
@@ -3645,6 +3678,7 @@ fn test_proposal_creation_permissions() {
                 msgs: vec![],
                 proposer: None,
                 vote: None,
+                schedule: None,
             }),
             &[],
         )
@@ -3676,6 +3710,7 @@ fn test_proposal_creation_permissions() {
                 msgs: vec![],
                 proposer: Some("ekez".to_string()),
                 vote: None,
+                schedule: None,
             }),
             &[],
         )
@@ -4246,3 +4281,563 @@ fn test_proposal_count_goes_up() {
     let next = query_next_proposal_id(&app, &proposal_module);
     assert_eq!(next, 3);
 }
+
+fn setup_review_period_test(review_period: Option<Duration>) -> (App, Addr, Addr) {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    instantiate.review_period = review_period;
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: "one".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "two".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "three".to_string(),
+                amount: Uint128::new(1),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    (app, core_addr, proposal_module)
+}
+
+#[test]
+fn test_review_period_amendments() {
+    let (mut app, _core_addr, proposal_module) =
+        setup_review_period_test(Some(Duration::Time(60 * 60 * 24)));
+    let start = app.block_info();
+    let proposal_id = make_proposal(&mut app, &proposal_module, "one", vec![], None);
+
+    // voting opens once the review period ends, and the voting
+    // period begins from then.
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(
+        proposal.review_period,
+        Some(Expiration::AtTime(start.time.plus_seconds(60 * 60 * 24)))
+    );
+    assert_eq!(
+        proposal.expiration,
+        Expiration::AtTime(start.time.plus_seconds(60 * 60 * 24 + 604800))
+    );
+    let err =
+        vote_on_proposal_should_fail(&mut app, &proposal_module, "two", proposal_id, Vote::Yes);
+    assert!(matches!(err, ContractError::InReview { id } if id == proposal_id));
+
+    // only the proposer may amend.
+    let err = amend_proposal_should_fail(&mut app, &proposal_module, "two", proposal_id);
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    let msgs: Vec<CosmosMsg> = vec![BankMsg::Send {
+        to_address: "one".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()];
+    amend_proposal(
+        &mut app,
+        &proposal_module,
+        "one",
+        proposal_id,
+        "amended",
+        msgs.clone(),
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.title, "amended");
+    assert_eq!(proposal.msgs, msgs);
+
+    let revisions = query_list_revisions(&app, &proposal_module, proposal_id, None, None);
+    assert_eq!(
+        revisions
+            .revisions
+            .iter()
+            .map(|r| (r.id, r.revision.title.as_str()))
+            .collect::<Vec<_>>(),
+        vec![(1, "title"), (2, "amended")]
+    );
+    assert_eq!(revisions.revisions[0].revision.msgs, vec![]);
+    assert_eq!(revisions.revisions[1].revision.msgs, msgs);
+
+    let revisions = query_list_revisions(&app, &proposal_module, proposal_id, Some(1), None);
+    assert_eq!(revisions.revisions.len(), 1);
+    assert_eq!(revisions.revisions[0].id, 2);
+
+    // once the review period ends, amendments are closed and voting
+    // is open.
+    app.update_block(|b| b.time = b.time.plus_seconds(60 * 60 * 24));
+    let err = amend_proposal_should_fail(&mut app, &proposal_module, "one", proposal_id);
+    assert!(matches!(err, ContractError::NotInReview { id } if id == proposal_id));
+
+    vote_on_proposal(&mut app, &proposal_module, "one", proposal_id, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "two", proposal_id, Vote::Yes);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Passed);
+}
+
+#[test]
+fn test_no_review_period() {
+    let (mut app, _core_addr, proposal_module) = setup_review_period_test(None);
+    let proposal_id = make_proposal(&mut app, &proposal_module, "one", vec![], None);
+
+    let err = amend_proposal_should_fail(&mut app, &proposal_module, "one", proposal_id);
+    assert!(matches!(err, ContractError::NotInReview { id } if id == proposal_id));
+    vote_on_proposal(&mut app, &proposal_module, "one", proposal_id, Vote::Yes);
+
+    let revisions = query_list_revisions(&app, &proposal_module, proposal_id, None, None);
+    assert_eq!(revisions.revisions, vec![]);
+}
+
+#[test]
+fn test_review_period_amend_hook() {
+    let (mut app, core_addr, proposal_module) =
+        setup_review_period_test(Some(Duration::Time(60 * 60 * 24)));
+    let proposal_id = make_proposal(&mut app, &proposal_module, "one", vec![], None);
+
+    add_proposal_hook(
+        &mut app,
+        &proposal_module,
+        core_addr.as_str(),
+        "proposalhook",
+    );
+    amend_proposal(
+        &mut app,
+        &proposal_module,
+        "one",
+        proposal_id,
+        "amended",
+        vec![],
+    );
+
+    // the hook is fired, and removed as it is not a contract.
+    let proposal_hooks = query_proposal_hooks(&app, &proposal_module);
+    assert_eq!(proposal_hooks.hooks.len(), 0);
+}
+
+#[test]
+fn test_review_period_pre_proposed() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = get_pre_propose_info(&mut app, None, true);
+    instantiate.review_period = Some(Duration::Time(60 * 60 * 24));
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![Cw20Coin {
+            address: "one".to_string(),
+            amount: Uint128::new(1),
+        }]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, "one", vec![], None);
+
+    // dao-pre-propose-single does not approve the contents of
+    // proposals, so the proposer may amend them.
+    amend_proposal(
+        &mut app,
+        &proposal_module,
+        "one",
+        proposal_id,
+        "amended",
+        vec![],
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.title, "amended");
+    assert_eq!(proposal.proposer, Addr::unchecked("one"));
+}
+
+#[test]
+fn test_review_period_approval_pre_proposed() {
+    let mut app = App::default();
+    let pre_propose_id = app.store_code(approval_pre_propose_contract());
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::ModuleMayPropose {
+        info: ModuleInstantiateInfo {
+            code_id: pre_propose_id,
+            msg: to_json_binary(&Empty {}).unwrap(),
+            admin: Some(Admin::CoreModule {}),
+            funds: vec![],
+            label: "pre_propose_contract".to_string(),
+        },
+    };
+    instantiate.review_period = Some(Duration::Time(60 * 60 * 24));
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![Cw20Coin {
+            address: "one".to_string(),
+            amount: Uint128::new(1),
+        }]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let pre_propose = match query_creation_policy(&app, &proposal_module) {
+        ProposalCreationPolicy::Module { addr } => addr,
+        ProposalCreationPolicy::Anyone {} => panic!("expected a pre-propose module"),
+    };
+    app.execute_contract(
+        Addr::unchecked("one"),
+        pre_propose,
+        &ExecuteMsg::Propose(ProposeMsg {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs: vec![],
+            proposer: Some("one".to_string()),
+            vote: None,
+        }),
+        &[],
+    )
+    .unwrap();
+    let proposal_id = 1;
+
+    // the proposer may not amend the proposal around the module that
+    // approved it.
+    let err = amend_proposal_should_fail(&mut app, &proposal_module, "one", proposal_id);
+    assert!(matches!(err, ContractError::PreProposedAmendment { id } if id == proposal_id));
+}
+
+#[test]
+fn test_review_period_auto_vote() {
+    let (mut app, _core_addr, proposal_module) =
+        setup_review_period_test(Some(Duration::Time(60 * 60 * 24)));
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("one"),
+            proposal_module,
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: None,
+                vote: Some(SingleChoiceAutoVote {
+                    vote: Vote::Yes,
+                    rationale: None,
+                }),
+                schedule: None,
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::InReview { .. }));
+}
+
+#[test]
+fn test_review_period_units_conflict() {
+    let (mut app, core_addr, proposal_module) = setup_review_period_test(None);
+
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            proposal_module,
+            &ExecuteMsg::UpdateConfig {
+                veto: None,
+                threshold: Threshold::AbsolutePercentage {
+                    percentage: PercentageThreshold::Majority {},
+                },
+                max_voting_period: Duration::Time(604800),
+                min_voting_period: None,
+                only_members_execute: true,
+                allow_revoting: false,
+                dao: core_addr.to_string(),
+                close_proposal_on_execution_failure: true,
+                review_period: Some(Duration::Height(10)),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::ReviewPeriodUnitsConflict {}));
+}
+
+fn make_scheduled_proposal(
+    app: &mut App,
+    proposal_module: &Addr,
+    msgs: Vec<CosmosMsg>,
+    schedule: ExecutionSchedule,
+) -> Result<u64, ContractError> {
+    let proposal_id = query_next_proposal_id(app, proposal_module);
+    app.execute_contract(
+        Addr::unchecked("one"),
+        proposal_module.clone(),
+        &ExecuteMsg::Propose(ProposeMsg {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs,
+            proposer: None,
+            vote: None,
+            schedule: Some(schedule),
+        }),
+        &[],
+    )
+    .map_err(|e| e.downcast::<ContractError>().unwrap())?;
+    Ok(proposal_id)
+}
+
+#[test]
+fn test_recurring_execution() {
+    let (mut app, core_addr, proposal_module) = setup_review_period_test(None);
+    mint_natives(&mut app, core_addr.as_str(), coins(100, "ujuno"));
+
+    // pay "four" monthly for three months, starting in a week.
+    let month = 60 * 60 * 24 * 30;
+    let start = app.block_info().time.plus_seconds(604800);
+    let msgs: Vec<CosmosMsg> = vec![BankMsg::Send {
+        to_address: "four".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()];
+    let proposal_id = make_scheduled_proposal(
+        &mut app,
+        &proposal_module,
+        msgs,
+        ExecutionSchedule::Recurring {
+            start,
+            interval: month,
+            times: 3,
+        },
+    )
+    .unwrap();
+    vote_on_proposal(&mut app, &proposal_module, "one", proposal_id, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "two", proposal_id, Vote::Yes);
+
+    // nothing is scheduled until the proposal is executed.
+    let err = execute_scheduled_should_fail(&mut app, &proposal_module, proposal_id);
+    assert!(matches!(err, ContractError::NotScheduled { id } if id == proposal_id));
+
+    execute_proposal(&mut app, &proposal_module, "one", proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Executed);
+    assert_eq!(query_balance_native(&app, "four", "ujuno"), Uint128::zero());
+    assert_eq!(
+        query_upcoming_executions(&app, &proposal_module, None, None).executions,
+        vec![UpcomingExecution {
+            proposal_id,
+            due_at: start,
+            remaining: 3,
+        }]
+    );
+
+    let err = execute_scheduled_should_fail(&mut app, &proposal_module, proposal_id);
+    assert!(
+        matches!(err, ContractError::NothingDue { id, due_at } if id == proposal_id && due_at == start)
+    );
+
+    app.update_block(|b| b.time = start);
+    execute_scheduled(&mut app, &proposal_module, proposal_id);
+    assert_eq!(
+        query_balance_native(&app, "four", "ujuno"),
+        Uint128::new(10)
+    );
+    assert_eq!(
+        query_upcoming_executions(&app, &proposal_module, None, None).executions,
+        vec![UpcomingExecution {
+            proposal_id,
+            due_at: start.plus_seconds(month),
+            remaining: 2,
+        }]
+    );
+
+    // executions that were missed are made when next cranked.
+    app.update_block(|b| b.time = start.plus_seconds(month * 5));
+    execute_scheduled(&mut app, &proposal_module, proposal_id);
+    assert_eq!(
+        query_balance_native(&app, "four", "ujuno"),
+        Uint128::new(30)
+    );
+    assert_eq!(
+        query_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::new(70)
+    );
+    assert_eq!(
+        query_upcoming_executions(&app, &proposal_module, None, None).executions,
+        vec![]
+    );
+
+    let err = execute_scheduled_should_fail(&mut app, &proposal_module, proposal_id);
+    assert!(matches!(err, ContractError::NotScheduled { id } if id == proposal_id));
+}
+
+#[test]
+fn test_scheduled_execution_already_due() {
+    let (mut app, core_addr, proposal_module) = setup_review_period_test(None);
+    mint_natives(&mut app, core_addr.as_str(), coins(100, "ujuno"));
+
+    let at = app.block_info().time.plus_seconds(60);
+    let msgs: Vec<CosmosMsg> = vec![BankMsg::Send {
+        to_address: "four".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()];
+    let proposal_id = make_scheduled_proposal(
+        &mut app,
+        &proposal_module,
+        msgs,
+        ExecutionSchedule::Once { at },
+    )
+    .unwrap();
+    vote_on_proposal(&mut app, &proposal_module, "one", proposal_id, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "two", proposal_id, Vote::Yes);
+
+    // executing after the scheduled time executes immediately.
+    app.update_block(|b| b.time = at.plus_seconds(1));
+    execute_proposal(&mut app, &proposal_module, "one", proposal_id);
+    assert_eq!(
+        query_balance_native(&app, "four", "ujuno"),
+        Uint128::new(10)
+    );
+    assert_eq!(
+        query_upcoming_executions(&app, &proposal_module, None, None).executions,
+        vec![]
+    );
+}
+
+#[test]
+fn test_scheduled_execution_limit() {
+    let (mut app, core_addr, proposal_module) = setup_review_period_test(None);
+    mint_natives(&mut app, core_addr.as_str(), coins(150, "ujuno"));
+
+    let start = app.block_info().time.plus_seconds(60);
+    let msgs: Vec<CosmosMsg> = vec![BankMsg::Send {
+        to_address: "four".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()];
+    let proposal_id = make_scheduled_proposal(
+        &mut app,
+        &proposal_module,
+        msgs,
+        ExecutionSchedule::Recurring {
+            start,
+            interval: 60,
+            times: 15,
+        },
+    )
+    .unwrap();
+    vote_on_proposal(&mut app, &proposal_module, "one", proposal_id, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "two", proposal_id, Vote::Yes);
+
+    // every execution is due, but at most ten are made at once.
+    app.update_block(|b| b.time = start.plus_seconds(60 * 15));
+    execute_proposal(&mut app, &proposal_module, "one", proposal_id);
+    assert_eq!(
+        query_balance_native(&app, "four", "ujuno"),
+        Uint128::new(100)
+    );
+    assert_eq!(
+        query_upcoming_executions(&app, &proposal_module, None, None).executions,
+        vec![UpcomingExecution {
+            proposal_id,
+            due_at: start.plus_seconds(60 * 10),
+            remaining: 5,
+        }]
+    );
+
+    // callers may make fewer.
+    app.execute_contract(
+        Addr::unchecked("anyone"),
+        proposal_module.clone(),
+        &ExecuteMsg::ExecuteScheduled {
+            proposal_id,
+            limit: Some(2),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        query_balance_native(&app, "four", "ujuno"),
+        Uint128::new(120)
+    );
+
+    execute_scheduled(&mut app, &proposal_module, proposal_id);
+    assert_eq!(
+        query_balance_native(&app, "four", "ujuno"),
+        Uint128::new(150)
+    );
+    assert_eq!(
+        query_upcoming_executions(&app, &proposal_module, None, None).executions,
+        vec![]
+    );
+}
+
+#[test]
+fn test_scheduled_execution_failure() {
+    let (mut app, _core_addr, proposal_module) = setup_review_period_test(None);
+
+    // the DAO has no funds, so every execution fails.
+    let start = app.block_info().time;
+    let msgs: Vec<CosmosMsg> = vec![BankMsg::Send {
+        to_address: "four".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()];
+    let proposal_id = make_scheduled_proposal(
+        &mut app,
+        &proposal_module,
+        msgs,
+        ExecutionSchedule::Recurring {
+            start: start.plus_seconds(60),
+            interval: 60,
+            times: 2,
+        },
+    )
+    .unwrap();
+    vote_on_proposal(&mut app, &proposal_module, "one", proposal_id, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, "two", proposal_id, Vote::Yes);
+    execute_proposal(&mut app, &proposal_module, "one", proposal_id);
+
+    // a failed execution closes the proposal and ends its schedule.
+    app.update_block(|b| b.time = start.plus_seconds(60));
+    execute_scheduled(&mut app, &proposal_module, proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::ExecutionFailed);
+
+    app.update_block(|b| b.time = start.plus_seconds(120));
+    let err = execute_scheduled_should_fail(&mut app, &proposal_module, proposal_id);
+    assert!(matches!(err, ContractError::NotScheduled { id } if id == proposal_id));
+}
+
+#[test]
+fn test_invalid_schedule() {
+    let (mut app, _core_addr, proposal_module) = setup_review_period_test(None);
+    let start = app.block_info().time;
+
+    let err = make_scheduled_proposal(
+        &mut app,
+        &proposal_module,
+        vec![],
+        ExecutionSchedule::Recurring {
+            start,
+            interval: 0,
+            times: 2,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ScheduleError(ScheduleError::ZeroInterval {})
+    );
+
+    let err = make_scheduled_proposal(
+        &mut app,
+        &proposal_module,
+        vec![],
+        ExecutionSchedule::Recurring {
+            start,
+            interval: 60,
+            times: 0,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::ScheduleError(ScheduleError::ZeroExecutions {})
+    );
+}
@@ -84,6 +84,9 @@ pub fn execute(
     match msg {
         ExecuteMsg::Receive(msg) => execute_receive(deps, env, info, msg),
         ExecuteMsg::Unstake { amount } => execute_unstake(deps, env, info, amount),
+        ExecuteMsg::Forfeit { address, amount } => {
+            execute_forfeit(deps, env, info, address, amount)
+        }
         ExecuteMsg::Claim {} => execute_claim(deps, env, info),
         ExecuteMsg::UpdateConfig { duration } => execute_update_config(info, deps, duration),
         ExecuteMsg::AddHook { addr } => execute_add_hook(deps, env, info, addr),
@@ -133,6 +136,10 @@ pub fn execute_receive(
     let sender = deps.api.addr_validate(&wrapper.sender)?;
     match msg {
         ReceiveMsg::Stake {} => execute_stake(deps, env, sender, wrapper.amount),
+        ReceiveMsg::StakeFor { address } => {
+            let staker = deps.api.addr_validate(&address)?;
+            execute_stake(deps, env, staker, wrapper.amount)
+        }
         ReceiveMsg::Fund {} => execute_fund(deps, env, &sender, wrapper.amount),
     }
 }
@@ -254,6 +261,65 @@ pub fn execute_unstake(
     }
 }
 
+pub fn execute_forfeit(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    if amount.is_zero() {
+        return Err(ContractError::ZeroForfeit {});
+    }
+    let address = deps.api.addr_validate(&address)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let balance = BALANCE.load(deps.storage)?;
+    let staked_total = STAKED_TOTAL.load(deps.storage)?;
+    if staked_total.is_zero() {
+        return Err(ContractError::NothingStaked {});
+    }
+    if amount > staked_total {
+        return Err(ContractError::ImpossibleUnstake {});
+    }
+    let amount_to_claim = math::amount_to_claim(staked_total, balance, amount);
+    STAKED_BALANCES.update(
+        deps.storage,
+        &address,
+        env.block.height,
+        |bal| -> Result<Uint128, ContractError> {
+            bal.unwrap_or_default()
+                .checked_sub(amount)
+                .map_err(|_| ContractError::ImpossibleUnstake {})
+        },
+    )?;
+    STAKED_TOTAL.save(deps.storage, &(staked_total - amount), env.block.height)?;
+    BALANCE.save(
+        deps.storage,
+        &balance
+            .checked_sub(amount_to_claim)
+            .map_err(StdError::overflow)?,
+    )?;
+    let hook_msgs = unstake_hook_msgs(HOOKS, deps.storage, address.clone(), amount)?;
+
+    let transfer = cosmwasm_std::WasmMsg::Execute {
+        contract_addr: config.token_address.to_string(),
+        msg: to_json_binary(&cw20::Cw20ExecuteMsg::Transfer {
+            recipient: info.sender.to_string(),
+            amount: amount_to_claim,
+        })?,
+        funds: vec![],
+    };
+    Ok(Response::new()
+        .add_message(transfer)
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "forfeit")
+        .add_attribute("address", address)
+        .add_attribute("amount", amount)
+        .add_attribute("amount_forfeited", amount_to_claim))
+}
+
 pub fn execute_claim(
     deps: DepsMut,
     _env: Env,
@@ -24,16 +24,36 @@ pub struct InstantiateMsg {
 #[derive(cw_orch::ExecuteFns)]
 pub enum ExecuteMsg {
     Receive(Cw20ReceiveMsg),
-    Unstake { amount: Uint128 },
+    Unstake {
+        amount: Uint128,
+    },
+    /// Callable by the owner. Removes `amount` of `address`'s stake
+    /// and sends the tokens it is worth to the owner. Used by
+    /// ragequit modules.
+    Forfeit {
+        address: String,
+        amount: Uint128,
+    },
     Claim {},
-    UpdateConfig { duration: Option<Duration> },
-    AddHook { addr: String },
-    RemoveHook { addr: String },
+    UpdateConfig {
+        duration: Option<Duration>,
+    },
+    AddHook {
+        addr: String,
+    },
+    RemoveHook {
+        addr: String,
+    },
 }
 
 #[cw_serde]
 pub enum ReceiveMsg {
     Stake {},
+    /// Stakes the sent tokens on behalf of `address`. Used by
+    /// rewards distributors to compound rewards.
+    StakeFor {
+        address: String,
+    },
     Fund {},
 }
 
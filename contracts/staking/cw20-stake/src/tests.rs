@@ -946,6 +946,156 @@ fn test_double_unstake_at_height() {
     assert_eq!(balance.balance, Uint128::zero())
 }
 
+#[test]
+fn test_forfeit() {
+    let mut app = mock_app();
+    let initial_balances = vec![
+        Cw20Coin {
+            address: ADDR1.to_string(),
+            amount: Uint128::new(100),
+        },
+        Cw20Coin {
+            address: ADDR2.to_string(),
+            amount: Uint128::new(100),
+        },
+    ];
+    let (staking_addr, cw20_addr) =
+        setup_test_case(&mut app, initial_balances, Some(Duration::Height(6)));
+
+    stake_tokens(
+        &mut app,
+        &staking_addr,
+        &cw20_addr,
+        mock_info(ADDR1, &[]),
+        Uint128::new(100),
+    )
+    .unwrap();
+    stake_tokens(
+        &mut app,
+        &staking_addr,
+        &cw20_addr,
+        mock_info(ADDR2, &[]),
+        Uint128::new(50),
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    // Only the owner may forfeit stake.
+    let forfeit = ExecuteMsg::Forfeit {
+        address: ADDR1.to_string(),
+        amount: Uint128::new(40),
+    };
+    let err: ContractError = app
+        .execute_contract(Addr::unchecked(ADDR2), staking_addr.clone(), &forfeit, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Ownership(OwnershipError::NotOwner));
+
+    // Forfeited tokens are sent to the owner immediately, regardless
+    // of the unstaking duration.
+    app.execute_contract(Addr::unchecked(OWNER), staking_addr.clone(), &forfeit, &[])
+        .unwrap();
+    assert_eq!(get_balance(&app, &cw20_addr, OWNER), Uint128::new(40));
+    assert_eq!(
+        query_staked_balance(&app, &staking_addr, ADDR1),
+        Uint128::new(100)
+    );
+    app.update_block(next_block);
+    assert_eq!(
+        query_staked_balance(&app, &staking_addr, ADDR1),
+        Uint128::new(60)
+    );
+    assert_eq!(query_total_staked(&app, &staking_addr), Uint128::new(110));
+    assert_eq!(query_total_value(&app, &staking_addr), Uint128::new(110));
+    assert_eq!(query_claims(&app, &staking_addr, ADDR1), vec![]);
+
+    // Can not forfeit zero tokens.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            staking_addr.clone(),
+            &ExecuteMsg::Forfeit {
+                address: ADDR1.to_string(),
+                amount: Uint128::zero(),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ZeroForfeit {});
+
+    // Can not forfeit more than has been staked.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            staking_addr,
+            &ExecuteMsg::Forfeit {
+                address: ADDR2.to_string(),
+                amount: Uint128::new(51),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ImpossibleUnstake {});
+}
+
+#[test]
+fn test_stake_for() {
+    let mut app = mock_app();
+    let initial_balances = vec![Cw20Coin {
+        address: ADDR1.to_string(),
+        amount: Uint128::new(100),
+    }];
+    let (staking_addr, cw20_addr) = setup_test_case(&mut app, initial_balances, None);
+
+    // ADDR1 stakes on behalf of ADDR2.
+    app.execute_contract(
+        Addr::unchecked(ADDR1),
+        cw20_addr.clone(),
+        &cw20::Cw20ExecuteMsg::Send {
+            contract: staking_addr.to_string(),
+            amount: Uint128::new(100),
+            msg: to_json_binary(&ReceiveMsg::StakeFor {
+                address: ADDR2.to_string(),
+            })
+            .unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    assert_eq!(
+        query_staked_balance(&app, &staking_addr, ADDR1),
+        Uint128::zero()
+    );
+    assert_eq!(
+        query_staked_balance(&app, &staking_addr, ADDR2),
+        Uint128::new(100)
+    );
+
+    // The stake belongs to ADDR2, so only they may unstake it.
+    unstake_tokens(
+        &mut app,
+        &staking_addr,
+        mock_info(ADDR1, &[]),
+        Uint128::new(100),
+    )
+    .unwrap_err();
+    unstake_tokens(
+        &mut app,
+        &staking_addr,
+        mock_info(ADDR2, &[]),
+        Uint128::new(100),
+    )
+    .unwrap();
+    assert_eq!(get_balance(&app, &cw20_addr, ADDR2), Uint128::new(100));
+}
+
 #[test]
 fn test_query_list_stakers() {
     let mut app = App::default();
@@ -74,6 +74,7 @@ pub fn execute_proposal_hook(
             count = count.checked_add(1).unwrap_or_default();
             STATUS_CHANGED_COUNTER.save(deps.storage, &count)?;
         }
+        ProposalHookMsg::ProposalAmended { .. } => {}
     }
 
     Ok(Response::new().add_attribute("action", "proposal_hook"))
@@ -155,6 +155,7 @@ fn test_counters() {
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         close_proposal_on_execution_failure: true,
+        review_period: None,
         veto: None,
     };
 
@@ -261,6 +262,7 @@ fn test_counters() {
             msgs: vec![],
             proposer: None,
             vote: None,
+            schedule: None,
         }),
         &[],
     )
@@ -362,6 +364,7 @@ fn test_counters() {
             msgs: vec![],
             proposer: None,
             vote: None,
+            schedule: None,
         }),
         &[],
     )
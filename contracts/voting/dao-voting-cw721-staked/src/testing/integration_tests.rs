@@ -111,6 +111,7 @@ fn test_full_integration_with_factory() {
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                review_period: None,
                 pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                 veto: None,
             })
@@ -135,6 +135,7 @@ impl TestEnvBuilder {
                     allow_revoting: false,
                     only_members_execute: true,
                     close_proposal_on_execution_failure: false,
+                    review_period: None,
                     pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
                     veto: None,
                 })
@@ -2,8 +2,9 @@
 use cosmwasm_std::entry_point;
 
 use cosmwasm_std::{
-    coins, from_json, to_json_binary, BankMsg, BankQuery, Binary, Coin, CosmosMsg, Deps, DepsMut,
-    Env, MessageInfo, Order, Reply, Response, StdResult, SubMsg, Uint128, Uint256, WasmMsg,
+    coins, from_json, to_json_binary, Addr, BankMsg, BankQuery, Binary, Coin, CosmosMsg, Deps,
+    DepsMut, Env, MessageInfo, Order, Reply, Response, StdResult, SubMsg, Uint128, Uint256,
+    WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw_controllers::ClaimsResponse;
@@ -166,8 +167,18 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Stake {} => execute_stake(deps, env, info),
+        ExecuteMsg::Stake {} => {
+            let staker = info.sender.clone();
+            execute_stake(deps, env, info, staker)
+        }
+        ExecuteMsg::StakeFor { address } => {
+            let staker = deps.api.addr_validate(&address)?;
+            execute_stake(deps, env, info, staker)
+        }
         ExecuteMsg::Unstake { amount } => execute_unstake(deps, env, info, amount),
+        ExecuteMsg::Forfeit { address, amount } => {
+            execute_forfeit(deps, env, info, address, amount)
+        }
         ExecuteMsg::UpdateConfig { duration } => execute_update_config(deps, info, duration),
         ExecuteMsg::Claim {} => execute_claim(deps, env, info),
         ExecuteMsg::UpdateActiveThreshold { new_threshold } => {
@@ -182,13 +193,14 @@ pub fn execute_stake(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    staker: Addr,
 ) -> Result<Response, ContractError> {
     let denom = DENOM.load(deps.storage)?;
     let amount = must_pay(&info, &denom)?;
 
     STAKED_BALANCES.update(
         deps.storage,
-        &info.sender,
+        &staker,
         env.block.height,
         |balance| -> StdResult<Uint128> { Ok(balance.unwrap_or_default().checked_add(amount)?) },
     )?;
@@ -199,13 +211,14 @@ pub fn execute_stake(
     )?;
 
     // Add stake hook messages
-    let hook_msgs = stake_hook_msgs(HOOKS, deps.storage, info.sender.clone(), amount)?;
+    let hook_msgs = stake_hook_msgs(HOOKS, deps.storage, staker.clone(), amount)?;
 
     Ok(Response::new()
         .add_submessages(hook_msgs)
         .add_attribute("action", "stake")
         .add_attribute("amount", amount.to_string())
-        .add_attribute("from", info.sender))
+        .add_attribute("from", info.sender)
+        .add_attribute("staker", staker))
 }
 
 pub fn execute_unstake(
@@ -281,6 +294,58 @@ pub fn execute_unstake(
     }
 }
 
+pub fn execute_forfeit(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let dao = DAO.load(deps.storage)?;
+    if info.sender != dao {
+        return Err(ContractError::Unauthorized {});
+    }
+    if amount.is_zero() {
+        return Err(ContractError::ZeroUnstake {});
+    }
+    let address = deps.api.addr_validate(&address)?;
+
+    STAKED_BALANCES.update(
+        deps.storage,
+        &address,
+        env.block.height,
+        |balance| -> Result<Uint128, ContractError> {
+            balance
+                .unwrap_or_default()
+                .checked_sub(amount)
+                .map_err(|_e| ContractError::InvalidUnstakeAmount {})
+        },
+    )?;
+    STAKED_TOTAL.update(
+        deps.storage,
+        env.block.height,
+        |total| -> Result<Uint128, ContractError> {
+            total
+                .unwrap_or_default()
+                .checked_sub(amount)
+                .map_err(|_e| ContractError::InvalidUnstakeAmount {})
+        },
+    )?;
+
+    let hook_msgs = unstake_hook_msgs(HOOKS, deps.storage, address.clone(), amount)?;
+
+    let denom = DENOM.load(deps.storage)?;
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: dao.to_string(),
+            amount: coins(amount.u128(), denom),
+        })
+        .add_submessages(hook_msgs)
+        .add_attribute("action", "forfeit")
+        .add_attribute("address", address)
+        .add_attribute("amount", amount))
+}
+
 pub fn execute_update_config(
     deps: DepsMut,
     info: MessageInfo,
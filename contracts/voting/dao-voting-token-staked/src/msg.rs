@@ -42,8 +42,15 @@ pub enum ExecuteMsg {
     /// Stakes tokens with the contract to get voting power in the DAO
     #[cw_orch(payable)]
     Stake {},
+    /// Stakes tokens on behalf of `address`, giving them the voting
+    /// power. Used by rewards distributors to compound rewards.
+    #[cw_orch(payable)]
+    StakeFor { address: String },
     /// Unstakes tokens so that they begin unbonding
     Unstake { amount: Uint128 },
+    /// Callable by the DAO. Removes `amount` of `address`'s stake and
+    /// sends the tokens to the DAO. Used by ragequit modules.
+    Forfeit { address: String, amount: Uint128 },
     /// Updates the contract configuration
     UpdateConfig { duration: Option<Duration> },
     /// Claims unstaked tokens that have completed the unbonding period
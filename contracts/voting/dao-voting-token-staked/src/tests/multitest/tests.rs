@@ -4,6 +4,7 @@ use crate::msg::{
     StakerBalanceResponse, TokenInfo,
 };
 use crate::state::Config;
+use crate::ContractError;
 use cosmwasm_std::testing::{mock_dependencies, mock_env};
 use cosmwasm_std::{coins, Addr, Coin, Decimal, Empty, Uint128};
 use cw_controllers::ClaimsResponse;
@@ -477,6 +478,109 @@ fn test_unstake_no_unstaking_duration() {
     assert_eq!(balance, Uint128::new(10000))
 }
 
+#[test]
+fn test_forfeit() {
+    let mut app = mock_app();
+
+    let staking_id = app.store_code(staking_contract());
+    let addr = instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            token_info: TokenInfo::Existing {
+                denom: DENOM.to_string(),
+            },
+            unstaking_duration: Some(Duration::Height(5)),
+            active_threshold: None,
+        },
+    );
+
+    stake_tokens(&mut app, addr.clone(), ADDR1, 100, DENOM).unwrap();
+    stake_tokens(&mut app, addr.clone(), ADDR2, 50, DENOM).unwrap();
+    app.update_block(next_block);
+
+    let forfeit = ExecuteMsg::Forfeit {
+        address: ADDR1.to_string(),
+        amount: Uint128::new(40),
+    };
+
+    // Only the DAO may forfeit stake.
+    let err: ContractError = app
+        .execute_contract(Addr::unchecked(ADDR2), addr.clone(), &forfeit, &[])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    // Forfeited tokens are sent to the DAO without unbonding.
+    app.execute_contract(Addr::unchecked(DAO_ADDR), addr.clone(), &forfeit, &[])
+        .unwrap();
+    assert_eq!(get_balance(&mut app, DAO_ADDR, DENOM), Uint128::new(10040));
+    app.update_block(next_block);
+
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(60));
+    let resp = get_total_power_at_height(&mut app, addr.clone(), None);
+    assert_eq!(resp.power, Uint128::new(110));
+    let claims = get_claims(&mut app, addr.clone(), ADDR1.to_string());
+    assert_eq!(claims.claims.len(), 0);
+
+    // Can not forfeit more than has been staked.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(DAO_ADDR),
+            addr,
+            &ExecuteMsg::Forfeit {
+                address: ADDR2.to_string(),
+                amount: Uint128::new(51),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::InvalidUnstakeAmount {});
+}
+
+#[test]
+fn test_stake_for() {
+    let mut app = mock_app();
+
+    let staking_id = app.store_code(staking_contract());
+    let addr = instantiate_staking(
+        &mut app,
+        staking_id,
+        InstantiateMsg {
+            token_info: TokenInfo::Existing {
+                denom: DENOM.to_string(),
+            },
+            unstaking_duration: Some(Duration::Height(5)),
+            active_threshold: None,
+        },
+    );
+
+    // ADDR1 stakes on behalf of ADDR2.
+    app.execute_contract(
+        Addr::unchecked(ADDR1),
+        addr.clone(),
+        &ExecuteMsg::StakeFor {
+            address: ADDR2.to_string(),
+        },
+        &coins(100, DENOM),
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR1.to_string(), None);
+    assert_eq!(resp.power, Uint128::zero());
+    let resp = get_voting_power_at_height(&mut app, addr.clone(), ADDR2.to_string(), None);
+    assert_eq!(resp.power, Uint128::new(100));
+
+    // The stake belongs to ADDR2, so only they may unstake it.
+    unstake_tokens(&mut app, addr.clone(), ADDR1, 100).unwrap_err();
+    unstake_tokens(&mut app, addr, ADDR2, 100).unwrap();
+}
+
 #[test]
 #[should_panic(expected = "Nothing to claim")]
 fn test_claim_no_claims() {
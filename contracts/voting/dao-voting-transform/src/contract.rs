@@ -0,0 +1,279 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+    Uint128,
+};
+use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use cw4::MemberChangedHookMsg;
+use cw_utils::nonpayable;
+use dao_hooks::{nft_stake::NftStakeChangedHookMsg, stake::StakeChangedHookMsg};
+use dao_interface::voting::{
+    IsActiveResponse, Query as VotingQueryMsg, TotalPowerAtHeightResponse,
+    VotingPowerAtHeightResponse,
+};
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::state::{Config, CONFIG, DAO, SYNCED_FROM, TOTAL_POWER, VOTING_POWER};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-voting-transform";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    msg.transform.validate()?;
+
+    // Validate that the voting module implements the voting module
+    // interface.
+    let voting_module = deps.api.addr_validate(&msg.voting_module)?;
+    let total: TotalPowerAtHeightResponse = deps.querier.query_wasm_smart(
+        &voting_module,
+        &VotingQueryMsg::TotalPowerAtHeight { height: None },
+    )?;
+    // With no voting power yet there is nothing to sync.
+    if total.power.is_zero() {
+        SYNCED_FROM.save(deps.storage, &env.block.height)?;
+    }
+
+    DAO.save(deps.storage, &info.sender)?;
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            voting_module: voting_module.clone(),
+            transform: msg.transform,
+        },
+    )?;
+    TOTAL_POWER.save(deps.storage, &Uint128::zero(), env.block.height)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("voting_module", voting_module))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    nonpayable(&info)?;
+
+    match msg {
+        ExecuteMsg::Sync { addresses } => execute_sync(deps, env, addresses),
+        ExecuteMsg::CompleteSync {} => execute_complete_sync(deps, env, info),
+        ExecuteMsg::MemberChangedHook(msg) => execute_membership_changed(deps, env, msg),
+        ExecuteMsg::NftStakeChangeHook(msg) => execute_nft_stake_changed(deps, env, msg),
+        ExecuteMsg::StakeChangeHook(msg) => execute_stake_changed(deps, env, msg),
+    }
+}
+
+pub fn execute_sync(
+    mut deps: DepsMut,
+    env: Env,
+    addresses: Vec<String>,
+) -> Result<Response, ContractError> {
+    for address in addresses {
+        let address = deps.api.addr_validate(&address)?;
+        sync_voting_power(deps.branch(), &env, &address)?;
+    }
+
+    Ok(Response::new().add_attribute("action", "sync"))
+}
+
+pub fn execute_complete_sync(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    if info.sender != DAO.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    if SYNCED_FROM.exists(deps.storage) {
+        return Err(ContractError::AlreadySynced {});
+    }
+
+    // Syncs earlier in this block are only visible at the next height.
+    let synced_from = env.block.height + 1;
+    SYNCED_FROM.save(deps.storage, &synced_from)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "complete_sync")
+        .add_attribute("synced_from", synced_from.to_string()))
+}
+
+pub fn execute_membership_changed(
+    mut deps: DepsMut,
+    env: Env,
+    msg: MemberChangedHookMsg,
+) -> Result<Response, ContractError> {
+    for member in msg.diffs {
+        let address = deps.api.addr_validate(&member.key)?;
+        sync_voting_power(deps.branch(), &env, &address)?;
+    }
+
+    Ok(Response::new().add_attribute("action", "membership_changed"))
+}
+
+pub fn execute_nft_stake_changed(
+    deps: DepsMut,
+    env: Env,
+    msg: NftStakeChangedHookMsg,
+) -> Result<Response, ContractError> {
+    let address = match msg {
+        NftStakeChangedHookMsg::Stake { addr, .. } => addr,
+        NftStakeChangedHookMsg::Unstake { addr, .. } => addr,
+    };
+    sync_voting_power(deps, &env, &address)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "nft_stake_changed")
+        .add_attribute("address", address))
+}
+
+pub fn execute_stake_changed(
+    deps: DepsMut,
+    env: Env,
+    msg: StakeChangedHookMsg,
+) -> Result<Response, ContractError> {
+    let address = match msg {
+        StakeChangedHookMsg::Stake { addr, .. } => addr,
+        StakeChangedHookMsg::Unstake { addr, .. } => addr,
+    };
+    sync_voting_power(deps, &env, &address)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "stake_changed")
+        .add_attribute("address", address))
+}
+
+/// Reads `address`' voting power from the voting module, including
+/// any changes made earlier in this block, and updates its transformed
+/// voting power and the total accordingly.
+fn sync_voting_power(deps: DepsMut, env: &Env, address: &Addr) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Voting modules snapshot at the start of each block, so changes
+    // made during this block are only visible at the next height.
+    let res: VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
+        &config.voting_module,
+        &VotingQueryMsg::VotingPowerAtHeight {
+            address: address.to_string(),
+            height: Some(env.block.height + 1),
+        },
+    )?;
+    let power = config.transform.apply(res.power)?;
+
+    let previous = VOTING_POWER
+        .may_load(deps.storage, address)?
+        .unwrap_or_default();
+    if previous == power {
+        return Ok(());
+    }
+
+    VOTING_POWER.save(deps.storage, address, &power, env.block.height)?;
+    let total = TOTAL_POWER
+        .load(deps.storage)?
+        .checked_sub(previous)?
+        .checked_add(power)?;
+    TOTAL_POWER.save(deps.storage, &total, env.block.height)?;
+
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::VotingPowerAtHeight { address, height } => {
+            query_voting_power_at_height(deps, env, address, height)
+        }
+        QueryMsg::TotalPowerAtHeight { height } => query_total_power_at_height(deps, env, height),
+        QueryMsg::Info {} => query_info(deps),
+        QueryMsg::IsActive {} => query_is_active(deps, env),
+        QueryMsg::Dao {} => to_json_binary(&DAO.load(deps.storage)?),
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+    }
+}
+
+/// Queries `address`' voting power from the voting module at `height`
+/// and transforms it, so addresses need not be synced to vote.
+pub fn query_voting_power_at_height(
+    deps: Deps,
+    env: Env,
+    address: String,
+    height: Option<u64>,
+) -> StdResult<Binary> {
+    let config = CONFIG.load(deps.storage)?;
+    let address = deps.api.addr_validate(&address)?;
+    let res: VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
+        &config.voting_module,
+        &VotingQueryMsg::VotingPowerAtHeight {
+            address: address.to_string(),
+            height,
+        },
+    )?;
+    let power = config.transform.apply(res.power)?;
+
+    to_json_binary(&VotingPowerAtHeightResponse {
+        power,
+        height: height.unwrap_or(env.block.height),
+    })
+}
+
+/// Returns the sum of the transformed voting power of all synced
+/// addresses. Until every address with voting power has been synced
+/// this would be less than the voting power that may be cast, so it is
+/// only available from the height `CompleteSync` marked.
+pub fn query_total_power_at_height(deps: Deps, env: Env, height: Option<u64>) -> StdResult<Binary> {
+    let height = height.unwrap_or(env.block.height);
+    if !is_synced_at(deps, height)? {
+        return Err(StdError::generic_err(
+            "Total voting power is not available until every address has been synced",
+        ));
+    }
+    let power = TOTAL_POWER
+        .may_load_at_height(deps.storage, height)?
+        .unwrap_or_default();
+
+    to_json_binary(&TotalPowerAtHeightResponse { power, height })
+}
+
+/// This module is active once total voting power is available, so
+/// proposals can't be made against an incomplete total.
+pub fn query_is_active(deps: Deps, env: Env) -> StdResult<Binary> {
+    to_json_binary(&IsActiveResponse {
+        active: is_synced_at(deps, env.block.height)?,
+    })
+}
+
+fn is_synced_at(deps: Deps, height: u64) -> StdResult<bool> {
+    Ok(SYNCED_FROM
+        .may_load(deps.storage)?
+        .is_some_and(|synced_from| height >= synced_from))
+}
+
+pub fn query_info(deps: Deps) -> StdResult<Binary> {
+    let info = cw2::get_contract_version(deps.storage)?;
+    to_json_binary(&dao_interface::voting::InfoResponse { info })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let storage_version: ContractVersion = get_contract_version(deps.storage)?;
+
+    // Only migrate if newer
+    if storage_version.version.as_str() < CONTRACT_VERSION {
+        // Set contract to version to latest
+        set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    }
+
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
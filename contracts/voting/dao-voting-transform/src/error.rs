@@ -0,0 +1,24 @@
+use cosmwasm_std::{OverflowError, StdError};
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("{0}")]
+    Payment(#[from] PaymentError),
+
+    #[error("Capped linear transform cap must be greater than zero")]
+    ZeroCap {},
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Sync has already been completed")]
+    AlreadySynced {},
+}
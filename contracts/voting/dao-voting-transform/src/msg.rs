@@ -0,0 +1,50 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cw4::MemberChangedHookMsg;
+use dao_dao_macros::{active_query, voting_module_query};
+use dao_hooks::{nft_stake::NftStakeChangedHookMsg, stake::StakeChangedHookMsg};
+
+use crate::state::Config;
+use crate::transform::Transform;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The voting module whose voting power is transformed.
+    pub voting_module: String,
+    /// The transform applied to each address' voting power.
+    pub transform: Transform,
+}
+
+#[cw_serde]
+#[derive(cw_orch::ExecuteFns)]
+pub enum ExecuteMsg {
+    /// Updates the transformed voting power of each address from the
+    /// voting module. Anyone may call this method, as voting power is
+    /// always read from the voting module. Used to add addresses that
+    /// had voting power before this module was set up.
+    Sync { addresses: Vec<String> },
+    /// Marks every address that had voting power before this module
+    /// was set up as synced. Total voting power is available from the
+    /// next block on, and this module is inactive until then. Only
+    /// callable by the DAO, and only once.
+    CompleteSync {},
+    /// Called when a member is added or removed
+    /// to a cw4-groups or cw721-roles contract.
+    MemberChangedHook(MemberChangedHookMsg),
+    /// Called when NFTs are staked or unstaked.
+    NftStakeChangeHook(NftStakeChangedHookMsg),
+    /// Called when tokens are staked or unstaked.
+    StakeChangeHook(StakeChangedHookMsg),
+}
+
+#[active_query]
+#[voting_module_query]
+#[cw_serde]
+#[derive(QueryResponses, cw_orch::QueryFns)]
+pub enum QueryMsg {
+    /// Returns the config.
+    #[returns(Config)]
+    Config {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
@@ -0,0 +1,36 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, SnapshotItem, SnapshotMap, Strategy};
+
+use crate::transform::Transform;
+
+#[cw_serde]
+pub struct Config {
+    /// The voting module whose voting power is transformed.
+    pub voting_module: Addr,
+    /// The transform applied to each address' voting power.
+    pub transform: Transform,
+}
+
+pub const DAO: Item<Addr> = Item::new("dao_address");
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// The height from which total voting power is available, once the DAO
+/// has marked every address with voting power as synced.
+pub const SYNCED_FROM: Item<u64> = Item::new("synced_from");
+
+/// The transformed voting power of each synced address.
+pub const VOTING_POWER: SnapshotMap<&Addr, Uint128> = SnapshotMap::new(
+    "voting_power",
+    "voting_power__checkpoints",
+    "voting_power__changelog",
+    Strategy::EveryBlock,
+);
+
+/// The sum of the transformed voting power of all synced addresses.
+pub const TOTAL_POWER: SnapshotItem<Uint128> = SnapshotItem::new(
+    "total_power",
+    "total_power__checkpoints",
+    "total_power__changelog",
+    Strategy::EveryBlock,
+);
@@ -0,0 +1,541 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env},
+    Addr, Empty, Uint128,
+};
+use cw2::ContractVersion;
+use cw_multi_test::{next_block, App, Contract, ContractWrapper, Executor};
+use dao_interface::voting::{
+    InfoResponse, IsActiveResponse, TotalPowerAtHeightResponse, VotingPowerAtHeightResponse,
+};
+
+use crate::{
+    contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
+    msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    state::Config,
+    transform::{Transform, LOG_SCALE},
+    ContractError,
+};
+
+const DAO_ADDR: &str = "dao";
+const ADDR1: &str = "addr1";
+const ADDR2: &str = "addr2";
+const ADDR3: &str = "addr3";
+const ADDR4: &str = "addr4";
+
+fn cw4_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cw4_group::contract::execute,
+        cw4_group::contract::instantiate,
+        cw4_group::contract::query,
+    );
+    Box::new(contract)
+}
+
+fn cw4_voting_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_voting_cw4::contract::execute,
+        dao_voting_cw4::contract::instantiate,
+        dao_voting_cw4::contract::query,
+    )
+    .with_reply(dao_voting_cw4::contract::reply);
+    Box::new(contract)
+}
+
+fn transform_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    )
+    .with_migrate(crate::contract::migrate);
+    Box::new(contract)
+}
+
+struct TestCase {
+    group: Addr,
+    voting: Addr,
+    transform: Addr,
+}
+
+/// Sets up a cw4 voting module with members of weight 100, 400 and
+/// 10,000 and a transform module in front of it that receives the
+/// group's membership change hooks and has synced the members.
+fn setup_test_case(app: &mut App, transform: Transform) -> TestCase {
+    let cw4_id = app.store_code(cw4_contract());
+    let cw4_voting_id = app.store_code(cw4_voting_contract());
+    let transform_id = app.store_code(transform_contract());
+
+    let voting = app
+        .instantiate_contract(
+            cw4_voting_id,
+            Addr::unchecked(DAO_ADDR),
+            &dao_voting_cw4::msg::InstantiateMsg {
+                group_contract: dao_voting_cw4::msg::GroupContract::New {
+                    cw4_group_code_id: cw4_id,
+                    initial_members: vec![
+                        cw4::Member {
+                            addr: ADDR1.to_string(),
+                            weight: 100,
+                        },
+                        cw4::Member {
+                            addr: ADDR2.to_string(),
+                            weight: 400,
+                        },
+                        cw4::Member {
+                            addr: ADDR3.to_string(),
+                            weight: 10_000,
+                        },
+                    ],
+                },
+            },
+            &[],
+            "voting module",
+            None,
+        )
+        .unwrap();
+    let group: Addr = app
+        .wrap()
+        .query_wasm_smart(&voting, &dao_voting_cw4::msg::QueryMsg::GroupContract {})
+        .unwrap();
+
+    let transform = app
+        .instantiate_contract(
+            transform_id,
+            Addr::unchecked(DAO_ADDR),
+            &InstantiateMsg {
+                voting_module: voting.to_string(),
+                transform,
+            },
+            &[],
+            "transform module",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        group.clone(),
+        &cw4_group::msg::ExecuteMsg::AddHook {
+            addr: transform.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Members existed before the transform module, so sync them.
+    app.execute_contract(
+        Addr::unchecked(ADDR1),
+        transform.clone(),
+        &ExecuteMsg::Sync {
+            addresses: vec![ADDR1.to_string(), ADDR2.to_string(), ADDR3.to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        transform.clone(),
+        &ExecuteMsg::CompleteSync {},
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    TestCase {
+        group,
+        voting,
+        transform,
+    }
+}
+
+fn query_voting_power(app: &App, transform: &Addr, address: &str, height: Option<u64>) -> u128 {
+    let res: VotingPowerAtHeightResponse = app
+        .wrap()
+        .query_wasm_smart(
+            transform,
+            &QueryMsg::VotingPowerAtHeight {
+                address: address.to_string(),
+                height,
+            },
+        )
+        .unwrap();
+    res.power.u128()
+}
+
+fn query_total_power(app: &App, transform: &Addr, height: Option<u64>) -> u128 {
+    let res: TotalPowerAtHeightResponse = app
+        .wrap()
+        .query_wasm_smart(transform, &QueryMsg::TotalPowerAtHeight { height })
+        .unwrap();
+    res.power.u128()
+}
+
+#[test]
+fn test_instantiate() {
+    let mut app = App::default();
+    let TestCase {
+        voting, transform, ..
+    } = setup_test_case(&mut app, Transform::Sqrt {});
+
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(&transform, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(
+        config,
+        Config {
+            voting_module: voting.clone(),
+            transform: Transform::Sqrt {},
+        }
+    );
+
+    let dao: Addr = app
+        .wrap()
+        .query_wasm_smart(&transform, &QueryMsg::Dao {})
+        .unwrap();
+    assert_eq!(dao, Addr::unchecked(DAO_ADDR));
+
+    let info: InfoResponse = app
+        .wrap()
+        .query_wasm_smart(&transform, &QueryMsg::Info {})
+        .unwrap();
+    assert_eq!(
+        info.info,
+        ContractVersion {
+            contract: CONTRACT_NAME.to_string(),
+            version: CONTRACT_VERSION.to_string(),
+        }
+    );
+
+    // A zero cap is invalid.
+    let transform_id = app.store_code(transform_contract());
+    let err: ContractError = app
+        .instantiate_contract(
+            transform_id,
+            Addr::unchecked(DAO_ADDR),
+            &InstantiateMsg {
+                voting_module: voting.to_string(),
+                transform: Transform::CappedLinear {
+                    cap: Uint128::zero(),
+                },
+            },
+            &[],
+            "transform module",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ZeroCap {});
+
+    // The voting module must implement the voting module interface.
+    app.instantiate_contract(
+        transform_id,
+        Addr::unchecked(DAO_ADDR),
+        &InstantiateMsg {
+            voting_module: ADDR1.to_string(),
+            transform: Transform::Sqrt {},
+        },
+        &[],
+        "transform module",
+        None,
+    )
+    .unwrap_err();
+}
+
+#[test]
+fn test_sqrt() {
+    let mut app = App::default();
+    let TestCase { transform, .. } = setup_test_case(&mut app, Transform::Sqrt {});
+
+    assert_eq!(query_voting_power(&app, &transform, ADDR1, None), 10);
+    assert_eq!(query_voting_power(&app, &transform, ADDR2, None), 20);
+    assert_eq!(query_voting_power(&app, &transform, ADDR3, None), 100);
+    assert_eq!(query_voting_power(&app, &transform, ADDR4, None), 0);
+    assert_eq!(query_total_power(&app, &transform, None), 130);
+}
+
+#[test]
+fn test_capped_linear() {
+    let mut app = App::default();
+    let TestCase { transform, .. } = setup_test_case(
+        &mut app,
+        Transform::CappedLinear {
+            cap: Uint128::new(400),
+        },
+    );
+
+    assert_eq!(query_voting_power(&app, &transform, ADDR1, None), 100);
+    assert_eq!(query_voting_power(&app, &transform, ADDR2, None), 400);
+    assert_eq!(query_voting_power(&app, &transform, ADDR3, None), 400);
+    assert_eq!(query_total_power(&app, &transform, None), 900);
+}
+
+#[test]
+fn test_log() {
+    let mut app = App::default();
+    let TestCase { transform, .. } = setup_test_case(&mut app, Transform::Log {});
+
+    // log2(101), log2(401) and log2(10001), scaled.
+    assert_eq!(query_voting_power(&app, &transform, ADDR1, None), 6_658_211);
+    assert_eq!(query_voting_power(&app, &transform, ADDR2, None), 8_647_458);
+    assert_eq!(
+        query_voting_power(&app, &transform, ADDR3, None),
+        13_287_856
+    );
+    assert_eq!(
+        query_total_power(&app, &transform, None),
+        6_658_211 + 8_647_458 + 13_287_856
+    );
+}
+
+#[test]
+fn test_transform_apply() {
+    let sqrt = Transform::Sqrt {};
+    assert_eq!(sqrt.apply(Uint128::zero()).unwrap(), Uint128::zero());
+    assert_eq!(sqrt.apply(Uint128::new(99)).unwrap(), Uint128::new(9));
+    assert_eq!(
+        sqrt.apply(Uint128::MAX).unwrap(),
+        Uint128::new(u64::MAX as u128)
+    );
+
+    let log = Transform::Log {};
+    assert_eq!(log.apply(Uint128::zero()).unwrap(), Uint128::zero());
+    assert_eq!(log.apply(Uint128::one()).unwrap(), Uint128::new(LOG_SCALE));
+    assert_eq!(
+        log.apply(Uint128::new(1023)).unwrap(),
+        Uint128::new(10 * LOG_SCALE)
+    );
+    // Does not overflow for the largest voting power that can be
+    // transformed.
+    assert_eq!(
+        log.apply(Uint128::MAX - Uint128::one()).unwrap(),
+        Uint128::new(128 * LOG_SCALE - 1)
+    );
+    log.apply(Uint128::MAX).unwrap_err();
+}
+
+#[test]
+fn test_membership_changed() {
+    let mut app = App::default();
+    let TestCase {
+        group, transform, ..
+    } = setup_test_case(&mut app, Transform::Sqrt {});
+    let start_height = app.block_info().height;
+
+    // ADDR1 grows, ADDR3 leaves and ADDR4 joins.
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        group,
+        &cw4_group::msg::ExecuteMsg::UpdateMembers {
+            remove: vec![ADDR3.to_string()],
+            add: vec![
+                cw4::Member {
+                    addr: ADDR1.to_string(),
+                    weight: 900,
+                },
+                cw4::Member {
+                    addr: ADDR4.to_string(),
+                    weight: 25,
+                },
+            ],
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Changes take effect at the next block.
+    assert_eq!(
+        query_voting_power(&app, &transform, ADDR1, Some(start_height)),
+        10
+    );
+    assert_eq!(query_total_power(&app, &transform, Some(start_height)), 130);
+
+    app.update_block(next_block);
+
+    assert_eq!(query_voting_power(&app, &transform, ADDR1, None), 30);
+    assert_eq!(query_voting_power(&app, &transform, ADDR2, None), 20);
+    assert_eq!(query_voting_power(&app, &transform, ADDR3, None), 0);
+    assert_eq!(query_voting_power(&app, &transform, ADDR4, None), 5);
+    assert_eq!(query_total_power(&app, &transform, None), 55);
+
+    // History is preserved.
+    assert_eq!(
+        query_voting_power(&app, &transform, ADDR3, Some(start_height)),
+        100
+    );
+    assert_eq!(query_total_power(&app, &transform, Some(start_height)), 130);
+}
+
+#[test]
+fn test_sync_is_idempotent() {
+    let mut app = App::default();
+    let TestCase {
+        voting, transform, ..
+    } = setup_test_case(&mut app, Transform::Sqrt {});
+
+    // Anyone may sync, and syncing again changes nothing.
+    app.execute_contract(
+        Addr::unchecked(ADDR4),
+        transform.clone(),
+        &ExecuteMsg::Sync {
+            addresses: vec![ADDR1.to_string(), ADDR3.to_string(), ADDR4.to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    assert_eq!(query_total_power(&app, &transform, None), 130);
+
+    // The underlying voting module is unaffected.
+    let res: TotalPowerAtHeightResponse = app
+        .wrap()
+        .query_wasm_smart(
+            voting,
+            &dao_voting_cw4::msg::QueryMsg::TotalPowerAtHeight { height: None },
+        )
+        .unwrap();
+    assert_eq!(res.power, Uint128::new(10_500));
+}
+
+fn query_is_active(app: &App, transform: &Addr) -> bool {
+    let res: IsActiveResponse = app
+        .wrap()
+        .query_wasm_smart(transform, &QueryMsg::IsActive {})
+        .unwrap();
+    res.active
+}
+
+#[test]
+fn test_unsynced() {
+    let mut app = App::default();
+    let TestCase { group, voting, .. } = setup_test_case(&mut app, Transform::Sqrt {});
+
+    // Four members of weight 100 each.
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        group,
+        &cw4_group::msg::ExecuteMsg::UpdateMembers {
+            remove: vec![],
+            add: [ADDR1, ADDR2, ADDR3, ADDR4]
+                .into_iter()
+                .map(|addr| cw4::Member {
+                    addr: addr.to_string(),
+                    weight: 100,
+                })
+                .collect(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+
+    // A new transform module that has not been synced.
+    let transform_id = app.store_code(transform_contract());
+    let transform = app
+        .instantiate_contract(
+            transform_id,
+            Addr::unchecked(DAO_ADDR),
+            &InstantiateMsg {
+                voting_module: voting.to_string(),
+                transform: Transform::Sqrt {},
+            },
+            &[],
+            "transform module",
+            None,
+        )
+        .unwrap();
+    app.update_block(next_block);
+
+    // Voting power is read from the voting module, so two members may
+    // cast 10 + 10 votes. Against the square root of the underlying
+    // total, 20, that would be every vote, while the true total is 40.
+    // A proposal module can't read the total or create proposals until
+    // the members are synced.
+    assert_eq!(query_voting_power(&app, &transform, ADDR1, None), 10);
+    assert_eq!(query_voting_power(&app, &transform, ADDR2, None), 10);
+    app.wrap()
+        .query_wasm_smart::<TotalPowerAtHeightResponse>(
+            &transform,
+            &QueryMsg::TotalPowerAtHeight { height: None },
+        )
+        .unwrap_err();
+    assert!(!query_is_active(&app, &transform));
+
+    // Only the DAO may mark the sync complete.
+    app.execute_contract(
+        Addr::unchecked(ADDR1),
+        transform.clone(),
+        &ExecuteMsg::Sync {
+            addresses: vec![ADDR1.to_string(), ADDR2.to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ADDR1),
+            transform.clone(),
+            &ExecuteMsg::CompleteSync {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    app.execute_contract(
+        Addr::unchecked(ADDR1),
+        transform.clone(),
+        &ExecuteMsg::Sync {
+            addresses: vec![ADDR3.to_string(), ADDR4.to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(DAO_ADDR),
+        transform.clone(),
+        &ExecuteMsg::CompleteSync {},
+        &[],
+    )
+    .unwrap();
+    let synced_height = app.block_info().height;
+
+    // The total is available from the next block.
+    assert!(!query_is_active(&app, &transform));
+    app.update_block(next_block);
+    assert!(query_is_active(&app, &transform));
+    assert_eq!(query_total_power(&app, &transform, None), 40);
+    app.wrap()
+        .query_wasm_smart::<TotalPowerAtHeightResponse>(
+            &transform,
+            &QueryMsg::TotalPowerAtHeight {
+                height: Some(synced_height),
+            },
+        )
+        .unwrap_err();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(DAO_ADDR),
+            transform,
+            &ExecuteMsg::CompleteSync {},
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::AlreadySynced {});
+}
+
+#[test]
+pub fn test_migrate_update_version() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, "my-contract", "old-version").unwrap();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    let version = cw2::get_contract_version(&deps.storage).unwrap();
+    assert_eq!(version.version, CONTRACT_VERSION);
+    assert_eq!(version.contract, CONTRACT_NAME);
+}
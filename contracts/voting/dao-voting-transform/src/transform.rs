@@ -0,0 +1,71 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, StdResult, Uint128};
+use integer_sqrt::IntegerSquareRoot;
+
+use crate::ContractError;
+
+/// Log transformed voting power is scaled by this factor so that
+/// small differences in voting power are not lost to rounding.
+pub const LOG_SCALE: u128 = 1_000_000;
+
+/// The number of fractional bits computed when taking a logarithm.
+const LOG_FRACTION_BITS: u32 = 24;
+
+/// A transform applied to the voting power of each address.
+#[cw_serde]
+pub enum Transform {
+    /// Voting power is the square root of the underlying voting
+    /// power, rounded down.
+    Sqrt {},
+    /// Voting power equals the underlying voting power up to `cap`,
+    /// after which it stays at `cap`.
+    CappedLinear { cap: Uint128 },
+    /// Voting power is `log2(1 + power)` of the underlying voting
+    /// power, scaled by `LOG_SCALE` and rounded down.
+    Log {},
+}
+
+impl Transform {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        match self {
+            Transform::CappedLinear { cap } if cap.is_zero() => Err(ContractError::ZeroCap {}),
+            _ => Ok(()),
+        }
+    }
+
+    /// Applies the transform to `power`. Zero voting power is always
+    /// transformed to zero.
+    pub fn apply(&self, power: Uint128) -> StdResult<Uint128> {
+        if power.is_zero() {
+            return Ok(Uint128::zero());
+        }
+        match self {
+            Transform::Sqrt {} => Ok(Uint128::new(power.u128().integer_sqrt())),
+            Transform::CappedLinear { cap } => Ok(power.min(*cap)),
+            Transform::Log {} => log2_scaled(power.checked_add(Uint128::one())?),
+        }
+    }
+}
+
+/// Computes `log2(x) * LOG_SCALE`, rounded down, for `x >= 1`.
+///
+/// The integer part of the logarithm is the position of the highest
+/// set bit. The fractional part is found one bit at a time by
+/// repeatedly squaring the remaining mantissa, which lies in [1, 2).
+fn log2_scaled(x: Uint128) -> StdResult<Uint128> {
+    let integer = 127 - x.u128().leading_zeros();
+    let two = Decimal::percent(200);
+
+    let mut mantissa = Decimal::from_ratio(x, 1u128 << integer);
+    let mut fixed = u128::from(integer);
+    for _ in 0..LOG_FRACTION_BITS {
+        mantissa = mantissa.checked_mul(mantissa)?;
+        fixed <<= 1;
+        if mantissa >= two {
+            mantissa = Decimal::raw(mantissa.atomics().u128() / 2);
+            fixed |= 1;
+        }
+    }
+
+    Ok(Uint128::new(fixed).multiply_ratio(LOG_SCALE, 1u128 << LOG_FRACTION_BITS))
+}
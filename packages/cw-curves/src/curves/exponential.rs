@@ -0,0 +1,89 @@
+use cosmwasm_std::{Decimal as StdDecimal, StdError, StdResult, Uint128};
+use rust_decimal::Decimal;
+
+use crate::{
+    utils::{decimal_to_std, exp, ln},
+    Curve, DecimalPlaces,
+};
+
+/// Implements an Exponential bonding curve where spot price is value * e^(growth * supply)
+#[derive(Debug)]
+pub struct Exponential {
+    /// The spot price at zero supply
+    pub value: Decimal,
+    /// The growth rate of the spot price per supply token
+    pub growth: Decimal,
+    /// Decimal places for normalization between supply and reserve tokens
+    pub normalize: DecimalPlaces,
+}
+
+impl Exponential {
+    /// Creates a new Exponential curve instance
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The spot price at zero supply
+    /// * `growth` - The growth rate of the spot price, which must be positive
+    /// * `normalize` - DecimalPlaces for normalization between supply and reserve tokens
+    pub fn new(value: Decimal, growth: Decimal, normalize: DecimalPlaces) -> Self {
+        Self {
+            value,
+            growth,
+            normalize,
+        }
+    }
+}
+
+impl Curve for Exponential {
+    /// Calculates the spot price for a given supply
+    ///
+    /// The spot price is calculated as: f(x) = value * e^(growth * supply)
+    fn spot_price(&self, supply: Uint128) -> StdResult<StdDecimal> {
+        let exponent = self
+            .normalize
+            .from_supply(supply)?
+            .checked_mul(self.growth)
+            .ok_or_else(|| StdError::generic_err("Overflow in spot price calculation"))?;
+        exp(exponent)?
+            .checked_mul(self.value)
+            .ok_or_else(|| StdError::generic_err("Overflow in spot price calculation"))
+            .and_then(decimal_to_std)
+    }
+
+    /// Calculates the reserve for a given supply
+    ///
+    /// The reserve is calculated as: f(x) = value * (e^(growth * supply) - 1) / growth
+    fn reserve(&self, supply: Uint128) -> StdResult<Uint128> {
+        let exponent = self
+            .normalize
+            .from_supply(supply)?
+            .checked_mul(self.growth)
+            .ok_or_else(|| StdError::generic_err("Overflow in reserve calculation"))?;
+        let reserve = (exp(exponent)? - Decimal::ONE)
+            .checked_mul(self.value)
+            .and_then(|r| r.checked_div(self.growth))
+            .ok_or_else(|| {
+                StdError::generic_err("Overflow or division by zero in reserve calculation")
+            })?;
+        self.normalize.to_reserve(reserve)
+    }
+
+    /// Calculates the supply for a given reserve
+    ///
+    /// The supply is calculated as: f(x) = ln(1 + reserve * growth / value) / growth
+    fn supply(&self, reserve: Uint128) -> StdResult<Uint128> {
+        let base = self
+            .normalize
+            .from_reserve(reserve)?
+            .checked_mul(self.growth)
+            .and_then(|r| r.checked_div(self.value))
+            .and_then(|r| r.checked_add(Decimal::ONE))
+            .ok_or_else(|| {
+                StdError::generic_err("Overflow or division by zero in supply calculation")
+            })?;
+        let supply = ln(base)?.checked_div(self.growth).ok_or_else(|| {
+            StdError::generic_err("Division by zero or overflow in supply calculation")
+        })?;
+        self.normalize.to_supply(supply)
+    }
+}
@@ -6,3 +6,12 @@ pub use linear::Linear;
 
 pub mod square_root;
 pub use square_root::SquareRoot;
+
+pub mod exponential;
+pub use exponential::Exponential;
+
+pub mod sigmoid;
+pub use sigmoid::Sigmoid;
+
+pub mod piecewise_linear;
+pub use piecewise_linear::PiecewiseLinear;
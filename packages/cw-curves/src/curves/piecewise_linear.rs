@@ -0,0 +1,198 @@
+use cosmwasm_std::{Decimal as StdDecimal, StdError, StdResult, Uint128};
+use rust_decimal::Decimal;
+
+use crate::{
+    utils::{decimal_to_std, precise_square_root},
+    Curve, DecimalPlaces,
+};
+
+/// Implements a Piecewise Linear bonding curve defined by a list of
+/// (supply, spot price) points
+///
+/// The spot price is interpolated linearly between points, and stays at the
+/// price of the last point for supplies beyond it.
+#[derive(Debug)]
+pub struct PiecewiseLinear {
+    /// The (supply, spot price) points of the curve, ordered by supply
+    pub points: Vec<(Decimal, Decimal)>,
+    /// Decimal places for normalization between supply and reserve tokens
+    pub normalize: DecimalPlaces,
+}
+
+impl PiecewiseLinear {
+    /// The maximum number of points of a curve, which bounds the gas used to
+    /// calculate the reserve and supply
+    pub const MAX_POINTS: usize = 100;
+
+    /// Creates a new PiecewiseLinear curve instance
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The (supply, spot price) points of the curve, which must pass [`PiecewiseLinear::validate`]
+    /// * `normalize` - DecimalPlaces for normalization between supply and reserve tokens
+    pub fn new(points: Vec<(Decimal, Decimal)>, normalize: DecimalPlaces) -> Self {
+        Self { points, normalize }
+    }
+
+    /// Checks that the points describe a valid curve
+    ///
+    /// There may be at most [`PiecewiseLinear::MAX_POINTS`] points. The
+    /// first point must be at zero supply, supplies must be strictly
+    /// increasing, prices must never decrease, and the final price must be
+    /// positive so that any reserve can be converted back to a supply.
+    pub fn validate(points: &[(Decimal, Decimal)]) -> StdResult<()> {
+        if points.len() > Self::MAX_POINTS {
+            return Err(StdError::generic_err(format!(
+                "Piecewise linear curve can have at most {} points",
+                Self::MAX_POINTS
+            )));
+        }
+        let (first, last) = match (points.first(), points.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => {
+                return Err(StdError::generic_err(
+                    "Piecewise linear curve requires at least one point",
+                ))
+            }
+        };
+        if !first.0.is_zero() {
+            return Err(StdError::generic_err(
+                "Piecewise linear curve must start at zero supply",
+            ));
+        }
+        for pair in points.windows(2) {
+            if pair[1].0 <= pair[0].0 {
+                return Err(StdError::generic_err(
+                    "Piecewise linear curve supplies must be strictly increasing",
+                ));
+            }
+            if pair[1].1 < pair[0].1 {
+                return Err(StdError::generic_err(
+                    "Piecewise linear curve prices must not decrease",
+                ));
+            }
+        }
+        if last.1.is_zero() {
+            return Err(StdError::generic_err(
+                "Piecewise linear curve must end at a positive price",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the slope of the segment starting at point `index`, or zero
+    /// for the constant segment after the last point
+    fn slope(&self, index: usize) -> StdResult<Decimal> {
+        match (self.points.get(index), self.points.get(index + 1)) {
+            (Some((s0, p0)), Some((s1, p1))) => (*p1 - *p0)
+                .checked_div(*s1 - *s0)
+                .ok_or_else(|| StdError::generic_err("Overflow in slope calculation")),
+            _ => Ok(Decimal::ZERO),
+        }
+    }
+
+    /// Returns the reserve needed to move `distance` along the segment
+    /// starting at point `index`
+    fn segment_reserve(&self, index: usize, distance: Decimal) -> StdResult<Decimal> {
+        let (_, price) = self.points[index];
+        // price * distance + slope * distance^2 / 2
+        distance
+            .checked_mul(self.slope(index)?)
+            .and_then(|d| d.checked_mul(Decimal::new(5, 1)))
+            .and_then(|d| d.checked_add(price))
+            .and_then(|d| d.checked_mul(distance))
+            .ok_or_else(|| StdError::generic_err("Overflow in reserve calculation"))
+    }
+
+    /// Returns the distance moved along the segment starting at point `index`
+    /// for a given reserve, which must not exceed the reserve of the segment
+    fn segment_supply(&self, index: usize, reserve: Decimal) -> StdResult<Decimal> {
+        if reserve.is_zero() {
+            return Ok(Decimal::ZERO);
+        }
+        let (_, price) = self.points[index];
+        let slope = self.slope(index)?;
+        // Solves slope * d^2 / 2 + price * d = reserve, in the form
+        // 2 * reserve / (price + sqrt(price^2 + 2 * slope * reserve)),
+        // which avoids cancellation and handles a zero slope or price
+        let discriminant = slope
+            .checked_mul(reserve)
+            .and_then(|d| d.checked_mul(Decimal::TWO))
+            .and_then(|d| price.checked_mul(price).and_then(|p| p.checked_add(d)))
+            .ok_or_else(|| StdError::generic_err("Overflow in supply calculation"))?;
+        let denominator = price
+            .checked_add(precise_square_root(discriminant)?)
+            .ok_or_else(|| StdError::generic_err("Overflow in supply calculation"))?;
+        reserve
+            .checked_mul(Decimal::TWO)
+            .and_then(|r| r.checked_div(denominator))
+            .ok_or_else(|| {
+                StdError::generic_err("Division by zero or overflow in supply calculation")
+            })
+    }
+}
+
+impl Curve for PiecewiseLinear {
+    /// Calculates the spot price for a given supply
+    ///
+    /// The spot price is interpolated between the points surrounding the supply
+    fn spot_price(&self, supply: Uint128) -> StdResult<StdDecimal> {
+        let supply = self.normalize.from_supply(supply)?;
+        let index = self
+            .points
+            .iter()
+            .rposition(|(s, _)| *s <= supply)
+            .ok_or_else(|| StdError::generic_err("Supply is before the first point"))?;
+        let (start, price) = self.points[index];
+        let out = (supply - start)
+            .checked_mul(self.slope(index)?)
+            .and_then(|p| p.checked_add(price))
+            .ok_or_else(|| StdError::generic_err("Overflow in spot price calculation"))?;
+        decimal_to_std(out)
+    }
+
+    /// Calculates the reserve for a given supply
+    ///
+    /// The reserve is the area under each segment up to the supply
+    fn reserve(&self, supply: Uint128) -> StdResult<Uint128> {
+        let supply = self.normalize.from_supply(supply)?;
+        let mut reserve = Decimal::ZERO;
+        for (index, (start, _)) in self.points.iter().enumerate() {
+            if supply <= *start {
+                break;
+            }
+            let end = self
+                .points
+                .get(index + 1)
+                .map_or(supply, |(next, _)| supply.min(*next));
+            reserve = reserve
+                .checked_add(self.segment_reserve(index, end - *start)?)
+                .ok_or_else(|| StdError::generic_err("Overflow in reserve calculation"))?;
+        }
+        self.normalize.to_reserve(reserve)
+    }
+
+    /// Calculates the supply for a given reserve
+    ///
+    /// The supply is found by consuming the reserve of each full segment, and
+    /// solving for the distance into the segment where the reserve runs out
+    fn supply(&self, reserve: Uint128) -> StdResult<Uint128> {
+        let mut remaining = self.normalize.from_reserve(reserve)?;
+        for (index, (start, _)) in self.points.iter().enumerate() {
+            if let Some((next, _)) = self.points.get(index + 1) {
+                let segment = self.segment_reserve(index, *next - *start)?;
+                if remaining > segment {
+                    remaining -= segment;
+                    continue;
+                }
+            }
+            let supply = start
+                .checked_add(self.segment_supply(index, remaining)?)
+                .ok_or_else(|| StdError::generic_err("Overflow in supply calculation"))?;
+            return self.normalize.to_supply(supply);
+        }
+        Err(StdError::generic_err(
+            "Piecewise linear curve requires at least one point",
+        ))
+    }
+}
@@ -0,0 +1,153 @@
+use cosmwasm_std::{Decimal as StdDecimal, StdError, StdResult, Uint128};
+use rust_decimal::Decimal;
+
+use crate::{
+    utils::{decimal_to_std, exp, ln},
+    Curve, DecimalPlaces,
+};
+
+/// Implements a Sigmoid (S-curve) bonding curve where spot price is
+/// max_price / (1 + e^(-steepness * (supply - midpoint)))
+///
+/// The spot price starts low, rises fastest around the midpoint, and then
+/// levels off towards the max price.
+#[derive(Debug)]
+pub struct Sigmoid {
+    /// The spot price the curve approaches as supply grows
+    pub max_price: Decimal,
+    /// The supply at which the spot price is half of the max price
+    pub midpoint: Decimal,
+    /// How quickly the spot price rises around the midpoint
+    pub steepness: Decimal,
+    /// Decimal places for normalization between supply and reserve tokens
+    pub normalize: DecimalPlaces,
+}
+
+impl Sigmoid {
+    /// Creates a new Sigmoid curve instance
+    ///
+    /// # Arguments
+    ///
+    /// * `max_price` - The spot price the curve approaches as supply grows
+    /// * `midpoint` - The supply at which the spot price is half of the max price
+    /// * `steepness` - How quickly the spot price rises around the midpoint, which must be positive
+    /// * `normalize` - DecimalPlaces for normalization between supply and reserve tokens
+    pub fn new(
+        max_price: Decimal,
+        midpoint: Decimal,
+        steepness: Decimal,
+        normalize: DecimalPlaces,
+    ) -> Self {
+        Self {
+            max_price,
+            midpoint,
+            steepness,
+            normalize,
+        }
+    }
+
+    /// Returns steepness * (supply - midpoint) for a normalized supply
+    fn exponent(&self, supply: Decimal) -> StdResult<Decimal> {
+        supply
+            .checked_sub(self.midpoint)
+            .and_then(|d| d.checked_mul(self.steepness))
+            .ok_or_else(|| StdError::generic_err("Overflow in sigmoid exponent"))
+    }
+}
+
+/// Calculates ln(1 + e^z) without overflowing for large z
+fn softplus(z: Decimal) -> StdResult<Decimal> {
+    if z.is_sign_positive() {
+        // ln(1 + e^z) = z + ln(1 + e^-z)
+        Ok(z + ln(Decimal::ONE + exp(-z)?)?)
+    } else {
+        ln(Decimal::ONE + exp(z)?)
+    }
+}
+
+/// Calculates the inverse of softplus, ln(e^y - 1), for a positive y
+fn softplus_inverse(y: Decimal) -> StdResult<Decimal> {
+    if y > Decimal::ONE {
+        // ln(e^y - 1) = y + ln(1 - e^-y)
+        Ok(y + ln(Decimal::ONE - exp(-y)?)?)
+    } else {
+        // Sum the series of e^y - 1 directly, as subtracting 1 from e^y would
+        // lose precision for small y
+        let mut term = Decimal::ONE;
+        let mut sum = Decimal::ZERO;
+        for n in 1u32..64 {
+            term = term * y / Decimal::from(n);
+            if term.is_zero() {
+                break;
+            }
+            sum += term;
+        }
+        ln(sum)
+    }
+}
+
+impl Curve for Sigmoid {
+    /// Calculates the spot price for a given supply
+    ///
+    /// The spot price is calculated as: f(x) = max_price / (1 + e^(-steepness * (supply - midpoint)))
+    fn spot_price(&self, supply: Uint128) -> StdResult<StdDecimal> {
+        let exponent = self.exponent(self.normalize.from_supply(supply)?)?;
+        let price = if exponent.is_sign_negative() {
+            // Below the midpoint e^-z may overflow, so calculate
+            // max_price * e^z / (1 + e^z) instead
+            let e = exp(exponent)?;
+            self.max_price
+                .checked_mul(e)
+                .and_then(|p| p.checked_div(Decimal::ONE + e))
+        } else {
+            exp(-exponent)?
+                .checked_add(Decimal::ONE)
+                .and_then(|d| self.max_price.checked_div(d))
+        };
+        price
+            .ok_or_else(|| StdError::generic_err("Overflow in spot price calculation"))
+            .and_then(decimal_to_std)
+    }
+
+    /// Calculates the reserve for a given supply
+    ///
+    /// The reserve is calculated as:
+    /// f(x) = max_price * (ln(1 + e^(steepness * (supply - midpoint))) - ln(1 + e^(-steepness * midpoint))) / steepness
+    fn reserve(&self, supply: Uint128) -> StdResult<Uint128> {
+        let exponent = self.exponent(self.normalize.from_supply(supply)?)?;
+        let start = self.exponent(Decimal::ZERO)?;
+        let reserve = (softplus(exponent)? - softplus(start)?)
+            .checked_mul(self.max_price)
+            .and_then(|r| r.checked_div(self.steepness))
+            .ok_or_else(|| {
+                StdError::generic_err("Overflow or division by zero in reserve calculation")
+            })?;
+        self.normalize.to_reserve(reserve)
+    }
+
+    /// Calculates the supply for a given reserve
+    ///
+    /// The supply is calculated as:
+    /// f(x) = midpoint + ln(e^(reserve * steepness / max_price + ln(1 + e^(-steepness * midpoint))) - 1) / steepness
+    fn supply(&self, reserve: Uint128) -> StdResult<Uint128> {
+        let start = self.exponent(Decimal::ZERO)?;
+        let softplus_value = self
+            .normalize
+            .from_reserve(reserve)?
+            .checked_mul(self.steepness)
+            .and_then(|r| r.checked_div(self.max_price))
+            .ok_or_else(|| {
+                StdError::generic_err("Overflow or division by zero in supply calculation")
+            })?
+            .checked_add(softplus(start)?)
+            .ok_or_else(|| StdError::generic_err("Overflow in supply calculation"))?;
+        let supply = softplus_inverse(softplus_value)?
+            .checked_div(self.steepness)
+            .and_then(|s| s.checked_add(self.midpoint))
+            .ok_or_else(|| {
+                StdError::generic_err("Division by zero or overflow in supply calculation")
+            })?;
+        // Rounding may leave the supply a hair below zero for a zero reserve
+        self.normalize.to_supply(supply.max(Decimal::ZERO))
+    }
+}
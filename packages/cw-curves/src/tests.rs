@@ -1,7 +1,7 @@
 use cosmwasm_std::{Decimal as StdDecimal, StdResult, Uint128};
 
 use crate::{
-    curves::{Constant, Linear, SquareRoot},
+    curves::{Constant, Exponential, Linear, PiecewiseLinear, Sigmoid, SquareRoot},
     utils::decimal,
     Curve, DecimalPlaces,
 };
@@ -109,3 +109,236 @@ fn generic_curve_test() -> StdResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn exponential_curve() -> StdResult<()> {
+    // Set up normalization for 6 decimal places input and output
+    let normalize = DecimalPlaces::new(6, 6);
+    // Create an exponential curve starting at 1 and growing at a rate of 0.1
+    let curve = Exponential::new(decimal(1u128, 0)?, decimal(1u128, 1)?, normalize);
+
+    // Test spot price (0 input should yield the starting value of 1)
+    assert_eq!(StdDecimal::one(), curve.spot_price(Uint128::zero())?);
+    // Test reserve calculation (10 million input should yield (e - 1) * 10 million output)
+    assert_eq!(
+        Uint128::new(17_182_818),
+        curve.reserve(Uint128::new(10_000_000))?
+    );
+    // Test supply calculation (rounding down the reserve loses the last unit of supply)
+    assert_eq!(
+        Uint128::new(9_999_999),
+        curve.supply(Uint128::new(17_182_818))?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sigmoid_curve() -> StdResult<()> {
+    // Set up normalization for 6 decimal places input and output
+    let normalize = DecimalPlaces::new(6, 6);
+    // Create a sigmoid curve with a max price of 2, a midpoint of 10 and a steepness of 1
+    let curve = Sigmoid::new(
+        decimal(2u128, 0)?,
+        decimal(10u128, 0)?,
+        decimal(1u128, 0)?,
+        normalize,
+    );
+
+    // Test spot price (the midpoint should yield half the max price)
+    assert_eq!(
+        StdDecimal::one(),
+        curve.spot_price(Uint128::new(10_000_000))?
+    );
+    // Test that the spot price approaches the max price
+    assert!(curve.spot_price(Uint128::new(100_000_000))? > StdDecimal::percent(199));
+    // Test reserve calculation (10 million input should yield 1,386,203 output)
+    assert_eq!(
+        Uint128::new(1_386_203),
+        curve.reserve(Uint128::new(10_000_000))?
+    );
+    // Test supply calculation (1,386,203 input should yield 9,999,999 output)
+    assert_eq!(
+        Uint128::new(9_999_999),
+        curve.supply(Uint128::new(1_386_203))?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sigmoid_curve_large_midpoint() -> StdResult<()> {
+    let normalize = DecimalPlaces::new(6, 6);
+    // Create a sigmoid curve with a max price of 2, a midpoint of 1,000,000
+    // and a steepness of 0.0001, so e^(steepness * midpoint) overflows
+    let curve = Sigmoid::new(
+        decimal(2u128, 0)?,
+        decimal(1_000_000u128, 0)?,
+        decimal(1u128, 4)?,
+        normalize,
+    );
+
+    // Test spot price far below the midpoint (should round to zero)
+    assert_eq!(StdDecimal::zero(), curve.spot_price(Uint128::zero())?);
+    assert_eq!(Uint128::zero(), curve.reserve(Uint128::zero())?);
+    // Test spot price at the midpoint (should yield half the max price)
+    assert_eq!(
+        StdDecimal::one(),
+        curve.spot_price(Uint128::new(1_000_000_000_000))?
+    );
+    // Test reserve and supply calculations at the midpoint (rounding down
+    // loses one unit of supply)
+    let reserve = curve.reserve(Uint128::new(1_000_000_000_000))?;
+    assert_eq!(Uint128::new(13_862_943_611), reserve);
+    assert_eq!(Uint128::new(999_999_999_999), curve.supply(reserve)?);
+
+    Ok(())
+}
+
+#[test]
+fn piecewise_linear_curve() -> StdResult<()> {
+    // Set up normalization for 6 decimal places input and output
+    let normalize = DecimalPlaces::new(6, 6);
+    // Create a curve rising from 1 to 2 over the first 10 tokens, then flat
+    let points = vec![
+        (decimal(0u128, 0)?, decimal(1u128, 0)?),
+        (decimal(10u128, 0)?, decimal(2u128, 0)?),
+        (decimal(20u128, 0)?, decimal(2u128, 0)?),
+    ];
+    PiecewiseLinear::validate(&points)?;
+    let curve = PiecewiseLinear::new(points, normalize);
+
+    // Test spot price (5 million input is halfway along the first segment)
+    assert_eq!(
+        StdDecimal::percent(150),
+        curve.spot_price(Uint128::new(5_000_000))?
+    );
+    // Test spot price past the last point (should stay at the last price)
+    assert_eq!(
+        StdDecimal::percent(200),
+        curve.spot_price(Uint128::new(50_000_000))?
+    );
+    // Test reserve calculation within and past the points
+    assert_eq!(
+        Uint128::new(15_000_000),
+        curve.reserve(Uint128::new(10_000_000))?
+    );
+    assert_eq!(
+        Uint128::new(55_000_000),
+        curve.reserve(Uint128::new(30_000_000))?
+    );
+    // Test supply calculation within and past the points
+    assert_eq!(
+        Uint128::new(10_000_000),
+        curve.supply(Uint128::new(15_000_000))?
+    );
+    assert_eq!(
+        Uint128::new(30_000_000),
+        curve.supply(Uint128::new(55_000_000))?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn piecewise_linear_validation() -> StdResult<()> {
+    let one = decimal(1u128, 0)?;
+    let two = decimal(2u128, 0)?;
+    let zero = decimal(0u128, 0)?;
+
+    // No points
+    assert!(PiecewiseLinear::validate(&[]).is_err());
+    // Doesn't start at zero supply
+    assert!(PiecewiseLinear::validate(&[(one, one)]).is_err());
+    // Supplies aren't strictly increasing
+    assert!(PiecewiseLinear::validate(&[(zero, one), (zero, two)]).is_err());
+    // Prices decrease
+    assert!(PiecewiseLinear::validate(&[(zero, two), (one, one)]).is_err());
+    // Ends at a zero price
+    assert!(PiecewiseLinear::validate(&[(zero, zero)]).is_err());
+    // A curve starting at a zero price is fine as long as it rises
+    PiecewiseLinear::validate(&[(zero, zero), (one, one)])?;
+
+    // Too many points
+    let points = (0..=PiecewiseLinear::MAX_POINTS as u128)
+        .map(|supply| Ok((decimal(supply, 0)?, one)))
+        .collect::<StdResult<Vec<_>>>()?;
+    assert!(PiecewiseLinear::validate(&points).is_err());
+    PiecewiseLinear::validate(&points[..PiecewiseLinear::MAX_POINTS])?;
+
+    Ok(())
+}
+
+/// Minimal xorshift generator, so the property test is deterministic
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self, max: u128) -> u128 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 as u128 % max
+    }
+}
+
+#[test]
+fn round_trip_property() -> StdResult<()> {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+
+    for (supply_places, reserve_places) in [(6, 6), (6, 9), (9, 6), (18, 6)] {
+        let normalize = DecimalPlaces::new(supply_places, reserve_places);
+        let curves: Vec<Box<dyn Curve>> = vec![
+            Box::new(Exponential::new(
+                decimal(5u128, 1)?,
+                decimal(1u128, 1)?,
+                normalize,
+            )),
+            Box::new(Sigmoid::new(
+                decimal(3u128, 0)?,
+                decimal(40u128, 0)?,
+                decimal(2u128, 1)?,
+                normalize,
+            )),
+            Box::new(PiecewiseLinear::new(
+                vec![
+                    (decimal(0u128, 0)?, decimal(0u128, 0)?),
+                    (decimal(25u128, 0)?, decimal(1u128, 0)?),
+                    (decimal(60u128, 0)?, decimal(1u128, 0)?),
+                    (decimal(80u128, 0)?, decimal(4u128, 0)?),
+                ],
+                normalize,
+            )),
+        ];
+        // Supplies of up to 100 whole tokens
+        let max_supply = 100 * 10u128.pow(normalize.supply);
+
+        for curve in curves.iter() {
+            for _ in 0..200 {
+                let supply = Uint128::new(rng.next(max_supply));
+                let price = curve.spot_price(supply)?;
+                if price.is_zero() {
+                    continue;
+                }
+                let calculated_supply = curve.supply(curve.reserve(supply)?)?;
+
+                // Rounding the reserve down loses up to one unit of reserve,
+                // which is worth up to 1 / price whole supply tokens, and
+                // rounding the supply down loses up to one unit of supply
+                let price = decimal(price.atomics(), 18)?;
+                let lost = normalize.from_reserve(Uint128::one())? / price;
+                let tolerance = normalize.to_supply(lost * decimal(2u128, 0)?)?.u128() + 2;
+                let diff = calculated_supply.u128().abs_diff(supply.u128());
+                assert!(
+                    diff <= tolerance,
+                    "Supply mismatch for {:?}: original={}, calculated={}, tolerance={}",
+                    curve,
+                    supply,
+                    calculated_supply,
+                    tolerance
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -207,3 +207,151 @@ pub(crate) fn cube_root(cube: Decimal) -> StdResult<Decimal> {
         Ok(root_decimal)
     }
 }
+
+/// Euler's number, to the full precision of a Decimal.
+fn euler() -> Decimal {
+    Decimal::from_i128_with_scale(27_182_818_284_590_452_353_602_874_714, 28)
+}
+
+/// Calculates the square root of a Decimal to the full precision of a Decimal.
+///
+/// This function refines the estimate from [`square_root`] with Newton's method,
+/// for curves whose supply depends on the root of a value that may be small.
+///
+/// # Arguments
+///
+/// * `square` - The Decimal value for which to compute the square root.
+///
+/// # Returns
+///
+/// * `StdResult<Decimal>` - The square root of `square`, or an error if the calculation fails.
+pub(crate) fn precise_square_root(square: Decimal) -> StdResult<Decimal> {
+    // Start from the lower precision estimate, which is zero for very small inputs
+    let mut root = square_root(square)?;
+    if square.is_zero() {
+        return Ok(root);
+    }
+    if root.is_zero() {
+        root = Decimal::ONE;
+    }
+
+    // Newton's method converges quadratically, so this is reached only if
+    // the estimate oscillates in the last digit
+    const MAX_ITERATIONS: usize = 100;
+
+    for _ in 0..MAX_ITERATIONS {
+        let next = square
+            .checked_div(root)
+            .and_then(|q| q.checked_add(root))
+            .and_then(|s| s.checked_div(Decimal::TWO))
+            .ok_or_else(|| StdError::generic_err("precise_square_root: Overflow"))?;
+        if next == root {
+            break;
+        }
+        root = next;
+    }
+
+    Ok(root)
+}
+
+/// Calculates `e^x` for a Decimal.
+///
+/// The integer part of `x` is computed by repeated multiplication with `e`, and
+/// the fractional part with its Taylor series. Results too small to be
+/// represented by a Decimal are returned as zero.
+///
+/// # Arguments
+///
+/// * `x` - The exponent.
+///
+/// # Returns
+///
+/// * `StdResult<Decimal>` - `e^x`, or an error if the result overflows.
+pub(crate) fn exp(x: Decimal) -> StdResult<Decimal> {
+    if x.is_sign_negative() {
+        // e^-x = 1 / e^x, which rounds to zero if e^x overflows
+        return match exp(-x) {
+            Ok(inverse) => Decimal::ONE
+                .checked_div(inverse)
+                .ok_or_else(|| StdError::generic_err("exp: Division overflow")),
+            Err(_) => Ok(Decimal::ZERO),
+        };
+    }
+
+    let integer = x.trunc();
+    let fraction = x - integer;
+
+    // Taylor series of e^fraction, which converges quickly as fraction < 1
+    let mut term = Decimal::ONE;
+    let mut result = Decimal::ONE;
+    for n in 1u32..64 {
+        term = term
+            .checked_mul(fraction)
+            .and_then(|t| t.checked_div(Decimal::from(n)))
+            .ok_or_else(|| StdError::generic_err("exp: Overflow in series"))?;
+        if term.is_zero() {
+            break;
+        }
+        result += term;
+    }
+
+    let integer = integer
+        .to_u32()
+        .ok_or_else(|| StdError::generic_err("exp: Exponent too large"))?;
+    for _ in 0..integer {
+        result = result
+            .checked_mul(euler())
+            .ok_or_else(|| StdError::generic_err("exp: Overflow"))?;
+    }
+
+    Ok(result)
+}
+
+/// Calculates the natural logarithm of a Decimal.
+///
+/// The input is divided by `e` until it is less than `e`, and the logarithm of
+/// the remainder is computed with the series `ln(y) = 2 * atanh((y - 1) / (y + 1))`.
+///
+/// # Arguments
+///
+/// * `x` - The Decimal value for which to compute the logarithm, which must be positive.
+///
+/// # Returns
+///
+/// * `StdResult<Decimal>` - `ln(x)`, or an error if `x` is not positive.
+pub(crate) fn ln(x: Decimal) -> StdResult<Decimal> {
+    if x.is_sign_negative() || x.is_zero() {
+        return Err(StdError::generic_err("ln: Input must be positive"));
+    }
+
+    // ln(x) = -ln(1 / x)
+    if x < Decimal::ONE {
+        let inverse = Decimal::ONE
+            .checked_div(x)
+            .ok_or_else(|| StdError::generic_err("ln: Division overflow"))?;
+        return ln(inverse).map(|result| -result);
+    }
+
+    let e = euler();
+    let mut powers = 0u32;
+    let mut y = x;
+    while y >= e {
+        y /= e;
+        powers += 1;
+    }
+
+    let z = (y - Decimal::ONE) / (y + Decimal::ONE);
+    let z_squared = z * z;
+    let mut term = z;
+    let mut sum = z;
+    for n in 1u32..128 {
+        term *= z_squared;
+        let next = sum + term / Decimal::from(2 * n + 1);
+        if next == sum {
+            break;
+        }
+        sum = next;
+    }
+
+    Ok(Decimal::from(powers) + sum * Decimal::TWO)
+}
@@ -0,0 +1,20 @@
+use cw_orch::{interface, prelude::*};
+
+use dao_vote_delegation::contract::{execute, instantiate, migrate, query};
+use dao_vote_delegation::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+
+#[interface(InstantiateMsg, ExecuteMsg, QueryMsg, MigrateMsg)]
+pub struct DaoVoteDelegation;
+
+impl<Chain> Uploadable for DaoVoteDelegation<Chain> {
+    /// Return the path to the wasm file corresponding to the contract
+    fn wasm(_chain: &ChainInfoOwned) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path("dao_vote_delegation")
+            .unwrap()
+    }
+    /// Returns a CosmWasm contract wrapper
+    fn wrapper() -> Box<dyn MockContract<Empty>> {
+        Box::new(ContractWrapper::new_with_empty(execute, instantiate, query).with_migrate(migrate))
+    }
+}
@@ -0,0 +1,3 @@
+mod dao_vote_delegation;
+
+pub use dao_vote_delegation::DaoVoteDelegation;
@@ -0,0 +1,20 @@
+use cw_orch::{interface, prelude::*};
+
+use dao_merkle_distributor::contract::{execute, instantiate, query};
+use dao_merkle_distributor::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+
+#[interface(InstantiateMsg, ExecuteMsg, QueryMsg, Empty)]
+pub struct DaoMerkleDistributor;
+
+impl<Chain> Uploadable for DaoMerkleDistributor<Chain> {
+    /// Return the path to the wasm file corresponding to the contract
+    fn wasm(_chain: &ChainInfoOwned) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path("dao_merkle_distributor")
+            .unwrap()
+    }
+    /// Returns a CosmWasm contract wrapper
+    fn wrapper() -> Box<dyn MockContract<Empty>> {
+        Box::new(ContractWrapper::new_with_empty(execute, instantiate, query))
+    }
+}
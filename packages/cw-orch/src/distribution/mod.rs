@@ -1,5 +1,9 @@
 mod cw_fund_distributor;
+mod dao_merkle_distributor;
+mod dao_participation_rewards;
 mod dao_rewards_distributor;
 
 pub use cw_fund_distributor::DaoFundsDistributor;
+pub use dao_merkle_distributor::DaoMerkleDistributor;
+pub use dao_participation_rewards::DaoParticipationRewards;
 pub use dao_rewards_distributor::DaoRewardsDistributor;
@@ -5,6 +5,7 @@ mod cw_abc;
 mod cw_vesting;
 mod migrator;
 mod payroll_factory;
+mod ragequit;
 mod token_swap;
 mod tokenfactory_issuer;
 
@@ -15,5 +16,6 @@ pub use cw_abc::DaoExternalCwAbc;
 pub use cw_vesting::DaoExternalCwVesting;
 pub use migrator::DaoExternalMigrator;
 pub use payroll_factory::DaoExternalPayrollFactory;
+pub use ragequit::DaoExternalRagequit;
 pub use token_swap::DaoExternalTokenSwap;
 pub use tokenfactory_issuer::DaoExternalTokenfactoryIssuer;
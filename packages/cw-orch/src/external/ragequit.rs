@@ -0,0 +1,20 @@
+use cw_orch::{interface, prelude::*};
+
+use dao_ragequit::contract::{execute, instantiate, query};
+use dao_ragequit::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+
+#[interface(InstantiateMsg, ExecuteMsg, QueryMsg, Empty)]
+pub struct DaoExternalRagequit;
+
+impl<Chain> Uploadable for DaoExternalRagequit<Chain> {
+    /// Return the path to the wasm file corresponding to the contract
+    fn wasm(_chain: &ChainInfoOwned) -> WasmPath {
+        artifacts_dir_from_workspace!()
+            .find_wasm_path("dao_ragequit")
+            .unwrap()
+    }
+    /// Returns a CosmWasm contract wrapper
+    fn wrapper() -> Box<dyn MockContract<Empty>> {
+        Box::new(ContractWrapper::new_with_empty(execute, instantiate, query))
+    }
+}
@@ -1,9 +1,11 @@
 mod approval_single;
 mod approver;
 mod multiple;
+mod pipeline;
 mod single;
 
 pub use approval_single::DaoPreProposeApprovalSingle;
 pub use approver::DaoPreProposeApprover;
 pub use multiple::DaoPreProposeMultiple;
+pub use pipeline::DaoPreProposePipeline;
 pub use single::DaoPreProposeSingle;
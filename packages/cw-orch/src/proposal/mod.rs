@@ -1,7 +1,9 @@
 mod condorcet;
 mod multiple;
+mod optimistic;
 mod single;
 
 pub use condorcet::DaoProposalCondorcet;
 pub use multiple::DaoProposalMultiple;
+pub use optimistic::DaoProposalOptimistic;
 pub use single::DaoProposalSingle;
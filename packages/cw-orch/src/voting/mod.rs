@@ -3,9 +3,11 @@ mod cw4;
 mod cw721_roles;
 mod cw721_staked;
 mod token_staked;
+mod transform;
 
 pub use cw20_staked::DaoVotingCw20Staked;
 pub use cw4::DaoVotingCw4;
 pub use cw721_roles::DaoVotingCw721Roles;
 pub use cw721_staked::DaoVotingCw721Staked;
 pub use token_staked::DaoVotingTokenStaked;
+pub use transform::DaoVotingTransform;
@@ -0,0 +1,45 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Uint128};
+
+use crate::voting::VotingPowerAtHeightResponse;
+
+/// The subset of queries a vote delegation module must implement for
+/// proposal modules to apply delegated voting power and delegator
+/// overrides.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum Query {
+    /// Returns the total voting power delegated to a registered
+    /// delegate at a given height. Delegates that were not registered
+    /// at the height have no delegated voting power.
+    #[returns(VotingPowerAtHeightResponse)]
+    DelegatedVotingPower {
+        delegate: ::std::string::String,
+        height: ::std::option::Option<::std::primitive::u64>,
+    },
+    /// Returns the voting power a delegator had delegated to each of
+    /// their delegates at a given height. Only delegations to delegates
+    /// that were registered at the height are returned.
+    #[returns(DelegatedPowerResponse)]
+    DelegatedPowerAtHeight {
+        delegator: ::std::string::String,
+        height: ::std::option::Option<::std::primitive::u64>,
+        start_after: ::std::option::Option<::std::string::String>,
+        limit: ::std::option::Option<::std::primitive::u32>,
+    },
+}
+
+/// An amount of voting power delegated to a delegate.
+#[cw_serde]
+pub struct DelegatedPower {
+    /// The delegate receiving the voting power.
+    pub delegate: Addr,
+    /// The amount of voting power delegated.
+    pub power: Uint128,
+}
+
+#[cw_serde]
+pub struct DelegatedPowerResponse {
+    pub delegations: Vec<DelegatedPower>,
+    pub height: u64,
+}
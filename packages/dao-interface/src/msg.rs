@@ -2,7 +2,7 @@ use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{CosmosMsg, Empty};
 use cw_utils::Duration;
 
-use crate::state::Config;
+use crate::state::{Config, ModulePolicy};
 use crate::{migrate_msg::MigrateParams, query::SubDao, state::ModuleInstantiateInfo};
 
 /// Information about an item to be stored in the items list.
@@ -60,7 +60,12 @@ pub enum ExecuteMsg {
     ExecuteAdminMsgs { msgs: Vec<CosmosMsg<Empty>> },
     /// Callable by proposal modules. The DAO will execute the
     /// messages in the hook in order.
-    ExecuteProposalHook { msgs: Vec<CosmosMsg<Empty>> },
+    ExecuteProposalHook {
+        msgs: Vec<CosmosMsg<Empty>>,
+        /// The ID of the proposal being executed, if any. Outbound
+        /// transfers in the treasury ledger are tagged with it.
+        proposal_id: Option<u64>,
+    },
     /// Pauses the DAO for a set duration.
     /// When paused the DAO is unable to execute proposals
     Pause { duration: Duration },
@@ -127,6 +132,13 @@ pub enum ExecuteMsg {
     /// voting module with a new one instantiated by the governance
     /// contract.
     UpdateVotingModule { module: ModuleInstantiateInfo },
+    /// Callable by the core contract. Sets the policy limiting the
+    /// messages a proposal module may execute, or removes it if
+    /// `None`.
+    UpdateModulePolicy {
+        module: String,
+        policy: Option<ModulePolicy>,
+    },
     /// Update the core module to add/remove SubDAOs and their charters
     UpdateSubDaos {
         to_add: Vec<SubDao>,
@@ -211,6 +223,24 @@ pub enum QueryMsg {
     /// Gets the contract's voting module.
     #[returns(cosmwasm_std::Addr)]
     VotingModule {},
+    /// Gets the policy of a proposal module and how much it has
+    /// spent.
+    #[returns(crate::query::ModulePolicyResponse)]
+    ModulePolicy { module: String },
+    /// Lists entries in the DAO's treasury ledger, oldest first. If
+    /// `denom` is set, only entries for that native denom or cw20 or
+    /// cw721 contract are listed. `min_height` and `max_height` are
+    /// inclusive. Native tokens sent to the DAO with a plain bank
+    /// send are not recorded, nor are cw20 and cw721 tokens whose
+    /// contract is not in the DAO's token lists.
+    #[returns(Vec<crate::query::LedgerEntry>)]
+    TreasuryLedger {
+        denom: Option<String>,
+        min_height: Option<u64>,
+        max_height: Option<u64>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
     /// Returns all SubDAOs with their charters in a vec.
     /// start_after is bound exclusive and asks for a string address.
     #[returns(Vec<crate::query::SubDao>)]
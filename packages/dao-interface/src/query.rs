@@ -1,9 +1,9 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Timestamp, Uint128};
 use cw2::ContractVersion;
 use cw_utils::Expiration;
 
-use crate::state::{Config, ProposalModule};
+use crate::state::{Config, ModulePolicy, ProposalModule};
 
 /// Relevant state for the governance module. Returned by the
 /// `DumpState` query.
@@ -80,3 +80,74 @@ pub struct ProposalModuleCountResponse {
     /// The total number of proposal modules.
     pub total_proposal_module_count: u32,
 }
+
+/// Returned by the `ModulePolicy` query.
+#[cw_serde]
+pub struct ModulePolicyResponse {
+    /// The module's policy, or None if the module is unrestricted.
+    pub policy: Option<ModulePolicy>,
+    /// How much of each limited denom the module has spent in the
+    /// current period.
+    pub spending: Vec<ModuleSpending>,
+}
+
+/// The amount of a denom a proposal module has spent.
+#[cw_serde]
+pub struct ModuleSpending {
+    /// The native denom or cw20 token address spent.
+    pub denom: String,
+    /// The amount spent in the current period.
+    pub spent: Uint128,
+    /// When the current period ends.
+    pub resets: Expiration,
+}
+
+/// Whether a treasury ledger entry records assets entering or leaving
+/// the treasury.
+#[cw_serde]
+pub enum LedgerDirection {
+    Inbound,
+    Outbound,
+}
+
+/// An asset recorded in the treasury ledger.
+#[cw_serde]
+pub enum LedgerAsset {
+    Native { denom: String, amount: Uint128 },
+    Cw20 { address: String, amount: Uint128 },
+    Cw721 { address: String, token_id: String },
+}
+
+impl LedgerAsset {
+    /// The native denom or token contract address of the asset.
+    pub fn denom(&self) -> &str {
+        match self {
+            LedgerAsset::Native { denom, .. } => denom,
+            LedgerAsset::Cw20 { address, .. } | LedgerAsset::Cw721 { address, .. } => address,
+        }
+    }
+}
+
+/// An entry in the treasury ledger. Returned by the `TreasuryLedger`
+/// query.
+#[cw_serde]
+pub struct LedgerEntry {
+    /// The entry's ID. IDs increase in the order entries are
+    /// recorded.
+    pub id: u64,
+    /// The block height at which the entry was recorded.
+    pub height: u64,
+    /// The block time at which the entry was recorded.
+    pub time: Timestamp,
+    pub direction: LedgerDirection,
+    pub asset: LedgerAsset,
+    /// The sender of inbound assets or the recipient of outbound
+    /// ones. None if there is no recipient, as is the case for burns
+    /// and funds sent to newly instantiated contracts.
+    pub counterparty: Option<String>,
+    /// The proposal module whose proposal sent outbound assets.
+    pub module: Option<Addr>,
+    /// The proposal that sent outbound assets, if the proposal module
+    /// provided its ID.
+    pub proposal_id: Option<u64>,
+}
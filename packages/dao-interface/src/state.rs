@@ -1,5 +1,6 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, WasmMsg};
+use cosmwasm_std::{Addr, Binary, Coin, CosmosMsg, Uint128, WasmMsg};
+use cw_utils::Duration;
 
 /// Top level config type for core module.
 #[cw_serde]
@@ -40,6 +41,71 @@ pub enum ProposalModuleStatus {
     Disabled,
 }
 
+/// Limits on the messages a proposal module may have the core module
+/// execute. Set with `UpdateModulePolicy`.
+#[cw_serde]
+pub struct ModulePolicy {
+    /// The maximum amount of each denom the module may spend per
+    /// period. Denoms without a limit may be spent freely. Modules
+    /// with spend limits must have `allowed_contracts` set.
+    pub spend_limits: Vec<SpendLimit>,
+    /// If set, the only kinds of messages the module may execute.
+    pub allowed_msgs: Option<Vec<MsgKind>>,
+    /// If set, the only contracts the module may execute, migrate or
+    /// update the admin of. Instantiating contracts is not allowed.
+    pub allowed_contracts: Option<Vec<String>>,
+}
+
+/// A limit on how much of a denom a proposal module may spend.
+#[cw_serde]
+pub struct SpendLimit {
+    /// The native denom or cw20 token address the limit applies to.
+    pub denom: String,
+    /// The maximum amount that may be spent in a period.
+    pub amount: Uint128,
+    /// The length of a period. The first period begins when the
+    /// module first spends the denom, and the next once it ends.
+    pub period: Duration,
+}
+
+/// A kind of `CosmosMsg`.
+#[cw_serde]
+pub enum MsgKind {
+    Bank,
+    Wasm,
+    Stargate,
+    Ibc,
+    Gov,
+    /// Staking, distribution and custom messages.
+    Other,
+}
+
+impl MsgKind {
+    pub fn of<T>(msg: &CosmosMsg<T>) -> Self {
+        match msg {
+            CosmosMsg::Bank(_) => MsgKind::Bank,
+            CosmosMsg::Wasm(_) => MsgKind::Wasm,
+            CosmosMsg::Stargate { .. } => MsgKind::Stargate,
+            CosmosMsg::Ibc(_) => MsgKind::Ibc,
+            CosmosMsg::Gov(_) => MsgKind::Gov,
+            _ => MsgKind::Other,
+        }
+    }
+}
+
+impl std::fmt::Display for MsgKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MsgKind::Bank => write!(f, "bank"),
+            MsgKind::Wasm => write!(f, "wasm"),
+            MsgKind::Stargate => write!(f, "stargate"),
+            MsgKind::Ibc => write!(f, "ibc"),
+            MsgKind::Gov => write!(f, "gov"),
+            MsgKind::Other => write!(f, "other"),
+        }
+    }
+}
+
 /// Information about the CosmWasm level admin of a contract. Used in
 /// conjunction with `ModuleInstantiateInfo` to instantiate modules.
 #[cw_serde]
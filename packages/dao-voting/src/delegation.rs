@@ -0,0 +1,55 @@
+use cosmwasm_std::{Addr, Deps, StdResult, Uint128};
+use dao_interface::{
+    delegation::{self, DelegatedPower, DelegatedPowerResponse},
+    voting::VotingPowerAtHeightResponse,
+};
+
+/// The number of delegations requested per page when loading all of a
+/// delegator's delegations from a delegation module.
+const DELEGATIONS_PAGE_SIZE: u32 = 30;
+
+/// Queries the voting power delegated to `delegate` at `height` from
+/// a vote delegation module.
+pub fn get_delegated_voting_power(
+    deps: Deps,
+    delegation_module: &Addr,
+    delegate: &Addr,
+    height: u64,
+) -> StdResult<Uint128> {
+    let response: VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
+        delegation_module,
+        &delegation::Query::DelegatedVotingPower {
+            delegate: delegate.to_string(),
+            height: Some(height),
+        },
+    )?;
+    Ok(response.power)
+}
+
+/// Loads every delegation `delegator` had active at `height` from a
+/// vote delegation module, paging through the results.
+pub fn get_delegations_at_height(
+    deps: Deps,
+    delegation_module: &Addr,
+    delegator: &Addr,
+    height: u64,
+) -> StdResult<Vec<DelegatedPower>> {
+    let mut delegations: Vec<DelegatedPower> = vec![];
+    loop {
+        let start_after = delegations.last().map(|d| d.delegate.to_string());
+        let page: DelegatedPowerResponse = deps.querier.query_wasm_smart(
+            delegation_module,
+            &delegation::Query::DelegatedPowerAtHeight {
+                delegator: delegator.to_string(),
+                height: Some(height),
+                start_after,
+                limit: Some(DELEGATIONS_PAGE_SIZE),
+            },
+        )?;
+        let done = (page.delegations.len() as u32) < DELEGATIONS_PAGE_SIZE;
+        delegations.extend(page.delegations);
+        if done {
+            return Ok(delegations);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 
+pub mod delegation;
 pub mod deposit;
 pub mod duration;
 pub mod error;
@@ -7,6 +8,7 @@ pub mod multiple_choice;
 pub mod pre_propose;
 pub mod proposal;
 pub mod reply;
+pub mod schedule;
 pub mod status;
 pub mod threshold;
 pub mod veto;
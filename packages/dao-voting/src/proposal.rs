@@ -3,6 +3,7 @@ use cosmwasm_std::{CosmosMsg, Empty};
 
 use crate::{
     multiple_choice::{MultipleChoiceAutoVote, MultipleChoiceOptions},
+    schedule::ExecutionSchedule,
     voting::SingleChoiceAutoVote,
 };
 
@@ -36,8 +37,14 @@ pub struct SingleChoiceProposeMsg {
     /// pre-propose module is attached, this must be Some and will
     /// set the proposer of the proposal it creates.
     pub proposer: Option<String>,
-    /// An optional vote cast by the proposer.
+    /// An optional vote cast by the proposer. Proposal modules with
+    /// a review period reject this, as voting has not yet opened.
     pub vote: Option<SingleChoiceAutoVote>,
+    /// An optional schedule for executing the proposal's messages
+    /// once it passes. If None, the messages are executed
+    /// immediately when the proposal is executed.
+    #[serde(default)]
+    pub schedule: Option<ExecutionSchedule>,
 }
 
 /// The contents of a message to create a proposal in the multiple
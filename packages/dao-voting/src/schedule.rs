@@ -0,0 +1,194 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Timestamp;
+use thiserror::Error;
+
+/// The maximum number of times a scheduled proposal may be executed.
+pub const MAX_SCHEDULED_EXECUTIONS: u32 = 120;
+
+/// The maximum number of due executions of a scheduled proposal made
+/// at once. Any more that are due are left to be made by the next
+/// call.
+pub const MAX_EXECUTIONS_PER_CALL: u32 = 10;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ScheduleError {
+    #[error("an execution schedule must execute at least once")]
+    ZeroExecutions {},
+
+    #[error("an execution schedule may execute at most ({max}) times")]
+    TooManyExecutions { max: u32 },
+
+    #[error("the interval of a recurring execution schedule must be non-zero")]
+    ZeroInterval {},
+
+    #[error("the last execution of this schedule is too far in the future")]
+    Overflow {},
+}
+
+/// When the messages of a passed proposal should be executed. Once
+/// a scheduled proposal is executed, each execution that has come due
+/// may be triggered by anyone. Supported by `dao-proposal-single` and
+/// `dao-proposal-optimistic`, but not `dao-proposal-multiple`.
+#[cw_serde]
+pub enum ExecutionSchedule {
+    /// Execute the proposal's messages once, at or after `at`.
+    Once { at: Timestamp },
+    /// Execute the proposal's messages `times` times. The first
+    /// execution is due at `start`, and each following execution
+    /// `interval` seconds after the one before it.
+    Recurring {
+        start: Timestamp,
+        interval: u64,
+        times: u32,
+    },
+}
+
+impl ExecutionSchedule {
+    pub fn validate(&self) -> Result<(), ScheduleError> {
+        let times = self.times();
+        if times == 0 {
+            return Err(ScheduleError::ZeroExecutions {});
+        }
+        if times > MAX_SCHEDULED_EXECUTIONS {
+            return Err(ScheduleError::TooManyExecutions {
+                max: MAX_SCHEDULED_EXECUTIONS,
+            });
+        }
+        if let ExecutionSchedule::Recurring {
+            start, interval, ..
+        } = self
+        {
+            if *interval == 0 {
+                return Err(ScheduleError::ZeroInterval {});
+            }
+            // `Timestamp` stores nanoseconds, so the last execution
+            // must be representable in nanoseconds.
+            interval
+                .checked_mul(times as u64 - 1)
+                .and_then(|offset| start.seconds().checked_add(offset))
+                .and_then(|last| last.checked_mul(1_000_000_000))
+                .ok_or(ScheduleError::Overflow {})?;
+        }
+        Ok(())
+    }
+
+    /// The total number of executions in this schedule.
+    pub fn times(&self) -> u32 {
+        match self {
+            ExecutionSchedule::Once { .. } => 1,
+            ExecutionSchedule::Recurring { times, .. } => *times,
+        }
+    }
+
+    /// The time at which execution number `n` (starting from zero) is
+    /// due, or None if the schedule has fewer than `n + 1`
+    /// executions.
+    pub fn due_at(&self, n: u32) -> Option<Timestamp> {
+        match self {
+            ExecutionSchedule::Once { at } => (n == 0).then_some(*at),
+            ExecutionSchedule::Recurring {
+                start,
+                interval,
+                times,
+            } => (n < *times).then(|| start.plus_seconds(interval * n as u64)),
+        }
+    }
+
+    /// The number of executions that have come due as of `now`.
+    pub fn due_count(&self, now: &Timestamp) -> u32 {
+        match self {
+            ExecutionSchedule::Once { at } => (now >= at) as u32,
+            ExecutionSchedule::Recurring {
+                start,
+                interval,
+                times,
+            } => {
+                if now < start {
+                    0
+                } else {
+                    let elapsed = (now.seconds() - start.seconds()) / interval + 1;
+                    elapsed.min(*times as u64) as u32
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule_validation() {
+        let s = ExecutionSchedule::Recurring {
+            start: Timestamp::from_seconds(0),
+            interval: 10,
+            times: 0,
+        };
+        assert_eq!(s.validate().unwrap_err(), ScheduleError::ZeroExecutions {});
+
+        let s = ExecutionSchedule::Recurring {
+            start: Timestamp::from_seconds(0),
+            interval: 10,
+            times: MAX_SCHEDULED_EXECUTIONS + 1,
+        };
+        assert_eq!(
+            s.validate().unwrap_err(),
+            ScheduleError::TooManyExecutions {
+                max: MAX_SCHEDULED_EXECUTIONS
+            }
+        );
+
+        let s = ExecutionSchedule::Recurring {
+            start: Timestamp::from_seconds(0),
+            interval: 0,
+            times: 2,
+        };
+        assert_eq!(s.validate().unwrap_err(), ScheduleError::ZeroInterval {});
+
+        let s = ExecutionSchedule::Recurring {
+            start: Timestamp::from_seconds(0),
+            interval: u64::MAX / 2,
+            times: 3,
+        };
+        assert_eq!(s.validate().unwrap_err(), ScheduleError::Overflow {});
+
+        let s = ExecutionSchedule::Recurring {
+            start: Timestamp::from_seconds(0),
+            interval: 10,
+            times: MAX_SCHEDULED_EXECUTIONS,
+        };
+        s.validate().unwrap();
+
+        let s = ExecutionSchedule::Once {
+            at: Timestamp::from_seconds(100),
+        };
+        s.validate().unwrap();
+    }
+
+    #[test]
+    fn test_due_executions() {
+        let s = ExecutionSchedule::Once {
+            at: Timestamp::from_seconds(100),
+        };
+        assert_eq!(s.due_count(&Timestamp::from_seconds(99)), 0);
+        assert_eq!(s.due_count(&Timestamp::from_seconds(100)), 1);
+        assert_eq!(s.due_count(&Timestamp::from_seconds(1000)), 1);
+        assert_eq!(s.due_at(0), Some(Timestamp::from_seconds(100)));
+        assert_eq!(s.due_at(1), None);
+
+        let s = ExecutionSchedule::Recurring {
+            start: Timestamp::from_seconds(100),
+            interval: 10,
+            times: 3,
+        };
+        assert_eq!(s.due_count(&Timestamp::from_seconds(99)), 0);
+        assert_eq!(s.due_count(&Timestamp::from_seconds(100)), 1);
+        assert_eq!(s.due_count(&Timestamp::from_seconds(109)), 1);
+        assert_eq!(s.due_count(&Timestamp::from_seconds(110)), 2);
+        assert_eq!(s.due_count(&Timestamp::from_seconds(120)), 3);
+        assert_eq!(s.due_count(&Timestamp::from_seconds(1000)), 3);
+        assert_eq!(s.due_at(2), Some(Timestamp::from_seconds(120)));
+        assert_eq!(s.due_at(3), None);
+    }
+}
@@ -158,6 +158,14 @@ cd contracts/proposal/dao-proposal-condorcet
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"
 
+cd contracts/proposal/dao-proposal-optimistic
+cargo hack publish --no-dev-deps --allow-dirty
+cd "$START_DIR"
+
+cd contracts/pre-propose/dao-pre-propose-pipeline
+cargo hack publish --no-dev-deps --allow-dirty
+cd "$START_DIR"
+
 cd contracts/staking/cw20-stake
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"
@@ -196,6 +204,10 @@ cd contracts/voting/dao-voting-onft-staked
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"
 
+cd contracts/voting/dao-voting-transform
+cargo hack publish --no-dev-deps --allow-dirty
+cd "$START_DIR"
+
 cd contracts/dao-dao-core
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"
@@ -213,6 +225,14 @@ cd contracts/distribution/dao-rewards-distributor
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"
 
+cd contracts/distribution/dao-merkle-distributor
+cargo hack publish --no-dev-deps --allow-dirty
+cd "$START_DIR"
+
+cd contracts/distribution/dao-participation-rewards
+cargo hack publish --no-dev-deps --allow-dirty
+cd "$START_DIR"
+
 sleep 120
 
 cd contracts/external/btsg-ft-factory
@@ -223,6 +243,10 @@ cd contracts/external/dao-migrator
 cargo hack publish --no-dev-deps --allow-dirty
 cd "$START_DIR"
 
+cd contracts/external/dao-ragequit
+cargo hack publish --no-dev-deps --allow-dirty
+cd "$START_DIR"
+
 cd packages/dao-testing
 cargo publish
 cd "$START_DIR"
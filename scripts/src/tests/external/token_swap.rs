@@ -1,5 +1,5 @@
 use cw_orch::{anyhow, prelude::*};
-use cw_token_swap::msg::{Counterparty, InstantiateMsg, TokenInfo};
+use cw_token_swap::msg::{Counterparty, InstantiateMsg, Promise, TokenInfo};
 use dao_cw_orch::DaoExternalTokenSwap;
 
 fn _setup_tokenswap_helper(
@@ -9,20 +9,29 @@ fn _setup_tokenswap_helper(
 ) -> anyhow::Result<()> {
     app.instantiate(
         &InstantiateMsg {
-            counterparty_one: Counterparty {
-                address: sender,
-                promise: TokenInfo::Native {
-                    denom: "juno".to_string(),
-                    amount: 1_000u128.into(),
+            counterparties: vec![
+                Counterparty {
+                    address: sender.clone(),
+                    promises: vec![Promise {
+                        recipient: counterparty.clone(),
+                        token: TokenInfo::Native {
+                            denom: "juno".to_string(),
+                            amount: 1_000u128.into(),
+                        },
+                    }],
                 },
-            },
-            counterparty_two: Counterparty {
-                address: counterparty,
-                promise: TokenInfo::Native {
-                    denom: "juno".to_string(),
-                    amount: 1_000u128.into(),
+                Counterparty {
+                    address: counterparty,
+                    promises: vec![Promise {
+                        recipient: sender,
+                        token: TokenInfo::Native {
+                            denom: "juno".to_string(),
+                            amount: 1_000u128.into(),
+                        },
+                    }],
                 },
-            },
+            ],
+            deadline: None,
         },
         None,
         None,